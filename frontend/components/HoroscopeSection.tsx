@@ -9,7 +9,7 @@ import { useStore } from '@/store/useStore';
 import { AstroCard } from './AstroCard';
 import { LotteryCountdown } from './LotteryCountdown';
 import { AstroCard as AstroCardType } from '@/types';
-import { buildEnterLotteryInstruction } from '@/lib/hastrology_program';
+import { buildEnterLotteryInstruction, fetchEntryQuote, validateEntry } from '@/lib/hastrology_program';
 
 const PAYMENT_AMOUNT = 0.01; // SOL
 
@@ -130,8 +130,17 @@ export const HoroscopeSection: FC = () => {
             if (!isPaid) {
                 if (!sendTransaction) return;
 
+                // Surface the exact reason an entry would fail before asking for a signature
+                const entryError = await validateEntry(publicKey, connection);
+                if (entryError) {
+                    throw new Error(entryError);
+                }
+
+                // Cap the entry at the quoted price so it can't charge more than expected
+                const quote = await fetchEntryQuote(connection, publicKey);
+
                 setStatus('paying');
-                const instruction = await buildEnterLotteryInstruction(publicKey, connection);
+                const instruction = await buildEnterLotteryInstruction(publicKey, connection, undefined, undefined, quote?.price);
                 const transaction = new Transaction().add(instruction);
 
                 signature = await sendTransaction(transaction, connection);
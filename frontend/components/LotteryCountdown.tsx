@@ -4,7 +4,7 @@ import { FC, useState, useEffect } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { motion, AnimatePresence } from 'framer-motion';
 import BN from 'bn.js';
-import { fetchLotteryState, fetchUserTicket, LotteryState } from '@/lib/hastrology_program';
+import { fetchLotteryState, fetchPayoutPreview, fetchUserTicket, LotteryState, PayoutPreview } from '@/lib/hastrology_program';
 
 interface LotteryCountdownProps {
     onBack?: () => void;
@@ -20,6 +20,7 @@ export const LotteryCountdown: FC<LotteryCountdownProps> = ({ onBack }) => {
     const [result, setResult] = useState<'won' | 'lost' | 'pending' | null>(null);
     const [winnerAddress, setWinnerAddress] = useState<string | null>(null);
     const [prize, setPrize] = useState<string | null>(null);
+    const [preview, setPreview] = useState<PayoutPreview | null>(null);
 
     // Poll for lottery state
     useEffect(() => {
@@ -29,6 +30,11 @@ export const LotteryCountdown: FC<LotteryCountdownProps> = ({ onBack }) => {
                 if (lotteryState) {
                     setState(lotteryState);
 
+                    // Simulated with the connected wallet as fee payer; nothing is sent
+                    if (publicKey) {
+                        setPreview(await fetchPayoutPreview(connection, publicKey));
+                    }
+
                     // Logic to determine status
                     const now = Math.floor(Date.now() / 1000);
                     const end = lotteryState.lotteryEndtime.toNumber();
@@ -47,7 +53,7 @@ export const LotteryCountdown: FC<LotteryCountdownProps> = ({ onBack }) => {
         checkState();
         const interval = setInterval(checkState, 10000); // Check every 10s
         return () => clearInterval(interval);
-    }, [connection]);
+    }, [connection, publicKey]);
 
     // Countdown Timer
     useEffect(() => {
@@ -199,6 +205,7 @@ export const LotteryCountdown: FC<LotteryCountdownProps> = ({ onBack }) => {
                             <div className="mt-8 pt-6 border-t border-white/5">
                                 <p className="text-sm text-slate-500">
                                     Lottery ID: #{state.currentLotteryId.toString()} • Total Pot: {(Number(state.ticketPrice) * Number(state.totalParticipants) / 1e9).toFixed(2)} SOL
+                                    {preview && <> • Winner Prize: {(preview.winnerPrize.add(preview.prizeTopUp).toNumber() / 1e9).toFixed(4)} SOL</>}
                                 </p>
                             </div>
 
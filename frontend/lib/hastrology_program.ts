@@ -5,7 +5,15 @@
  * on Solana.
  */
 
-import { PublicKey, Connection, TransactionInstruction, SystemProgram } from '@solana/web3.js';
+import {
+    PublicKey,
+    Connection,
+    TransactionInstruction,
+    SystemProgram,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    TransactionMessage,
+    VersionedTransaction,
+} from '@solana/web3.js';
 import BN from 'bn.js';
 
 // New Program ID (deployed 2025-12-20)
@@ -16,10 +24,30 @@ export const LOTTERY_STATE_SEED = Buffer.from('lottery_state');
 export const POT_VAULT_SEED = Buffer.from('pot_vault');
 export const USER_RECEIPT_SEED = Buffer.from('user-receipt');
 export const USER_TICKET_SEED = Buffer.from('user-ticket');
+export const PARTICIPANT_INDEX_SEED = Buffer.from('participant_index');
+export const QUEUED_ENTRY_SEED = Buffer.from('queued-entry');
+export const REFERRAL_EPOCH_SEED = Buffer.from('referral_epoch');
+export const PLAYER_PROFILE_SEED = Buffer.from('player_profile');
+export const FEE_TREASURY_SEED = Buffer.from('fee_treasury');
+export const WINNER_RECORD_SEED = Buffer.from('winner_record');
+export const MEGA_JACKPOT_SEED = Buffer.from('mega_jackpot');
+export const UPCOMING_ROUND_SEED = Buffer.from('upcoming_round');
+export const DEVICE_ENTRIES_SEED = Buffer.from('device_entries');
+
+// Most free tickets an early-bird entry can receive (MAX_EARLY_BIRD_BONUS on-chain)
+const MAX_EARLY_BIRD_BONUS = 3;
 
 // Instruction discriminators from IDL
 // enter_lottery: [252, 72, 239, 78, 58, 56, 149, 231]
 const ENTER_LOTTERY_DISCRIMINATOR = Buffer.from([252, 72, 239, 78, 58, 56, 149, 231]);
+// enter_next_round: [220, 191, 41, 252, 87, 50, 102, 171]
+const ENTER_NEXT_ROUND_DISCRIMINATOR = Buffer.from([220, 191, 41, 252, 87, 50, 102, 171]);
+// preview_payout: [215, 111, 95, 8, 67, 251, 144, 85]
+const PREVIEW_PAYOUT_DISCRIMINATOR = Buffer.from([215, 111, 95, 8, 67, 251, 144, 85]);
+// validate_entry: [196, 30, 169, 245, 209, 148, 225, 169]
+const VALIDATE_ENTRY_DISCRIMINATOR = Buffer.from([196, 30, 169, 245, 209, 148, 225, 169]);
+// quote_entry: [73, 5, 87, 196, 147, 173, 37, 3]
+const QUOTE_ENTRY_DISCRIMINATOR = Buffer.from([73, 5, 87, 196, 147, 173, 37, 3]);
 
 /**
  * Derive the LotteryState PDA
@@ -37,9 +65,12 @@ export function getPotVaultPDA(): [PublicKey, number] {
 
 /**
  * Derive the UserEntryReceipt PDA for a specific user and lottery
+ *
+ * One receipt totals all of a user's entries in the round.
  */
 export function getUserReceiptPDA(userPubkey: PublicKey, lotteryId: BN): [PublicKey, number] {
     const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+
     return PublicKey.findProgramAddressSync(
         [USER_RECEIPT_SEED, userPubkey.toBuffer(), lotteryIdBuffer],
         PROGRAM_ID
@@ -51,13 +82,46 @@ export function getUserReceiptPDA(userPubkey: PublicKey, lotteryId: BN): [Public
  */
 export function getUserTicketPDA(lotteryId: BN, ticketNumber: BN): [PublicKey, number] {
     const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
-    const ticketNumberBuffer = ticketNumber.toArrayLike(Buffer, 'le', 8);
+    // Ticket indices are u32 on-chain
+    const ticketNumberBuffer = ticketNumber.toArrayLike(Buffer, 'le', 4);
     return PublicKey.findProgramAddressSync(
         [USER_TICKET_SEED, lotteryIdBuffer, ticketNumberBuffer],
         PROGRAM_ID
     );
 }
 
+/**
+ * Derive the ParticipantIndex PDA for a specific lottery
+ */
+export function getParticipantIndexPDA(lotteryId: BN): [PublicKey, number] {
+    const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+    return PublicKey.findProgramAddressSync(
+        [PARTICIPANT_INDEX_SEED, lotteryIdBuffer],
+        PROGRAM_ID
+    );
+}
+
+/**
+ * Derive the QueuedEntry PDA for a user's escrowed entry into a lottery
+ */
+export function getQueuedEntryPDA(userPubkey: PublicKey, lotteryId: BN): [PublicKey, number] {
+    const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+    return PublicKey.findProgramAddressSync(
+        [QUEUED_ENTRY_SEED, userPubkey.toBuffer(), lotteryIdBuffer],
+        PROGRAM_ID
+    );
+}
+
+/**
+ * Derive the PlayerProfile PDA holding a user's spending cap
+ */
+export function getPlayerProfilePDA(userPubkey: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [PLAYER_PROFILE_SEED, userPubkey.toBuffer()],
+        PROGRAM_ID
+    );
+}
+
 /**
  * LotteryState account structure
  */
@@ -157,12 +221,32 @@ export async function fetchLotteryState(connection: Connection): Promise<Lottery
  * 
  * This creates the instruction to enter the lottery, which:
  * 1. Pays the ticket price (0.01 SOL) to the pot vault
- * 2. Creates a UserEntryReceipt PDA for this user + lottery
+ * 2. Creates or updates the UserEntryReceipt PDA for this user + lottery
  * 3. Creates a UserTicket PDA for tracking
+ * 4. Creates any early-bird bonus tickets the entry earns
+ *
+ * While a draw is in progress it builds enter_next_round instead, which
+ * escrows the ticket price until the entry is processed into the next round.
+ *
+ * Pass the same `entryNonce` when resending a dropped entry: if the first
+ * transaction landed after all, the retry fails instead of buying again.
+ * Nonces must increase within a round.
+ *
+ * `ticketClass` buys a star (0), moon (1) or sun (2) ticket when the round sells them.
+ *
+ * Pass the `price` from fetchEntryQuote as `maxPrice` so the entry fails rather than
+ * charging more than the user was shown.
+ *
+ * `deviceCommitment` is the 32-byte hash of a salt stored on the device; it is
+ * required while the lottery caps entries per device.
  */
 export async function buildEnterLotteryInstruction(
     userPubkey: PublicKey,
-    connection: Connection
+    connection: Connection,
+    entryNonce?: BN,
+    ticketClass?: number,
+    maxPrice?: BN,
+    deviceCommitment?: Uint8Array
 ): Promise<TransactionInstruction> {
     // Fetch lottery state to get current lottery ID and total participants
     const lotteryState = await fetchLotteryState(connection);
@@ -171,11 +255,24 @@ export async function buildEnterLotteryInstruction(
         throw new Error('Lottery not initialized');
     }
 
+    const [lotteryStatePDA] = getLotteryStatePDA();
+
+    // While the draw resolves, the ticket price is escrowed for the next round instead
     if (lotteryState.isDrawing) {
-        throw new Error('Lottery is currently drawing. Please try again later.');
+        const [queuedEntryPDA] = getQueuedEntryPDA(userPubkey, lotteryState.currentLotteryId.addn(1));
+
+        return new TransactionInstruction({
+            keys: [
+                { pubkey: userPubkey, isSigner: true, isWritable: true },
+                { pubkey: lotteryStatePDA, isSigner: false, isWritable: false },
+                { pubkey: queuedEntryPDA, isSigner: false, isWritable: true },
+                { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            ],
+            programId: PROGRAM_ID,
+            data: ENTER_NEXT_ROUND_DISCRIMINATOR,
+        });
     }
 
-    const [lotteryStatePDA] = getLotteryStatePDA();
     const [potVaultPDA] = getPotVaultPDA();
 
     // Derive user receipt PDA
@@ -184,10 +281,27 @@ export async function buildEnterLotteryInstruction(
     // Derive user ticket PDA (uses current total_participants as index)
     const [userTicketPDA] = getUserTicketPDA(lotteryState.currentLotteryId, lotteryState.totalParticipants);
 
+    // Derive participant index PDA (grown by one slot per entry)
+    const [participantIndexPDA] = getParticipantIndexPDA(lotteryState.currentLotteryId);
+
+    // Always passed so the user's spending cap is enforced; it need not exist yet
+    const [playerProfilePDA] = getPlayerProfilePDA(userPubkey);
+
+    // Passed whenever it exists, since a linked lottery rejects entries without it
+    const [megaJackpotPDA] = getMegaJackpotPDA();
+    const megaJackpotInfo = await connection.getAccountInfo(megaJackpotPDA);
+
     // Build the instruction
     // Anchor instruction format: [8-byte discriminator] + [serialized args]
-    // enter_lottery has no args, so just the discriminator
-    const data = ENTER_LOTTERY_DISCRIMINATOR;
+    // enter_lottery takes entry_nonce: Option<u64>, ticket_class: Option<u8>,
+    // max_price: Option<u64> and device_commitment: Option<[u8; 32]>, each a 0/1 tag then the value
+    const data = Buffer.concat([
+        ENTER_LOTTERY_DISCRIMINATOR,
+        entryNonce ? Buffer.concat([Buffer.from([1]), entryNonce.toArrayLike(Buffer, 'le', 8)]) : Buffer.from([0]),
+        ticketClass !== undefined ? Buffer.from([1, ticketClass]) : Buffer.from([0]),
+        maxPrice ? Buffer.concat([Buffer.from([1]), maxPrice.toArrayLike(Buffer, 'le', 8)]) : Buffer.from([0]),
+        deviceCommitment ? Buffer.concat([Buffer.from([1]), Buffer.from(deviceCommitment)]) : Buffer.from([0]),
+    ]);
 
     const keys = [
         { pubkey: userPubkey, isSigner: true, isWritable: true },
@@ -195,9 +309,33 @@ export async function buildEnterLotteryInstruction(
         { pubkey: potVaultPDA, isSigner: false, isWritable: true },
         { pubkey: userReceiptPDA, isSigner: false, isWritable: true },
         { pubkey: userTicketPDA, isSigner: false, isWritable: true },
+        { pubkey: participantIndexPDA, isSigner: false, isWritable: true },
+        // Optional eligibility token account, eligibility attestation, metrics and snipe window;
+        // the program id marks them as omitted
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        megaJackpotInfo
+            ? { pubkey: megaJackpotPDA, isSigner: false, isWritable: true }
+            : { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        deviceCommitment
+            ? { pubkey: getDeviceEntriesPDA(lotteryState.currentLotteryId, deviceCommitment)[0], isSigner: false, isWritable: true }
+            : { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: playerProfilePDA, isSigner: false, isWritable: true },
+        { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ];
 
+    // Early-bird bonus tickets take the next ticket indexes; the program only uses as many as it grants
+    for (let offset = 1; offset <= MAX_EARLY_BIRD_BONUS; offset++) {
+        const [bonusTicketPDA] = getUserTicketPDA(
+            lotteryState.currentLotteryId,
+            lotteryState.totalParticipants.addn(offset)
+        );
+        keys.push({ pubkey: bonusTicketPDA, isSigner: false, isWritable: true });
+    }
+
     return new TransactionInstruction({
         keys,
         programId: PROGRAM_ID,
@@ -205,6 +343,130 @@ export async function buildEnterLotteryInstruction(
     });
 }
 
+/**
+ * Entry price returned by quote_entry
+ */
+export interface EntryQuote {
+    lotteryId: BN;
+    price: BN;
+    quotedSlot: BN;
+    bonusTickets: number;
+}
+
+/**
+ * Simulate quote_entry for the price enter_lottery would charge right now.
+ * `feePayer` only needs to exist; nothing is signed or sent.
+ */
+export async function fetchEntryQuote(
+    connection: Connection,
+    feePayer: PublicKey,
+    ticketClass?: number
+): Promise<EntryQuote | null> {
+    const [lotteryStatePDA] = getLotteryStatePDA();
+
+    const instruction = new TransactionInstruction({
+        keys: [
+            { pubkey: lotteryStatePDA, isSigner: false, isWritable: false },
+        ],
+        programId: PROGRAM_ID,
+        data: Buffer.concat([
+            QUOTE_ENTRY_DISCRIMINATOR,
+            ticketClass !== undefined ? Buffer.from([1, ticketClass]) : Buffer.from([0]),
+        ]),
+    });
+
+    const { blockhash } = await connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+        payerKey: feePayer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message();
+
+    const simulation = await connection.simulateTransaction(new VersionedTransaction(message), {
+        sigVerify: false,
+    });
+    const returnData = simulation.value.returnData;
+
+    if (simulation.value.err || !returnData || returnData.programId !== PROGRAM_ID.toBase58()) {
+        return null;
+    }
+
+    // Return data has its trailing zero bytes trimmed, so pad back to three u64s and a u8
+    const data = Buffer.alloc(25);
+    Buffer.from(returnData.data[0], 'base64').copy(data);
+
+    return {
+        lotteryId: new BN(data.slice(0, 8), 'le'),
+        price: new BN(data.slice(8, 16), 'le'),
+        quotedSlot: new BN(data.slice(16, 24), 'le'),
+        bonusTickets: data.readUInt8(24),
+    };
+}
+
+/**
+ * Simulate validate_entry to find out whether enter_lottery would succeed
+ *
+ * Returns the program's error message, or null when the entry is valid. Entries
+ * made while a draw is in progress are queued instead and are not validated here.
+ */
+export async function validateEntry(
+    userPubkey: PublicKey,
+    connection: Connection
+): Promise<string | null> {
+    const lotteryState = await fetchLotteryState(connection);
+
+    if (!lotteryState) {
+        return 'Lottery not initialized';
+    }
+
+    if (lotteryState.isDrawing) {
+        return null;
+    }
+
+    const [lotteryStatePDA] = getLotteryStatePDA();
+    const [potVaultPDA] = getPotVaultPDA();
+    const [playerProfilePDA] = getPlayerProfilePDA(userPubkey);
+
+    const instruction = new TransactionInstruction({
+        keys: [
+            { pubkey: userPubkey, isSigner: true, isWritable: false },
+            { pubkey: lotteryStatePDA, isSigner: false, isWritable: false },
+            { pubkey: potVaultPDA, isSigner: false, isWritable: false },
+            // Optional eligibility token account, eligibility attestation and snipe window;
+            // the program id marks them as omitted
+            { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: playerProfilePDA, isSigner: false, isWritable: false },
+            { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+        ],
+        programId: PROGRAM_ID,
+        data: VALIDATE_ENTRY_DISCRIMINATOR,
+    });
+
+    const { blockhash } = await connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+        payerKey: userPubkey,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message();
+
+    const simulation = await connection.simulateTransaction(new VersionedTransaction(message), {
+        sigVerify: false,
+    });
+
+    if (!simulation.value.err) {
+        return null;
+    }
+
+    // Anchor logs "AnchorError ... Error Message: <msg>." for program errors
+    const anchorError = simulation.value.logs
+        ?.map((log) => log.match(/Error Message: (.*)$/)?.[1])
+        .find((message) => message !== undefined);
+
+    return anchorError ?? 'This entry would fail';
+}
+
 /**
  * UserTicket account structure
  */
@@ -267,6 +529,94 @@ export async function fetchUserTicket(
     return decodeUserTicket(accountInfo.data as Buffer);
 }
 
+/**
+ * Derive the WinnerRecord PDA for a wallet's win in a specific lottery
+ */
+export function getWinnerRecordPDA(userPubkey: PublicKey, lotteryId: BN): [PublicKey, number] {
+    const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+    return PublicKey.findProgramAddressSync(
+        [WINNER_RECORD_SEED, userPubkey.toBuffer(), lotteryIdBuffer],
+        PROGRAM_ID
+    );
+}
+
+/**
+ * WinnerRecord account structure
+ */
+export interface WinnerRecord {
+    wallet: PublicKey;
+    lotteryId: BN;
+    prizeLamports: BN;
+    winningTickets: number;
+    wonAt: BN;
+}
+
+/**
+ * Decode WinnerRecord from account data
+ */
+export function decodeWinnerRecord(data: Buffer): WinnerRecord {
+    let offset = 8; // Skip 8-byte discriminator
+
+    const wallet = new PublicKey(data.slice(offset, offset + 32));
+    offset += 32;
+
+    const lotteryId = new BN(data.slice(offset, offset + 8), 'le');
+    offset += 8;
+
+    const prizeLamports = new BN(data.slice(offset, offset + 8), 'le');
+    offset += 8;
+
+    const winningTickets = data.readUInt32LE(offset);
+    offset += 4;
+
+    const wonAt = new BN(data.slice(offset, offset + 8), 'le').fromTwos(64);
+
+    return {
+        wallet,
+        lotteryId,
+        prizeLamports,
+        winningTickets,
+        wonAt,
+    };
+}
+
+/**
+ * Fetch every round a wallet has won, oldest first
+ *
+ * Derives the wallet's WinnerRecord PDA for each round up to the current one
+ * and fetches them in batches, so no round needs to be scanned.
+ */
+export async function fetchWinHistory(
+    connection: Connection,
+    userPubkey: PublicKey
+): Promise<WinnerRecord[]> {
+    const lotteryState = await fetchLotteryState(connection);
+
+    if (!lotteryState) {
+        return [];
+    }
+
+    const addresses: PublicKey[] = [];
+    for (let id = 1; id <= lotteryState.currentLotteryId.toNumber(); id++) {
+        addresses.push(getWinnerRecordPDA(userPubkey, new BN(id))[0]);
+    }
+
+    const records: WinnerRecord[] = [];
+
+    // getMultipleAccountsInfo accepts at most 100 addresses per call
+    for (let start = 0; start < addresses.length; start += 100) {
+        const accounts = await connection.getMultipleAccountsInfo(addresses.slice(start, start + 100));
+
+        for (const account of accounts) {
+            if (account) {
+                records.push(decodeWinnerRecord(account.data as Buffer));
+            }
+        }
+    }
+
+    return records;
+}
+
 /**
  * Check if a user has already entered the current lottery
  */
@@ -285,3 +635,190 @@ export async function hasUserEnteredLottery(
 
     return accountInfo !== null;
 }
+
+/**
+ * Derive the ReferralEpoch PDA for a specific lottery
+ */
+export function getReferralEpochPDA(lotteryId: BN): [PublicKey, number] {
+    const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+    return PublicKey.findProgramAddressSync(
+        [REFERRAL_EPOCH_SEED, lotteryIdBuffer],
+        PROGRAM_ID
+    );
+}
+
+/**
+ * Derive the FeeTreasury PDA
+ */
+export function getFeeTreasuryPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([FEE_TREASURY_SEED], PROGRAM_ID);
+}
+
+/**
+ * Derive the MegaJackpot PDA
+ */
+export function getMegaJackpotPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync([MEGA_JACKPOT_SEED], PROGRAM_ID);
+}
+
+/**
+ * Derive the DeviceEntries PDA for a device commitment in a round
+ */
+export function getDeviceEntriesPDA(lotteryId: BN, deviceCommitment: Uint8Array): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [DEVICE_ENTRIES_SEED, lotteryId.toArrayLike(Buffer, 'le', 8), Buffer.from(deviceCommitment)],
+        PROGRAM_ID
+    );
+}
+
+/**
+ * Lamport-exact payout breakdown returned by preview_payout
+ */
+export interface PayoutPreview {
+    lotteryId: BN;
+    potLamports: BN;
+    platformFee: BN;
+    referralBonus: BN;
+    platformShare: BN;
+    winnerPrize: BN;
+    tokenPrizeAmount: BN;
+    rollover: BN;
+    prizeTopUp: BN;
+    prizeDust: BN;
+    insuranceCut: BN;
+}
+
+/**
+ * Simulate preview_payout and decode its return data. The program computes this with the
+ * same code payout uses, so the numbers match what the winner will receive.
+ * `feePayer` only needs to exist; nothing is signed or sent.
+ */
+export async function fetchPayoutPreview(
+    connection: Connection,
+    feePayer: PublicKey
+): Promise<PayoutPreview | null> {
+    const lotteryState = await fetchLotteryState(connection);
+
+    if (!lotteryState) {
+        return null;
+    }
+
+    const [lotteryStatePDA] = getLotteryStatePDA();
+    const [potVaultPDA] = getPotVaultPDA();
+    const [referralEpochPDA] = getReferralEpochPDA(lotteryState.currentLotteryId);
+    const referralEpoch = await connection.getAccountInfo(referralEpochPDA);
+    const [feeTreasuryPDA] = getFeeTreasuryPDA();
+    const feeTreasury = await connection.getAccountInfo(feeTreasuryPDA);
+
+    const instruction = new TransactionInstruction({
+        keys: [
+            { pubkey: lotteryStatePDA, isSigner: false, isWritable: false },
+            { pubkey: potVaultPDA, isSigner: false, isWritable: false },
+            // Optional referral epoch; the program id marks it as omitted
+            { pubkey: referralEpoch ? referralEpochPDA : PROGRAM_ID, isSigner: false, isWritable: false },
+            { pubkey: feeTreasury ? feeTreasuryPDA : PROGRAM_ID, isSigner: false, isWritable: false },
+        ],
+        programId: PROGRAM_ID,
+        data: PREVIEW_PAYOUT_DISCRIMINATOR,
+    });
+
+    const { blockhash } = await connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+        payerKey: feePayer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message();
+
+    const simulation = await connection.simulateTransaction(new VersionedTransaction(message), {
+        sigVerify: false,
+    });
+    const returnData = simulation.value.returnData;
+
+    if (simulation.value.err || !returnData || returnData.programId !== PROGRAM_ID.toBase58()) {
+        return null;
+    }
+
+    // Return data has its trailing zero bytes trimmed, so pad back to the 11 u64 fields
+    const data = Buffer.alloc(88);
+    Buffer.from(returnData.data[0], 'base64').copy(data);
+    const readU64 = (index: number) => new BN(data.slice(index * 8, index * 8 + 8), 'le');
+
+    return {
+        lotteryId: readU64(0),
+        potLamports: readU64(1),
+        platformFee: readU64(2),
+        referralBonus: readU64(3),
+        platformShare: readU64(4),
+        winnerPrize: readU64(5),
+        tokenPrizeAmount: readU64(6),
+        rollover: readU64(7),
+        prizeTopUp: readU64(8),
+        prizeDust: readU64(9),
+        insuranceCut: readU64(10),
+    };
+}
+
+/**
+ * Derive the UpcomingRound PDA announcing a specific lottery
+ */
+export function getUpcomingRoundPDA(lotteryId: BN): [PublicKey, number] {
+    const lotteryIdBuffer = lotteryId.toArrayLike(Buffer, 'le', 8);
+    return PublicKey.findProgramAddressSync([UPCOMING_ROUND_SEED, lotteryIdBuffer], PROGRAM_ID);
+}
+
+/**
+ * UpcomingRound account structure
+ */
+export interface UpcomingRound {
+    lotteryId: BN;
+    theme: string;
+    expectedPrizeSeed: BN;
+    opensAt: BN;
+}
+
+/**
+ * Decode UpcomingRound from account data
+ */
+export function decodeUpcomingRound(data: Buffer): UpcomingRound {
+    let offset = 8; // Skip 8-byte discriminator
+
+    const lotteryId = new BN(data.slice(offset, offset + 8), 'le');
+    offset += 8;
+
+    const themeLength = data.readUInt32LE(offset);
+    offset += 4;
+    const theme = data.slice(offset, offset + themeLength).toString('utf8');
+    offset += themeLength;
+
+    const expectedPrizeSeed = new BN(data.slice(offset, offset + 8), 'le');
+    offset += 8;
+
+    const opensAt = new BN(data.slice(offset, offset + 8), 'le').fromTwos(64);
+
+    return {
+        lotteryId,
+        theme,
+        expectedPrizeSeed,
+        opensAt,
+    };
+}
+
+/**
+ * Fetch the announcement of the round after the current one, for a countdown to its opening
+ */
+export async function fetchUpcomingRound(connection: Connection): Promise<UpcomingRound | null> {
+    const lotteryState = await fetchLotteryState(connection);
+
+    if (!lotteryState) {
+        return null;
+    }
+
+    const [upcomingRoundPDA] = getUpcomingRoundPDA(lotteryState.currentLotteryId.addn(1));
+    const accountInfo = await connection.getAccountInfo(upcomingRoundPDA);
+
+    if (!accountInfo) {
+        return null;
+    }
+
+    return decodeUpcomingRound(accountInfo.data as Buffer);
+}
@@ -0,0 +1,364 @@
+//! A `serde::Serializer` producing `serde_json::Value`, like `serde_json::to_value`, except
+//! that pubkeys come out as base58 and byte arrays as hex instead of arrays of numbers,
+//! and 128-bit integers as decimal strings.
+
+use serde::ser::{self, Serialize};
+use serde_json::{Map, Value};
+
+use crate::SchemaError;
+
+/// Renders a decoded account or event as JSON.
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<Value, SchemaError> {
+    value.serialize(JsonSerializer).map(Node::into_value)
+}
+
+// Bytes are kept apart from other numbers until their container ends, so a `[u8; N]` or
+// `Vec<u8>` can be told from a `[u32; N]`
+enum Node {
+    Byte(u8),
+    Bytes(Vec<u8>),
+    Value(Value)
+}
+
+impl Node {
+    fn into_value(self) -> Value {
+        match self {
+            Node::Byte(byte) => Value::from(byte),
+            Node::Bytes(bytes) => Value::String(bytes.iter().map(|byte| format!("{byte:02x}")).collect()),
+            Node::Value(value) => value,
+        }
+    }
+}
+
+fn tagged(variant: &str, value: Value) -> Node {
+    let mut map = Map::new();
+    map.insert(variant.to_owned(), value);
+    Node::Value(Value::Object(map))
+}
+
+struct JsonSerializer;
+
+impl ser::Serializer for JsonSerializer {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    type SerializeSeq = SeqCollector;
+    type SerializeTuple = SeqCollector;
+    type SerializeTupleStruct = SeqCollector;
+    type SerializeTupleVariant = VariantCollector<SeqCollector>;
+    type SerializeMap = MapCollector;
+    type SerializeStruct = MapCollector;
+    type SerializeStructVariant = VariantCollector<MapCollector>;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Bool(v)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::String(v.to_string())))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, SchemaError> {
+        Ok(Node::Byte(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::String(v.to_string())))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::from(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::String(v.to_string())))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::String(v.to_owned())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, SchemaError> {
+        Ok(Node::Bytes(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Null))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, SchemaError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Null))
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::String(variant.to_owned())))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, name: &'static str, value: &T) -> Result<Node, SchemaError> {
+        match (name, value.serialize(self)?) {
+            ("Pubkey", Node::Bytes(bytes)) => Ok(Node::Value(Value::String(bs58::encode(bytes).into_string()))),
+            (_, node) => Ok(node),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T
+    ) -> Result<Node, SchemaError> {
+        Ok(tagged(variant, value.serialize(self)?.into_value()))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqCollector, SchemaError> {
+        Ok(SeqCollector { items: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqCollector, SchemaError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqCollector, SchemaError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize
+    ) -> Result<VariantCollector<SeqCollector>, SchemaError> {
+        Ok(VariantCollector { variant, inner: self.serialize_seq(Some(len))? })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapCollector, SchemaError> {
+        Ok(MapCollector { map: Map::new(), next_key: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapCollector, SchemaError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize
+    ) -> Result<VariantCollector<MapCollector>, SchemaError> {
+        Ok(VariantCollector { variant, inner: self.serialize_map(Some(len))? })
+    }
+}
+
+struct SeqCollector {
+    items: Vec<Node>
+}
+
+impl SeqCollector {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.items.push(value.serialize(JsonSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Node {
+        let is_bytes = !self.items.is_empty() && self.items.iter().all(|item| matches!(item, Node::Byte(_)));
+
+        if is_bytes {
+            Node::Bytes(self.items.into_iter().map(|item| match item {
+                Node::Byte(byte) => byte,
+                _ => unreachable!(),
+            }).collect())
+        } else {
+            Node::Value(Value::Array(self.items.into_iter().map(Node::into_value).collect()))
+        }
+    }
+}
+
+impl ser::SerializeSeq for SeqCollector {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SeqCollector {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqCollector {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(self.finish())
+    }
+}
+
+struct MapCollector {
+    map: Map<String, Value>,
+    next_key: Option<String>
+}
+
+impl ser::SerializeMap for MapCollector {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SchemaError> {
+        self.next_key = Some(match key.serialize(JsonSerializer)?.into_value() {
+            Value::String(key) => key,
+            key => key.to_string(),
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        let key = self.next_key.take().ok_or_else(|| SchemaError::Json("map value without a key".into()))?;
+        self.map.insert(key, value.serialize(JsonSerializer)?.into_value());
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Object(self.map)))
+    }
+}
+
+impl ser::SerializeStruct for MapCollector {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), SchemaError> {
+        self.map.insert(key.to_owned(), value.serialize(JsonSerializer)?.into_value());
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(Node::Value(Value::Object(self.map)))
+    }
+}
+
+struct VariantCollector<C> {
+    variant: &'static str,
+    inner: C
+}
+
+impl ser::SerializeTupleVariant for VariantCollector<SeqCollector> {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SchemaError> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(tagged(self.variant, self.inner.finish().into_value()))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantCollector<MapCollector> {
+    type Ok = Node;
+    type Error = SchemaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), SchemaError> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Node, SchemaError> {
+        Ok(tagged(self.variant, Value::Object(self.inner.map)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use serde::Serialize;
+    use serde_json::json;
+
+    use super::to_json;
+
+    #[derive(Serialize)]
+    struct Sample {
+        owner: Pubkey,
+        root: [u8; 4],
+        counts: [u32; 2],
+        maybe: Option<u64>,
+        total: u128,
+    }
+
+    #[test]
+    fn renders_pubkeys_and_bytes_as_strings() {
+        let owner = Pubkey::new_from_array([1u8; 32]);
+        let sample = Sample { owner, root: [0xde, 0xad, 0xbe, 0xef], counts: [1, 2], maybe: None, total: u128::MAX };
+
+        assert_eq!(
+            to_json(&sample).unwrap(),
+            json!({
+                "owner": owner.to_string(),
+                "root": "deadbeef",
+                "counts": [1, 2],
+                "maybe": null,
+                "total": u128::MAX.to_string(),
+            })
+        );
+    }
+}
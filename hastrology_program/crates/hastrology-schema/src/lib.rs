@@ -0,0 +1,204 @@
+//! Decodes Hastrology accounts and events for off-chain services.
+//!
+//! The types are the program's own, built with its `serde` feature, so layouts live in one
+//! place and a service picks up account changes on its next build instead of maintaining
+//! byte offsets. [`to_json`] renders anything decoded here for HTTP responses.
+
+pub mod json;
+
+use std::fmt;
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hastrology_program::{
+    events,
+    state::{self, AuditEntry, AuditLog, MerkleEntryTree, PackedTicket, TicketPage, AUDIT_LOG_CAPACITY}
+};
+use serde::Serialize;
+
+pub use json::to_json;
+
+const EVENT_LOG_PREFIX: &str = "Program data: ";
+
+#[derive(Debug)]
+pub enum SchemaError {
+    UnknownDiscriminator([u8; 8]),
+    Decode(String),
+    Json(String),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::UnknownDiscriminator(discriminator) => {
+                write!(f, "no Hastrology account or event has discriminator {discriminator:?}")
+            }
+            SchemaError::Decode(err) => write!(f, "failed to decode: {err}"),
+            SchemaError::Json(err) => write!(f, "failed to render JSON: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl serde::ser::Error for SchemaError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        SchemaError::Json(msg.to_string())
+    }
+}
+
+/// `AuditLog` with its ring buffer unrolled, oldest entry first.
+#[derive(Serialize)]
+pub struct AuditLogView {
+    pub next_index: u64,
+    pub total_entries: u64,
+    pub entries: Vec<AuditEntry>,
+}
+
+impl From<&AuditLog> for AuditLogView {
+    fn from(log: &AuditLog) -> Self {
+        let filled = log.total_entries.min(AUDIT_LOG_CAPACITY as u64) as usize;
+        // Until the buffer wraps the oldest entry is at 0; after, it's the next one overwritten
+        let start = if filled == AUDIT_LOG_CAPACITY { log.next_index as usize } else { 0 };
+
+        AuditLogView {
+            next_index: log.next_index,
+            total_entries: log.total_entries,
+            entries: (0..filled).map(|i| log.entries[(start + i) % AUDIT_LOG_CAPACITY]).collect(),
+        }
+    }
+}
+
+/// `TicketPage` with only its `count` filled entries.
+#[derive(Serialize)]
+pub struct TicketPageView {
+    pub lottery_id: u64,
+    pub page: u32,
+    pub count: u32,
+    pub entries: Vec<PackedTicket>,
+}
+
+impl From<&TicketPage> for TicketPageView {
+    fn from(page: &TicketPage) -> Self {
+        let count = (page.count as usize).min(page.entries.len());
+
+        TicketPageView {
+            lottery_id: page.lottery_id,
+            page: page.page,
+            count: page.count,
+            entries: page.entries[..count].to_vec(),
+        }
+    }
+}
+
+macro_rules! program_accounts {
+    ($($name:ident),* $(,)?) => {
+        /// Any account owned by the program. Serializes as `{"type": <name>, "data": {...}}`.
+        #[derive(Serialize)]
+        #[serde(tag = "type", content = "data")]
+        pub enum ProgramAccount {
+            $($name(Box<state::$name>),)*
+            MerkleEntryTree(Box<MerkleEntryTree>),
+            AuditLog(AuditLogView),
+            TicketPage(TicketPageView),
+        }
+
+        /// Decodes account data as returned by RPC, discriminator included.
+        pub fn decode_account(data: &[u8]) -> Result<ProgramAccount, SchemaError> {
+            $(
+                if data.starts_with(state::$name::DISCRIMINATOR) {
+                    return borsh_body(data).map(|account| ProgramAccount::$name(Box::new(account)));
+                }
+            )*
+
+            if data.starts_with(MerkleEntryTree::DISCRIMINATOR) {
+                return pod_body(data).map(|tree| ProgramAccount::MerkleEntryTree(Box::new(tree)));
+            }
+            if data.starts_with(AuditLog::DISCRIMINATOR) {
+                return pod_body::<AuditLog>(data).map(|log| ProgramAccount::AuditLog(AuditLogView::from(&log)));
+            }
+            if data.starts_with(TicketPage::DISCRIMINATOR) {
+                return pod_body::<TicketPage>(data).map(|page| ProgramAccount::TicketPage(TicketPageView::from(&page)));
+            }
+
+            Err(SchemaError::UnknownDiscriminator(discriminator(data)))
+        }
+    };
+}
+
+macro_rules! program_events {
+    ($($name:ident),* $(,)?) => {
+        /// Any event the program emits. Serializes as `{"type": <name>, "data": {...}}`.
+        #[derive(Serialize)]
+        #[serde(tag = "type", content = "data")]
+        pub enum ProgramEvent {
+            $($name(events::$name),)*
+        }
+
+        /// Decodes an event's bytes, discriminator included.
+        pub fn decode_event(data: &[u8]) -> Result<ProgramEvent, SchemaError> {
+            $(
+                if data.starts_with(events::$name::DISCRIMINATOR) {
+                    return borsh_body(data).map(ProgramEvent::$name);
+                }
+            )*
+
+            Err(SchemaError::UnknownDiscriminator(discriminator(data)))
+        }
+    };
+}
+
+program_accounts!(
+    AirdropSnapshot, ClaimBitmap, Compensation, ConditionalEntry, DeviceEntries,
+    DrawAttestation, EntryPool, FeeTreasury, HistoryArchive, HostedLottery,
+    InsuranceReserve, LotteryState, LottoPick, LottoRound, LuckySignPick, LuckySignRound,
+    MegaJackpot, Metrics, NftPrize, NoLossDeposit, ParticipantIndex, PendingKycEntry,
+    PlatformConfig, PlayerProfile, PoolMember, PotVault, PrizeSwap, QueuedEntry,
+    ReferralEpoch, ReferralStats, Registry, RoundHistory, SeasonWinners, SessionAuthority,
+    SnipeWindow, UpcomingRound, UserEntryReceipt, UserTicket, WinnerRecord,
+);
+
+program_events!(
+    AirdropSnapshotted, ClassPrizePaid, ClassWinnerLocated, CompensationPaid,
+    CompensationProposed, ConfigApplied, ConfigProposed, DrawNonceRevealed,
+    DrawNonceWithheld, FeesForwarded, HistoryArchived, KeeperRewarded, LosingTicketsClosed,
+    LotterySoldOut, MegaJackpotPaid, MerkleEntryAppended, NftPrizeAttached, NftPrizeReleased,
+    PrizeFloorRollover, ProgramSunset, RoundAnnounced, RoundOpened, RoundPaidOut,
+    RoundReopened, StakeShortfall, SunsetEntryRefunded, TicketPurchased, VaultReconciled,
+    WinnerRevealed,
+);
+
+/// Decodes a `Program data: <base64>` line from a transaction's log messages. Returns `None`
+/// for other lines; lines emitted by other programs in the transaction fail with
+/// `UnknownDiscriminator`.
+pub fn decode_event_log(line: &str) -> Option<Result<ProgramEvent, SchemaError>> {
+    let encoded = line.strip_prefix(EVENT_LOG_PREFIX)?;
+
+    Some(
+        STANDARD
+            .decode(encoded)
+            .map_err(|err| SchemaError::Decode(err.to_string()))
+            .and_then(|data| decode_event(&data))
+    )
+}
+
+fn borsh_body<T: AnchorDeserialize>(data: &[u8]) -> Result<T, SchemaError> {
+    // Accounts grown by realloc may carry trailing zeroes, so the body isn't read to the end
+    T::deserialize(&mut &data[8..]).map_err(|err| SchemaError::Decode(err.to_string()))
+}
+
+fn pod_body<T: bytemuck::Pod>(data: &[u8]) -> Result<T, SchemaError> {
+    let end = 8 + std::mem::size_of::<T>();
+    let body = data
+        .get(8..end)
+        .ok_or_else(|| SchemaError::Decode(format!("account data is shorter than {end} bytes")))?;
+
+    Ok(bytemuck::pod_read_unaligned(body))
+}
+
+fn discriminator(data: &[u8]) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    let len = data.len().min(8);
+    discriminator[..len].copy_from_slice(&data[..len]);
+    discriminator
+}
@@ -0,0 +1,126 @@
+//! Replays a round's draw with the selection code the program runs in `resolve_draw`
+//! and checks it against the winner recorded in `RoundHistory`.
+//!
+//! Usage: `hastrology-verify <lottery_id> [--rpc <url>]`
+
+use std::process::ExitCode;
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use hastrology_program::{
+    constants::{LOTTO_MAX_NUMBER, LOTTO_PICK_COUNT, PARTICIPANT_INDEX_SEED, ROUND_HISTORY_SEED},
+    state::{ParticipantIndex, RoundHistory, RoundKind},
+    VrfRandomness, ID
+};
+use lottery_core::selection::{select_numbers, select_sign, select_ticket_attempt};
+use solana_client::rpc_client::RpcClient;
+
+const DEFAULT_RPC_URL: &str = "https://api.devnet.solana.com";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut args = std::env::args().skip(1);
+    let mut lottery_id = None;
+    let mut rpc_url = DEFAULT_RPC_URL.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rpc" => rpc_url = args.next().ok_or("--rpc needs a url")?,
+            _ => lottery_id = Some(arg.parse::<u64>().map_err(|_| format!("invalid lottery id `{arg}`"))?),
+        }
+    }
+
+    let lottery_id = lottery_id.ok_or("usage: hastrology-verify <lottery_id> [--rpc <url>]")?;
+    let client = RpcClient::new(rpc_url);
+
+    let history: RoundHistory = fetch(&client, &[ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()])?;
+
+    println!("Lottery #{lottery_id}");
+    println!("  draw request    {}", history.draw_request_id);
+    println!("  vrf signer      {}", history.vrf_signer);
+    println!("  resolve slot    {}", history.resolve_slot);
+    println!("  randomness      {}", hex(&history.randomness));
+    println!("  participants    {}", history.total_participants);
+    println!("  recorded winner {}", history.winner);
+    println!("  season rerolls  {}", history.winner_rerolls);
+
+    if history.entropy_mixed {
+        println!("  slot hash       {}", hex(&history.entropy_blockhash));
+        println!("  operator nonce  {}", hex(&history.entropy_nonce));
+    }
+
+    if history.resolve_slot == 0 {
+        return Err("draw has not been resolved yet".into());
+    }
+
+    let Some(draw_randomness) = history.draw_randomness() else {
+        println!("PENDING: waiting for the operator nonce");
+        return Ok(true);
+    };
+
+    if history.entropy_mixed {
+        println!("  mixed           {}", hex(&draw_randomness));
+    }
+
+    let randomness = VrfRandomness(draw_randomness);
+
+    let expected = match history.round_kind {
+        RoundKind::Standard | RoundKind::Raffle { .. } => {
+            // Re-rolls past earlier season winners depend on the season ledger at draw time,
+            // so the recorded re-roll count is replayed rather than recomputed
+            select_ticket_attempt(&randomness, history.total_participants, history.winner_rerolls).unwrap_or(0)
+        }
+        RoundKind::LuckySign => {
+            select_sign(&randomness, history.total_participants).map_or(0, |sign| sign as u64 + 1)
+        }
+        RoundKind::LuckyNumbers => {
+            select_numbers(&randomness, history.total_participants, LOTTO_PICK_COUNT as u8, LOTTO_MAX_NUMBER).unwrap_or(0)
+        }
+        RoundKind::NoLoss => {
+            println!("  no-loss rounds draw over deposit weight, which history does not record; skipped");
+            return Ok(true);
+        }
+    };
+
+    println!("  replayed winner {expected}");
+
+    if matches!(history.round_kind, RoundKind::Standard | RoundKind::Raffle { .. }) && expected > 0 {
+        let index: ParticipantIndex = fetch(&client, &[PARTICIPANT_INDEX_SEED, &lottery_id.to_le_bytes()])?;
+        match index.participants.get(expected as usize - 1) {
+            Some(wallet) => println!("  winning wallet  {wallet}"),
+            None => println!("  winning wallet  missing from participant index"),
+        }
+    }
+
+    if history.winner == 0 && expected > 0 {
+        println!("PENDING: winner is committed but not revealed yet");
+        return Ok(true);
+    }
+
+    let matches = history.winner == expected;
+    println!("{}", if matches { "MATCH" } else { "MISMATCH" });
+
+    Ok(matches)
+}
+
+fn fetch<T: AccountDeserialize>(client: &RpcClient, seeds: &[&[u8]]) -> Result<T, String> {
+    let (address, _) = Pubkey::find_program_address(seeds, &ID);
+    let data = client
+        .get_account_data(&address)
+        .map_err(|err| format!("failed to fetch {address}: {err}"))?;
+
+    T::try_deserialize(&mut data.as_slice()).map_err(|err| format!("failed to decode {address}: {err}"))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,34 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LotteryError {
+    Overflow,
+    InsufficientFunds,
+    InvalidBasisPoints,
+    RoundOver,
+    RoundIsFull,
+    PotCapReached,
+    RoundIsDrawing,
+    DrawNotRequested,
+    NoWinner,
+}
+
+impl fmt::Display for LotteryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            LotteryError::Overflow => "arithmetic overflow",
+            LotteryError::InsufficientFunds => "insufficient funds",
+            LotteryError::InvalidBasisPoints => "invalid basis points",
+            LotteryError::RoundOver => "round is over",
+            LotteryError::RoundIsFull => "round is full",
+            LotteryError::PotCapReached => "pot cap reached",
+            LotteryError::RoundIsDrawing => "round is drawing",
+            LotteryError::DrawNotRequested => "draw not requested",
+            LotteryError::NoWinner => "round has no winner",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for LotteryError {}
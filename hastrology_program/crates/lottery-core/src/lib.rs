@@ -0,0 +1,15 @@
+//! Round lifecycle, winner selection and prize math shared by the Hastrology program.
+//!
+//! Nothing here knows about Solana. Payment rails and randomness come in through the
+//! [`PaymentBackend`] and [`RandomnessSource`] traits so the same engine can be embedded
+//! behind other adapters.
+
+pub mod error;
+pub mod math;
+pub mod round;
+pub mod selection;
+pub mod traits;
+
+pub use error::LotteryError;
+pub use round::{Round, Settlement};
+pub use traits::{PaymentBackend, RandomnessSource};
@@ -0,0 +1,261 @@
+use crate::LotteryError;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// `bps` basis points of `amount`, rounded down. Uses a u128 intermediate so large pots cannot overflow.
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64, LotteryError> {
+    if bps as u64 > BPS_DENOMINATOR {
+        return Err(LotteryError::InvalidBasisPoints);
+    }
+
+    let share = (amount as u128) * (bps as u128) / (BPS_DENOMINATOR as u128);
+
+    Ok(share as u64)
+}
+
+/// Splits a pot into `(fee, prize)`; rounding dust stays with the prize.
+pub fn split_fee(pot: u64, fee_bps: u16) -> Result<(u64, u64), LotteryError> {
+    let fee = bps_of(pot, fee_bps)?;
+
+    Ok((fee, pot - fee))
+}
+
+/// Splits a prize across tiers given in basis points that must sum to 10_000.
+/// Rounding dust goes to the first tier so the parts always add up to `prize`.
+pub fn split_prize_tiers(prize: u64, tier_bps: &[u16]) -> Result<Vec<u64>, LotteryError> {
+    let total_bps: u64 = tier_bps.iter().map(|bps| *bps as u64).sum();
+
+    if tier_bps.is_empty() || total_bps != BPS_DENOMINATOR {
+        return Err(LotteryError::InvalidBasisPoints);
+    }
+
+    let mut parts = tier_bps
+        .iter()
+        .map(|bps| bps_of(prize, *bps))
+        .collect::<Result<Vec<u64>, LotteryError>>()?;
+
+    let distributed: u64 = parts.iter().sum();
+    parts[0] += prize - distributed;
+
+    Ok(parts)
+}
+
+/// Rounds `amount` down to a multiple of `granularity`, returning `(rounded, dust)`.
+/// A granularity of 0 or 1 leaves the amount as is.
+pub fn round_down(amount: u64, granularity: u64) -> (u64, u64) {
+    if granularity <= 1 {
+        return (amount, 0);
+    }
+
+    let dust = amount % granularity;
+
+    (amount - dust, dust)
+}
+
+/// Amount left in the pot for the next round after `distributed` has been paid out.
+pub fn rollover_amount(pot: u64, distributed: u64) -> Result<u64, LotteryError> {
+    pot.checked_sub(distributed).ok_or(LotteryError::InsufficientFunds)
+}
+
+/// Balance that is free to distribute once every reserved amount has been set aside.
+pub fn available_balance(balance: u64, reserves: &[u64]) -> u64 {
+    reserves.iter().fold(balance, |left, reserve| left.saturating_sub(*reserve))
+}
+
+/// Time-weighted stake of a no-loss deposit.
+pub fn time_weight(amount: u64, seconds: i64) -> Result<u128, LotteryError> {
+    if seconds <= 0 {
+        return Err(LotteryError::RoundOver);
+    }
+
+    (amount as u128)
+        .checked_mul(seconds as u128)
+        .ok_or(LotteryError::Overflow)
+}
+
+/// Uniform 0-based index in `0..count`, or `None` for an empty round.
+pub fn sample_index(random: u64, count: u64) -> Option<u64> {
+    if count == 0 {
+        return None;
+    }
+
+    Some(random % count)
+}
+
+/// Point in `0..total_weight` drawn from the first 16 bytes of the randomness.
+pub fn sample_weight(randomness: &[u8; 32], total_weight: u128) -> Option<u128> {
+    if total_weight == 0 {
+        return None;
+    }
+
+    let mut wide = [0u8; 16];
+    wide.copy_from_slice(&randomness[..16]);
+
+    Some(u128::from_le_bytes(wide) % total_weight)
+}
+
+/// Whether `target` falls in the half-open weight range `[start, start + weight)`.
+pub fn weight_contains(start: u128, weight: u128, target: u128) -> bool {
+    target >= start && target - start < weight
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bps_of_basic_shares() {
+        assert_eq!(bps_of(10_000, 250).unwrap(), 250);
+        assert_eq!(bps_of(1_000_000_000, 500).unwrap(), 50_000_000);
+        assert_eq!(bps_of(12_345, 0).unwrap(), 0);
+        assert_eq!(bps_of(12_345, 10_000).unwrap(), 12_345);
+    }
+
+    #[test]
+    fn bps_of_rounds_down() {
+        assert_eq!(bps_of(9_999, 1).unwrap(), 0);
+        assert_eq!(bps_of(19_999, 1).unwrap(), 1);
+        assert_eq!(bps_of(3, 3_333).unwrap(), 0);
+    }
+
+    #[test]
+    fn bps_of_uses_wide_intermediate() {
+        // u64::MAX * 10_000 overflows u64; the u128 intermediate must not
+        assert_eq!(bps_of(u64::MAX, 10_000).unwrap(), u64::MAX);
+        assert_eq!(bps_of(u64::MAX, 5_000).unwrap(), u64::MAX / 2);
+        assert_eq!(bps_of(u64::MAX, 1).unwrap(), u64::MAX / 10_000);
+    }
+
+    #[test]
+    fn bps_of_rejects_more_than_100_percent() {
+        assert!(bps_of(100, 10_001).is_err());
+        assert!(bps_of(0, u16::MAX).is_err());
+    }
+
+    #[test]
+    fn split_fee_conserves_pot() {
+        for pot in [0u64, 1, 7, 9_999, 10_000, 1_234_567_891, u64::MAX] {
+            for fee_bps in [0u16, 1, 250, 5_000, 9_999, 10_000] {
+                let (fee, prize) = split_fee(pot, fee_bps).unwrap();
+                assert_eq!(fee + prize, pot);
+                assert_eq!(fee, bps_of(pot, fee_bps).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn split_fee_dust_goes_to_prize() {
+        assert_eq!(split_fee(10_001, 100).unwrap(), (100, 9_901));
+    }
+
+    #[test]
+    fn round_down_conserves_amount() {
+        assert_eq!(round_down(1_234_567, 1_000), (1_234_000, 567));
+        assert_eq!(round_down(999, 1_000), (0, 999));
+        assert_eq!(round_down(5_000, 1_000), (5_000, 0));
+        assert_eq!(round_down(1_234_567, 0), (1_234_567, 0));
+        assert_eq!(round_down(u64::MAX, 1), (u64::MAX, 0));
+    }
+
+    #[test]
+    fn split_prize_tiers_even_split() {
+        assert_eq!(split_prize_tiers(1_000, &[5_000, 3_000, 2_000]).unwrap(), vec![500, 300, 200]);
+        assert_eq!(split_prize_tiers(1_000, &[10_000]).unwrap(), vec![1_000]);
+    }
+
+    #[test]
+    fn split_prize_tiers_dust_goes_to_first_tier() {
+        let parts = split_prize_tiers(100, &[3_333, 3_333, 3_334]).unwrap();
+        assert_eq!(parts, vec![34, 33, 33]);
+        assert_eq!(parts.iter().sum::<u64>(), 100);
+    }
+
+    #[test]
+    fn split_prize_tiers_conserves_large_prizes() {
+        let parts = split_prize_tiers(u64::MAX, &[7_000, 2_000, 1_000]).unwrap();
+        assert_eq!(parts.iter().map(|p| *p as u128).sum::<u128>(), u64::MAX as u128);
+    }
+
+    #[test]
+    fn split_prize_tiers_rejects_bad_tables() {
+        assert!(split_prize_tiers(100, &[]).is_err());
+        assert!(split_prize_tiers(100, &[5_000, 4_999]).is_err());
+        assert!(split_prize_tiers(100, &[5_000, 5_001]).is_err());
+        assert!(split_prize_tiers(100, &[10_001]).is_err());
+    }
+
+    #[test]
+    fn rollover_amount_subtracts_distributed() {
+        assert_eq!(rollover_amount(1_000, 900).unwrap(), 100);
+        assert_eq!(rollover_amount(1_000, 1_000).unwrap(), 0);
+        assert!(rollover_amount(1_000, 1_001).is_err());
+    }
+
+    #[test]
+    fn available_balance_saturates() {
+        assert_eq!(available_balance(1_000, &[]), 1_000);
+        assert_eq!(available_balance(1_000, &[100, 200]), 700);
+        assert_eq!(available_balance(1_000, &[600, 600]), 0);
+        assert_eq!(available_balance(0, &[1]), 0);
+    }
+
+    #[test]
+    fn time_weight_scales_with_amount_and_time() {
+        assert_eq!(time_weight(1_000, 60).unwrap(), 60_000);
+        assert_eq!(time_weight(u64::MAX, i64::MAX).unwrap(), u64::MAX as u128 * i64::MAX as u128);
+    }
+
+    #[test]
+    fn time_weight_rejects_non_positive_time() {
+        assert!(time_weight(1_000, 0).is_err());
+        assert!(time_weight(1_000, -5).is_err());
+    }
+
+    #[test]
+    fn sample_index_stays_in_range() {
+        assert_eq!(sample_index(42, 0), None);
+        assert_eq!(sample_index(u64::MAX, 1), Some(0));
+        for count in [1u64, 2, 3, 10, 250_000] {
+            for random in [0u64, 1, 12_345, u64::MAX - 1, u64::MAX] {
+                assert!(sample_index(random, count).unwrap() < count);
+            }
+        }
+    }
+
+    #[test]
+    fn sample_weight_uses_low_16_bytes() {
+        let mut randomness = [0xffu8; 32];
+        randomness[..16].copy_from_slice(&7u128.to_le_bytes());
+        assert_eq!(sample_weight(&randomness, 5), Some(2));
+        assert_eq!(sample_weight(&randomness, 0), None);
+        assert_eq!(sample_weight(&[0xff; 32], u128::MAX), Some(0));
+    }
+
+    #[test]
+    fn weight_contains_is_half_open() {
+        assert!(weight_contains(100, 50, 100));
+        assert!(weight_contains(100, 50, 149));
+        assert!(!weight_contains(100, 50, 150));
+        assert!(!weight_contains(100, 50, 99));
+        assert!(!weight_contains(100, 0, 100));
+    }
+
+    #[test]
+    fn weighted_ranges_partition_total_weight() {
+        let weights = [30u128, 0, 45, 25];
+        let mut start = 0u128;
+        let ranges: Vec<(u128, u128)> = weights
+            .iter()
+            .map(|weight| {
+                let range = (start, *weight);
+                start += weight;
+                range
+            })
+            .collect();
+
+        for target in 0..start {
+            let hits = ranges.iter().filter(|(s, w)| weight_contains(*s, *w, target)).count();
+            assert_eq!(hits, 1);
+        }
+    }
+}
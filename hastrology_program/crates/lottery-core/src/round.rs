@@ -0,0 +1,244 @@
+use crate::{
+    math::split_fee,
+    selection::select_ticket,
+    LotteryError, PaymentBackend, RandomnessSource
+};
+
+/// Accounting for a single round: entries, draw and settlement.
+/// Adapters persist these fields however their platform stores state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub lottery_id: u64,
+    pub ticket_price: u64,
+    pub max_tickets: u32,
+    pub max_pot: u64, // 0 means no cap
+    pub pot: u64,
+    pub total_participants: u64,
+    pub is_drawing: bool,
+    pub winner: u64, // 1-based ticket number, 0 when none was drawn
+}
+
+/// How a round's pot is divided at payout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settlement {
+    pub fee: u64,
+    pub prize: u64,
+}
+
+impl Round {
+    pub fn new(lottery_id: u64, ticket_price: u64, max_tickets: u32, max_pot: u64) -> Self {
+        Round {
+            lottery_id,
+            ticket_price,
+            max_tickets,
+            max_pot,
+            pot: 0,
+            total_participants: 0,
+            is_drawing: false,
+            winner: 0,
+        }
+    }
+
+    /// 1-based number the next ticket would get.
+    pub fn next_ticket_number(&self) -> Result<u32, LotteryError> {
+        if self.total_participants >= self.max_tickets as u64 {
+            return Err(LotteryError::RoundIsFull);
+        }
+
+        Ok(self.total_participants as u32 + 1)
+    }
+
+    /// Checks an entry of `amount` is allowed without recording it.
+    pub fn check_entry(&self, amount: u64) -> Result<u32, LotteryError> {
+        if self.is_drawing {
+            return Err(LotteryError::RoundIsDrawing);
+        }
+
+        if self.max_pot > 0 {
+            let pot_after_entry = self.pot.checked_add(amount).ok_or(LotteryError::Overflow)?;
+            if pot_after_entry > self.max_pot {
+                return Err(LotteryError::PotCapReached);
+            }
+        }
+
+        self.next_ticket_number()
+    }
+
+    /// Collects one ticket price from `payer` and returns the new ticket's number.
+    pub fn enter<P: PaymentBackend>(&mut self, backend: &mut P, payer: &P::Account) -> Result<u32, LotteryError> {
+        let ticket_number = self.check_entry(self.ticket_price)?;
+
+        backend.collect(payer, self.ticket_price)?;
+
+        self.pot = self.pot.checked_add(self.ticket_price).ok_or(LotteryError::Overflow)?;
+        self.total_participants = ticket_number as u64;
+
+        Ok(ticket_number)
+    }
+
+    pub fn begin_draw(&mut self) -> Result<(), LotteryError> {
+        if self.is_drawing {
+            return Err(LotteryError::RoundIsDrawing);
+        }
+
+        self.is_drawing = true;
+
+        Ok(())
+    }
+
+    /// Picks the winning ticket; returns 0 for an empty round.
+    pub fn resolve<R: RandomnessSource>(&mut self, randomness: &R) -> Result<u64, LotteryError> {
+        if !self.is_drawing {
+            return Err(LotteryError::DrawNotRequested);
+        }
+
+        self.winner = select_ticket(randomness, self.total_participants).unwrap_or(0);
+
+        Ok(self.winner)
+    }
+
+    pub fn settlement(&self, fee_bps: u16) -> Result<Settlement, LotteryError> {
+        let (fee, prize) = split_fee(self.pot, fee_bps)?;
+
+        Ok(Settlement { fee, prize })
+    }
+
+    /// Pays out the pot and rolls over to the next round.
+    pub fn settle<P: PaymentBackend>(
+        &mut self,
+        backend: &mut P,
+        fee_bps: u16,
+        winner: &P::Account,
+        platform: &P::Account
+    ) -> Result<Settlement, LotteryError> {
+        if !self.is_drawing {
+            return Err(LotteryError::DrawNotRequested);
+        }
+
+        if self.winner == 0 {
+            return Err(LotteryError::NoWinner);
+        }
+
+        let settlement = self.settlement(fee_bps)?;
+
+        backend.pay(platform, settlement.fee)?;
+        backend.pay(winner, settlement.prize)?;
+
+        self.lottery_id = self.lottery_id.checked_add(1).ok_or(LotteryError::Overflow)?;
+        self.pot = 0;
+        self.total_participants = 0;
+        self.is_drawing = false;
+        self.winner = 0;
+
+        Ok(settlement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Ledger {
+        balances: HashMap<&'static str, u64>,
+        pot: u64,
+    }
+
+    impl PaymentBackend for Ledger {
+        type Account = &'static str;
+
+        fn collect(&mut self, from: &Self::Account, amount: u64) -> Result<(), LotteryError> {
+            let balance = self.balances.entry(*from).or_default();
+            *balance = balance.checked_sub(amount).ok_or(LotteryError::InsufficientFunds)?;
+            self.pot += amount;
+            Ok(())
+        }
+
+        fn pay(&mut self, to: &Self::Account, amount: u64) -> Result<(), LotteryError> {
+            self.pot = self.pot.checked_sub(amount).ok_or(LotteryError::InsufficientFunds)?;
+            *self.balances.entry(*to).or_default() += amount;
+            Ok(())
+        }
+    }
+
+    struct Fixed(u64);
+
+    impl RandomnessSource for Fixed {
+        fn random_bytes(&self) -> [u8; 32] {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+            bytes
+        }
+    }
+
+    fn funded_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.balances.insert("alice", 1_000);
+        ledger.balances.insert("bob", 1_000);
+        ledger
+    }
+
+    #[test]
+    fn full_round_lifecycle() {
+        let mut ledger = funded_ledger();
+        let mut round = Round::new(1, 100, 10, 0);
+
+        assert_eq!(round.enter(&mut ledger, &"alice").unwrap(), 1);
+        assert_eq!(round.enter(&mut ledger, &"bob").unwrap(), 2);
+        assert_eq!(round.pot, 200);
+
+        round.begin_draw().unwrap();
+        assert_eq!(round.enter(&mut ledger, &"alice"), Err(LotteryError::RoundIsDrawing));
+
+        assert_eq!(round.resolve(&Fixed(3)).unwrap(), 2);
+
+        let settlement = round.settle(&mut ledger, 500, &"bob", &"platform").unwrap();
+        assert_eq!(settlement, Settlement { fee: 10, prize: 190 });
+        assert_eq!(ledger.balances["bob"], 1_090);
+        assert_eq!(ledger.balances["platform"], 10);
+        assert_eq!(ledger.pot, 0);
+        assert_eq!(round.lottery_id, 2);
+        assert_eq!(round.total_participants, 0);
+    }
+
+    #[test]
+    fn failed_payment_does_not_record_entry() {
+        let mut ledger = Ledger::default();
+        let mut round = Round::new(1, 100, 10, 0);
+
+        assert_eq!(round.enter(&mut ledger, &"carol"), Err(LotteryError::InsufficientFunds));
+        assert_eq!(round.total_participants, 0);
+        assert_eq!(round.pot, 0);
+    }
+
+    #[test]
+    fn entry_limits() {
+        let mut ledger = funded_ledger();
+
+        let mut capped = Round::new(1, 100, 10, 150);
+        capped.enter(&mut ledger, &"alice").unwrap();
+        assert_eq!(capped.enter(&mut ledger, &"bob"), Err(LotteryError::PotCapReached));
+
+        let mut full = Round::new(1, 100, 1, 0);
+        full.enter(&mut ledger, &"alice").unwrap();
+        assert_eq!(full.enter(&mut ledger, &"bob"), Err(LotteryError::RoundIsFull));
+    }
+
+    #[test]
+    fn empty_round_has_no_winner() {
+        let mut ledger = funded_ledger();
+        let mut round = Round::new(1, 100, 10, 0);
+
+        round.begin_draw().unwrap();
+        assert_eq!(round.resolve(&Fixed(42)).unwrap(), 0);
+        assert_eq!(round.settle(&mut ledger, 0, &"alice", &"platform"), Err(LotteryError::NoWinner));
+    }
+
+    #[test]
+    fn resolve_requires_draw() {
+        let mut round = Round::new(1, 100, 10, 0);
+        assert_eq!(round.resolve(&Fixed(0)), Err(LotteryError::DrawNotRequested));
+    }
+}
@@ -0,0 +1,280 @@
+//! Winner selection as run by `resolve_draw`. Kept here so off-chain verifiers can replay
+//! a draw from its recorded randomness with exactly the code the program executes.
+
+use crate::{
+    math::{sample_index, sample_weight},
+    RandomnessSource
+};
+
+/// Number of signs a lucky sign round draws from.
+pub const ZODIAC_SIGN_COUNT: usize = 12;
+
+/// Offsets of the 8-byte words re-rolls draw from, skipping the anti-snipe word.
+const REROLL_OFFSETS: [usize; 2] = [8, 24];
+
+/// Re-rolls available after the regular draw.
+pub const MAX_REROLLS: u8 = REROLL_OFFSETS.len() as u8;
+
+/// 1-based winning ticket number of a uniform draw, or `None` for an empty round.
+pub fn select_ticket<R: RandomnessSource>(randomness: &R, participants: u64) -> Option<u64> {
+    sample_index(randomness.random_u64(), participants).map(|index| index + 1)
+}
+
+/// Ticket of draw `attempt`: 0 is the regular draw, later attempts re-roll on unused
+/// randomness bytes. `None` for an empty round or once the bytes run out.
+pub fn select_ticket_attempt<R: RandomnessSource>(randomness: &R, participants: u64, attempt: u8) -> Option<u64> {
+    if attempt == 0 {
+        return select_ticket(randomness, participants);
+    }
+
+    let offset = *REROLL_OFFSETS.get(attempt as usize - 1)?;
+    let bytes = randomness.random_bytes();
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&bytes[offset..offset + 8]);
+
+    sample_index(u64::from_le_bytes(word), participants).map(|index| index + 1)
+}
+
+/// First drawn ticket that isn't excluded, with the number of re-rolls it took. When every
+/// attempt is excluded the last one is kept, so a non-empty round always has a winner.
+pub fn select_ticket_excluding<R, F>(randomness: &R, participants: u64, mut is_excluded: F) -> Option<(u64, u8)>
+where
+    R: RandomnessSource,
+    F: FnMut(u64) -> bool
+{
+    let mut last = None;
+
+    for attempt in 0..=MAX_REROLLS {
+        let ticket = select_ticket_attempt(randomness, participants, attempt)?;
+
+        if !is_excluded(ticket) {
+            return Some((ticket, attempt));
+        }
+
+        last = Some((ticket, attempt));
+    }
+
+    last
+}
+
+/// Winning point in the cumulative deposit weight of a no-loss round.
+pub fn select_weight<R: RandomnessSource>(randomness: &R, total_weight: u128) -> Option<u128> {
+    sample_weight(&randomness.random_bytes(), total_weight)
+}
+
+/// Where a draw over ticket classes lands: on one ticket's base weight, as in a uniform draw,
+/// or on the extra weight class tickets carry on top of it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WeightedPick {
+    /// 1-based winning ticket number.
+    Ticket(u64),
+    /// 0-based point in the round's extra class weight; the class ticket covering it wins.
+    ClassWeight(u64)
+}
+
+/// Draw over `participants` tickets of weight 1 plus `class_weight` extra weight from
+/// ticket classes, or `None` for an empty round.
+pub fn select_weighted_ticket<R: RandomnessSource>(randomness: &R, participants: u64, class_weight: u64) -> Option<WeightedPick> {
+    let point = select_weight(randomness, participants as u128 + class_weight as u128)?;
+
+    Some(match point.checked_sub(participants as u128) {
+        Some(class_point) => WeightedPick::ClassWeight(class_point as u64),
+        None => WeightedPick::Ticket(point as u64 + 1)
+    })
+}
+
+/// 0-based winning sign of a lucky sign round, or `None` for an empty round.
+pub fn select_sign<R: RandomnessSource>(randomness: &R, participants: u64) -> Option<u8> {
+    if participants == 0 {
+        return None;
+    }
+
+    Some((randomness.random_u64() % ZODIAC_SIGN_COUNT as u64) as u8)
+}
+
+/// Offsets of the 2-byte words lotto numbers are drawn from, skipping the anti-snipe word.
+const NUMBER_WORD_OFFSETS: [usize; 12] = [0, 2, 4, 6, 8, 10, 12, 14, 24, 26, 28, 30];
+
+/// `count` distinct numbers in `1..=max_number` as a bitmask with bit `n` set for number `n`.
+/// `None` for an empty round, or when the draw doesn't fit the mask or the randomness.
+pub fn select_numbers<R: RandomnessSource>(randomness: &R, participants: u64, count: u8, max_number: u8) -> Option<u64> {
+    if participants == 0 || count == 0 || count > max_number || max_number > 63
+        || count as usize > NUMBER_WORD_OFFSETS.len()
+    {
+        return None;
+    }
+
+    let bytes = randomness.random_bytes();
+    let mut drawn = 0u64;
+
+    // Partial Fisher-Yates: each word picks among the numbers not drawn yet
+    for (drawn_so_far, offset) in NUMBER_WORD_OFFSETS.iter().take(count as usize).enumerate() {
+        let remaining = (max_number - drawn_so_far as u8) as u16;
+        let mut skip = u16::from_le_bytes([bytes[*offset], bytes[offset + 1]]) % remaining;
+
+        for number in 1..=max_number {
+            if drawn & (1 << number) != 0 {
+                continue;
+            }
+
+            if skip == 0 {
+                drawn |= 1 << number;
+                break;
+            }
+
+            skip -= 1;
+        }
+    }
+
+    Some(drawn)
+}
+
+/// Bitmask of picked numbers, or `None` if any is outside `1..=max_number` or repeated.
+pub fn numbers_mask(numbers: &[u8], max_number: u8) -> Option<u64> {
+    if max_number > 63 {
+        return None;
+    }
+
+    let mut mask = 0u64;
+
+    for &number in numbers {
+        if number == 0 || number > max_number || mask & (1 << number) != 0 {
+            return None;
+        }
+
+        mask |= 1 << number;
+    }
+
+    Some(mask)
+}
+
+/// How many picked numbers were drawn.
+pub fn count_matches(picked: u64, drawn: u64) -> u8 {
+    (picked & drawn).count_ones() as u8
+}
+
+/// Hidden anti-snipe extension in `0..=max_extension` seconds. Uses bytes the
+/// other selections leave untouched.
+pub fn snipe_extension(randomness: &[u8; 32], max_extension: i64) -> i64 {
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&randomness[16..24]);
+
+    (u64::from_le_bytes(word) % (max_extension.max(0) as u64 + 1)) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Bytes([u8; 32]);
+
+    impl RandomnessSource for Bytes {
+        fn random_bytes(&self) -> [u8; 32] {
+            self.0
+        }
+    }
+
+    fn with_u64(value: u64) -> Bytes {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&value.to_le_bytes());
+        Bytes(bytes)
+    }
+
+    #[test]
+    fn ticket_is_one_based() {
+        assert_eq!(select_ticket(&with_u64(0), 5), Some(1));
+        assert_eq!(select_ticket(&with_u64(14), 5), Some(5));
+        assert_eq!(select_ticket(&with_u64(14), 0), None);
+    }
+
+    #[test]
+    fn rerolls_read_fresh_words() {
+        let mut bytes = [0u8; 32];
+        bytes[8..16].copy_from_slice(&7u64.to_le_bytes());
+        bytes[24..32].copy_from_slice(&9u64.to_le_bytes());
+        let randomness = Bytes(bytes);
+
+        assert_eq!(select_ticket_attempt(&randomness, 10, 0), Some(1));
+        assert_eq!(select_ticket_attempt(&randomness, 10, 1), Some(8));
+        assert_eq!(select_ticket_attempt(&randomness, 10, 2), Some(10));
+        assert_eq!(select_ticket_attempt(&randomness, 10, MAX_REROLLS + 1), None);
+    }
+
+    #[test]
+    fn excluded_tickets_are_rerolled() {
+        let mut bytes = [0u8; 32];
+        bytes[8..16].copy_from_slice(&7u64.to_le_bytes());
+        bytes[24..32].copy_from_slice(&9u64.to_le_bytes());
+        let randomness = Bytes(bytes);
+
+        assert_eq!(select_ticket_excluding(&randomness, 10, |_| false), Some((1, 0)));
+        assert_eq!(select_ticket_excluding(&randomness, 10, |ticket| ticket == 1), Some((8, 1)));
+        assert_eq!(select_ticket_excluding(&randomness, 10, |_| true), Some((10, MAX_REROLLS)));
+        assert_eq!(select_ticket_excluding(&randomness, 0, |_| false), None);
+    }
+
+    #[test]
+    fn weighted_draw_splits_base_and_class_weight() {
+        let with_u128 = |value: u128| {
+            let mut bytes = [0u8; 32];
+            bytes[..16].copy_from_slice(&value.to_le_bytes());
+            Bytes(bytes)
+        };
+
+        assert_eq!(select_weighted_ticket(&with_u128(2), 3, 4), Some(WeightedPick::Ticket(3)));
+        assert_eq!(select_weighted_ticket(&with_u128(3), 3, 4), Some(WeightedPick::ClassWeight(0)));
+        assert_eq!(select_weighted_ticket(&with_u128(13), 3, 4), Some(WeightedPick::ClassWeight(3)));
+        assert_eq!(select_weighted_ticket(&with_u128(5), 3, 0), Some(WeightedPick::Ticket(3)));
+        assert_eq!(select_weighted_ticket(&with_u128(5), 0, 0), None);
+    }
+
+    #[test]
+    fn numbers_are_distinct_and_in_range() {
+        for seed in 0..200u64 {
+            let mut bytes = [0u8; 32];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = (seed.wrapping_mul(31).wrapping_add(i as u64 * 17) % 251) as u8;
+            }
+
+            let drawn = select_numbers(&Bytes(bytes), 1, 5, 36).unwrap();
+            assert_eq!(drawn.count_ones(), 5);
+            assert_eq!(drawn & 1, 0);
+            assert_eq!(drawn >> 37, 0);
+        }
+
+        assert_eq!(select_numbers(&Bytes([0u8; 32]), 1, 5, 36), Some(0b111110));
+        assert_eq!(select_numbers(&Bytes([0u8; 32]), 0, 5, 36), None);
+        assert_eq!(select_numbers(&Bytes([0u8; 32]), 1, 37, 36), None);
+    }
+
+    #[test]
+    fn picks_are_validated_and_matched() {
+        let picked = numbers_mask(&[1, 2, 3, 4, 36], 36).unwrap();
+
+        assert_eq!(numbers_mask(&[1, 1, 3, 4, 5], 36), None);
+        assert_eq!(numbers_mask(&[0, 2, 3, 4, 5], 36), None);
+        assert_eq!(numbers_mask(&[1, 2, 3, 4, 37], 36), None);
+        assert_eq!(count_matches(picked, 0b111110), 4);
+        assert_eq!(count_matches(picked, 0), 0);
+    }
+
+    #[test]
+    fn sign_stays_in_range() {
+        for value in 0..100 {
+            let sign = select_sign(&with_u64(value), 1).unwrap();
+            assert!((sign as usize) < ZODIAC_SIGN_COUNT);
+        }
+        assert_eq!(select_sign(&with_u64(13), 1), Some(1));
+        assert_eq!(select_sign(&with_u64(13), 0), None);
+    }
+
+    #[test]
+    fn snipe_extension_is_bounded() {
+        let mut bytes = [0u8; 32];
+        bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert_eq!(snipe_extension(&bytes, 0), 0);
+        assert!(snipe_extension(&bytes, 300) <= 300);
+        assert_eq!(snipe_extension(&bytes, -5), 0);
+    }
+}
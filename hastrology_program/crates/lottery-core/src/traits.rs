@@ -0,0 +1,25 @@
+use crate::LotteryError;
+
+/// Moves value between participants and the pot. The Anchor program uses system transfers
+/// and direct lamport moves; other embedders can plug in SPL tokens or off-chain ledgers.
+pub trait PaymentBackend {
+    type Account;
+
+    /// Takes `amount` from `from` into the pot.
+    fn collect(&mut self, from: &Self::Account, amount: u64) -> Result<(), LotteryError>;
+
+    /// Pays `amount` out of the pot to `to`.
+    fn pay(&mut self, to: &Self::Account, amount: u64) -> Result<(), LotteryError>;
+}
+
+/// Source of unbiased randomness for winner selection (VRF callback, beacon, test fixture...).
+pub trait RandomnessSource {
+    fn random_bytes(&self) -> [u8; 32];
+
+    fn random_u64(&self) -> u64 {
+        let bytes = self.random_bytes();
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[..8]);
+        u64::from_le_bytes(word)
+    }
+}
@@ -1,4 +1,103 @@
 use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::consts::DEFAULT_QUEUE;
+
+// ----Cluster----
+// Selected with exactly one of the `devnet` (default) or `mainnet` cargo features.
+
+#[cfg(feature = "devnet")]
+#[constant]
+pub const CLUSTER: &str = "devnet";
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const CLUSTER: &str = "mainnet";
+
+// Semantic version of this deployment, from the crate version, so clients can tell
+// which features it supports
+#[constant]
+pub const PROGRAM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Ephemeral VRF queue draws are requested from by default. MagicBlock serves its default
+// queue at the same address on both clusters; `set_randomness_provider` can point a
+// deployment at another one
+#[cfg(feature = "devnet")]
+pub const ORACLE_QUEUE: Pubkey = DEFAULT_QUEUE;
+
+#[cfg(feature = "mainnet")]
+pub const ORACLE_QUEUE: Pubkey = DEFAULT_QUEUE;
+
+// PDA the program signs randomness requests as; the ephemeral VRF SDK's `IDENTITY` seed
+#[constant]
+pub const RANDOMNESS_IDENTITY_SEED: &[u8] = b"identity";
+
+// Bits of RoundHistory::draw_sources: which queue a VRF callback came from
+pub const DRAW_SOURCE_PRIMARY: u8 = 1;
+pub const DRAW_SOURCE_SECOND: u8 = 2;
+
+// Shortest first round accepted by initialize, so it can't start already drawable
+#[cfg(feature = "devnet")]
+#[constant]
+pub const MIN_ROUND_DURATION: i64 = 10;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const MIN_ROUND_DURATION: i64 = 3600;
+
+// Furthest ahead initialize and update_config may set an endtime, before any tighter PlatformConfig bound
+#[constant]
+pub const MAX_ROUND_DURATION: i64 = 30 * 86400;
+
+// Length of rounds started by open_round until changed with set_round_duration
+#[constant]
+pub const DEFAULT_ROUND_DURATION: i64 = 86400;
+
+#[cfg(feature = "devnet")]
+#[constant]
+pub const MIN_TICKET_PRICE: u64 = 1;
+
+// 0.001 SOL
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const MIN_TICKET_PRICE: u64 = 1_000_000;
+
+#[cfg(feature = "devnet")]
+#[constant]
+pub const MAX_PLATFORM_FEE_BPS: u16 = 10_000;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const MAX_PLATFORM_FEE_BPS: u16 = 2_000;
+
+// Highest fee the authority can set alone; up to MAX_PLATFORM_FEE_BPS needs fee governance
+#[constant]
+pub const SOFT_MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+// Delay before a fee ceiling raised by fee governance can be used
+#[cfg(feature = "devnet")]
+#[constant]
+pub const FEE_CEILING_TIMELOCK: i64 = 60;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const FEE_CEILING_TIMELOCK: i64 = 7 * 86400;
+
+// Delay between update_config proposing a change and apply_config being allowed to apply it
+#[cfg(feature = "devnet")]
+#[constant]
+pub const CONFIG_TIMELOCK: i64 = 60;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const CONFIG_TIMELOCK: i64 = 48 * 3600;
+
+// Minimum time between forward_fees cranks
+#[cfg(feature = "devnet")]
+#[constant]
+pub const FEE_FORWARD_INTERVAL: i64 = 60;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const FEE_FORWARD_INTERVAL: i64 = 86400;
 
 #[constant]
 pub const LOTTERY_STATE_SEED: &[u8] = b"lottery_state";
@@ -13,4 +112,332 @@ pub const USER_RECEIPT_SEED: &[u8] = b"user-receipt";
 pub const USER_TICKET_SEED: &[u8] = b"user-ticket";
 
 #[constant]
-pub const PRIZE_VAULT_SEED: &[u8] = b"prize_vault";
\ No newline at end of file
+pub const QUEUED_ENTRY_SEED: &[u8] = b"queued-entry";
+
+#[constant]
+pub const CONDITIONAL_ENTRY_SEED: &[u8] = b"conditional-entry";
+
+// How many rounds past the current one a ticket can be pre-purchased for
+#[constant]
+pub const MAX_ROUNDS_AHEAD: u64 = 90;
+
+#[constant]
+pub const NO_LOSS_DEPOSIT_SEED: &[u8] = b"no-loss-deposit";
+
+#[constant]
+pub const REFERRAL_STATS_SEED: &[u8] = b"referral-stats";
+
+#[constant]
+pub const REFERRAL_EPOCH_SEED: &[u8] = b"referral_epoch";
+
+#[constant]
+pub const DEPOSIT_ADDRESS_SEED: &[u8] = b"deposit-address";
+
+#[constant]
+pub const PRIZE_VAULT_SEED: &[u8] = b"prize_vault";
+
+#[constant]
+pub const PARTICIPANT_INDEX_SEED: &[u8] = b"participant_index";
+
+#[constant]
+pub const ENTRY_POOL_SEED: &[u8] = b"entry_pool";
+
+#[constant]
+pub const POOL_MEMBER_SEED: &[u8] = b"pool_member";
+
+#[constant]
+pub const REGISTRY_SEED: &[u8] = b"registry";
+
+#[constant]
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+
+#[constant]
+pub const HOSTED_LOTTERY_SEED: &[u8] = b"hosted_lottery";
+
+#[constant]
+pub const HOSTED_VAULT_SEED: &[u8] = b"hosted_vault";
+
+pub const MAX_APPROVED_HOSTS: usize = 20;
+
+#[constant]
+pub const PRIZE_SWAP_SEED: &[u8] = b"prize_swap";
+
+#[constant]
+pub const WINNER_RECORD_SEED: &[u8] = b"winner_record";
+
+#[constant]
+pub const PENDING_KYC_SEED: &[u8] = b"pending-kyc";
+
+#[constant]
+pub const MEGA_JACKPOT_SEED: &[u8] = b"mega_jackpot";
+
+// Mint authority the reward token is handed to, so the program can mint loser rebates
+#[constant]
+pub const REWARD_MINT_AUTHORITY_SEED: &[u8] = b"reward_mint_authority";
+
+// Largest share of a ticket sale a lottery can route into the mega jackpot
+#[constant]
+pub const MAX_MEGA_JACKPOT_BPS: u16 = 2_000;
+
+// Runs of consecutive tickets one mega jackpot epoch can link; later entries skip the jackpot until it is drawn
+pub const MAX_MEGA_JACKPOT_SEGMENTS: usize = 64;
+
+// Bumped whenever the set or derivation of registry addresses changes
+#[constant]
+pub const REGISTRY_VERSION: u16 = 1;
+
+#[constant]
+pub const TICKET_PAGE_SEED: &[u8] = b"ticket_page";
+
+#[constant]
+pub const CLAIM_BITMAP_SEED: &[u8] = b"claim_bitmap";
+
+#[constant]
+pub const TICKET_PAGE_SIZE: usize = 1000;
+
+// Tickets copied per pack_round_tickets call, to stay within the compute budget
+#[constant]
+pub const TICKET_PACK_BATCH: usize = 250;
+
+#[constant]
+pub const ROUND_HISTORY_SEED: &[u8] = b"round_history";
+
+#[constant]
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
+
+#[constant]
+pub const SESSION_AUTHORITY_SEED: &[u8] = b"session_authority";
+
+// Longest a session key may stay valid, in seconds
+#[constant]
+pub const MAX_SESSION_DURATION: i64 = 7 * 86400;
+
+#[constant]
+pub const MERKLE_TREE_SEED: &[u8] = b"merkle_tree";
+
+// 2^18 leaves covers MAX_TICKETS_PER_ROUND
+#[constant]
+pub const MERKLE_TREE_DEPTH: usize = 18;
+
+#[constant]
+pub const SNIPE_WINDOW_SEED: &[u8] = b"snipe_window";
+
+// Upper bound on the hidden anti-snipe extension, in seconds
+#[constant]
+pub const MAX_ANTI_SNIPE_EXTENSION: i64 = 300;
+
+pub const MAX_SNIPE_WINDOW_ENTRIES: usize = 512;
+
+#[constant]
+pub const PLAYER_PROFILE_SEED: &[u8] = b"player_profile";
+
+// Seed prefix of attestation PDAs under the configured attestation program
+#[constant]
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+#[constant]
+pub const SEASON_WINNERS_SEED: &[u8] = b"season_winners";
+
+// Length of the period a player's spending cap applies to
+#[constant]
+pub const SPENDING_PERIOD: i64 = 7 * 86400;
+
+// Wait before a raised or removed spending cap takes effect; lowering applies at once
+#[constant]
+pub const SPENDING_CAP_INCREASE_DELAY: i64 = 86400;
+
+// Longest self-exclusion a single call can set (5 years)
+#[constant]
+pub const MAX_SELF_EXCLUSION: i64 = 5 * 365 * 86400;
+
+// Winners remembered per season; later winners of a longer season are not excluded
+pub const MAX_SEASON_WINNERS: usize = 100;
+
+#[constant]
+pub const METRICS_SEED: &[u8] = b"metrics";
+
+#[constant]
+pub const FEE_TREASURY_SEED: &[u8] = b"fee_treasury";
+
+#[constant]
+pub const DRAW_ATTESTATION_SEED: &[u8] = b"draw_attestation";
+
+pub const MAX_DRAW_OPERATORS: usize = 5;
+
+pub const MAX_CPI_ALLOWLIST: usize = 5;
+
+pub const MAX_ROLE_GRANTS: usize = 8;
+
+// Slots a VRF request may stay pending before retry_draw may replace it (~1 minute)
+#[constant]
+pub const DRAW_RETRY_TIMEOUT_SLOTS: u64 = 150;
+
+// Failed requests after which the round is reopened instead of retried again
+#[constant]
+pub const MAX_DRAW_RETRIES: u8 = 3;
+
+// Longest announcement delay between resolve_draw and reveal_winner
+#[constant]
+pub const MAX_REVEAL_DELAY: i64 = 86400;
+
+#[constant]
+pub const LUCKY_SIGN_ROUND_SEED: &[u8] = b"lucky_sign_round";
+
+#[constant]
+pub const LUCKY_SIGN_PICK_SEED: &[u8] = b"lucky_sign_pick";
+
+pub const ZODIAC_SIGN_COUNT: usize = lottery_core::selection::ZODIAC_SIGN_COUNT;
+
+#[constant]
+pub const LOTTO_ROUND_SEED: &[u8] = b"lotto_round";
+
+#[constant]
+pub const LOTTO_PICK_SEED: &[u8] = b"lotto_pick";
+
+// Numbers picked per ticket and drawn per round, out of 1..=LOTTO_MAX_NUMBER
+pub const LOTTO_PICK_COUNT: usize = 5;
+
+#[constant]
+pub const LOTTO_MAX_NUMBER: u8 = 36;
+
+// Fewest matches that win; each match count from here up to LOTTO_PICK_COUNT is a tier
+pub const LOTTO_MIN_MATCHES: usize = 3;
+
+pub const LOTTO_TIER_COUNT: usize = LOTTO_PICK_COUNT - LOTTO_MIN_MATCHES + 1;
+
+// Share of the prize pool per tier, from LOTTO_MIN_MATCHES matches up to a full match
+pub const LOTTO_TIER_BPS: [u16; LOTTO_TIER_COUNT] = [2_000, 3_000, 5_000];
+
+// Time after settlement for winning tickets to register before tier shares are fixed
+#[constant]
+pub const LOTTO_REGISTRATION_WINDOW: i64 = 3 * 86400;
+
+// Seconds added past the current time when a round is reopened
+#[constant]
+pub const ROUND_REOPEN_EXTENSION: i64 = 3600;
+
+// Keeps the per-round participant index (32 bytes per entry) under the 10 MiB account limit
+#[constant]
+pub const MAX_TICKETS_PER_ROUND: u32 = 250_000;
+
+// Upper bound on the keeper reward (0.01 SOL) so a draw trigger can't drain the pot
+#[constant]
+pub const MAX_KEEPER_REWARD: u64 = 10_000_000;
+
+// Target slot time, used to estimate the slot a round's endtime falls on
+#[constant]
+pub const MS_PER_SLOT: u64 = 400;
+
+// Longest grace the authority can allow the cluster clock to trail lottery_endtime by
+#[constant]
+pub const MAX_ENDTIME_GRACE: i64 = 120;
+
+// Largest per-step streak bonus (1 SOL), and how many consecutive wins past the first keep escalating it
+#[constant]
+pub const MAX_STREAK_BONUS: u64 = 1_000_000_000;
+#[constant]
+pub const MAX_STREAK_BONUS_STEPS: u16 = 5;
+
+// Drift between the pot vault and its ledger (0.01 SOL) past which reconcile_vault flags the lottery for review
+#[constant]
+pub const VAULT_REVIEW_THRESHOLD: u64 = 10_000_000;
+
+// Ticket classes a round can sell (star, moon, sun), and the most draw weight one can carry
+#[constant]
+pub const MAX_TICKET_CLASSES: usize = 3;
+#[constant]
+pub const MAX_TICKET_CLASS_WEIGHT: u16 = 10;
+
+// Keys each class's draw apart from the main draw and the other classes
+#[constant]
+pub const CLASS_DRAW_DOMAIN: &[u8] = b"class_draw";
+
+// Coarsest granularity (1 SOL) winner prizes can be rounded down to
+#[constant]
+pub const MAX_PRIZE_GRANULARITY: u64 = 1_000_000_000;
+
+// Instruction payout calls on the winner hook: Anchor's sighash of `on_round_paid_out`
+pub const WINNER_HOOK_DISCRIMINATOR: [u8; 8] = [59, 91, 96, 197, 146, 230, 44, 105];
+
+// Free tickets an early entry can earn; enter_lottery takes that many ticket accounts at most
+#[constant]
+pub const MAX_EARLY_BIRD_BONUS: u8 = 3;
+
+// Longest currency symbol snapshotted into state and round history
+pub const MAX_CURRENCY_SYMBOL_LEN: usize = 10;
+
+#[constant]
+pub const UPCOMING_ROUND_SEED: &[u8] = b"upcoming_round";
+
+// Longest theme a round announcement can carry
+pub const MAX_ROUND_THEME_LEN: usize = 32;
+
+#[constant]
+pub const SOL_DECIMALS: u8 = 9;
+
+#[constant]
+pub const STAKE_STAGING_SEED: &[u8] = b"stake_staging";
+
+// SPL stake pool instruction tags
+pub const STAKE_POOL_DEPOSIT_SOL_IX: u8 = 14;
+pub const STAKE_POOL_WITHDRAW_SOL_IX: u8 = 16;
+
+#[constant]
+pub const INSURANCE_RESERVE_SEED: &[u8] = b"insurance_reserve";
+
+#[constant]
+pub const COMPENSATION_SEED: &[u8] = b"compensation";
+
+// Largest share of a pot payout can set aside for the insurance reserve
+#[constant]
+pub const MAX_INSURANCE_BPS: u16 = 1_000;
+
+// Delay between propose_compensation and compensate paying it out
+#[cfg(feature = "devnet")]
+#[constant]
+pub const COMPENSATION_TIMELOCK: i64 = 60;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const COMPENSATION_TIMELOCK: i64 = 48 * 3600;
+
+#[constant]
+pub const HISTORY_ARCHIVE_SEED: &[u8] = b"history_archive";
+
+// Round histories one prune_history call can fold into an archive, and the depth of its Merkle tree
+#[constant]
+pub const MAX_ARCHIVED_ROUNDS: usize = 16;
+pub const HISTORY_ARCHIVE_DEPTH: usize = 4;
+
+// Slots a round history stays open after its draw request before prune_history may archive it
+#[cfg(feature = "devnet")]
+#[constant]
+pub const HISTORY_RETENTION_SLOTS: u64 = 1_000;
+
+#[cfg(feature = "mainnet")]
+#[constant]
+pub const HISTORY_RETENTION_SLOTS: u64 = 90 * 216_000;
+
+#[constant]
+pub const NFT_PRIZE_SEED: &[u8] = b"nft_prize";
+
+#[constant]
+pub const NFT_PRIZE_ESCROW_SEED: &[u8] = b"nft_prize_escrow";
+
+#[constant]
+pub const DEVICE_ENTRIES_SEED: &[u8] = b"device_entries";
+
+// After a sunset, how long (90 days) refunds and claims stay protected before accounts still holding funds can be closed
+#[constant]
+pub const SUNSET_CLAIM_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+#[constant]
+pub const AIRDROP_SNAPSHOT_SEED: &[u8] = b"airdrop_snapshot";
+
+// Rounds one airdrop snapshot can cover
+#[constant]
+pub const MAX_SNAPSHOT_ROUNDS: usize = 16;
+
+// Tickets read per crank_airdrop_snapshot call; each costs MERKLE_TREE_DEPTH hashes
+#[constant]
+pub const SNAPSHOT_CRANK_BATCH: usize = 64;
\ No newline at end of file
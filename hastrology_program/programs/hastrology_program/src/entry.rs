@@ -0,0 +1,188 @@
+//! Checks and bookkeeping shared by every instruction that sells a ticket or takes a no-loss
+//! deposit, so a limit one entry path enforces holds on all of them.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    constants::PROGRAM_VERSION,
+    errors::HashtrologyErrors,
+    events::{LotterySoldOut, TicketPurchased},
+    lamports::{credit_lamports, debit_lamports},
+    math::bps_of,
+    state::{LotteryState, MegaJackpot, Metrics, PlayerProfile}
+};
+
+/// How the buyer of a ticket was cleared to enter.
+pub enum EntryClearance<'a, 'info> {
+    /// Through the lottery's entry gate and, with KYC escrow on, a credential gate. Paths
+    /// whose buyer can't present a token account or attestation pass `None`, leaving only
+    /// the wallet-level gates open to them.
+    Eligibility {
+        token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        attestation: Option<&'a AccountInfo<'info>>
+    },
+    /// By the KYC verifier, whose approval stands in for the entry gate.
+    KycApproved
+}
+
+/// A ticket sale that passed `open_entry`.
+pub struct EntrySale {
+    pub buyer: Pubkey,
+    pub ticket_number: u32,
+    pub price: u64,
+    pub pot_after_entry: u64, // pot balance once the price is paid in
+    pub mega_cut: u64, // part of the price passed on to the mega jackpot, set by take_mega_cut
+    pub now: i64
+}
+
+/// Checks `buyer` may enter at all, whatever the entry buys: the instruction isn't wrapped
+/// in a CPI from a program off the allowlist, the buyer was cleared by `clearance`, and the
+/// entry carries a device commitment while `max_entries_per_device` is set.
+///
+/// Only `enter_lottery` can carry a `device_commitment` and count it against
+/// `max_entries_per_device`, so every other path passes `None` and stays closed while that
+/// limit is set.
+pub fn check_entrant(
+    lottery_state: &LotteryState,
+    buyer: &AccountInfo,
+    clearance: EntryClearance,
+    device_commitment: Option<[u8; 32]>,
+    instructions_sysvar: &AccountInfo
+) -> Result<()> {
+    lottery_state.check_entry_caller(instructions_sysvar)?;
+
+    if let EntryClearance::Eligibility { token_account, attestation } = clearance {
+        lottery_state.check_kyc_gate()?;
+        lottery_state.entry_eligibility.check(buyer, token_account, attestation)?;
+    }
+
+    require!(
+        device_commitment.is_some() || lottery_state.max_entries_per_device == 0,
+        HashtrologyErrors::DeviceCommitmentRequired
+    );
+
+    Ok(())
+}
+
+/// Checks the sale of a ticket at `price` to `buyer` against `check_entrant`, the pot cap,
+/// the round's ticket and slot limits and the buyer's profile, recording the slot entry and
+/// the spend. `merkle_leaf` sells it as a leaf of the round's Merkle tree instead of a
+/// ticket account. `player_profile` is `None` only for buyers that can't have a profile,
+/// or whose path charges a profile it already holds.
+#[allow(clippy::too_many_arguments)]
+pub fn open_entry(
+    lottery_state: &mut LotteryState,
+    buyer: &AccountInfo,
+    merkle_leaf: bool,
+    clearance: EntryClearance,
+    player_profile: Option<&AccountInfo>,
+    device_commitment: Option<[u8; 32]>,
+    instructions_sysvar: &AccountInfo,
+    pot_lamports: u64,
+    price: u64,
+    clock: &Clock
+) -> Result<EntrySale> {
+    lottery_state.check_takes_tickets(merkle_leaf)?;
+    check_entrant(lottery_state, buyer, clearance, device_commitment, instructions_sysvar)?;
+
+    let pot_after_entry = lottery_state.check_pot_cap(pot_lamports, price)?;
+    let ticket_number = lottery_state.next_ticket_number()?;
+    lottery_state.record_slot_entry(clock.slot)?;
+
+    if let Some(player_profile) = player_profile {
+        PlayerProfile::charge(player_profile, price, clock.unix_timestamp)?;
+    }
+
+    Ok(EntrySale {
+        buyer: buyer.key(),
+        ticket_number,
+        price,
+        pot_after_entry,
+        mega_cut: 0,
+        now: clock.unix_timestamp
+    })
+}
+
+impl EntrySale {
+    /// Sets aside the mega jackpot's cut of the price while the lottery is linked to it,
+    /// returning what the sale adds to the pot. While the jackpot's draw is underway the
+    /// whole price stays in the pot.
+    pub fn take_mega_cut(&mut self, lottery_state: &LotteryState, mega_jackpot: Option<&MegaJackpot>) -> Result<u64> {
+        if lottery_state.mega_jackpot_bps > 0 {
+            let mega_jackpot = mega_jackpot.ok_or(HashtrologyErrors::MegaJackpotRequired)?;
+
+            if mega_jackpot.accepts_entries(lottery_state.current_lottery_id, self.first_ticket()) {
+                self.mega_cut = bps_of(self.price, lottery_state.mega_jackpot_bps)?;
+            }
+        }
+
+        Ok(self.price - self.mega_cut)
+    }
+
+    /// Books the sale once its price has reached the pot vault and the buyer holds
+    /// `tickets_granted` new tickets, passing the mega cut on with every ticket granted
+    /// entered in the jackpot, then announces it.
+    pub fn settle<'info>(
+        &self,
+        lottery_state: &mut LotteryState,
+        metrics: Option<&mut Metrics>,
+        pot_vault: &AccountInfo<'info>,
+        mega_jackpot: Option<&mut Account<'info, MegaJackpot>>,
+        tickets_granted: u8,
+        ticket_class: Option<u8>
+    ) -> Result<()> {
+        if self.mega_cut > 0 {
+            let mega_jackpot = mega_jackpot.ok_or(HashtrologyErrors::MegaJackpotRequired)?;
+
+            mega_jackpot.link(lottery_state.current_lottery_id, self.first_ticket(), tickets_granted as u64, self.mega_cut)?;
+            debit_lamports(pot_vault, self.mega_cut)?;
+            credit_lamports(&mega_jackpot.to_account_info(), self.mega_cut)?;
+        }
+
+        let pot_contribution = self.price - self.mega_cut;
+
+        lottery_state.record_vault_inflow(pot_contribution)?;
+        lottery_state.record_promo_entry(pot_contribution, self.now)?;
+        lottery_state.record_class_pot(ticket_class, pot_contribution)?;
+
+        lottery_state.total_participants = lottery_state.total_participants
+            .checked_add(tickets_granted as u64)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        if let Some(metrics) = metrics {
+            metrics.record_entry(self.price)?;
+        }
+
+        emit!(TicketPurchased {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            user: self.buyer,
+            ticket_number: self.ticket_number,
+            price_paid: self.price,
+            tickets_granted,
+            effective_price: self.price / tickets_granted as u64,
+            ticket_class,
+        });
+
+        if lottery_state.max_pot_lamports > 0
+            && self.pot_after_entry.saturating_add(lottery_state.ticket_price) > lottery_state.max_pot_lamports
+        {
+            emit!(LotterySoldOut {
+                event_seq: lottery_state.next_event_seq(),
+                program_version: PROGRAM_VERSION.to_string(),
+                lottery_id: lottery_state.current_lottery_id,
+                pot_lamports: self.pot_after_entry,
+                max_pot_lamports: lottery_state.max_pot_lamports,
+                total_participants: lottery_state.total_participants,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn first_ticket(&self) -> u64 {
+        (self.ticket_number - 1) as u64
+    }
+}
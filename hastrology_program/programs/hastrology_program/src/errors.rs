@@ -1,20 +1,30 @@
 use anchor_lang::prelude::*;
+use lottery_core::LotteryError;
 
+/// Each `#[msg]` is the description published for its code in the IDL, so explorers and the
+/// app can show it for a failed transaction. Checks whose cause depends on live values log
+/// them first through `require_ctx!`.
 #[error_code]
 pub enum HashtrologyErrors {
     #[msg("Math Overflow")]
     Overflow,
 
     // --- Initialize Errors ---
-    #[msg("The platform fee must be 10,000 (100%) or less.")]
+    #[msg("The platform fee exceeds the maximum for this cluster.")]
     InvalidPlatformFee,
 
-    #[msg("The ticket price cannot be zero.")]
+    #[msg("The ticket price is below the minimum for this cluster.")]
     InvalidTicketPrice,
 
     // --- EnterLottery Errors ---
     #[msg("The lottery is currently drawing a winner. Please try again later.")]
     LotteryIsDrawing,
+
+    #[msg("The pot has reached its maximum size. This lottery is sold out.")]
+    PotCapReached,
+
+    #[msg("This round has reached the maximum number of tickets.")]
+    RoundIsFull,
     
     // --- RequestDraw Errors ---
     #[msg("The lottery is not over yet. Cannot request a draw.")]
@@ -48,6 +58,12 @@ pub enum HashtrologyErrors {
     #[msg("No draw has been requested for this lottery.")]
     DrawNotRequested,
 
+    #[msg("The pending randomness request has already been resolved.")]
+    DrawAlreadyResolved,
+
+    #[msg("The pending draw request has not timed out yet.")]
+    DrawNotTimedOut,
+
     #[msg("The randomness request has expired.")]
     RandomnessExpired,
     
@@ -66,4 +82,758 @@ pub enum HashtrologyErrors {
 
     #[msg("The new lottery endtime must be in the future.")]
     InvalidEndtime,
-}
\ No newline at end of file
+
+    // --- ClaimPrize Errors ---
+    #[msg("The token prize amount cannot be zero.")]
+    InvalidTokenPrizeAmount,
+
+    #[msg("The provided mint does not match the configured prize mint.")]
+    InvalidPrizeMint,
+
+    #[msg("This ticket has no token prize to claim.")]
+    NoTokenPrize,
+
+    #[msg("The prize for this ticket has already been claimed.")]
+    PrizeAlreadyClaimed,
+
+    // --- Draw Attestation Errors ---
+    #[msg("Too many draw operators were provided.")]
+    TooManyDrawOperators,
+
+    #[msg("The approval threshold cannot exceed the number of operators.")]
+    InvalidDrawThreshold,
+
+    #[msg("The same draw operator was provided more than once.")]
+    DuplicateDrawOperator,
+
+    #[msg("The signer is not a registered draw operator.")]
+    UnauthorizedOperator,
+
+    #[msg("This operator has already approved the draw.")]
+    DrawAlreadyApproved,
+
+    #[msg("This pot requires a draw attestation before requesting randomness.")]
+    DrawAttestationRequired,
+
+    #[msg("The draw attestation does not have enough operator approvals.")]
+    InsufficientDrawApprovals,
+
+    // --- Queued Entry Errors ---
+    #[msg("Next-round tickets can only be queued while the current round is drawing.")]
+    LotteryNotDrawing,
+
+    #[msg("The round this entry was queued for is not open yet.")]
+    QueuedRoundNotOpen,
+
+    #[msg("The user does not match the queued entry.")]
+    InvalidQueuedEntry,
+
+    // --- Pot Staking Errors ---
+    #[msg("The stake pool accounts do not match the configured pool.")]
+    InvalidStakePool,
+
+    #[msg("The stake amount must be greater than zero.")]
+    InvalidStakeAmount,
+
+    #[msg("The pot does not hold enough unstaked lamports.")]
+    InsufficientPotBalance,
+
+    #[msg("There is nothing staked to withdraw.")]
+    NothingStaked,
+
+    #[msg("The pot is still staked. Unstake before paying out.")]
+    PotStillStaked,
+
+    // --- No-Loss Mode Errors ---
+    #[msg("No-loss mode is not enabled.")]
+    NoLossModeDisabled,
+
+    #[msg("This instruction is not available while no-loss mode is enabled.")]
+    NoLossModeActive,
+
+    #[msg("The game mode can only be changed between rounds.")]
+    CannotSwitchModeMidRound,
+
+    #[msg("The deposit must be at least the ticket price.")]
+    DepositTooSmall,
+
+    #[msg("The lottery has already ended. Wait for the next round.")]
+    LotteryIsOver,
+
+    #[msg("The deposit does not belong to this user.")]
+    InvalidDeposit,
+
+    #[msg("This deposit has already been withdrawn.")]
+    DepositAlreadyWithdrawn,
+
+    // --- Referral Errors ---
+    #[msg("A referral has already been recorded for this entry.")]
+    ReferralAlreadyRecorded,
+
+    #[msg("Users cannot refer themselves.")]
+    SelfReferral,
+
+    #[msg("The account is not the top referrer for this round.")]
+    InvalidReferrer,
+
+    #[msg("There is no referral bonus to distribute for this round.")]
+    NoReferralBonus,
+
+    #[msg("The referral bonus for this round has already been paid.")]
+    ReferralBonusAlreadyPaid,
+
+    // --- Deposit Address Errors ---
+    #[msg("The deposit address does not hold exactly one ticket price.")]
+    DepositAmountMismatch,
+
+    #[msg("The deposit address is empty or holds an exact ticket payment.")]
+    DepositNotRefundable,
+
+    // --- Eligibility Errors ---
+    #[msg("The user does not meet this lottery's entry requirements.")]
+    NotEligible,
+
+    #[msg("This lottery requires a token account to prove eligibility.")]
+    EligibilityAccountMissing,
+
+    #[msg("The eligibility token account is not owned by the user or has the wrong mint.")]
+    InvalidEligibilityAccount,
+
+    #[msg("Token balance requirements must be greater than zero.")]
+    InvalidEligibilityRule,
+
+    // --- Session Key Errors ---
+    #[msg("Session expiry must be in the future and within the maximum session duration.")]
+    InvalidSessionExpiry,
+
+    #[msg("Session spend cap must be greater than zero.")]
+    InvalidSpendCap,
+
+    #[msg("The signer is not the session key authorized for this user.")]
+    InvalidSessionKey,
+
+    #[msg("This session key has expired.")]
+    SessionExpired,
+
+    #[msg("This entry would exceed the session's spend cap.")]
+    SessionSpendCapExceeded,
+
+    // --- Lamport Errors ---
+    #[msg("The source account does not hold enough lamports for this transfer.")]
+    InsufficientLamports,
+
+    // --- Math Errors ---
+    #[msg("Basis points must not exceed 10,000 and tier tables must sum to exactly 10,000.")]
+    InvalidBasisPoints,
+
+    // --- Payout Destination Errors ---
+    #[msg("The prize for this ticket has already been paid out.")]
+    PrizeAlreadyPaid,
+
+    #[msg("The payout destination cannot be the default public key.")]
+    InvalidPayoutDestination,
+
+    // --- Merkle Entry Errors ---
+    #[msg("This round takes entries as Merkle leaves; use enter_merkle and claim_merkle_prize.")]
+    MerkleEntryModeActive,
+
+    #[msg("Merkle entry mode is not enabled.")]
+    MerkleEntryModeDisabled,
+
+    #[msg("The Merkle tree leaf count does not match the round's participant count.")]
+    MerkleTreeOutOfSync,
+
+    #[msg("The Merkle proof does not match the winning entry.")]
+    InvalidMerkleProof,
+
+    #[msg("Merkle entry mode only applies to single-winner rounds.")]
+    MerkleEntryNeedsSingleWinner,
+
+    // --- Anti-Snipe Errors ---
+    #[msg("Anti-snipe extension must be between 0 and the maximum allowed.")]
+    InvalidAntiSnipeExtension,
+
+    #[msg("Anti-snipe end times are not enabled.")]
+    AntiSnipeDisabled,
+
+    #[msg("Entries after the advertised end time must go through enter_lottery with the snipe window.")]
+    SnipeWindowRequired,
+
+    #[msg("The anti-snipe window has closed.")]
+    SnipeWindowClosed,
+
+    #[msg("The anti-snipe window cannot log more entries.")]
+    SnipeWindowFull,
+
+    #[msg("The round's true end time has not been revealed yet.")]
+    SnipeWindowNotResolved,
+
+    #[msg("This ticket was entered before the round's true end time.")]
+    NotLateEntry,
+
+    // --- Rate Limit Errors ---
+    #[msg("This wallet has reached its entry limit for the round.")]
+    WalletEntryLimitReached,
+
+    #[msg("Too many entries in this slot. Try again shortly.")]
+    SlotEntryLimitReached,
+
+    // --- Round Kind Errors ---
+    #[msg("Invalid round kind configuration.")]
+    InvalidRoundKind,
+
+    // --- Keeper Reward Errors ---
+    #[msg("Keeper reward exceeds the allowed maximum.")]
+    InvalidKeeperReward,
+
+    // --- Currency Errors ---
+    #[msg("Currency symbol must be 1 to 10 bytes.")]
+    InvalidCurrencySymbol,
+
+    // --- Initialize Errors ---
+    #[msg("The first lottery end time must be at least the minimum round duration in the future.")]
+    EndtimeTooSoon,
+
+    // --- Winner Reveal Errors ---
+    #[msg("Reveal delay exceeds the allowed maximum.")]
+    InvalidRevealDelay,
+
+    #[msg("The winner has not been revealed yet.")]
+    WinnerNotRevealed,
+
+    #[msg("No winner commitment is pending.")]
+    NoPendingReveal,
+
+    #[msg("The reveal delay has not elapsed yet.")]
+    RevealTooEarly,
+
+    #[msg("The revealed winner does not match the commitment.")]
+    InvalidWinnerReveal,
+
+    // --- Co-Winner Errors ---
+    #[msg("This round has co-winners; settle it with settle_lucky_sign or settle_lotto.")]
+    CoWinnerRound,
+
+    #[msg("The current round is not a lucky sign round.")]
+    NotLuckySignRound,
+
+    #[msg("Invalid zodiac sign.")]
+    InvalidSign,
+
+    #[msg("Co-winner prizes for this round have not been settled.")]
+    CoWinnersNotSettled,
+
+    #[msg("Co-winner claims must be passed as (pick, ticket, wallet) triples.")]
+    InvalidCoWinnerAccounts,
+
+    #[msg("This pick did not win or was already claimed.")]
+    InvalidCoWinnerClaim,
+
+    // --- Round Opening Errors ---
+    #[msg("The previous round is finalized; the next one has not been opened yet.")]
+    RoundNotOpen,
+
+    #[msg("The current round has not been finalized.")]
+    RoundNotFinalized,
+
+    #[msg("Round duration must be at least the minimum round duration.")]
+    InvalidRoundDuration,
+
+    // --- Draw Window Queueing Errors ---
+    #[msg("Entries during the draw window are not queued for this lottery.")]
+    DrawWindowQueueingDisabled,
+
+    // --- Pool Errors ---
+    #[msg("This pool has already entered or belongs to another round.")]
+    PoolClosed,
+
+    #[msg("Pool contributions must be positive and cannot exceed the ticket price.")]
+    InvalidPoolContribution,
+
+    #[msg("The pool has not raised the full ticket price yet.")]
+    PoolNotFunded,
+
+    #[msg("Pools cannot enter while an entry eligibility gate is set.")]
+    PoolEntryNotEligible,
+
+    #[msg("Every pool member's account and player profile must be passed, ordered by member.")]
+    InvalidPoolMembers,
+
+    // --- Config Timelock Errors ---
+    #[msg("There is no pending config change.")]
+    NoPendingConfig,
+
+    #[msg("The pending config change is still timelocked.")]
+    ConfigTimelocked,
+
+    // --- Season Errors ---
+    #[msg("Season length cannot be negative.")]
+    InvalidSeasonLength,
+
+    // --- Spending Cap Errors ---
+    #[msg("This entry would exceed your spending cap for the current period.")]
+    SpendingCapExceeded,
+
+    // --- Self-Exclusion Errors ---
+    #[msg("Self-exclusion must last between one second and five years.")]
+    InvalidExclusionPeriod,
+
+    #[msg("This wallet has excluded itself from entering until its exclusion period ends.")]
+    SelfExcluded,
+
+    // --- Lotto Errors ---
+    #[msg("The current round is not a lucky numbers round.")]
+    NotLottoRound,
+
+    #[msg("Pick exactly the required count of distinct numbers within range.")]
+    InvalidLottoNumbers,
+
+    #[msg("Lotto ticket registration has closed.")]
+    LottoRegistrationClosed,
+
+    #[msg("Lotto ticket registration is still open.")]
+    LottoRegistrationOpen,
+
+    #[msg("This ticket does not match enough numbers to win.")]
+    LottoTicketNotWinning,
+
+    #[msg("This ticket is already registered.")]
+    LottoTicketAlreadyRegistered,
+
+    #[msg("This ticket was not registered or was already claimed.")]
+    InvalidLottoClaim,
+
+    #[msg("Lotto tier shares have not been finalized.")]
+    LottoNotFinalized,
+
+    #[msg("Lotto tier shares are already finalized.")]
+    LottoAlreadyFinalized,
+
+    // --- Fee Forwarding Errors ---
+    #[msg("Fees were forwarded too recently; wait for the forwarding interval.")]
+    FeeForwardTooSoon,
+
+    #[msg("There are no accrued fees to forward.")]
+    NoFeesToForward,
+
+    // --- CPI Guard Errors ---
+    #[msg("Entries through CPI are only accepted from allowlisted programs.")]
+    CpiEntryNotAllowed,
+
+    #[msg("Too many programs on the CPI allowlist.")]
+    TooManyAllowlistedPrograms,
+
+    #[msg("The CPI allowlist contains a duplicate program.")]
+    DuplicateAllowlistedProgram,
+
+    // --- Entry Validation Errors ---
+    #[msg("This wallet already has a ticket in the current round.")]
+    AlreadyEntered,
+
+    // --- Pre-Purchase Errors ---
+    #[msg("Tickets can only be pre-purchased for a later round within the allowed range.")]
+    InvalidFutureRound,
+
+    // --- Claim Bitmap Errors ---
+    #[msg("The round is still taking entries, so its ticket count is not fixed yet.")]
+    RoundStillOpen,
+
+    #[msg("The ticket index is outside this round's claim bitmap.")]
+    InvalidClaimIndex,
+
+    #[msg("The claim bitmap has not been grown to full size yet; keep calling init_claim_bitmap.")]
+    ClaimBitmapIncomplete,
+
+    // --- Hosted Lottery Errors ---
+    #[msg("This wallet is not an approved lottery host.")]
+    HostNotApproved,
+
+    #[msg("This host is already approved.")]
+    HostAlreadyApproved,
+
+    #[msg("Too many approved hosts.")]
+    TooManyHosts,
+
+    // --- Prize Floor Errors ---
+    #[msg("The fee treasury must be passed while a minimum prize is set.")]
+    FeeTreasuryRequired,
+
+    #[msg("Fees can't be forwarded while a draw backed by the prize floor is pending.")]
+    FeeForwardDuringDraw,
+
+    // --- Entropy Mixing Errors ---
+    #[msg("This pot needs a committed operator nonce before it can be drawn.")]
+    EntropyCommitmentRequired,
+
+    #[msg("The SlotHashes sysvar must be passed to mix entropy.")]
+    SlotHashesRequired,
+
+    #[msg("An operator nonce is already committed for this round.")]
+    EntropyAlreadyCommitted,
+
+    #[msg("Operator nonces must be committed before the round ends.")]
+    EntropyCommitClosed,
+
+    #[msg("No draw is waiting for an operator nonce.")]
+    NoEntropyRevealPending,
+
+    #[msg("The nonce does not match the committed hash.")]
+    InvalidEntropyNonce,
+
+    #[msg("The operator nonce has not been revealed yet.")]
+    EntropyRevealPending,
+
+    #[msg("The draw accounts don't match the ones the draw was requested with.")]
+    DrawAccountsMismatch,
+
+    // --- Round Snapshot Errors ---
+    #[msg("The round changed after its draw was requested.")]
+    RoundSnapshotMismatch,
+
+    // --- Prize Swap Errors ---
+    #[msg("Prize swaps are not enabled.")]
+    SwapNotEnabled,
+
+    #[msg("There is no held prize to swap for this ticket.")]
+    NoSwapPrize,
+
+    #[msg("The swap returned less than the minimum amount out.")]
+    SlippageExceeded,
+
+    #[msg("The prize swap can't be cancelled after payout.")]
+    PrizeSwapLocked,
+
+    // --- Accounting Errors ---
+    #[msg("Round counters don't match the pot vault. Run reconcile before drawing.")]
+    AccountingMismatch,
+
+    // --- Early Bird Errors ---
+    #[msg("Early-bird bonus exceeds the maximum.")]
+    InvalidEarlyBirdBonus,
+
+    #[msg("The early-bird bonus ticket accounts are missing or wrong.")]
+    InvalidBonusTicket,
+
+    // --- Role Errors ---
+    #[msg("The signer does not hold the role this instruction requires.")]
+    MissingRole,
+
+    #[msg("This member already holds the role.")]
+    RoleAlreadyGranted,
+
+    #[msg("This member does not hold the role.")]
+    RoleNotGranted,
+
+    #[msg("Too many role grants.")]
+    TooManyRoleGrants,
+
+    #[msg("Entries are paused.")]
+    EntriesPaused,
+
+    // --- Initialize Recovery Errors ---
+    #[msg("The existing pot vault is not empty and can't be adopted.")]
+    VaultNotAdoptable,
+
+    // --- Fee Governance Errors ---
+    #[msg("Fees above the soft cap need a ceiling approved by fee governance.")]
+    FeeAboveCeiling,
+
+    #[msg("The signer is not the fee governance key.")]
+    UnauthorizedFeeGovernance,
+
+    // --- KYC Escrow Errors ---
+    #[msg("KYC escrow is not enabled.")]
+    KycEscrowDisabled,
+
+    #[msg("Unverified users must enter through KYC escrow.")]
+    KycApprovalRequired,
+
+    #[msg("The signer is not the KYC verifier.")]
+    UnauthorizedVerifier,
+
+    #[msg("The round this entry was escrowed for is no longer open.")]
+    PendingEntryExpired,
+
+    // --- Mega Jackpot Errors ---
+    #[msg("The mega jackpot account is required while this lottery is linked to it.")]
+    MegaJackpotRequired,
+
+    #[msg("The mega jackpot contribution exceeds the allowed maximum.")]
+    MegaJackpotBpsTooHigh,
+
+    #[msg("The mega jackpot draw interval must be positive.")]
+    InvalidMegaJackpotInterval,
+
+    #[msg("The mega jackpot is not due for a draw yet.")]
+    MegaJackpotNotDue,
+
+    #[msg("A mega jackpot draw is already in progress.")]
+    MegaJackpotDrawPending,
+
+    #[msg("No mega jackpot draw is waiting for randomness.")]
+    MegaJackpotNotDrawing,
+
+    #[msg("The mega jackpot has no drawn entry to settle.")]
+    MegaJackpotNotDrawn,
+
+    #[msg("The ticket does not hold the winning mega jackpot entry.")]
+    InvalidMegaJackpotTicket,
+
+    // --- Clock Skew Errors ---
+    #[msg("Endtime grace must be between 0 and the maximum allowed.")]
+    InvalidEndtimeGrace,
+
+    // --- Held Prize Errors ---
+    #[msg("This ticket has no held prize to claim.")]
+    NoHeldPrize,
+
+    #[msg("The recipient must be a wallet that can receive lamports.")]
+    RecipientCannotReceiveLamports,
+
+    // --- Win Streak Errors ---
+    #[msg("Streak bonus exceeds the maximum allowed.")]
+    StreakBonusTooHigh,
+
+    // --- Winner Hook Errors ---
+    #[msg("The registered winner hook program must be passed as the first remaining account.")]
+    WinnerHookMissing,
+
+    #[msg("The winner hook must be an executable program.")]
+    WinnerHookNotExecutable,
+
+    // --- Ticket Class Errors ---
+    #[msg("Too many ticket classes.")]
+    TooManyTicketClasses,
+
+    #[msg("Ticket classes need a price and a weight between 1 and the maximum.")]
+    InvalidTicketClass,
+
+    #[msg("This round doesn't sell that ticket class.")]
+    UnknownTicketClass,
+
+    #[msg("Ticket classes only apply to single-winner rounds.")]
+    TicketClassesNeedSingleWinner,
+
+    #[msg("The drawn class weight hasn't been matched to a ticket yet. Run locate_class_winner.")]
+    ClassWinnerNotLocated,
+
+    #[msg("No class weight is waiting to be located.")]
+    NoClassWeightDrawn,
+
+    #[msg("The ticket doesn't cover the drawn class weight.")]
+    ClassWeightNotCovered,
+
+    #[msg("Ticket classes aren't sold inside the anti-snipe window.")]
+    TicketClassesClosed,
+
+    // --- Loser Rebate Errors ---
+    #[msg("This round has no reward-token rebate for losing tickets.")]
+    NoLoserReward,
+
+    #[msg("Only losing tickets that took part in the draw earn a rebate.")]
+    NotALosingTicket,
+
+    // --- Prize Rounding Errors ---
+    #[msg("The prize granularity is above MAX_PRIZE_GRANULARITY.")]
+    PrizeGranularityTooHigh,
+
+    // --- Ticket Close Errors ---
+    #[msg("Remaining accounts must be a (ticket, rent payer) pair for each ticket index.")]
+    InvalidTicketCloseAccounts,
+
+    #[msg("Only losing tickets of a drawn single-winner round can be closed.")]
+    TicketNotClosable,
+
+    #[msg("The ticket's reward-token rebate hasn't been claimed yet.")]
+    LoserRewardUnclaimed,
+
+    // --- Config Bounds Errors ---
+    #[msg("Config bounds must stay within the compiled limits, with the minimum round duration under the maximum.")]
+    InvalidConfigBounds,
+
+    #[msg("The lottery end time is further out than the maximum round duration.")]
+    EndtimeTooFar,
+
+    // --- Round Announcement Errors ---
+    #[msg("The round theme is longer than the maximum allowed.")]
+    RoundThemeTooLong,
+
+    #[msg("An announced round must open in the future.")]
+    InvalidAnnouncementTime,
+
+    #[msg("The announced round can't open yet.")]
+    AnnouncementNotDue,
+
+    #[msg("This round was announced; open it with open_announced_round.")]
+    RoundWasAnnounced,
+
+    #[msg("Rent goes back to the account that announced the round.")]
+    InvalidAnnouncer,
+
+    // --- Conditional Entry Errors ---
+    #[msg("The user does not match the conditional entry.")]
+    InvalidConditionalEntry,
+
+    #[msg("The round doesn't meet the entry's pot or participant condition yet.")]
+    EntryConditionNotMet,
+
+    #[msg("Only the user can cancel a conditional entry before its round is over.")]
+    ConditionalEntryStillLive,
+
+    // --- Stake Payout Errors ---
+    #[msg("The vote account doesn't match the one chosen for the stake payout.")]
+    InvalidVoteAccount,
+
+    // --- Trivial Round Errors ---
+    #[msg("Trivial round resolution is disabled.")]
+    TrivialResolutionDisabled,
+
+    #[msg("This round needs a VRF draw; use request_draw.")]
+    TrivialRoundNeedsDraw,
+
+    // --- Entry Quote Errors ---
+    #[msg("The ticket price is above the max_price this entry accepts. Fetch a new quote.")]
+    EntryPriceAboveMax,
+
+    // --- Insurance Reserve Errors ---
+    #[msg("The insurance cut is above the maximum.")]
+    InsuranceBpsTooHigh,
+
+    #[msg("The insurance reserve account is required while payouts fund it.")]
+    InsuranceReserveRequired,
+
+    #[msg("The insurance reserve doesn't hold enough uncommitted lamports.")]
+    InsuranceReserveTooLow,
+
+    #[msg("The compensation is still timelocked.")]
+    CompensationTimelocked,
+
+    #[msg("The compensation has already been paid.")]
+    CompensationAlreadyPaid,
+
+    #[msg("A compensation must pay a non-zero amount.")]
+    InvalidCompensation,
+
+    // --- Dual Draw Errors ---
+    #[msg("The second oracle queue must be set and differ from the primary one.")]
+    InvalidSecondOracleQueue,
+
+    #[msg("This draw must also be requested from the second oracle queue.")]
+    SecondOracleQueueRequired,
+
+    #[msg("This callback comes from a queue the draw didn't ask, or one that already answered.")]
+    UnexpectedDrawSource,
+
+    // --- History Pruning Errors ---
+    #[msg("Round histories must be passed in lottery order, up to the page limit.")]
+    InvalidHistoryPage,
+
+    #[msg("This round history is still within its retention period.")]
+    HistoryStillRetained,
+
+    // --- Pot Vault Migration Errors ---
+    #[msg("The pot vault has already been migrated.")]
+    PotVaultAlreadyMigrated,
+
+    // --- Lottery State Migration Errors ---
+    #[msg("The lottery state is not in its original layout.")]
+    LotteryStateAlreadyMigrated,
+
+    // --- NFT Prize Errors ---
+    #[msg("NFT prizes must be a mint with 0 decimals and a supply of 1.")]
+    InvalidNftPrizeMint,
+    #[msg("NFT prizes can only be attached to the open round or a future one.")]
+    NftPrizeRoundClosed,
+    #[msg("This NFT prize can't be reclaimed: the round hasn't ended or it has a winner.")]
+    NftPrizeNotReclaimable,
+
+    // --- Fee Promotion Errors ---
+    #[msg("A fee promotion must end in the future, after it starts, for an open or future round, at no more than the platform fee.")]
+    InvalidFeePromotion,
+    #[msg("Entries were already paid in under this round's fee promotion, so it can't be replaced.")]
+    FeePromotionInUse,
+
+    // --- Entry Receipt Errors ---
+    #[msg("This entry nonce is not higher than one already used this round.")]
+    EntryNonceReused,
+
+    // --- Class Draw Errors ---
+    #[msg("The round's main prize must be paid before its class pots.")]
+    ClassPrizeNotSettled,
+    #[msg("This class had no pot of its own this round, or its draw isn't final yet.")]
+    NoClassPrize,
+    #[msg("This class pot was already paid.")]
+    ClassPrizeAlreadyPaid,
+    #[msg("This ticket did not win its class draw.")]
+    NotClassWinner,
+
+    // --- Sunset Errors ---
+    #[msg("The program is winding down: no new entries, rounds or draws.")]
+    ProgramSunset,
+    #[msg("Only allowed once the program has been sunset.")]
+    NotSunset,
+    #[msg("The round's tickets can't be refunded: it isn't open with tickets after a sunset.")]
+    SunsetRefundsClosed,
+    #[msg("This account still holds funds above rent, which must be claimed or refunded first.")]
+    SunsetAccountNotEmpty,
+    #[msg("Only accounts owned by the program can be closed, and the lottery state only on its own.")]
+    SunsetAccountNotClosable,
+
+    // --- Slot Deadline Errors ---
+    #[msg("The minimum round length in slots can't exceed the round duration.")]
+    InvalidMinRoundSlots,
+
+    // --- Randomness Provider Errors ---
+    #[msg("No randomness provider adapter is built for this program id.")]
+    UnsupportedRandomnessProvider,
+    #[msg("Accounts must match the configured randomness provider and queue.")]
+    InvalidRandomnessProvider,
+
+    // --- Device Commitment Errors ---
+    #[msg("This lottery limits entries per device, so entries must carry a device commitment.")]
+    DeviceCommitmentRequired,
+    #[msg("An entry with a device commitment must pass its device entries account.")]
+    DeviceEntriesRequired,
+    #[msg("This device has reached its entry limit for the round.")]
+    DeviceEntryLimitReached,
+
+    // --- Airdrop Snapshot Errors ---
+    #[msg("An airdrop snapshot covers one to sixteen rounds that have opened, each sold through its index or its Merkle tree.")]
+    InvalidSnapshotRange,
+    #[msg("This airdrop snapshot has already read every round.")]
+    SnapshotComplete,
+
+    // --- Held Token Prize Errors ---
+    #[msg("This ticket has no held token prize to claim.")]
+    NoHeldTokenPrize,
+    #[msg("The destination token account is frozen.")]
+    TokenAccountFrozen,
+}
+
+impl From<LotteryError> for HashtrologyErrors {
+    fn from(err: LotteryError) -> Self {
+        match err {
+            LotteryError::Overflow => HashtrologyErrors::Overflow,
+            LotteryError::InsufficientFunds => HashtrologyErrors::InsufficientLamports,
+            LotteryError::InvalidBasisPoints => HashtrologyErrors::InvalidBasisPoints,
+            LotteryError::RoundOver => HashtrologyErrors::LotteryIsOver,
+            LotteryError::RoundIsFull => HashtrologyErrors::RoundIsFull,
+            LotteryError::PotCapReached => HashtrologyErrors::PotCapReached,
+            LotteryError::RoundIsDrawing => HashtrologyErrors::LotteryIsDrawing,
+            LotteryError::DrawNotRequested => HashtrologyErrors::DrawNotRequested,
+            LotteryError::NoWinner => HashtrologyErrors::InvalidWinner,
+        }
+    }
+}
+
+/// `require!` that logs `key=value` context before failing with `$error`, so explorer logs
+/// show why a transaction failed and not only which check it hit.
+#[macro_export]
+macro_rules! require_ctx {
+    ($invariant:expr, $error:expr, $($context:tt)+) => {
+        if !($invariant) {
+            anchor_lang::prelude::msg!($($context)+);
+            return Err(anchor_lang::error!($error));
+        }
+    };
+}
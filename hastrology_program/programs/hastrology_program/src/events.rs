@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+
+// Every event starts with `event_seq`, taken from `LotteryState::next_event_seq`, so
+// consumers can detect gaps and backfill from transaction history, followed by the
+// `PROGRAM_VERSION` of the deployment that emitted it.
+//
+// Amounts are raw lamports, rates raw bps and times unix seconds; clients format them
+// for the user's locale, so events never carry pre-formatted text.
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LotterySoldOut {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub pot_lamports: u64,
+    pub max_pot_lamports: u64,
+    pub total_participants: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RoundReopened {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub failed_requests: u8,
+    pub new_endtime: i64,
+    pub rollover_lamports: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MerkleEntryAppended {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub index: u32,
+    pub user: Pubkey,
+    pub leaf: [u8; 32],
+    pub root: [u8; 32],
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RoundOpened {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub lottery_endtime: i64,
+    pub ticket_price: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WinnerRevealed {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub winner: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KeeperRewarded {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub keeper: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ConfigProposed {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub ticket_price: Option<u64>,
+    pub platform_fee_bps: Option<u16>,
+    pub platform_wallet: Option<Pubkey>,
+    pub lottery_endtime: Option<i64>,
+    pub max_pot_lamports: Option<u64>,
+    pub referral_bonus_bps: Option<u16>,
+    pub activates_at: i64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ConfigApplied {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub ticket_price: u64,
+    pub platform_fee_bps: u16,
+    pub platform_wallet: Pubkey,
+    pub lottery_endtime: i64,
+    pub max_pot_lamports: u64,
+    pub referral_bonus_bps: u16,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FeesForwarded {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub platform_wallet: Pubkey,
+    pub amount: u64,
+    pub total_forwarded: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PrizeFloorRollover {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub projected_prize: u64, // winner prize plus the most the fee treasury could add
+    pub min_prize_lamports: u64,
+    pub new_endtime: i64,
+    pub rollover_lamports: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DrawNonceRevealed {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub nonce: [u8; 32],
+    pub blockhash: [u8; 32],
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DrawNonceWithheld {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TicketPurchased {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub user: Pubkey,
+    pub ticket_number: u32,
+    pub price_paid: u64,
+    pub tickets_granted: u8, // the paid ticket plus any early-bird bonus
+    pub effective_price: u64, // price_paid / tickets_granted
+    pub ticket_class: Option<u8>, // None for a regular ticket
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RoundPaidOut {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub winning_ticket: u64,
+    pub winner: Pubkey,
+    pub pot_lamports: u64,
+    pub winner_prize: u64, // includes prize_top_up and streak_bonus
+    pub prize_top_up: u64,
+    pub platform_fee: u64,
+    pub platform_fee_bps: u16,
+    pub referral_bonus: u64,
+    pub rollover_lamports: u64, // left in the vault for the next round
+    pub held_for_swap: bool,
+    pub held_for_claim: bool, // the winner's address couldn't receive lamports; see claim_held_prize
+    pub win_streak: u16, // consecutive rounds won by this wallet, including this one
+    pub streak_bonus: u64, // included in winner_prize, paid by the fee treasury
+    pub prize_dust: u64, // rounded off the prize to prize_granularity and sent to the fee treasury
+    pub insurance_cut: u64, // moved from the platform share into the insurance reserve
+    pub fee_promo_lamports: u64, // part of the pot charged the promotional fee rate
+    pub fee_promo_bps: u16,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MegaJackpotPaid {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub epoch: u64,
+    pub lottery_id: u64,
+    pub ticket_index: u64, // 0-based index of the winning ticket within its round
+    pub winner: Pubkey,
+    pub prize_lamports: u64,
+    pub total_entries: u64, // tickets linked during the epoch
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VaultReconciled {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub vault_lamports: u64,
+    pub expected_lamports: i64, // ledger base plus tracked inflows minus tracked outflows
+    pub discrepancy: i64,
+    pub needs_review: bool,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClassWinnerLocated {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub winner: u64, // 1-based ticket number
+    pub ticket_class: u8,
+    pub winning_weight: u64, // drawn point in the round's class weight
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LosingTicketsClosed {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub closed: u32, // tickets in the batch that weren't already closed
+    pub lamports_returned: u64, // rent sent back to the tickets' payers
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RoundAnnounced {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub theme: String,
+    pub expected_prize_seed: u64,
+    pub opens_at: i64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CompensationProposed {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub compensation_id: u64,
+    pub lottery_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason_hash: [u8; 32],
+    pub activates_at: i64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CompensationPaid {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub compensation_id: u64,
+    pub lottery_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reserve_balance: u64, // left in the insurance reserve after this payment
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HistoryArchived {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub first_lottery_id: u64,
+    pub root: [u8; 32],
+    pub leaves: Vec<[u8; 32]>, // one per archived round, in lottery order
+    pub lamports_returned: u64, // rent of the closed histories, sent to the authority
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NftPrizeAttached {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NftPrizeReleased {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub held_prize_lamports: u64, // SOL prize paid alongside the NFT; 0 on reclaim or if payout already sent it
+    pub reclaimed: bool, // returned to the depositor because the round ended without a winner
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClassPrizePaid {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub ticket_class: u8,
+    pub winner: Pubkey,
+    pub ticket_index: u32,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProgramSunset {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub sunset_at: i64,
+    pub refundable_participants: u64, // tickets of the open round left to refund; 0 if it was drawing or finalized
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SunsetEntryRefunded {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub user: Pubkey,
+    pub entry_count: u32,
+    pub amount: u64,
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AirdropSnapshotted {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub first_lottery_id: u64,
+    pub round_count: u8,
+    pub ticket_count: u64,
+    pub root: [u8; 32],
+}
+
+#[event]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StakeShortfall {
+    pub event_seq: u64,
+    pub program_version: String,
+    pub lottery_id: u64,
+    pub staked_lamports: u64,
+    pub received_lamports: u64,
+    pub shortfall: u64,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_ROUND_THEME_LEN, PROGRAM_VERSION, UPCOMING_ROUND_SEED},
+    errors::HashtrologyErrors,
+    events::RoundAnnounced,
+    require_role,
+    state::{LotteryState, Role, UpcomingRound}
+};
+
+/// Announces the next round, or revises the announcement until it opens. Entries stay
+/// closed for it until `opens_at`, and only `open_announced_round` can open it.
+#[derive(Accounts)]
+pub struct AnnounceRound<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UpcomingRound::INIT_SPACE,
+        seeds = [UPCOMING_ROUND_SEED, &(lottery_state.current_lottery_id + 1).to_le_bytes()],
+        bump
+    )]
+    pub upcoming_round: Account<'info, UpcomingRound>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> AnnounceRound<'info> {
+    pub fn announce_round_handler(
+        &mut self,
+        theme: String,
+        expected_prize_seed: u64,
+        opens_at: i64,
+        bumps: &AnnounceRoundBumps
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+        self.lottery_state.check_not_sunset()?;
+
+        require!(
+            theme.len() <= MAX_ROUND_THEME_LEN,
+            HashtrologyErrors::RoundThemeTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            opens_at > now,
+            HashtrologyErrors::InvalidAnnouncementTime
+        );
+
+        let lottery_state = &mut self.lottery_state;
+        let lottery_id = lottery_state.current_lottery_id + 1;
+        let upcoming_round = &mut self.upcoming_round;
+
+        // A revision keeps the original announcer, who paid the rent
+        let announced_by = if upcoming_round.lottery_id == lottery_id {
+            upcoming_round.announced_by
+        } else {
+            self.authority.key()
+        };
+
+        upcoming_round.set_inner(UpcomingRound {
+            lottery_id,
+            theme: theme.clone(),
+            expected_prize_seed,
+            opens_at,
+            announced_by,
+            announced_at: now,
+            bump: bumps.upcoming_round
+        });
+
+        lottery_state.announced_opens_at = opens_at;
+
+        emit!(RoundAnnounced {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            theme,
+            expected_prize_seed,
+            opens_at,
+        });
+
+        msg!("Lottery #{} announced to open at {}", lottery_id, opens_at);
+
+        Ok(())
+    }
+}
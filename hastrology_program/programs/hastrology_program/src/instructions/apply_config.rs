@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::ConfigApplied,
+    state::{AuditAction, AuditLog, LotteryState}
+};
+
+/// Permissionless: once the timelock has passed anyone can apply the change everyone has already seen.
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+}
+
+impl<'info> ApplyConfig<'info> {
+    pub fn apply_config_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        let pending = lottery_state.pending_config
+            .take()
+            .ok_or(HashtrologyErrors::NoPendingConfig)?;
+
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= pending.activates_at,
+            HashtrologyErrors::ConfigTimelocked
+        );
+
+        if let Some(price) = pending.ticket_price {
+            msg!("Updating ticket price from {} to {}", lottery_state.ticket_price, price);
+            lottery_state.ticket_price = price;
+        }
+
+        if let Some(fee_bps) = pending.platform_fee_bps {
+            // Fee governance may have lowered the ceiling since the proposal
+            require!(
+                fee_bps <= lottery_state.max_platform_fee_bps(clock.unix_timestamp),
+                HashtrologyErrors::FeeAboveCeiling
+            );
+
+            msg!("Updating platform fee from {} to {} bps", lottery_state.platform_fee_bps, fee_bps);
+            lottery_state.platform_fee_bps = fee_bps;
+        }
+
+        if let Some(wallet) = pending.platform_wallet {
+            msg!("Updating platform wallet to {}", wallet);
+            lottery_state.platform_wallet = wallet;
+        }
+
+        // Checked against the proposal's activation time, but application may come later
+        if let Some(endtime) = pending.lottery_endtime {
+            require!(
+                endtime > clock.unix_timestamp,
+                HashtrologyErrors::InvalidEndtime
+            );
+            msg!("Updating lottery endtime from {} to {}", lottery_state.lottery_endtime, endtime);
+            lottery_state.set_endtime(endtime, &clock);
+        }
+
+        // 0 removes the cap
+        if let Some(max_pot) = pending.max_pot_lamports {
+            msg!("Updating max pot from {} to {} lamports", lottery_state.max_pot_lamports, max_pot);
+            lottery_state.max_pot_lamports = max_pot;
+        }
+
+        if let Some(bonus_bps) = pending.referral_bonus_bps {
+            msg!("Updating referral bonus from {} to {} bps", lottery_state.referral_bonus_bps, bonus_bps);
+            lottery_state.referral_bonus_bps = bonus_bps;
+        }
+
+        // Hash the effective config so the log captures the result, not just the deltas
+        self.audit_log.load_mut()?.record(
+            AuditAction::UpdateConfig,
+            self.caller.key(),
+            &[
+                &lottery_state.ticket_price.to_le_bytes(),
+                &lottery_state.platform_fee_bps.to_le_bytes(),
+                lottery_state.platform_wallet.as_ref(),
+                &lottery_state.lottery_endtime.to_le_bytes(),
+                &lottery_state.max_pot_lamports.to_le_bytes(),
+                &lottery_state.referral_bonus_bps.to_le_bytes(),
+            ]
+        )?;
+
+        emit!(ConfigApplied {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            ticket_price: lottery_state.ticket_price,
+            platform_fee_bps: lottery_state.platform_fee_bps,
+            platform_wallet: lottery_state.platform_wallet,
+            lottery_endtime: lottery_state.lottery_endtime,
+            max_pot_lamports: lottery_state.max_pot_lamports,
+            referral_bonus_bps: lottery_state.referral_bonus_bps,
+        });
+
+        msg!("Config updated successfully");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DRAW_ATTESTATION_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    require_ctx,
+    state::{DrawAttestation, LotteryState}
+};
+
+#[derive(Accounts)]
+pub struct ApproveDraw<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + DrawAttestation::INIT_SPACE,
+        seeds = [DRAW_ATTESTATION_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub draw_attestation: Account<'info, DrawAttestation>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ApproveDraw<'info> {
+    pub fn approve_draw_handler(&mut self, bumps: &ApproveDrawBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        let attestation = &mut self.draw_attestation;
+        let clock = Clock::get()?;
+
+        require_ctx!(
+            lottery_state.has_ended(lottery_state.lottery_endtime, &clock),
+            HashtrologyErrors::LotteryNotOver,
+            "expected_end={} now={} end_slot={} slot={}",
+            lottery_state.lottery_endtime, clock.unix_timestamp, lottery_state.end_slot, clock.slot
+        );
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::DrawAlreadyRequested
+        );
+
+        let operator_index = lottery_state
+            .draw_operators
+            .iter()
+            .position(|operator| *operator == self.operator.key())
+            .ok_or(HashtrologyErrors::UnauthorizedOperator)?;
+
+        let mask = 1u8 << operator_index;
+
+        require!(
+            attestation.approvals & mask == 0,
+            HashtrologyErrors::DrawAlreadyApproved
+        );
+
+        attestation.lottery_id = lottery_state.current_lottery_id;
+        attestation.approvals |= mask;
+        attestation.approval_count = attestation.approval_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        attestation.bump = bumps.draw_attestation;
+
+        msg!(
+            "Draw for lottery #{} approved by {} ({}/{})",
+            lottery_state.current_lottery_id,
+            self.operator.key(),
+            attestation.approval_count,
+            lottery_state.draw_threshold
+        );
+
+        Ok(())
+    }
+}
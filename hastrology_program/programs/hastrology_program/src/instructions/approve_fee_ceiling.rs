@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_CEILING_TIMELOCK, LOTTERY_STATE_SEED, MAX_PLATFORM_FEE_BPS},
+    errors::HashtrologyErrors,
+    state::LotteryState
+};
+
+#[derive(Accounts)]
+pub struct ApproveFeeCeiling<'info> {
+    #[account(
+        constraint = fee_governance.key() == lottery_state.fee_governance @ HashtrologyErrors::UnauthorizedFeeGovernance
+    )]
+    pub fee_governance: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> ApproveFeeCeiling<'info> {
+    /// Raising the ceiling only takes effect after `FEE_CEILING_TIMELOCK`; lowering it applies at once
+    /// and clamps the current fee. `ceiling_bps` at or under the soft cap withdraws the approval.
+    pub fn approve_fee_ceiling_handler(&mut self, ceiling_bps: u16) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            ceiling_bps <= MAX_PLATFORM_FEE_BPS,
+            HashtrologyErrors::InvalidPlatformFee
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let raising = ceiling_bps > lottery_state.max_platform_fee_bps(now);
+
+        lottery_state.fee_ceiling_bps = ceiling_bps;
+        lottery_state.fee_ceiling_activates_at = if raising {
+            now.checked_add(FEE_CEILING_TIMELOCK).ok_or(HashtrologyErrors::Overflow)?
+        } else {
+            now
+        };
+
+        // A withdrawn or lowered ceiling also brings a fee already above it back down
+        let max_fee_bps = lottery_state.max_platform_fee_bps(now);
+
+        if !raising && lottery_state.platform_fee_bps > max_fee_bps {
+            msg!("Lowering platform fee from {} to {} bps", lottery_state.platform_fee_bps, max_fee_bps);
+            lottery_state.platform_fee_bps = max_fee_bps;
+        }
+
+        msg!(
+            "Fee ceiling set to {} bps from {}",
+            ceiling_bps,
+            lottery_state.fee_ceiling_activates_at
+        );
+
+        Ok(())
+    }
+}
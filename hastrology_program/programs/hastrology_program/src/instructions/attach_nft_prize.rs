@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, NFT_PRIZE_ESCROW_SEED, NFT_PRIZE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::NftPrizeAttached,
+    require_role,
+    state::{LotteryState, NftPrize, Role}
+};
+
+/// Escrows an NFT as an extra prize for round `lottery_id`, on top of its SOL pot. The
+/// winner takes it with `claim_nft_prize`; if the round ends without a winner the
+/// depositor takes it back with `reclaim_nft_prize`.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct AttachNftPrize<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = nft_mint.decimals == 0 && nft_mint.supply == 1 @ HashtrologyErrors::InvalidNftPrizeMint
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NftPrize::INIT_SPACE,
+        seeds = [NFT_PRIZE_SEED, &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub nft_prize: Account<'info, NftPrize>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [NFT_PRIZE_ESCROW_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        token::mint = nft_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub nft_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> AttachNftPrize<'info> {
+    pub fn attach_nft_prize_handler(&mut self, lottery_id: u64, bumps: &AttachNftPrizeBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &self.lottery_state;
+
+        // Players of the current round may already have entered, but not once the draw started
+        require!(
+            lottery_id > lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id
+                    && !lottery_state.is_drawing
+                    && !lottery_state.round_finalized),
+            HashtrologyErrors::NftPrizeRoundClosed
+        );
+
+        let accounts = TransferChecked {
+            from: self.depositor_token_account.to_account_info(),
+            mint: self.nft_mint.to_account_info(),
+            to: self.nft_escrow.to_account_info(),
+            authority: self.authority.to_account_info()
+        };
+
+        transfer_checked(
+            CpiContext::new(self.token_program.to_account_info(), accounts),
+            1,
+            self.nft_mint.decimals
+        )?;
+
+        self.nft_prize.set_inner(NftPrize {
+            lottery_id,
+            mint: self.nft_mint.key(),
+            depositor: self.authority.key(),
+            attached_at: Clock::get()?.unix_timestamp,
+            bump: bumps.nft_prize
+        });
+
+        emit!(NftPrizeAttached {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            mint: self.nft_mint.key(),
+            depositor: self.authority.key(),
+        });
+
+        msg!("NFT {} attached as a prize for lottery #{}", self.nft_mint.key(), lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{COMPENSATION_SEED, INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{Compensation, InsuranceReserve, LotteryState, Role}
+};
+
+/// Withdraws an unpaid compensation, releasing its lamports back to the reserve.
+#[derive(Accounts)]
+#[instruction(compensation_id: u64)]
+pub struct CancelCompensation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump = insurance_reserve.bump
+    )]
+    pub insurance_reserve: Account<'info, InsuranceReserve>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [COMPENSATION_SEED, &compensation_id.to_le_bytes()],
+        bump = compensation.bump
+    )]
+    pub compensation: Account<'info, Compensation>,
+}
+
+impl<'info> CancelCompensation<'info> {
+    pub fn cancel_compensation_handler(&mut self, compensation_id: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(self.compensation.paid_at == 0, HashtrologyErrors::CompensationAlreadyPaid);
+
+        self.insurance_reserve.release(self.compensation.amount);
+
+        msg!("Compensation #{} cancelled", compensation_id);
+
+        Ok(())
+    }
+}
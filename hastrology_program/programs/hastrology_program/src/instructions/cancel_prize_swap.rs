@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PRIZE_SWAP_SEED,
+    errors::HashtrologyErrors,
+    state::{PrizeSwap, UserTicket}
+};
+
+#[derive(Accounts)]
+pub struct CancelPrizeSwap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        has_one = user @ HashtrologyErrors::Unauthorized
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [PRIZE_SWAP_SEED, user_ticket.key().as_ref()],
+        bump = prize_swap.bump,
+        constraint = prize_swap.lamports == 0 @ HashtrologyErrors::PrizeSwapLocked
+    )]
+    pub prize_swap: Account<'info, PrizeSwap>,
+}
+
+impl<'info> CancelPrizeSwap<'info> {
+    pub fn cancel_prize_swap_handler(&mut self) -> Result<()> {
+        msg!(
+            "Prize swap for lottery #{} ticket cancelled",
+            self.user_ticket.lottery_id
+        );
+
+        Ok(())
+    }
+}
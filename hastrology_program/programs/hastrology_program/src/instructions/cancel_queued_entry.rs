@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::QUEUED_ENTRY_SEED,
+    state::QueuedEntry
+};
+
+#[derive(Accounts)]
+pub struct CancelQueuedEntry<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [QUEUED_ENTRY_SEED, user.key().as_ref(), &queued_entry.lottery_id.to_le_bytes()],
+        bump = queued_entry.bump
+    )]
+    pub queued_entry: Account<'info, QueuedEntry>,
+}
+
+impl<'info> CancelQueuedEntry<'info> {
+    pub fn cancel_queued_entry_handler(&mut self) -> Result<()> {
+        msg!(
+            "Queued entry for lottery #{} cancelled. {} lamports refunded.",
+            self.queued_entry.lottery_id,
+            self.queued_entry.amount
+        );
+
+        Ok(())
+    }
+}
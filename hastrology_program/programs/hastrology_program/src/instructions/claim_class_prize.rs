@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_TICKET_CLASSES, POT_VAULT_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    events::ClassPrizePaid,
+    lamports::{can_receive_lamports, credit_lamports, debit_lamports},
+    state::{LotteryState, RoundHistory, UserTicket}
+};
+
+/// Permissionless: once a round with class draws is paid out, anyone may pay a class pot to
+/// its winning ticket, found off-chain from `RoundHistory::class_winner` and the tickets'
+/// `class_weight_start`.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct ClaimClassPrize<'info> {
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: Checked against the winning ticket's payout address.
+    #[account(
+        mut,
+        address = winning_ticket.payout_address() @ HashtrologyErrors::InvalidPayoutDestination
+    )]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> ClaimClassPrize<'info> {
+    pub fn claim_class_prize_handler(&mut self, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let round_history = &mut self.round_history;
+        let winning_ticket = &self.winning_ticket;
+
+        // Class pots move to `class_prize_lamports` when payout finalizes the round
+        require!(
+            lottery_id < lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id && lottery_state.round_finalized),
+            HashtrologyErrors::ClassPrizeNotSettled
+        );
+
+        let class_index = winning_ticket.ticket_class.ok_or(HashtrologyErrors::NotClassWinner)? as usize;
+
+        require!(
+            class_index < MAX_TICKET_CLASSES && round_history.class_prizes_paid & (1 << class_index) == 0,
+            HashtrologyErrors::ClassPrizeAlreadyPaid
+        );
+
+        let winning_position = round_history.class_winner(class_index).ok_or(HashtrologyErrors::NoClassPrize)?;
+
+        require!(
+            winning_ticket.class_weight_start == winning_position,
+            HashtrologyErrors::NotClassWinner
+        );
+
+        require!(
+            can_receive_lamports(&self.recipient),
+            HashtrologyErrors::RecipientCannotReceiveLamports
+        );
+
+        let amount = round_history.class_pots[class_index];
+
+        debit_lamports(&self.pot_vault, amount)?;
+        credit_lamports(&self.recipient, amount)?;
+        lottery_state.record_vault_outflow(amount)?;
+
+        lottery_state.class_prize_lamports = lottery_state.class_prize_lamports
+            .checked_sub(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+        round_history.class_prizes_paid |= 1 << class_index;
+
+        emit!(ClassPrizePaid {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            ticket_class: class_index as u8,
+            winner: winning_ticket.user,
+            ticket_index,
+            recipient: self.recipient.key(),
+            amount,
+        });
+
+        msg!("Class {} pot of {} lamports for lottery #{} paid to ticket {}", class_index, amount, lottery_id, ticket_index + 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LUCKY_SIGN_PICK_SEED, LUCKY_SIGN_ROUND_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{ClaimBitmap, LuckySignPick, LuckySignRound, UserTicket},
+    ID
+};
+
+/// Permissionless batch claim. Remaining accounts are `(pick, ticket, wallet)` triples,
+/// all writable, where `wallet` is the ticket's payout address.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct ClaimCoWinnerPrizes<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LUCKY_SIGN_ROUND_SEED, &lottery_id.to_le_bytes()],
+        bump = lucky_sign_round.bump
+    )]
+    pub lucky_sign_round: Account<'info, LuckySignRound>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump = claim_bitmap.bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+}
+
+impl<'info> ClaimCoWinnerPrizes<'info> {
+    pub fn claim_co_winner_prizes_handler(
+        &mut self,
+        lottery_id: u64,
+        remaining_accounts: &'info [AccountInfo<'info>]
+    ) -> Result<()> {
+        let round = &mut self.lucky_sign_round;
+
+        require!(
+            round.is_settled,
+            HashtrologyErrors::CoWinnersNotSettled
+        );
+
+        require!(
+            !remaining_accounts.is_empty() && remaining_accounts.len().is_multiple_of(3),
+            HashtrologyErrors::InvalidCoWinnerAccounts
+        );
+
+        let share_lamports = round.share_lamports;
+
+        for claim in remaining_accounts.chunks(3) {
+            let mut pick: Account<LuckySignPick> = Account::try_from(&claim[0])?;
+            let mut ticket: Account<UserTicket> = Account::try_from(&claim[1])?;
+            let wallet = &claim[2];
+
+            let (pick_address, _) = Pubkey::find_program_address(
+                &[LUCKY_SIGN_PICK_SEED, &lottery_id.to_le_bytes(), &pick.ticket_index.to_le_bytes()],
+                &ID
+            );
+            let (ticket_address, _) = Pubkey::find_program_address(
+                &[USER_TICKET_SEED, &lottery_id.to_le_bytes(), &pick.ticket_index.to_le_bytes()],
+                &ID
+            );
+
+            require!(
+                pick.key() == pick_address && ticket.key() == ticket_address,
+                HashtrologyErrors::InvalidCoWinnerAccounts
+            );
+
+            require!(
+                pick.sign == round.winning_sign && !pick.is_claimed,
+                HashtrologyErrors::InvalidCoWinnerClaim
+            );
+
+            require!(
+                wallet.key() == ticket.payout_address(),
+                HashtrologyErrors::InvalidWinner
+            );
+
+            debit_lamports(&round.to_account_info(), share_lamports)?;
+            credit_lamports(wallet, share_lamports)?;
+
+            ClaimBitmap::mark_claimed(&mut self.claim_bitmap, pick.ticket_index)?;
+
+            pick.is_claimed = true;
+            ticket.is_winner = true;
+            ticket.prize_amount = share_lamports;
+
+            pick.exit(&ID)?;
+            ticket.exit(&ID)?;
+
+            round.claimed_count = round.claimed_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        msg!(
+            "Lottery #{}: {} of {} co-winner prizes claimed",
+            lottery_id,
+            round.claimed_count,
+            round.winner_count()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{can_receive_lamports, credit_lamports, debit_lamports},
+    state::{LotteryState, UserTicket}
+};
+
+/// Pays a prize that `payout` held back, because the payout address couldn't receive
+/// lamports, to a wallet the winner names.
+#[derive(Accounts)]
+pub struct ClaimHeldPrize<'info> {
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.held_prize_lamports > 0 @ HashtrologyErrors::NoHeldPrize
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: Any wallet the winner chooses; checked to be able to receive lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> ClaimHeldPrize<'info> {
+    pub fn claim_held_prize_handler(&mut self) -> Result<()> {
+        require!(
+            can_receive_lamports(&self.recipient),
+            HashtrologyErrors::RecipientCannotReceiveLamports
+        );
+
+        let amount = self.winning_ticket.held_prize_lamports;
+
+        debit_lamports(&self.pot_vault, amount)?;
+        credit_lamports(&self.recipient, amount)?;
+        self.lottery_state.record_vault_outflow(amount)?;
+
+        self.winning_ticket.held_prize_lamports = 0;
+        self.lottery_state.held_prize_lamports = self.lottery_state.held_prize_lamports
+            .checked_sub(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        msg!(
+            "Held prize of {} lamports for lottery #{} claimed to {}",
+            amount,
+            self.winning_ticket.lottery_id,
+            self.recipient.key()
+        );
+
+        Ok(())
+    }
+}
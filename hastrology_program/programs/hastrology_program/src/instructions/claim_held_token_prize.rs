@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PRIZE_VAULT_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, UserTicket}
+};
+
+/// Pays a token prize that `claim_prize` held back, because the recipient's token account was
+/// frozen, to any unfrozen token account of the prize mint the winner names.
+#[derive(Accounts)]
+pub struct ClaimHeldTokenPrize<'info> {
+    pub winner: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump,
+        has_one = prize_mint @ HashtrologyErrors::InvalidPrizeMint
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.held_token_prize > 0 @ HashtrologyErrors::NoHeldTokenPrize
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    pub prize_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED, prize_mint.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = prize_mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>
+}
+
+impl<'info> ClaimHeldTokenPrize<'info> {
+    pub fn claim_held_token_prize_handler(&mut self) -> Result<()> {
+        require!(
+            !self.recipient_token_account.is_frozen(),
+            HashtrologyErrors::TokenAccountFrozen
+        );
+
+        let amount = self.winning_ticket.held_token_prize;
+
+        let seeds = &[LOTTERY_STATE_SEED, &[self.lottery_state.lottery_state_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = TransferChecked {
+            from: self.prize_vault.to_account_info(),
+            mint: self.prize_mint.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.prize_mint.decimals)?;
+
+        self.winning_ticket.held_token_prize = 0;
+
+        msg!(
+            "Held token prize of {} for lottery #{} claimed to {}",
+            amount,
+            self.winning_ticket.lottery_id,
+            self.recipient_token_account.key()
+        );
+
+        Ok(())
+    }
+}
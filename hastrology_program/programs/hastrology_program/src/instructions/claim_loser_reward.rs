@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface}
+};
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LOTTERY_STATE_SEED, REWARD_MINT_AUTHORITY_SEED, ROUND_HISTORY_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    state::{ClaimBitmap, LotteryState, RoundHistory, UserTicket}
+};
+
+/// Mints the round's reward-token rebate to a losing ticket's owner once the round is paid
+/// out. Each ticket claims once, tracked in the round's claim bitmap.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct ClaimLoserReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        seeds = [ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()],
+        bump = round_history.bump,
+        constraint = round_history.loser_reward_amount > 0 @ HashtrologyErrors::NoLoserReward
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump,
+        constraint = user_ticket.user == user.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = !user_ticket.is_winner @ HashtrologyErrors::NotALosingTicket
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump = claim_bitmap.bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+
+    /// CHECK: PDA holding the reward mint's authority.
+    #[account(
+        seeds = [REWARD_MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub reward_mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = round_history.reward_mint @ HashtrologyErrors::NoLoserReward
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = reward_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimLoserReward<'info> {
+    pub fn claim_loser_reward_handler(&mut self, lottery_id: u64, ticket_index: u32, bumps: &ClaimLoserRewardBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        // The winner is only marked on its ticket at payout
+        require!(
+            lottery_id < lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id && lottery_state.round_finalized),
+            HashtrologyErrors::RoundStillOpen
+        );
+
+        // Entries after a hidden anti-snipe end were refunded instead of drawn
+        require!(
+            (ticket_index as u64) < self.round_history.total_participants,
+            HashtrologyErrors::NotALosingTicket
+        );
+
+        ClaimBitmap::mark_claimed(&mut self.claim_bitmap, ticket_index)?;
+
+        let amount = self.round_history.loser_reward_amount;
+        let seeds = &[REWARD_MINT_AUTHORITY_SEED, &[bumps.reward_mint_authority]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = MintTo {
+            mint: self.reward_mint.to_account_info(),
+            to: self.user_token_account.to_account_info(),
+            authority: self.reward_mint_authority.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds);
+
+        mint_to(cpi_ctx, amount)?;
+
+        msg!(
+            "Rebate of {} reward tokens minted to {} for ticket {} of lottery #{}",
+            amount,
+            self.user.key(),
+            ticket_index,
+            lottery_id
+        );
+
+        Ok(())
+    }
+}
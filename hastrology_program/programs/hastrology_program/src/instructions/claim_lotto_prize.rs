@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LOTTO_PICK_SEED, LOTTO_ROUND_SEED, USER_TICKET_SEED, WINNER_RECORD_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{ClaimBitmap, LottoPick, LottoRound, UserTicket, WinnerRecord}
+};
+
+/// Permissionless; the prize always goes to the ticket's payout address. The payer covers
+/// the winner record's rent.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct ClaimLottoPrize<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTO_ROUND_SEED, &lottery_id.to_le_bytes()],
+        bump = lotto_round.bump,
+        constraint = lotto_round.is_finalized @ HashtrologyErrors::LottoNotFinalized
+    )]
+    pub lotto_round: Account<'info, LottoRound>,
+
+    #[account(
+        mut,
+        seeds = [LOTTO_PICK_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump = lotto_pick.bump,
+        constraint = lotto_pick.is_registered && !lotto_pick.is_claimed @ HashtrologyErrors::InvalidLottoClaim
+    )]
+    pub lotto_pick: Account<'info, LottoPick>,
+
+    #[account(
+        mut,
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: The ticket's payout address
+    #[account(
+        mut,
+        constraint = wallet.key() == user_ticket.payout_address() @ HashtrologyErrors::InvalidWinner
+    )]
+    pub wallet: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump = claim_bitmap.bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+
+    /// Shared by all of the wallet's winning tickets in the round.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WinnerRecord::INIT_SPACE,
+        seeds = [WINNER_RECORD_SEED, user_ticket.user.as_ref(), &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub winner_record: Account<'info, WinnerRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimLottoPrize<'info> {
+    pub fn claim_lotto_prize_handler(&mut self, lottery_id: u64, ticket_index: u32, bumps: &ClaimLottoPrizeBumps) -> Result<()> {
+        let round = &mut self.lotto_round;
+        let share = round.tier_shares[self.lotto_pick.tier as usize];
+
+        debit_lamports(&round.to_account_info(), share)?;
+        credit_lamports(&self.wallet, share)?;
+
+        ClaimBitmap::mark_claimed(&mut self.claim_bitmap, ticket_index)?;
+
+        self.lotto_pick.is_claimed = true;
+        self.user_ticket.is_winner = true;
+        self.user_ticket.prize_amount = share;
+
+        self.winner_record.record(
+            self.user_ticket.user,
+            lottery_id,
+            share,
+            Clock::get()?.unix_timestamp,
+            bumps.winner_record
+        )?;
+
+        round.claimed_count = round.claimed_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        msg!("Lottery #{} ticket {} claimed {} lamports", lottery_id, ticket_index, share);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_TREASURY_SEED, LOTTERY_STATE_SEED, MERKLE_TREE_SEED, POT_VAULT_SEED, WINNER_RECORD_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    math::round_down,
+    state::{FeeTreasury, LotteryState, MerkleEntryTree, WinnerRecord}
+};
+
+#[derive(Accounts)]
+pub struct ClaimMerklePrize<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    #[account(
+        seeds = [MERKLE_TREE_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub merkle_tree: AccountLoader<'info, MerkleEntryTree>,
+
+    #[account(
+        init,
+        payer = winner,
+        space = 8 + WinnerRecord::INIT_SPACE,
+        seeds = [WINNER_RECORD_SEED, winner.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub winner_record: Account<'info, WinnerRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimMerklePrize<'info> {
+    pub fn claim_merkle_prize_handler(&mut self, proof: Vec<[u8; 32]>, bumps: &ClaimMerklePrizeBumps) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.merkle_entry_mode,
+            HashtrologyErrors::MerkleEntryModeDisabled
+        );
+
+        require!(
+            lottery_state.is_drawing && lottery_state.winner > 0,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        require!(
+            !lottery_state.has_pending_reveal(),
+            HashtrologyErrors::WinnerNotRevealed
+        );
+
+        let index = (lottery_state.winner - 1) as u32;
+        let leaf = MerkleEntryTree::leaf(&self.winner.key(), lottery_state.current_lottery_id, index);
+
+        require!(
+            self.merkle_tree.load()?.verify(leaf, index as u64, &proof),
+            HashtrologyErrors::InvalidMerkleProof
+        );
+
+        let total_pot_balance = self.pot_vault
+            .lamports()
+            .checked_sub(lottery_state.reserved_referral_lamports)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        let (platform_fee, unrounded_prize) = lottery_state.split_round_fee(total_pot_balance)?;
+        let (winner_prize_amount, prize_dust) = round_down(unrounded_prize, lottery_state.prize_granularity);
+        let platform_fee_amount = platform_fee.checked_add(prize_dust).ok_or(HashtrologyErrors::Overflow)?;
+
+        debit_lamports(&self.pot_vault, platform_fee_amount)?;
+        credit_lamports(&self.fee_treasury.to_account_info(), platform_fee_amount)?;
+        self.fee_treasury.accrue(platform_fee_amount)?;
+        lottery_state.record_vault_outflow(platform_fee_amount)?;
+
+        debit_lamports(&self.pot_vault, winner_prize_amount)?;
+        credit_lamports(&self.winner, winner_prize_amount)?;
+        lottery_state.record_vault_outflow(winner_prize_amount)?;
+
+        self.winner_record.record(
+            self.winner.key(),
+            lottery_state.current_lottery_id,
+            winner_prize_amount,
+            Clock::get()?.unix_timestamp,
+            bumps.winner_record
+        )?;
+
+        lottery_state.total_participants = 0;
+        lottery_state.current_lottery_id = lottery_state.current_lottery_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        let endtime = lottery_state.lottery_endtime.checked_add(86400).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.set_endtime(endtime, &Clock::get()?);
+        lottery_state.is_drawing = false;
+        lottery_state.commit_slot = 0;
+
+        msg!(
+            "Lottery #{} claimed by Merkle proof! Winner: {}. Prize: {} lamports.",
+            lottery_state.current_lottery_id - 1,
+            self.winner.key(),
+            winner_prize_amount
+        );
+
+        Ok(())
+    }
+}
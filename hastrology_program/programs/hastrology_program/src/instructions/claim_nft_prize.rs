@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, NFT_PRIZE_ESCROW_SEED, NFT_PRIZE_SEED, POT_VAULT_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::NftPrizeReleased,
+    lamports::{can_receive_lamports, credit_lamports, debit_lamports},
+    state::{LotteryState, NftPrize, UserTicket}
+};
+
+/// Hands a round's prize bundle to its winner once `payout` has marked the ticket: the
+/// escrowed NFT goes to the ticket's payout address, and a SOL prize that `payout` had to
+/// hold back goes to the winner with it. Escrow and record rent return to the depositor.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct ClaimNftPrize<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.is_winner @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.lottery_id == lottery_id @ HashtrologyErrors::InvalidWinner
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        mut,
+        seeds = [NFT_PRIZE_SEED, &lottery_id.to_le_bytes()],
+        bump = nft_prize.bump,
+        has_one = depositor,
+        close = depositor
+    )]
+    pub nft_prize: Account<'info, NftPrize>,
+
+    /// CHECK: Receives the rent of the prize record and escrow; checked against `nft_prize`.
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        address = nft_prize.mint @ HashtrologyErrors::InvalidNftPrizeMint
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [NFT_PRIZE_ESCROW_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        token::mint = nft_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub nft_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The ticket's payout address; the winner unless they set a payout destination.
+    #[account(
+        address = winning_ticket.payout_address() @ HashtrologyErrors::InvalidPayoutDestination
+    )]
+    pub prize_recipient: UncheckedAccount<'info>,
+
+    /// The prize recipient's associated token account for the NFT; created on the fly if missing.
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = prize_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimNftPrize<'info> {
+    pub fn claim_nft_prize_handler(&mut self, lottery_id: u64) -> Result<()> {
+        let seeds = &[LOTTERY_STATE_SEED, &[self.lottery_state.lottery_state_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = TransferChecked {
+            from: self.nft_escrow.to_account_info(),
+            mint: self.nft_mint.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+        transfer_checked(
+            CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds),
+            1,
+            self.nft_mint.decimals
+        )?;
+
+        let accounts = CloseAccount {
+            account: self.nft_escrow.to_account_info(),
+            destination: self.depositor.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+        close_account(CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds))?;
+
+        // Normally payout already sent the SOL prize; only a held one is still in the vault
+        let held = self.winning_ticket.held_prize_lamports;
+        if held > 0 {
+            require!(
+                can_receive_lamports(&self.winner),
+                HashtrologyErrors::RecipientCannotReceiveLamports
+            );
+
+            debit_lamports(&self.pot_vault, held)?;
+            credit_lamports(&self.winner, held)?;
+            self.lottery_state.record_vault_outflow(held)?;
+
+            self.winning_ticket.held_prize_lamports = 0;
+            self.lottery_state.held_prize_lamports = self.lottery_state.held_prize_lamports
+                .checked_sub(held)
+                .ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        emit!(NftPrizeReleased {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            mint: self.nft_mint.key(),
+            recipient: self.prize_recipient.key(),
+            held_prize_lamports: held,
+            reclaimed: false,
+        });
+
+        msg!(
+            "NFT prize {} for lottery #{} claimed to {} with {} held lamports",
+            self.nft_mint.key(),
+            lottery_id,
+            self.prize_recipient.key(),
+            held
+        );
+
+        Ok(())
+    }
+}
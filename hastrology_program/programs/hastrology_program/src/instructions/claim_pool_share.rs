@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{POOL_MEMBER_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{EntryPool, PoolMember, UserTicket}
+};
+
+#[derive(Accounts)]
+pub struct ClaimPoolShare<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub entry_pool: Account<'info, EntryPool>,
+
+    #[account(
+        mut,
+        has_one = member @ HashtrologyErrors::Unauthorized,
+        seeds = [POOL_MEMBER_SEED, entry_pool.key().as_ref(), member.key().as_ref()],
+        bump = pool_member.bump
+    )]
+    pub pool_member: Account<'info, PoolMember>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &entry_pool.lottery_id.to_le_bytes(), &entry_pool.ticket_index.to_le_bytes()],
+        bump,
+        constraint = pool_ticket.user == entry_pool.key() @ HashtrologyErrors::InvalidWinner
+    )]
+    pub pool_ticket: Account<'info, UserTicket>,
+}
+
+impl<'info> ClaimPoolShare<'info> {
+    pub fn claim_pool_share_handler(&mut self) -> Result<()> {
+        let entry_pool = &self.entry_pool;
+        let pool_member = &mut self.pool_member;
+
+        require!(
+            entry_pool.is_entered && self.pool_ticket.is_winner,
+            HashtrologyErrors::InvalidWinner
+        );
+
+        require!(
+            !pool_member.has_claimed,
+            HashtrologyErrors::PrizeAlreadyClaimed
+        );
+
+        let share = pool_member
+            .share_of(self.pool_ticket.prize_amount, entry_pool.total_contributed)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        pool_member.has_claimed = true;
+
+        debit_lamports(&entry_pool.to_account_info(), share)?;
+        credit_lamports(&self.member, share)?;
+
+        msg!(
+            "{} claimed {} lamports from pool {}",
+            self.member.key(),
+            share,
+            entry_pool.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PRIZE_VAULT_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, UserTicket}
+};
+
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump,
+        has_one = prize_mint @ HashtrologyErrors::InvalidPrizeMint
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.is_winner @ HashtrologyErrors::InvalidWinner,
+        constraint = !winning_ticket.is_claimed @ HashtrologyErrors::PrizeAlreadyClaimed
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    pub prize_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The ticket's payout address; the winner unless they set a payout destination.
+    #[account(
+        address = winning_ticket.payout_address() @ HashtrologyErrors::InvalidPayoutDestination
+    )]
+    pub prize_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PRIZE_VAULT_SEED, prize_mint.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The prize recipient's associated token account for the prize mint; created on the fly if missing
+    /// or closed. If it is frozen, the prize is held for `claim_held_token_prize` instead.
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = prize_mint,
+        associated_token::authority = prize_recipient,
+        associated_token::token_program = token_program
+    )]
+    pub winner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimPrize<'info> {
+    pub fn claim_prize_handler(&mut self) -> Result<()> {
+        let amount = self.winning_ticket.token_prize_amount;
+
+        require!(
+            amount > 0,
+            HashtrologyErrors::NoTokenPrize
+        );
+
+        self.winning_ticket.is_claimed = true;
+
+        // A transfer into a frozen account would fail every retry, so the prize stays in the vault
+        if self.winner_token_account.is_frozen() {
+            self.winning_ticket.held_token_prize = amount;
+
+            msg!(
+                "Token account of {} is frozen; token prize of {} for lottery #{} held for claim_held_token_prize",
+                self.prize_recipient.key(),
+                amount,
+                self.winning_ticket.lottery_id
+            );
+
+            return Ok(());
+        }
+
+        let seeds = &[LOTTERY_STATE_SEED, &[self.lottery_state.lottery_state_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = TransferChecked {
+            from: self.prize_vault.to_account_info(),
+            mint: self.prize_mint.to_account_info(),
+            to: self.winner_token_account.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.prize_mint.decimals)?;
+
+        msg!(
+            "Token prize of {} claimed by {} to {} for lottery #{}",
+            amount,
+            self.winner.key(),
+            self.prize_recipient.key(),
+            self.winning_ticket.lottery_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::invoke,
+        stake::{
+            self,
+            instruction::{delegate_stake, initialize},
+            state::{Authorized, Lockup, StakeStateV2}
+        },
+        sysvar
+    },
+    system_program::{create_account, CreateAccount}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, UserTicket}
+};
+
+/// Moves a prize `payout` held for a stake payout into a new stake account, with the
+/// winner as staker and withdrawer, delegated to the validator they chose. The winner
+/// pays the account's rent so the whole prize is delegated.
+#[derive(Accounts)]
+pub struct ClaimPrizeAsStake<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.held_prize_lamports > 0 @ HashtrologyErrors::NoHeldPrize
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    /// A fresh keypair; created here as the stake account.
+    #[account(mut)]
+    pub stake_account: Signer<'info>,
+
+    /// CHECK: The vote account the winner chose; the stake program validates it on delegation.
+    #[account(
+        constraint = winning_ticket.stake_vote_account == Some(vote_account.key()) @ HashtrologyErrors::InvalidVoteAccount
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: Rent sysvar
+    #[account(address = sysvar::rent::ID)]
+    pub rent: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Stake config account
+    #[account(address = stake::config::ID)]
+    pub stake_config: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimPrizeAsStake<'info> {
+    pub fn claim_prize_as_stake_handler(&mut self) -> Result<()> {
+        let amount = self.winning_ticket.held_prize_lamports;
+        let space = StakeStateV2::size_of();
+
+        let accounts = CreateAccount {
+            from: self.winner.to_account_info(),
+            to: self.stake_account.to_account_info()
+        };
+        create_account(
+            CpiContext::new(self.system_program.to_account_info(), accounts),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &stake::program::ID
+        )?;
+
+        debit_lamports(&self.pot_vault, amount)?;
+        credit_lamports(&self.stake_account, amount)?;
+        self.lottery_state.record_vault_outflow(amount)?;
+
+        let authorized = Authorized::auto(&self.winner.key());
+
+        invoke(
+            &initialize(&self.stake_account.key(), &authorized, &Lockup::default()),
+            &[
+                self.stake_account.to_account_info(),
+                self.rent.to_account_info(),
+                self.stake_program.to_account_info()
+            ]
+        )?;
+
+        invoke(
+            &delegate_stake(&self.stake_account.key(), &self.winner.key(), &self.vote_account.key()),
+            &[
+                self.stake_account.to_account_info(),
+                self.vote_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                self.winner.to_account_info(),
+                self.stake_program.to_account_info()
+            ]
+        )?;
+
+        self.winning_ticket.held_prize_lamports = 0;
+        self.lottery_state.held_prize_lamports = self.lottery_state.held_prize_lamports
+            .checked_sub(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        msg!(
+            "Prize of {} lamports for lottery #{} staked in {} with {}",
+            amount,
+            self.winning_ticket.lottery_id,
+            self.stake_account.key(),
+            self.vote_account.key()
+        );
+
+        Ok(())
+    }
+}
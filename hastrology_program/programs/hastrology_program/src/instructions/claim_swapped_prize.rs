@@ -0,0 +1,186 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke
+    }
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint::ID as NATIVE_MINT, sync_native, SyncNative, Token},
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, PRIZE_SWAP_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, PrizeSwap, UserTicket}
+};
+
+/// Wraps a held prize into the winner's wSOL account and runs the client-built swap route
+/// (e.g. a Jupiter route) through `swap_program`, with the winner signing as the route's user.
+/// The route's accounts are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct ClaimSwappedPrize<'info> {
+    #[account(mut)]
+    pub winner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        constraint = winning_ticket.user == winner.key() @ HashtrologyErrors::InvalidWinner,
+        constraint = winning_ticket.is_winner @ HashtrologyErrors::InvalidWinner
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        mut,
+        close = winner,
+        seeds = [PRIZE_SWAP_SEED, winning_ticket.key().as_ref()],
+        bump = prize_swap.bump,
+        constraint = prize_swap.lamports > 0 @ HashtrologyErrors::NoSwapPrize
+    )]
+    pub prize_swap: Account<'info, PrizeSwap>,
+
+    /// CHECK: The ticket's payout address; the winner unless they set a payout destination.
+    #[account(
+        mut,
+        address = winning_ticket.payout_address() @ HashtrologyErrors::InvalidPayoutDestination
+    )]
+    pub prize_recipient: UncheckedAccount<'info>,
+
+    #[account(address = NATIVE_MINT)]
+    pub native_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The route's input account; left holding any wSOL the route didn't spend.
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = native_mint,
+        associated_token::authority = winner,
+        associated_token::token_program = token_program
+    )]
+    pub wsol_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = prize_swap.output_mint)]
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = winner,
+        associated_token::mint = output_mint,
+        associated_token::authority = prize_recipient,
+        associated_token::token_program = output_token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Must be the configured swap program.
+    #[account(
+        address = lottery_state.swap_program @ HashtrologyErrors::SwapNotEnabled,
+        executable
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub output_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimSwappedPrize<'info> {
+    /// An empty `route_data` pays the prize out as raw SOL instead, for when no route fills.
+    pub fn claim_swapped_prize_handler(
+        &mut self,
+        min_amount_out: u64,
+        route_data: Vec<u8>,
+        route_accounts: &'info [AccountInfo<'info>]
+    ) -> Result<()> {
+        let amount = self.prize_swap.lamports;
+
+        self.lottery_state.swap_prize_lamports = self.lottery_state.swap_prize_lamports
+            .checked_sub(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        debit_lamports(&self.pot_vault, amount)?;
+        self.lottery_state.record_vault_outflow(amount)?;
+
+        if route_data.is_empty() {
+            credit_lamports(&self.prize_recipient, amount)?;
+
+            msg!(
+                "Held prize of {} lamports paid to {} without a swap",
+                amount,
+                self.prize_recipient.key()
+            );
+
+            return Ok(());
+        }
+
+        require!(
+            self.lottery_state.swap_program != Pubkey::default(),
+            HashtrologyErrors::SwapNotEnabled
+        );
+
+        require!(
+            min_amount_out > 0,
+            HashtrologyErrors::SlippageExceeded
+        );
+
+        credit_lamports(&self.wsol_account.to_account_info(), amount)?;
+
+        sync_native(CpiContext::new(
+            self.token_program.to_account_info(),
+            SyncNative { account: self.wsol_account.to_account_info() }
+        ))?;
+
+        let balance_before = self.recipient_token_account.amount;
+
+        let ix = Instruction {
+            program_id: self.swap_program.key(),
+            accounts: route_accounts
+                .iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable
+                })
+                .collect(),
+            data: route_data
+        };
+
+        invoke(&ix, route_accounts)?;
+
+        self.recipient_token_account.reload()?;
+
+        let received = self.recipient_token_account.amount.saturating_sub(balance_before);
+
+        require!(
+            received >= min_amount_out,
+            HashtrologyErrors::SlippageExceeded
+        );
+
+        msg!(
+            "Prize of {} lamports swapped into {} of {} for {}",
+            amount,
+            received,
+            self.output_mint.key(),
+            self.prize_recipient.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LOTTERY_STATE_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    events::LosingTicketsClosed,
+    state::{ClaimBitmap, LotteryState, RoundHistory, UserTicket},
+    ID
+};
+
+/// Permissionless batch close of a paid-out round's losing tickets. Remaining accounts are
+/// `(ticket, rent_payer)` pairs, both writable, in the order of `ticket_indexes`; each
+/// ticket's rent goes back to the `rent_payer` recorded on it. Tickets already closed are
+/// skipped so overlapping cranks don't fail each other.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct CloseLosingTickets<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        seeds = [ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    /// Required when the round pays loser rebates, so a ticket closes only once its rebate is claimed.
+    #[account(
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump = claim_bitmap.bump
+    )]
+    pub claim_bitmap: Option<Account<'info, ClaimBitmap>>,
+}
+
+impl<'info> CloseLosingTickets<'info> {
+    pub fn close_losing_tickets_handler(
+        &mut self,
+        lottery_id: u64,
+        ticket_indexes: Vec<u32>,
+        remaining_accounts: &'info [AccountInfo<'info>]
+    ) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let round_history = &self.round_history;
+
+        require!(
+            lottery_id < lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id && lottery_state.round_finalized),
+            HashtrologyErrors::RoundStillOpen
+        );
+
+        // Co-winner rounds claim through their tickets after settlement, so only single-winner
+        // rounds that were drawn are closed here
+        require!(
+            round_history.round_kind.has_single_winner() && round_history.winner > 0,
+            HashtrologyErrors::TicketNotClosable
+        );
+
+        require!(
+            !ticket_indexes.is_empty() && remaining_accounts.len() == ticket_indexes.len() * 2,
+            HashtrologyErrors::InvalidTicketCloseAccounts
+        );
+
+        let claim_bitmap = if round_history.loser_reward_amount > 0 {
+            Some(self.claim_bitmap.as_ref().ok_or(HashtrologyErrors::LoserRewardUnclaimed)?.to_account_info())
+        } else {
+            None
+        };
+
+        let mut closed: u32 = 0;
+        let mut lamports_returned: u64 = 0;
+
+        for (ticket_index, accounts) in ticket_indexes.iter().zip(remaining_accounts.chunks(2)) {
+            let (ticket_info, rent_payer) = (&accounts[0], &accounts[1]);
+
+            let (ticket_address, _) = Pubkey::find_program_address(
+                &[USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+                &ID
+            );
+
+            require_keys_eq!(
+                ticket_info.key(),
+                ticket_address,
+                HashtrologyErrors::InvalidTicketCloseAccounts
+            );
+
+            if ticket_info.owner != &ID {
+                continue;
+            }
+
+            let ticket: Account<UserTicket> = Account::try_from(ticket_info)?;
+
+            require_keys_eq!(
+                rent_payer.key(),
+                ticket.rent_payer,
+                HashtrologyErrors::InvalidTicketCloseAccounts
+            );
+
+            // Entries after a hidden anti-snipe end are refunded through their ticket instead
+            require!(
+                !ticket.is_winner
+                    && ticket.held_prize_lamports == 0
+                    && (*ticket_index as u64) < round_history.total_participants,
+                HashtrologyErrors::TicketNotClosable
+            );
+
+            if let Some(claim_bitmap) = &claim_bitmap {
+                require!(
+                    ClaimBitmap::raw_is_claimed(&claim_bitmap.try_borrow_data()?, *ticket_index),
+                    HashtrologyErrors::LoserRewardUnclaimed
+                );
+            }
+
+            lamports_returned = lamports_returned
+                .checked_add(ticket_info.lamports())
+                .ok_or(HashtrologyErrors::Overflow)?;
+            closed += 1;
+
+            ticket.close(rent_payer.clone())?;
+        }
+
+        emit!(LosingTicketsClosed {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            closed,
+            lamports_returned,
+        });
+
+        msg!(
+            "Lottery #{}: closed {} losing tickets, returning {} lamports of rent",
+            lottery_id,
+            closed,
+            lamports_returned
+        );
+
+        Ok(())
+    }
+}
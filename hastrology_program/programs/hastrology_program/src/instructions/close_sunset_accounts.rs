@@ -0,0 +1,83 @@
+use anchor_lang::{prelude::*, AccountsClose};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, SUNSET_CLAIM_PERIOD},
+    errors::HashtrologyErrors,
+    lamports::close_program_account,
+    require_role,
+    state::{LotteryState, Role},
+    ID
+};
+
+/// Remaining accounts are the program-owned accounts to close, all writable. Their lamports
+/// go to `authority`.
+#[derive(Accounts)]
+pub struct CloseSunsetAccounts<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> CloseSunsetAccounts<'info> {
+    /// Closes what's left of a sunset program. Until `SUNSET_CLAIM_PERIOD` has passed an
+    /// account only closes once it holds nothing but its rent, so the pot vault stays open
+    /// until every refund and held prize is paid. With `close_state` the lottery state closes
+    /// too, last, after which nothing else can be closed.
+    pub fn close_sunset_accounts_handler(
+        &mut self,
+        close_state: bool,
+        remaining_accounts: &'info [AccountInfo<'info>]
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            lottery_state.sunset_at != 0 && !lottery_state.is_drawing,
+            HashtrologyErrors::NotSunset
+        );
+
+        let rent = Rent::get()?;
+        let claims_expired = Clock::get()?.unix_timestamp >= lottery_state.sunset_at.saturating_add(SUNSET_CLAIM_PERIOD);
+
+        let mut lamports_returned: u64 = 0;
+
+        for account in remaining_accounts {
+            require!(
+                account.owner == &ID && account.key() != lottery_state.key(),
+                HashtrologyErrors::SunsetAccountNotClosable
+            );
+
+            require!(
+                claims_expired || account.lamports() <= rent.minimum_balance(account.data_len()),
+                HashtrologyErrors::SunsetAccountNotEmpty
+            );
+
+            lamports_returned = lamports_returned
+                .checked_add(account.lamports())
+                .ok_or(HashtrologyErrors::Overflow)?;
+
+            close_program_account(account, &self.authority)?;
+        }
+
+        msg!("Closed {} accounts, returning {} lamports", remaining_accounts.len(), lamports_returned);
+
+        if close_state {
+            require!(
+                claims_expired || lottery_state.reserved_lamports()? == 0,
+                HashtrologyErrors::SunsetAccountNotEmpty
+            );
+
+            self.lottery_state.close(self.authority.to_account_info())?;
+            msg!("Lottery state closed");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct CommitDrawNonce<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> CommitDrawNonce<'info> {
+    /// `commitment` is `entropy_commitment_for(nonce)`. It has to land before the round ends,
+    /// so the operator can't pick a nonce after seeing who entered.
+    pub fn commit_draw_nonce_handler(&mut self, commitment: [u8; 32]) -> Result<()> {
+        require_role!(self.lottery_state, self.operator, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            Clock::get()?.unix_timestamp < lottery_state.lottery_endtime && !lottery_state.is_drawing,
+            HashtrologyErrors::EntropyCommitClosed
+        );
+
+        require!(
+            !lottery_state.has_entropy_commitment(),
+            HashtrologyErrors::EntropyAlreadyCommitted
+        );
+
+        require!(
+            commitment != [0u8; 32],
+            HashtrologyErrors::InvalidEntropyNonce
+        );
+
+        lottery_state.entropy_commitment = commitment;
+
+        msg!(
+            "Draw nonce for lottery #{} committed by {}",
+            lottery_state.current_lottery_id,
+            self.operator.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{COMPENSATION_SEED, INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::CompensationPaid,
+    lamports::{credit_lamports, debit_lamports},
+    require_role,
+    state::{Compensation, InsuranceReserve, LotteryState, Role}
+};
+
+/// Pays a proposed compensation from the insurance reserve once its timelock has passed.
+/// The record stays open as the history of the payment.
+#[derive(Accounts)]
+#[instruction(compensation_id: u64)]
+pub struct Compensate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump = insurance_reserve.bump
+    )]
+    pub insurance_reserve: Account<'info, InsuranceReserve>,
+
+    #[account(
+        mut,
+        seeds = [COMPENSATION_SEED, &compensation_id.to_le_bytes()],
+        bump = compensation.bump
+    )]
+    pub compensation: Account<'info, Compensation>,
+
+    /// CHECK: The recipient named in the proposal
+    #[account(
+        mut,
+        address = compensation.recipient @ HashtrologyErrors::InvalidCompensation
+    )]
+    pub recipient: AccountInfo<'info>,
+}
+
+impl<'info> Compensate<'info> {
+    pub fn compensate_handler(&mut self, compensation_id: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let compensation = &mut self.compensation;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(compensation.paid_at == 0, HashtrologyErrors::CompensationAlreadyPaid);
+        require!(now >= compensation.activates_at, HashtrologyErrors::CompensationTimelocked);
+
+        let amount = compensation.amount;
+        self.insurance_reserve.pay(amount)?;
+        debit_lamports(&self.insurance_reserve.to_account_info(), amount)?;
+        credit_lamports(&self.recipient, amount)?;
+
+        compensation.paid_at = now;
+
+        emit!(CompensationPaid {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            compensation_id,
+            lottery_id: compensation.lottery_id,
+            recipient: compensation.recipient,
+            amount,
+            reserve_balance: self.insurance_reserve.balance,
+        });
+
+        msg!("Compensation #{} paid: {} lamports to {}", compensation_id, amount, compensation.recipient);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, REWARD_MINT_AUTHORITY_SEED},
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct ConfigureLoserRewards<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: PDA holding the reward mint's authority; signs the rebate mints.
+    #[account(
+        seeds = [REWARD_MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub reward_mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mint::authority = reward_mint_authority
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+}
+
+impl<'info> ConfigureLoserRewards<'info> {
+    /// Sets the reward tokens each losing ticket of later draws can claim. The mint's
+    /// authority must already be handed to the program's reward mint authority PDA.
+    /// An amount of 0 stops rebates; rounds already drawn keep theirs.
+    pub fn configure_loser_rewards_handler(&mut self, loser_reward_amount: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        lottery_state.reward_mint = self.reward_mint.key();
+        lottery_state.loser_reward_amount = loser_reward_amount;
+
+        msg!(
+            "Loser rebate set to {} of {} per losing ticket",
+            loser_reward_amount,
+            lottery_state.reward_mint
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PRIZE_VAULT_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{CurrencyInfo, LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct ConfigurePrizeMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    pub prize_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [PRIZE_VAULT_SEED, prize_mint.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub prize_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ConfigurePrizeMint<'info> {
+    pub fn configure_prize_mint_handler(&mut self, token_prize_amount: u64, symbol: String) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            token_prize_amount > 0,
+            HashtrologyErrors::InvalidTokenPrizeAmount
+        );
+
+        lottery_state.prize_mint = self.prize_mint.key();
+        lottery_state.token_prize_amount = token_prize_amount;
+        lottery_state.token_prize_currency = Some(CurrencyInfo::new(
+            self.prize_mint.key(),
+            self.prize_mint.decimals,
+            symbol
+        )?);
+
+        msg!(
+            "SPL prize mode enabled. Mint: {}, Prize per round: {}",
+            lottery_state.prize_mint,
+            token_prize_amount
+        );
+
+        Ok(())
+    }
+}
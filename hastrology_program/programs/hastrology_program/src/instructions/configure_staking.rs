@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct ConfigureStaking<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> ConfigureStaking<'info> {
+    pub fn configure_staking_handler(
+        &mut self,
+        stake_pool_program: Pubkey,
+        stake_pool: Pubkey,
+        yield_to_prize: bool,
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        // Switching pools with funds still deposited would strand the pool tokens.
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        lottery_state.stake_pool_program = stake_pool_program;
+        lottery_state.stake_pool = stake_pool;
+        lottery_state.stake_yield_to_prize = yield_to_prize;
+
+        msg!(
+            "Pot staking configured. Pool: {}, Yield to prize: {}",
+            stake_pool,
+            yield_to_prize
+        );
+
+        Ok(())
+    }
+}
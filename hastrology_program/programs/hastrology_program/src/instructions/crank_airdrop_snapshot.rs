@@ -0,0 +1,136 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    constants::{AIRDROP_SNAPSHOT_SEED, LOTTERY_STATE_SEED, MERKLE_TREE_SEED, PARTICIPANT_INDEX_SEED, PROGRAM_VERSION, SNAPSHOT_CRANK_BATCH},
+    errors::HashtrologyErrors,
+    events::AirdropSnapshotted,
+    state::{AirdropSnapshot, LotteryState, MerkleEntryTree, ParticipantIndex},
+    ID
+};
+
+/// Permissionless crank reading the snapshot's next round: up to `SNAPSHOT_CRANK_BATCH`
+/// tickets of a round sold through its participant index, or a Merkle-mode round whole, by
+/// its tree's root. Both of the round's accounts are passed so neither can be left out;
+/// rounds that sold nothing still pass their addresses.
+#[derive(Accounts)]
+pub struct CrankAirdropSnapshot<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [AIRDROP_SNAPSHOT_SEED, &airdrop_snapshot.first_lottery_id.to_le_bytes(), &[airdrop_snapshot.round_count]],
+        bump = airdrop_snapshot.bump
+    )]
+    pub airdrop_snapshot: Account<'info, AirdropSnapshot>,
+
+    /// CHECK: Read as raw bytes; deserializing the whole index would exceed the heap on large rounds.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &airdrop_snapshot.reading_lottery_id().to_le_bytes()],
+        bump
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    /// CHECK: Read as a `MerkleEntryTree` when the round was sold in Merkle entry mode.
+    #[account(
+        seeds = [MERKLE_TREE_SEED, &airdrop_snapshot.reading_lottery_id().to_le_bytes()],
+        bump
+    )]
+    pub merkle_tree: UncheckedAccount<'info>
+}
+
+impl<'info> CrankAirdropSnapshot<'info> {
+    pub fn crank_airdrop_snapshot_handler(&mut self) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        let airdrop_snapshot = &mut self.airdrop_snapshot;
+
+        require!(
+            !airdrop_snapshot.is_complete(),
+            HashtrologyErrors::SnapshotComplete
+        );
+
+        let lottery_id = airdrop_snapshot.reading_lottery_id();
+
+        // The round's entries are only fixed once it stops taking them
+        require!(
+            lottery_id < lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id
+                    && (lottery_state.is_drawing || lottery_state.round_finalized)),
+            HashtrologyErrors::RoundStillOpen
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if self.merkle_tree.owner == &ID {
+            // Merkle entry mode can only change while a round is empty, so a round sells one way
+            require!(
+                self.participant_index.owner != &ID,
+                HashtrologyErrors::InvalidSnapshotRange
+            );
+
+            let data = self.merkle_tree.try_borrow_data()?;
+
+            require!(
+                data.get(..8) == Some(MerkleEntryTree::DISCRIMINATOR),
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+
+            let tree: &MerkleEntryTree = bytemuck::from_bytes(&data[8..8 + std::mem::size_of::<MerkleEntryTree>()]);
+
+            airdrop_snapshot.ticket_count = airdrop_snapshot.ticket_count
+                .checked_add(tree.leaf_count)
+                .ok_or(HashtrologyErrors::Overflow)?;
+            airdrop_snapshot.finish_round(tree.root, now);
+        } else {
+            let ticket_count = if self.participant_index.owner == &ID {
+                ParticipantIndex::raw_len(&self.participant_index.try_borrow_data()?)
+            } else {
+                0
+            };
+
+            let start = airdrop_snapshot.next_ticket as usize;
+            let end = ticket_count.min(start + SNAPSHOT_CRANK_BATCH);
+
+            if end > start {
+                let index_data = self.participant_index.try_borrow_data()?;
+
+                for index in start..end {
+                    let wallet = ParticipantIndex::raw_participant(&index_data, index)
+                        .ok_or(HashtrologyErrors::InvalidSnapshotRange)?;
+                    airdrop_snapshot.append_ticket(&wallet);
+                }
+            }
+
+            if end == ticket_count {
+                let root = airdrop_snapshot.read_root();
+                airdrop_snapshot.finish_round(root, now);
+            }
+        }
+
+        msg!(
+            "Airdrop snapshot read {} of {} rounds, {} tickets so far",
+            airdrop_snapshot.rounds_read,
+            airdrop_snapshot.round_count,
+            airdrop_snapshot.ticket_count
+        );
+
+        if airdrop_snapshot.is_complete() {
+            emit!(AirdropSnapshotted {
+                event_seq: self.lottery_state.next_event_seq(),
+                program_version: PROGRAM_VERSION.to_string(),
+                first_lottery_id: airdrop_snapshot.first_lottery_id,
+                round_count: airdrop_snapshot.round_count,
+                ticket_count: airdrop_snapshot.ticket_count,
+                root: airdrop_snapshot.root,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{HOSTED_LOTTERY_SEED, HOSTED_VAULT_SEED, MAX_PLATFORM_FEE_BPS, MIN_ROUND_DURATION, MIN_TICKET_PRICE, PLATFORM_CONFIG_SEED},
+    errors::HashtrologyErrors,
+    state::{HostedLottery, PlatformConfig}
+};
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct CreateHostedLottery<'info> {
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.is_approved(&host.key()) @ HashtrologyErrors::HostNotApproved
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = host,
+        space = 8 + HostedLottery::INIT_SPACE,
+        seeds = [HOSTED_LOTTERY_SEED, host.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub hosted_lottery: Account<'info, HostedLottery>,
+
+    /// CHECK: This is the PDA vault that will hold the hosted lottery's pot.
+    #[account(
+        init,
+        payer = host,
+        space = 8,
+        seeds = [HOSTED_VAULT_SEED, hosted_lottery.key().as_ref()],
+        bump
+    )]
+    pub hosted_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> CreateHostedLottery<'info> {
+    pub fn create_hosted_lottery_handler(
+        &mut self,
+        index: u64,
+        fee_wallet: Pubkey,
+        host_fee_bps: u16,
+        ticket_price: u64,
+        endtime: i64,
+        bumps: &CreateHostedLotteryBumps
+    ) -> Result<()> {
+        let protocol_fee_bps = self.platform_config.protocol_fee_bps;
+
+        require!(
+            host_fee_bps as u32 + protocol_fee_bps as u32 <= MAX_PLATFORM_FEE_BPS as u32,
+            HashtrologyErrors::InvalidPlatformFee
+        );
+
+        require!(
+            ticket_price >= MIN_TICKET_PRICE,
+            HashtrologyErrors::InvalidTicketPrice
+        );
+
+        require!(
+            endtime >= Clock::get()?.unix_timestamp.saturating_add(MIN_ROUND_DURATION),
+            HashtrologyErrors::EndtimeTooSoon
+        );
+
+        self.hosted_lottery.set_inner(HostedLottery {
+            host: self.host.key(),
+            index,
+            fee_wallet,
+            host_fee_bps,
+            protocol_fee_bps,
+            ticket_price,
+            endtime,
+            total_participants: 0,
+            bump: bumps.hosted_lottery,
+            vault_bump: bumps.hosted_vault
+        });
+
+        msg!(
+            "Hosted lottery #{} created by {}: {} bps host fee, {} bps protocol fee",
+            index,
+            self.host.key(),
+            host_fee_bps,
+            protocol_fee_bps
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{ENTRY_POOL_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    state::{EntryPool, LotteryState}
+};
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + EntryPool::INIT_SPACE,
+        seeds = [ENTRY_POOL_SEED, creator.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub entry_pool: Account<'info, EntryPool>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> CreatePool<'info> {
+    pub fn create_pool_handler(&mut self, bumps: &CreatePoolBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            !lottery_state.is_drawing && !lottery_state.round_finalized,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        self.entry_pool.set_inner(EntryPool {
+            creator: self.creator.key(),
+            lottery_id: lottery_state.current_lottery_id,
+            total_contributed: 0,
+            member_count: 0,
+            is_entered: false,
+            ticket_index: 0,
+            bump: bumps.entry_pool
+        });
+
+        msg!(
+            "Pool {} created for lottery #{}",
+            self.entry_pool.key(),
+            lottery_state.current_lottery_id
+        );
+
+        Ok(())
+    }
+}
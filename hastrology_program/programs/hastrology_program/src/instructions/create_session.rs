@@ -0,0 +1,77 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{MAX_SESSION_DURATION, SESSION_AUTHORITY_SEED},
+    errors::HashtrologyErrors,
+    state::SessionAuthority
+};
+
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + SessionAuthority::INIT_SPACE,
+        seeds = [SESSION_AUTHORITY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub session_authority: Account<'info, SessionAuthority>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> CreateSession<'info> {
+    pub fn create_session_handler(
+        &mut self,
+        session_key: Pubkey,
+        expires_at: i64,
+        spend_cap: u64,
+        bumps: &CreateSessionBumps
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(
+            expires_at > clock.unix_timestamp && expires_at - clock.unix_timestamp <= MAX_SESSION_DURATION,
+            HashtrologyErrors::InvalidSessionExpiry
+        );
+
+        require!(
+            spend_cap > 0,
+            HashtrologyErrors::InvalidSpendCap
+        );
+
+        self.session_authority.set_inner(SessionAuthority {
+            user: self.user.key(),
+            session_key,
+            expires_at,
+            spend_cap,
+            spent: 0,
+            bump: bumps.session_authority
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.session_authority.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, spend_cap)?;
+
+        msg!(
+            "Session key {} authorized for {} until {} (cap {} lamports)",
+            session_key,
+            self.user.key(),
+            expires_at,
+            spend_cap
+        );
+
+        Ok(())
+    }
+}
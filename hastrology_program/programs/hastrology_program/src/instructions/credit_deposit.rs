@@ -0,0 +1,197 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as instructions_sysvar,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{DEPOSIT_ADDRESS_SEED, LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, METRICS_SEED, PARTICIPANT_INDEX_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, USER_RECEIPT_SEED, USER_TICKET_SEED},
+    entry::{open_entry, EntryClearance},
+    errors::HashtrologyErrors,
+    state::{LotteryState, MegaJackpot, Metrics, ParticipantIndex, UserEntryReceipt, UserTicket}
+};
+
+#[derive(Accounts)]
+pub struct CreditDeposit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Wallet the deposit address belongs to; becomes the ticket owner.
+    pub user: UncheckedAccount<'info>,
+
+    /// Plain system account users (or exchanges on their behalf) send SOL to.
+    #[account(
+        mut,
+        seeds = [DEPOSIT_ADDRESS_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub deposit_address: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault that will hold the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserEntryReceipt::INIT_SPACE,
+        seeds = [USER_RECEIPT_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub user_entry_receipt: Account<'info, UserEntryReceipt>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserTicket::INIT_SPACE,
+        seeds = [USER_TICKET_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &(lottery_state.total_participants as u32).to_le_bytes()],
+        bump
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: Appended to as raw bytes by `ParticipantIndex::raw_push`, which creates it
+    /// with the round's first entry.
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// Required while the lottery is linked to the mega jackpot (`mega_jackpot_bps > 0`).
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Option<Account<'info, MegaJackpot>>,
+
+    /// CHECK: Always required so a spending cap or self-exclusion can't be skipped by leaving it out.
+    /// Only enforced once the wallet has created its profile.
+    #[account(
+        mut,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    /// CHECK: The instructions sysvar, read to reject entries wrapped in a CPI by a program
+    /// that isn't on the allowlist.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> CreditDeposit<'info> {
+    pub fn credit_deposit_handler(&mut self, bumps: &CreditDepositBumps) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            !lottery_state.in_snipe_window(Clock::get()?.unix_timestamp),
+            HashtrologyErrors::SnipeWindowRequired
+        );
+
+        // Only exact deposits become tickets; anything else goes through refund_deposit
+        require!(
+            self.deposit_address.lamports() == lottery_state.ticket_price,
+            HashtrologyErrors::DepositAmountMismatch
+        );
+
+        let ticket_price = lottery_state.ticket_price;
+        let clock = Clock::get()?;
+        let mut sale = open_entry(
+            lottery_state,
+            &self.user,
+            false,
+            EntryClearance::Eligibility { token_account: None, attestation: None },
+            Some(&self.player_profile),
+            None,
+            &self.instructions_sysvar,
+            self.pot_vault.lamports(),
+            ticket_price,
+            &clock
+        )?;
+
+        let ticket_number = sale.ticket_number;
+        let pot_contribution = sale.take_mega_cut(lottery_state, self.mega_jackpot.as_deref())?;
+
+        self.user_entry_receipt.record_entry(
+            self.user.key(),
+            lottery_state.current_lottery_id,
+            ticket_number,
+            pot_contribution,
+            &clock,
+            None,
+            lottery_state.max_entries_per_wallet
+        )?;
+
+        self.user_ticket.set_inner(UserTicket {
+            user: self.user.key(),
+            lottery_id: lottery_state.current_lottery_id,
+            is_winner: false,
+            prize_amount: 0,
+            is_claimed: false,
+            token_prize_amount: 0,
+            payout_destination: None,
+            is_bonus: false,
+            bonus_tickets: 0,
+            held_prize_lamports: 0,
+            ticket_class: None,
+            class_weight_start: 0,
+            rent_payer: self.payer.key(),
+            stake_vote_account: None,
+            held_token_prize: 0
+        });
+
+        ParticipantIndex::raw_push(
+            &self.participant_index,
+            lottery_state.current_lottery_id,
+            bumps.participant_index,
+            self.user.key(),
+            &self.payer.to_account_info(),
+            &self.system_program
+        )?;
+
+        let user_key = self.user.key();
+        let seeds = &[DEPOSIT_ADDRESS_SEED, user_key.as_ref(), &[bumps.deposit_address]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = Transfer {
+            from: self.deposit_address.to_account_info(),
+            to: self.pot_vault.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+
+        transfer(cpi_ctx, ticket_price)?;
+
+        sale.settle(lottery_state, self.metrics.as_deref_mut(), &self.pot_vault, self.mega_jackpot.as_mut(), 1, None)?;
+
+        msg!(
+            "Deposit credited as ticket #{} for lottery #{}",
+            ticket_number,
+            lottery_state.current_lottery_id
+        );
+
+        Ok(())
+    }
+}
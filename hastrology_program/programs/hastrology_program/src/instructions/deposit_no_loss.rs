@@ -0,0 +1,137 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as instructions_sysvar,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, NO_LOSS_DEPOSIT_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED},
+    entry::{check_entrant, EntryClearance},
+    errors::HashtrologyErrors,
+    math::time_weight,
+    state::{LotteryState, NoLossDeposit, PlayerProfile}
+};
+
+#[derive(Accounts)]
+pub struct DepositNoLoss<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding deposits and accrued yield.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + NoLossDeposit::INIT_SPACE,
+        seeds = [NO_LOSS_DEPOSIT_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &(lottery_state.total_participants as u32).to_le_bytes()],
+        bump
+    )]
+    pub deposit: Account<'info, NoLossDeposit>,
+
+    /// CHECK: Always required so a spending cap or self-exclusion can't be skipped by leaving it out.
+    /// Only enforced once the wallet has created its profile.
+    #[account(
+        mut,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    /// CHECK: The instructions sysvar, read to reject entries wrapped in a CPI by a program
+    /// that isn't on the allowlist.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> DepositNoLoss<'info> {
+    pub fn deposit_no_loss_handler(&mut self, amount: u64) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let clock = Clock::get()?;
+
+        require!(
+            lottery_state.round_kind.accepts_deposits(),
+            HashtrologyErrors::NoLossModeDisabled
+        );
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            !lottery_state.round_finalized,
+            HashtrologyErrors::RoundNotOpen
+        );
+
+        require!(
+            amount >= lottery_state.ticket_price,
+            HashtrologyErrors::DepositTooSmall
+        );
+
+        // Deposits carry no token account, attestation or device commitment
+        check_entrant(
+            lottery_state,
+            &self.user,
+            EntryClearance::Eligibility { token_account: None, attestation: None },
+            None,
+            &self.instructions_sysvar
+        )?;
+
+        let deposit_number = lottery_state.next_ticket_number()?;
+        PlayerProfile::charge(&self.player_profile, amount, clock.unix_timestamp)?;
+
+        let seconds_left = lottery_state.lottery_endtime
+            .checked_sub(clock.unix_timestamp)
+            .filter(|seconds| *seconds > 0)
+            .ok_or(HashtrologyErrors::LotteryIsOver)?;
+
+        let weight = time_weight(amount, seconds_left)?;
+
+        self.deposit.set_inner(NoLossDeposit {
+            user: self.user.key(),
+            lottery_id: lottery_state.current_lottery_id,
+            amount,
+            weight_start: lottery_state.no_loss_total_weight,
+            weight,
+            withdrawn: false
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.pot_vault.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, amount)?;
+        lottery_state.record_vault_inflow(amount)?;
+
+        lottery_state.no_loss_total_weight = lottery_state.no_loss_total_weight.checked_add(weight).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.total_deposits = lottery_state.total_deposits.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.total_participants = deposit_number as u64;
+
+        msg!(
+            "Deposit of {} lamports for lottery #{} with weight {}",
+            amount,
+            lottery_state.current_lottery_id,
+            weight
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, REFERRAL_EPOCH_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, ReferralEpoch}
+};
+
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct DistributeReferralBonus<'info> {
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the reserved bonus.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_EPOCH_SEED, &lottery_id.to_le_bytes()],
+        bump = referral_epoch.bump,
+        constraint = !referral_epoch.is_distributed @ HashtrologyErrors::ReferralBonusAlreadyPaid
+    )]
+    pub referral_epoch: Account<'info, ReferralEpoch>,
+
+    /// CHECK: The top referrer of the round
+    #[account(
+        mut,
+        address = referral_epoch.top_referrer @ HashtrologyErrors::InvalidReferrer
+    )]
+    pub top_referrer: UncheckedAccount<'info>,
+}
+
+impl<'info> DistributeReferralBonus<'info> {
+    pub fn distribute_referral_bonus_handler(&mut self, lottery_id: u64) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let epoch = &mut self.referral_epoch;
+        let bonus = epoch.bonus_lamports;
+
+        require!(
+            bonus > 0,
+            HashtrologyErrors::NoReferralBonus
+        );
+
+        debit_lamports(&self.pot_vault, bonus)?;
+        credit_lamports(&self.top_referrer, bonus)?;
+        lottery_state.record_vault_outflow(bonus)?;
+
+        lottery_state.reserved_referral_lamports = lottery_state.reserved_referral_lamports.checked_sub(bonus).ok_or(HashtrologyErrors::Overflow)?;
+        epoch.is_distributed = true;
+
+        msg!(
+            "Referral bonus of {} lamports paid to {} for lottery #{}",
+            bonus,
+            epoch.top_referrer,
+            lottery_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_ROUNDS_AHEAD, QUEUED_ENTRY_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, QueuedEntry}
+};
+
+/// Pre-purchases a ticket for a later round. The price is escrowed in a `QueuedEntry`
+/// that `process_queued_entry` turns into a ticket once that round is open, or that
+/// the user can cancel for a refund until then.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct EnterFutureRound<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + QueuedEntry::INIT_SPACE,
+        seeds = [QUEUED_ENTRY_SEED, user.key().as_ref(), &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub queued_entry: Account<'info, QueuedEntry>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> EnterFutureRound<'info> {
+    pub fn enter_future_round_handler(&mut self, lottery_id: u64, bumps: &EnterFutureRoundBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        lottery_state.check_not_sunset()?;
+
+        require!(
+            lottery_id > lottery_state.current_lottery_id
+                && lottery_id - lottery_state.current_lottery_id <= MAX_ROUNDS_AHEAD,
+            HashtrologyErrors::InvalidFutureRound
+        );
+
+        self.queued_entry.set_inner(QueuedEntry {
+            user: self.user.key(),
+            lottery_id,
+            amount: lottery_state.ticket_price,
+            bump: bumps.queued_entry
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.queued_entry.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, lottery_state.ticket_price)?;
+
+        msg!(
+            "Ticket for lottery #{} pre-purchased by {}",
+            lottery_id,
+            self.user.key()
+        );
+
+        Ok(())
+    }
+}
@@ -1,15 +1,20 @@
 use anchor_lang::{
     prelude::*, 
+    solana_program::sysvar::instructions as instructions_sysvar,
     system_program::{Transfer, transfer}
 };
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::{
-    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, USER_RECEIPT_SEED, USER_TICKET_SEED}, 
+    constants::{DEVICE_ENTRIES_SEED, LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, METRICS_SEED, PARTICIPANT_INDEX_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, SNIPE_WINDOW_SEED, USER_RECEIPT_SEED, USER_TICKET_SEED}, 
+    entry::{open_entry, EntryClearance},
     errors::HashtrologyErrors, 
-    state::{LotteryState, UserEntryReceipt, UserTicket}
+    require_ctx,
+    state::{DeviceEntries, LotteryState, MegaJackpot, Metrics, ParticipantIndex, SnipeEntry, SnipeWindow, UserEntryReceipt, UserTicket}
 };
 
 #[derive(Accounts)]
+#[instruction(entry_nonce: Option<u64>, ticket_class: Option<u8>, max_price: Option<u64>, device_commitment: Option<[u8; 32]>)]
 pub struct EnterLottery<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -30,10 +35,10 @@ pub struct EnterLottery<'info> {
     pub pot_vault: AccountInfo<'info>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = user,
         space = 8 + UserEntryReceipt::INIT_SPACE,
-        seeds = [USER_RECEIPT_SEED,user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        seeds = [USER_RECEIPT_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
         bump
     )]
     pub user_entry_receipt: Account<'info, UserEntryReceipt>,
@@ -42,40 +47,229 @@ pub struct EnterLottery<'info> {
         init,
         payer = user,
         space = 8 + UserTicket::INIT_SPACE,
-        seeds = [USER_TICKET_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &lottery_state.total_participants.to_le_bytes()],
+        seeds = [USER_TICKET_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &(lottery_state.total_participants as u32).to_le_bytes()],
         bump
     )]
     pub user_ticket: Account<'info, UserTicket>,
 
+    /// CHECK: Appended to as raw bytes by `ParticipantIndex::raw_push`, which creates it
+    /// with the round's first entry.
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    /// Only required when the lottery gates entry on a token balance or verification NFT.
+    pub eligibility_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Only required when the lottery gates entry on an attestation; its address,
+    /// owner and contents are checked by `EntryEligibility::check`.
+    pub eligibility_attestation: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// Required for entries after `lottery_endtime` when anti-snipe is enabled.
+    #[account(
+        mut,
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = snipe_window.bump
+    )]
+    pub snipe_window: Option<Account<'info, SnipeWindow>>,
+
+    /// Required while the lottery is linked to the mega jackpot (`mega_jackpot_bps > 0`).
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Option<Account<'info, MegaJackpot>>,
+
+    /// Required with a `device_commitment`, which `max_entries_per_device` makes mandatory.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DeviceEntries::INIT_SPACE,
+        seeds = [DEVICE_ENTRIES_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &device_commitment.unwrap_or_default()],
+        bump
+    )]
+    pub device_entries: Option<Account<'info, DeviceEntries>>,
+
+    /// CHECK: Always required so a spending cap or self-exclusion can't be skipped by leaving it out.
+    /// Only enforced once the wallet has created its profile.
+    #[account(
+        mut,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    /// CHECK: The instructions sysvar, read to reject entries wrapped in a CPI by a program
+    /// that isn't on the allowlist.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System> 
 }
 
 impl<'info> EnterLottery<'info> {
-    pub fn enter_lottery_handler(&mut self) -> Result<()> { 
+    /// Entries in the early-bird window also get free tickets, created from `bonus_tickets`:
+    /// the ticket PDAs numbered right after this one, in order.
+    ///
+    /// A wallet may enter any number of times; its receipt totals the round's entries.
+    /// With an `entry_nonce` a client can resend a dropped entry under the same nonce: if
+    /// the first one landed, the retry fails instead of buying a second ticket. Nonces
+    /// must increase within a round, each new one being a separate purchase.
+    ///
+    /// A `ticket_class` buys one of the round's star, moon or sun tickets at its price and
+    /// draw weight instead of a regular ticket, or with class draws, at regular weight plus
+    /// a chance at its class's own pot. Early-bird tickets are always regular.
+    ///
+    /// A `device_commitment` is the hash of a salt the app keeps on the device; entries under
+    /// it are counted against `max_entries_per_device` whichever wallet makes them.
+    pub fn enter_lottery_handler(
+        &mut self,
+        entry_nonce: Option<u64>,
+        ticket_class: Option<u8>,
+        max_price: Option<u64>,
+        device_commitment: Option<[u8; 32]>,
+        bumps: &EnterLotteryBumps,
+        bonus_tickets: &'info [AccountInfo<'info>]
+    ) -> Result<()> { 
 
         let lottery_state = &mut self.lottery_state;
 
+        let price = lottery_state.ticket_price_for(ticket_class)?;
+
+        if let Some(max_price) = max_price {
+            require_ctx!(
+                price <= max_price,
+                HashtrologyErrors::EntryPriceAboveMax,
+                "price={} max_price={} ticket_class={:?}", price, max_price, ticket_class
+            );
+        }
+
+        let clock = Clock::get()?;
+        let mut sale = open_entry(
+            lottery_state,
+            &self.user,
+            false,
+            EntryClearance::Eligibility {
+                token_account: self.eligibility_token_account.as_ref(),
+                attestation: self.eligibility_attestation.as_deref()
+            },
+            Some(&self.player_profile),
+            device_commitment,
+            &self.instructions_sysvar,
+            self.pot_vault.lamports(),
+            price,
+            &clock
+        )?;
+
+        let ticket_number = sale.ticket_number;
+        let pot_contribution = sale.take_mega_cut(lottery_state, self.mega_jackpot.as_deref())?;
+        let now = sale.now;
+
+        if lottery_state.check_snipe_entry(now, self.snipe_window.as_deref())? {
+            // Late entries are refunded at the regular price, so only regular tickets are sold here
+            require!(
+                ticket_class.is_none(),
+                HashtrologyErrors::TicketClassesClosed
+            );
+
+            let snipe_window = self.snipe_window.as_mut().ok_or(HashtrologyErrors::SnipeWindowRequired)?;
+
+            snipe_window.entries.push(SnipeEntry {
+                ticket_index: ticket_number - 1,
+                entered_at: now
+            });
+        }
+
+        let bonus_count = lottery_state.early_bird_bonus_at(now);
+
         require!(
-            !lottery_state.is_drawing,
-            HashtrologyErrors::LotteryIsDrawing
+            bonus_tickets.len() >= bonus_count as usize,
+            HashtrologyErrors::InvalidBonusTicket
         );
 
-        let ticket_number = lottery_state.total_participants.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.user_entry_receipt.record_entry(
+            self.user.key(),
+            lottery_state.current_lottery_id,
+            ticket_number,
+            pot_contribution,
+            &clock,
+            entry_nonce,
+            lottery_state.max_entries_per_wallet
+        )?;
 
-        self.user_entry_receipt.set_inner(UserEntryReceipt { 
-            user: self.user.key(), 
-            lottery_id: lottery_state.current_lottery_id, 
-            ticket_number 
-        });
+        if let Some(commitment) = device_commitment {
+            let device_entries = self.device_entries.as_mut().ok_or(HashtrologyErrors::DeviceEntriesRequired)?;
+            let bump = bumps.device_entries.ok_or(HashtrologyErrors::DeviceEntriesRequired)?;
+
+            device_entries.record_entry(lottery_state.current_lottery_id, commitment, lottery_state.max_entries_per_device, bump)?;
+            self.user_entry_receipt.device_commitment = Some(commitment);
+        }
+
+        let class_weight_start = match ticket_class {
+            Some(index) => lottery_state.record_class_ticket(index)?,
+            None => 0
+        };
 
         self.user_ticket.set_inner(UserTicket { 
             user: self.user.key(), 
             lottery_id: lottery_state.current_lottery_id,
             is_winner: false,
             prize_amount: 0,
-            is_claimed: false 
+            is_claimed: false,
+            token_prize_amount: 0,
+            payout_destination: None,
+            is_bonus: false,
+            bonus_tickets: bonus_count,
+            held_prize_lamports: 0,
+            ticket_class,
+            class_weight_start,
+            rent_payer: self.user.key(),
+            stake_vote_account: None,
+            held_token_prize: 0
         });
 
+        ParticipantIndex::raw_push(
+            &self.participant_index,
+            lottery_state.current_lottery_id,
+            bumps.participant_index,
+            self.user.key(),
+            &self.user.to_account_info(),
+            &self.system_program
+        )?;
+
+        // Bonus tickets take the indexes right after the paid one
+        for (offset, bonus_ticket) in bonus_tickets.iter().take(bonus_count as usize).enumerate() {
+            UserTicket::create_bonus(
+                bonus_ticket,
+                &self.user.to_account_info(),
+                &self.system_program,
+                lottery_state.current_lottery_id,
+                ticket_number + offset as u32
+            )?;
+
+            ParticipantIndex::raw_push(
+                &self.participant_index,
+                lottery_state.current_lottery_id,
+                bumps.participant_index,
+                self.user.key(),
+                &self.user.to_account_info(),
+                &self.system_program
+            )?;
+        }
+
+        let tickets_granted = 1 + bonus_count;
+
         let accounts = Transfer {
             from: self.user.to_account_info(),
             to: self.pot_vault.to_account_info() 
@@ -83,16 +277,21 @@ impl<'info> EnterLottery<'info> {
 
         let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
 
-        transfer(cpi_ctx, lottery_state.ticket_price)?;
+        transfer(cpi_ctx, price)?;
 
-        lottery_state.total_participants = lottery_state.total_participants.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        // Linked lotteries pass a cut of the sale on to the mega jackpot, entering every ticket granted here
+        sale.settle(lottery_state, self.metrics.as_deref_mut(), &self.pot_vault, self.mega_jackpot.as_mut(), tickets_granted, ticket_class)?;
 
         msg!(
             "Ticket #{} purchased for lottery #{}",
             ticket_number,
             lottery_state.current_lottery_id
         );
-        
+
+        if bonus_count > 0 {
+            msg!("{} early-bird ticket(s) granted", bonus_count);
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
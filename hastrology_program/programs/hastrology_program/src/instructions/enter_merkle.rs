@@ -0,0 +1,149 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as instructions_sysvar,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, MERKLE_TREE_SEED, METRICS_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, PROGRAM_VERSION},
+    entry::{open_entry, EntryClearance},
+    errors::HashtrologyErrors,
+    events::MerkleEntryAppended,
+    state::{LotteryState, MegaJackpot, MerkleEntryTree, Metrics, PlayerProfile}
+};
+
+#[derive(Accounts)]
+pub struct EnterMerkle<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault that will hold the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [MERKLE_TREE_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub merkle_tree: AccountLoader<'info, MerkleEntryTree>,
+
+    /// Merkle entries have no receipt PDA, so per-wallet limits are counted here.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// Required while the lottery is linked to the mega jackpot (`mega_jackpot_bps > 0`).
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Option<Account<'info, MegaJackpot>>,
+
+    /// CHECK: The instructions sysvar, read to reject entries wrapped in a CPI by a program
+    /// that isn't on the allowlist.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> EnterMerkle<'info> {
+    pub fn enter_merkle_handler(&mut self, bumps: &EnterMerkleBumps) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            !lottery_state.in_snipe_window(Clock::get()?.unix_timestamp),
+            HashtrologyErrors::SnipeWindowRequired
+        );
+
+        let ticket_price = lottery_state.ticket_price;
+        let clock = Clock::get()?;
+
+        // The profile is already loaded as this path's entry counter, so its spend is charged on it below
+        let mut sale = open_entry(
+            lottery_state,
+            &self.user,
+            true,
+            EntryClearance::Eligibility { token_account: None, attestation: None },
+            None,
+            None,
+            &self.instructions_sysvar,
+            self.pot_vault.lamports(),
+            ticket_price,
+            &clock
+        )?;
+
+        let ticket_number = sale.ticket_number;
+        sale.take_mega_cut(lottery_state, self.mega_jackpot.as_deref())?;
+
+        if self.player_profile.user == Pubkey::default() {
+            self.player_profile.user = self.user.key();
+            self.player_profile.bump = bumps.player_profile;
+        }
+
+        self.player_profile.record_entry(lottery_state.current_lottery_id, lottery_state.max_entries_per_wallet)?;
+        self.player_profile.check_not_excluded(sale.now)?;
+        self.player_profile.record_spend(ticket_price, sale.now)?;
+
+        let index = ticket_number - 1;
+        let leaf = MerkleEntryTree::leaf(&self.user.key(), lottery_state.current_lottery_id, index);
+
+        let root = {
+            let mut tree = self.merkle_tree.load_mut()?;
+            require!(
+                tree.leaf_count == index as u64,
+                HashtrologyErrors::MerkleTreeOutOfSync
+            );
+            tree.append(leaf);
+            tree.root
+        };
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.pot_vault.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, ticket_price)?;
+
+        sale.settle(lottery_state, self.metrics.as_deref_mut(), &self.pot_vault, self.mega_jackpot.as_mut(), 1, None)?;
+
+        emit!(MerkleEntryAppended {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            index,
+            user: self.user.key(),
+            leaf,
+            root,
+        });
+
+        Ok(())
+    }
+}
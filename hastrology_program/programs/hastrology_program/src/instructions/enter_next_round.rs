@@ -0,0 +1,77 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, QUEUED_ENTRY_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, QueuedEntry}
+};
+
+#[derive(Accounts)]
+pub struct EnterNextRound<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + QueuedEntry::INIT_SPACE,
+        seeds = [QUEUED_ENTRY_SEED, user.key().as_ref(), &(lottery_state.current_lottery_id + 1).to_le_bytes()],
+        bump
+    )]
+    pub queued_entry: Account<'info, QueuedEntry>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> EnterNextRound<'info> {
+    pub fn enter_next_round_handler(&mut self, bumps: &EnterNextRoundBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        lottery_state.check_not_sunset()?;
+
+        require!(
+            lottery_state.queue_entries_while_drawing,
+            HashtrologyErrors::DrawWindowQueueingDisabled
+        );
+
+        // The gap runs from the draw request until open_round starts the next round
+        require!(
+            lottery_state.is_drawing || lottery_state.round_finalized,
+            HashtrologyErrors::LotteryNotDrawing
+        );
+
+        let next_lottery_id = lottery_state.current_lottery_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        self.queued_entry.set_inner(QueuedEntry {
+            user: self.user.key(),
+            lottery_id: next_lottery_id,
+            amount: lottery_state.ticket_price,
+            bump: bumps.queued_entry
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.queued_entry.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, lottery_state.ticket_price)?;
+
+        msg!(
+            "Ticket for lottery #{} queued by {}",
+            next_lottery_id,
+            self.user.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PENDING_KYC_SEED, PLAYER_PROFILE_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, PendingKycEntry, PlayerProfile}
+};
+
+#[derive(Accounts)]
+pub struct EnterPendingKyc<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + PendingKycEntry::INIT_SPACE,
+        seeds = [PENDING_KYC_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub pending_entry: Account<'info, PendingKycEntry>,
+
+    /// CHECK: Always required so a spending cap or self-exclusion can't be skipped by leaving it out.
+    /// Only read once the wallet has created its profile.
+    #[account(
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> EnterPendingKyc<'info> {
+    /// Escrows the ticket price for the current round until the KYC verifier approves the entry.
+    pub fn enter_pending_kyc_handler(&mut self, bumps: &EnterPendingKycBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            lottery_state.kyc_verifier != Pubkey::default(),
+            HashtrologyErrors::KycEscrowDisabled
+        );
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            lottery_state.round_kind.accepts_tickets(),
+            HashtrologyErrors::NoLossModeActive
+        );
+
+        // Fails early for closed, paused or full rounds; the ticket itself is issued at approval
+        lottery_state.next_ticket_number()?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // Checked on a copy so the wallet's limits fail the escrow early; the spend itself is
+        // recorded when approve_entry issues the ticket
+        if let Some(mut player_profile) = PlayerProfile::load(&self.player_profile)? {
+            player_profile.check_not_excluded(now)?;
+            player_profile.record_spend(lottery_state.ticket_price, now)?;
+        }
+
+        self.pending_entry.set_inner(PendingKycEntry {
+            user: self.user.key(),
+            lottery_id: lottery_state.current_lottery_id,
+            amount: lottery_state.ticket_price,
+            created_at: now,
+            bump: bumps.pending_entry
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.pending_entry.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, lottery_state.ticket_price)?;
+
+        msg!(
+            "Entry for lottery #{} escrowed by {} pending KYC approval",
+            lottery_state.current_lottery_id,
+            self.user.key()
+        );
+
+        Ok(())
+    }
+}
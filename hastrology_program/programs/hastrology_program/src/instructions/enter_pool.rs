@@ -0,0 +1,238 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as instructions_sysvar
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, METRICS_SEED, PARTICIPANT_INDEX_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, USER_RECEIPT_SEED, USER_TICKET_SEED},
+    entry::{open_entry, EntryClearance},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{EntryEligibility, EntryPool, LotteryState, MegaJackpot, Metrics, ParticipantIndex, PlayerProfile, PoolMember, UserEntryReceipt, UserTicket},
+    ID
+};
+
+/// Buys the pool's ticket once it is fully funded. The pool account owns the ticket,
+/// so the prize is paid to it and split by `claim_pool_share`.
+///
+/// Remaining accounts: each member's `PoolMember` followed by their player profile, ordered
+/// by member address, so a member who self-excluded after joining holds the entry back
+/// until they leave the pool.
+#[derive(Accounts)]
+pub struct EnterPool<'info> {
+    /// Any member (or anyone) may trigger the entry; they pay the account rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault that will hold the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub entry_pool: Account<'info, EntryPool>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserEntryReceipt::INIT_SPACE,
+        seeds = [USER_RECEIPT_SEED, entry_pool.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub user_entry_receipt: Account<'info, UserEntryReceipt>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserTicket::INIT_SPACE,
+        seeds = [USER_TICKET_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &(lottery_state.total_participants as u32).to_le_bytes()],
+        bump
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: Appended to as raw bytes by `ParticipantIndex::raw_push`, which creates it
+    /// with the round's first entry.
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// Required while the lottery is linked to the mega jackpot (`mega_jackpot_bps > 0`).
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Option<Account<'info, MegaJackpot>>,
+
+    /// CHECK: The instructions sysvar, read to reject entries wrapped in a CPI by a program
+    /// that isn't on the allowlist.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> EnterPool<'info> {
+    pub fn enter_pool_handler(&mut self, bumps: &EnterPoolBumps, member_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let entry_pool = &mut self.entry_pool;
+
+        require!(
+            !lottery_state.in_snipe_window(Clock::get()?.unix_timestamp),
+            HashtrologyErrors::SnipeWindowRequired
+        );
+
+        // Eligibility gates are per wallet and a pool has no wallet of its own
+        require!(
+            lottery_state.entry_eligibility == EntryEligibility::Open,
+            HashtrologyErrors::PoolEntryNotEligible
+        );
+
+        require!(
+            !entry_pool.is_entered && entry_pool.lottery_id == lottery_state.current_lottery_id,
+            HashtrologyErrors::PoolClosed
+        );
+
+        require!(
+            entry_pool.total_contributed == lottery_state.ticket_price,
+            HashtrologyErrors::PoolNotFunded
+        );
+
+        let ticket_price = lottery_state.ticket_price;
+        let clock = Clock::get()?;
+
+        check_members_not_excluded(entry_pool, member_accounts, clock.unix_timestamp)?;
+
+        // A pool has no profile of its own; members' spending caps apply as they join
+        let mut sale = open_entry(
+            lottery_state,
+            &entry_pool.to_account_info(),
+            false,
+            EntryClearance::Eligibility { token_account: None, attestation: None },
+            None,
+            None,
+            &self.instructions_sysvar,
+            self.pot_vault.lamports(),
+            ticket_price,
+            &clock
+        )?;
+
+        let ticket_number = sale.ticket_number;
+        let pot_contribution = sale.take_mega_cut(lottery_state, self.mega_jackpot.as_deref())?;
+
+        let pool_key = entry_pool.key();
+
+        self.user_entry_receipt.record_entry(
+            pool_key,
+            lottery_state.current_lottery_id,
+            ticket_number,
+            pot_contribution,
+            &clock,
+            None,
+            lottery_state.max_entries_per_wallet
+        )?;
+
+        self.user_ticket.set_inner(UserTicket {
+            user: pool_key,
+            lottery_id: lottery_state.current_lottery_id,
+            is_winner: false,
+            prize_amount: 0,
+            is_claimed: false,
+            token_prize_amount: 0,
+            payout_destination: None,
+            is_bonus: false,
+            bonus_tickets: 0,
+            held_prize_lamports: 0,
+            ticket_class: None,
+            class_weight_start: 0,
+            rent_payer: self.payer.key(),
+            stake_vote_account: None,
+            held_token_prize: 0
+        });
+
+        ParticipantIndex::raw_push(
+            &self.participant_index,
+            lottery_state.current_lottery_id,
+            bumps.participant_index,
+            pool_key,
+            &self.payer.to_account_info(),
+            &self.system_program
+        )?;
+
+        debit_lamports(&entry_pool.to_account_info(), ticket_price)?;
+        credit_lamports(&self.pot_vault, ticket_price)?;
+
+        entry_pool.is_entered = true;
+        entry_pool.ticket_index = ticket_number - 1;
+
+        sale.settle(lottery_state, self.metrics.as_deref_mut(), &self.pot_vault, self.mega_jackpot.as_mut(), 1, None)?;
+
+        msg!(
+            "Pool {} entered as ticket #{} for lottery #{}",
+            pool_key,
+            ticket_number,
+            lottery_state.current_lottery_id
+        );
+
+        Ok(())
+    }
+}
+
+fn check_members_not_excluded<'info>(
+    entry_pool: &Account<'info, EntryPool>,
+    member_accounts: &'info [AccountInfo<'info>],
+    now: i64
+) -> Result<()> {
+    require!(
+        member_accounts.len() == entry_pool.member_count as usize * 2,
+        HashtrologyErrors::InvalidPoolMembers
+    );
+
+    let mut last_member: Option<Pubkey> = None;
+
+    for accounts in member_accounts.chunks(2) {
+        let pool_member: Account<PoolMember> = Account::try_from(&accounts[0])?;
+
+        // Strictly increasing members rule out passing one member twice
+        require!(
+            pool_member.pool == entry_pool.key() && last_member.is_none_or(|last| pool_member.member > last),
+            HashtrologyErrors::InvalidPoolMembers
+        );
+
+        let (profile_address, _) = Pubkey::find_program_address(&[PLAYER_PROFILE_SEED, pool_member.member.as_ref()], &ID);
+
+        require_keys_eq!(
+            accounts[1].key(),
+            profile_address,
+            HashtrologyErrors::InvalidPoolMembers
+        );
+
+        if let Some(player_profile) = PlayerProfile::load(&accounts[1])? {
+            player_profile.check_not_excluded(now)?;
+        }
+
+        last_member = Some(pool_member.member);
+    }
+
+    Ok(())
+}
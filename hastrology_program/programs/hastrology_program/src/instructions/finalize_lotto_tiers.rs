@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, LOTTO_ROUND_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, LottoRound}
+};
+
+/// Permissionless once registration has closed.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct FinalizeLottoTiers<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTO_ROUND_SEED, &lottery_id.to_le_bytes()],
+        bump = lotto_round.bump
+    )]
+    pub lotto_round: Account<'info, LottoRound>,
+}
+
+impl<'info> FinalizeLottoTiers<'info> {
+    /// Fixes each tier's per-ticket share from its registered count. Pools of tiers nobody
+    /// won and the remainders of uneven splits roll over into the pot.
+    pub fn finalize_lotto_tiers_handler(&mut self, lottery_id: u64) -> Result<()> {
+        let round = &mut self.lotto_round;
+
+        require!(
+            Clock::get()?.unix_timestamp >= round.registration_ends,
+            HashtrologyErrors::LottoRegistrationOpen
+        );
+
+        require!(
+            !round.is_finalized,
+            HashtrologyErrors::LottoAlreadyFinalized
+        );
+
+        let mut owed: u64 = 0;
+        let mut pooled: u64 = 0;
+
+        for tier in 0..round.tier_pools.len() {
+            let pool = round.tier_pools[tier];
+            let count = round.tier_counts[tier] as u64;
+            pooled = pooled.checked_add(pool).ok_or(HashtrologyErrors::Overflow)?;
+
+            if let Some(share) = pool.checked_div(count) {
+                round.tier_shares[tier] = share;
+                owed = owed
+                    .checked_add(share * count)
+                    .ok_or(HashtrologyErrors::Overflow)?;
+            }
+        }
+
+        let rollover = pooled.checked_sub(owed).ok_or(HashtrologyErrors::Overflow)?;
+
+        debit_lamports(&round.to_account_info(), rollover)?;
+        credit_lamports(&self.pot_vault, rollover)?;
+        self.lottery_state.record_vault_inflow(rollover)?;
+
+        round.is_finalized = true;
+
+        msg!(
+            "Lottery #{} lotto tiers finalized: shares {:?}, {} lamports rolled over",
+            lottery_id,
+            round.tier_shares,
+            rollover
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_FORWARD_INTERVAL, FEE_TREASURY_SEED, LOTTERY_STATE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::FeesForwarded,
+    lamports::{credit_lamports, debit_lamports},
+    state::{FeeTreasury, LotteryState}
+};
+
+/// Permissionless crank, callable once per `FEE_FORWARD_INTERVAL`.
+#[derive(Accounts)]
+pub struct ForwardFees<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    /// CHECK: This is the platform wallet
+    #[account(
+        mut,
+        address = lottery_state.platform_wallet
+    )]
+    pub platform_wallet: AccountInfo<'info>,
+}
+
+impl<'info> ForwardFees<'info> {
+    /// Sends every fee accrued since the last forward to the platform wallet in one transfer.
+    pub fn forward_fees_handler(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let treasury = &mut self.fee_treasury;
+
+        // The pending payout may need these fees to reach the minimum prize
+        require!(
+            !(self.lottery_state.is_drawing && self.lottery_state.min_prize_lamports > 0),
+            HashtrologyErrors::FeeForwardDuringDraw
+        );
+
+        require!(
+            treasury.can_forward(now, FEE_FORWARD_INTERVAL),
+            HashtrologyErrors::FeeForwardTooSoon
+        );
+
+        let amount = treasury.accrued_fees;
+
+        require!(
+            amount > 0,
+            HashtrologyErrors::NoFeesToForward
+        );
+
+        debit_lamports(&treasury.to_account_info(), amount)?;
+        credit_lamports(&self.platform_wallet, amount)?;
+
+        treasury.accrued_fees = 0;
+        treasury.total_forwarded = treasury.total_forwarded
+            .checked_add(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+        treasury.last_forwarded_at = now;
+
+        emit!(FeesForwarded {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            platform_wallet: self.platform_wallet.key(),
+            amount,
+            total_forwarded: treasury.total_forwarded,
+        });
+
+        msg!("Forwarded {} lamports of fees to the platform wallet", amount);
+
+        Ok(())
+    }
+}
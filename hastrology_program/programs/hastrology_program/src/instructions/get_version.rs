@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROGRAM_VERSION;
+
+/// Takes no accounts; meant to be simulated so clients can read the deployed version
+/// from return data before choosing which instructions to use.
+#[derive(Accounts)]
+pub struct GetVersion {}
+
+impl GetVersion {
+    pub fn get_version_handler(&self) -> Result<String> {
+        Ok(PROGRAM_VERSION.to_string())
+    }
+}
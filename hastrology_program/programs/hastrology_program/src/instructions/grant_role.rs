@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED, MAX_ROLE_GRANTS},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{AuditAction, AuditLog, LotteryState, Role, RoleGrant}
+};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+}
+
+impl<'info> GrantRole<'info> {
+    pub fn grant_role_handler(&mut self, member: Pubkey, role: Role) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+        let grant = RoleGrant { member, role };
+
+        require!(
+            !lottery_state.role_grants.contains(&grant),
+            HashtrologyErrors::RoleAlreadyGranted
+        );
+
+        require!(
+            lottery_state.role_grants.len() < MAX_ROLE_GRANTS,
+            HashtrologyErrors::TooManyRoleGrants
+        );
+
+        lottery_state.role_grants.push(grant);
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::GrantRole,
+            self.authority.key(),
+            &[member.as_ref(), &[role as u8]]
+        )?;
+
+        msg!("Granted {:?} to {}", role, member);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    system_program::{create_account, transfer, CreateAccount, Transfer},
+    Discriminator
+};
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LOTTERY_STATE_SEED, PARTICIPANT_INDEX_SEED},
+    errors::HashtrologyErrors,
+    state::{ClaimBitmap, LotteryState, ParticipantIndex},
+    ID
+};
+
+/// Permissionless crank; the caller pays the bitmap's rent. Call until it reports full size.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct InitClaimBitmap<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: Read as raw bytes for its length only.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    /// CHECK: Large rounds exceed the CPI allocation limit, so it is created and grown here
+    /// in `MAX_PERMITTED_DATA_INCREASE` steps.
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub claim_bitmap: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitClaimBitmap<'info> {
+    pub fn init_claim_bitmap_handler(&mut self, lottery_id: u64, bumps: &InitClaimBitmapBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        // The ticket count is only fixed once the round stops taking entries
+        require!(
+            lottery_id < lottery_state.current_lottery_id
+                || (lottery_id == lottery_state.current_lottery_id
+                    && (lottery_state.is_drawing || lottery_state.round_finalized)),
+            HashtrologyErrors::RoundStillOpen
+        );
+
+        let bitmap_info = self.claim_bitmap.to_account_info();
+        let current_len = bitmap_info.data_len();
+
+        let ticket_count = if current_len == 0 {
+            ParticipantIndex::raw_len(&self.participant_index.try_borrow_data()?) as u32
+        } else {
+            ClaimBitmap::try_deserialize(&mut &bitmap_info.try_borrow_data()?[..])?.ticket_count
+        };
+
+        let full_len = ClaimBitmap::space(ticket_count);
+
+        if current_len >= full_len {
+            msg!("Claim bitmap for lottery #{} is already full size", lottery_id);
+            return Ok(());
+        }
+
+        let new_len = full_len.min(current_len + MAX_PERMITTED_DATA_INCREASE);
+        let required_lamports = Rent::get()?.minimum_balance(new_len);
+
+        if current_len == 0 {
+            let lottery_id_bytes = lottery_id.to_le_bytes();
+            let seeds: &[&[u8]] = &[CLAIM_BITMAP_SEED, &lottery_id_bytes, &[bumps.claim_bitmap]];
+
+            let accounts = CreateAccount {
+                from: self.cranker.to_account_info(),
+                to: bitmap_info.clone()
+            };
+
+            let signer_seeds = &[seeds];
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+
+            create_account(cpi_ctx, required_lamports, new_len as u64, &ID)?;
+
+            let header = ClaimBitmap {
+                lottery_id,
+                ticket_count,
+                claimed_count: 0,
+                bump: bumps.claim_bitmap
+            };
+
+            let mut data = bitmap_info.try_borrow_mut_data()?;
+            data[..8].copy_from_slice(ClaimBitmap::DISCRIMINATOR);
+            header.serialize(&mut &mut data[8..ClaimBitmap::BITS_OFFSET])?;
+        } else {
+            let top_up = required_lamports.saturating_sub(bitmap_info.lamports());
+
+            if top_up > 0 {
+                let accounts = Transfer {
+                    from: self.cranker.to_account_info(),
+                    to: bitmap_info.clone()
+                };
+
+                let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+                transfer(cpi_ctx, top_up)?;
+            }
+
+            bitmap_info.realloc(new_len, true)?;
+        }
+
+        msg!("Claim bitmap for lottery #{} grown to {} of {} bytes", lottery_id, new_len, full_len);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MERKLE_TREE_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, MerkleEntryTree}
+};
+
+#[derive(Accounts)]
+pub struct InitMerkleTree<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump,
+        constraint = lottery_state.merkle_entry_mode @ HashtrologyErrors::MerkleEntryModeDisabled
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MerkleEntryTree>(),
+        seeds = [MERKLE_TREE_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub merkle_tree: AccountLoader<'info, MerkleEntryTree>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitMerkleTree<'info> {
+    pub fn init_merkle_tree_handler(&mut self) -> Result<()> {
+        self.merkle_tree.load_init()?.init(self.lottery_state.current_lottery_id);
+
+        msg!("Merkle entry tree initialized for lottery #{}", self.lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
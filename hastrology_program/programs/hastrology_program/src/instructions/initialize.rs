@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED}, 
+    constants::{CLUSTER, DEFAULT_ROUND_DURATION, LOTTERY_STATE_SEED, MAX_TICKET_CLASSES, PLATFORM_CONFIG_SEED, SOFT_MAX_PLATFORM_FEE_BPS, POT_VAULT_SEED}, 
     errors::HashtrologyErrors, 
-    state::LotteryState
+    randomness::{EphemeralVrf, RandomnessProvider},
+    state::{ConfigBounds, CurrencyInfo, EntryEligibility, LotteryState, RoundKind}
 };
 
 #[derive(Accounts)]
@@ -30,6 +31,14 @@ pub struct Initialize<'info> {
     )] 
     pub pot_vault: AccountInfo<'info>,
 
+    /// CHECK: Always required so tuned bounds can't be skipped by leaving it out. Until
+    /// `initialize_platform_config` creates it, the compiled defaults apply.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System> 
 }
 
@@ -42,36 +51,189 @@ impl<'info> Initialize<'info> {
         first_lottery_endtime: i64,
         bumps: &InitializeBumps
     ) -> Result<()> {
+        let lottery_state = initial_state(
+            &ConfigBounds::load(&self.platform_config)?,
+            self.authority.key(),
+            self.pot_vault.key(),
+            platform_wallet_pubkey,
+            ticket_price,
+            platform_fee_bps,
+            first_lottery_endtime,
+            bumps.lottery_state,
+            bumps.pot_vault
+        )?;
 
-        require!(
-            platform_fee_bps <= 10_000, 
-            HashtrologyErrors::InvalidPlatformFee
-        );
-        
-        require!(
-            ticket_price > 0, 
-            HashtrologyErrors::InvalidTicketPrice
-        );
-
-        self.lottery_state.set_inner(LotteryState { 
-            authority: self.authority.key(), 
-            pot_vault: self.pot_vault.key(), 
-            platform_wallet: platform_wallet_pubkey, 
-            // last_winner: Pubkey::default(), 
-            winner: 0,
-            platform_fee_bps, 
-            ticket_price, 
-            current_lottery_id: 1, 
-            total_participants: 0, 
-            is_drawing: false,
-            lottery_endtime: first_lottery_endtime,
-            commit_slot: 0,
-            lottery_state_bump: bumps.lottery_state,
-            pot_vault_bump: bumps.pot_vault
-        });
+        self.lottery_state.set_inner(lottery_state);
+        self.lottery_state.rebase_vault_ledger(self.pot_vault.lamports());
 
-        msg!("Initialized...");
+        msg!("Initialized on {}...", CLUSTER);
         
         Ok(())
     }
+}
+
+/// Validates the initial config and builds the first round's state. Shared with
+/// `initialize_recover` so both paths start from the same state.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initial_state(
+    bounds: &ConfigBounds,
+    authority: Pubkey,
+    pot_vault: Pubkey,
+    platform_wallet_pubkey: Pubkey,
+    ticket_price: u64,
+    platform_fee_bps: u16,
+    first_lottery_endtime: i64,
+    lottery_state_bump: u8,
+    pot_vault_bump: u8
+) -> Result<LotteryState> {
+    require!(
+        platform_fee_bps <= SOFT_MAX_PLATFORM_FEE_BPS, 
+        HashtrologyErrors::InvalidPlatformFee
+    );
+
+    bounds.check_fee(platform_fee_bps)?;
+    bounds.check_ticket_price(ticket_price)?;
+
+    let clock = Clock::get()?;
+    bounds.check_endtime(clock.unix_timestamp, first_lottery_endtime)?;
+
+    Ok(fresh_state(
+        authority,
+        pot_vault,
+        platform_wallet_pubkey,
+        ticket_price,
+        platform_fee_bps,
+        first_lottery_endtime,
+        lottery_state_bump,
+        pot_vault_bump,
+        &clock
+    ))
+}
+
+/// The first round's state with every optional feature off. `migrate_lottery_state` starts
+/// from it too, so accounts it grows get the same defaults as new ones.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fresh_state(
+    authority: Pubkey,
+    pot_vault: Pubkey,
+    platform_wallet_pubkey: Pubkey,
+    ticket_price: u64,
+    platform_fee_bps: u16,
+    first_lottery_endtime: i64,
+    lottery_state_bump: u8,
+    pot_vault_bump: u8,
+    clock: &Clock
+) -> LotteryState {
+    LotteryState {
+        authority, 
+        pot_vault, 
+        platform_wallet: platform_wallet_pubkey, 
+        // last_winner: Pubkey::default(), 
+        winner: 0,
+        platform_fee_bps, 
+        ticket_price, 
+        max_pot_lamports: 0,
+        prize_mint: Pubkey::default(),
+        token_prize_amount: 0,
+        draw_operators: Vec::new(),
+        draw_threshold: 0,
+        attestation_min_pot: 0,
+        stake_pool_program: Pubkey::default(),
+        stake_pool: Pubkey::default(),
+        staked_lamports: 0,
+        stake_yield_to_prize: true,
+        no_loss_mode: false,
+        total_deposits: 0,
+        no_loss_total_weight: 0,
+        no_loss_winning_weight: 0,
+        referral_bonus_bps: 0,
+        reserved_referral_lamports: 0,
+        entry_eligibility: EntryEligibility::Open,
+        draw_retries: 0,
+        merkle_entry_mode: false,
+        anti_snipe_max_extension: 0,
+        late_entry_lamports: 0,
+        max_entries_per_wallet: 0,
+        max_entries_per_slot: 0,
+        rate_limit_slot: 0,
+        rate_limit_slot_entries: 0,
+        round_kind: RoundKind::Standard,
+        keeper_reward_lamports: 0,
+        payment_currency: CurrencyInfo::sol(),
+        token_prize_currency: None,
+        reveal_delay: 0,
+        winner_commitment: [0u8; 32],
+        reveal_after: 0,
+        round_duration: DEFAULT_ROUND_DURATION,
+        round_finalized: false,
+        queue_entries_while_drawing: true,
+        pending_config: None,
+        season_length: 0,
+        season_start: 0,
+        event_seq: 0,
+        cpi_allowlist: Vec::new(),
+        min_prize_lamports: 0,
+        entropy_mix_min_pot: 0,
+        entropy_commitment: [0u8; 32],
+        entropy_mix_pending: false,
+        swap_program: Pubkey::default(),
+        swap_prize_lamports: 0,
+        early_bird_window: 0,
+        early_bird_bonus: 0,
+        round_started_at: clock.unix_timestamp,
+        role_grants: Vec::new(),
+        entries_paused: false,
+        fee_governance: Pubkey::default(),
+        fee_ceiling_bps: 0,
+        fee_ceiling_activates_at: 0,
+        kyc_verifier: Pubkey::default(),
+        mega_jackpot_bps: 0,
+        end_slot: LotteryState::end_slot_for(first_lottery_endtime, clock),
+        endtime_grace: 0,
+        held_prize_lamports: 0,
+        streak_bonus_lamports: 0,
+        streak_wallet: Pubkey::default(),
+        streak_round: 0,
+        win_streak: 0,
+        vault_ledger_base: 0,
+        vault_inflow_lamports: 0,
+        vault_outflow_lamports: 0,
+        needs_review: false,
+        winner_hook_program: Pubkey::default(),
+        ticket_classes: Vec::new(),
+        class_weight_total: 0,
+        class_winning_weight: None,
+        reward_mint: Pubkey::default(),
+        loser_reward_amount: 0,
+        prize_granularity: 0,
+        announced_opens_at: 0,
+        trivial_resolution: false,
+        insurance_bps: 0,
+        dual_draw_min_pot: 0,
+        second_oracle_queue: Pubkey::default(),
+        fee_promo_lottery_id: 0,
+        fee_promo_start: 0,
+        fee_promo_end: 0,
+        fee_promo_bps: 0,
+        fee_promo_lamports: 0,
+        class_draws: false,
+        class_pots: [0; MAX_TICKET_CLASSES],
+        class_prize_lamports: 0,
+        sunset_at: 0,
+        slot_deadline: false,
+        min_round_slots: 0,
+        round_started_slot: clock.slot,
+        randomness_provider: EphemeralVrf.program_id(),
+        randomness_queue: EphemeralVrf.default_queue(),
+        max_entries_per_device: 0,
+        current_lottery_id: 1, 
+        total_participants: 0, 
+        is_drawing: false,
+        lottery_endtime: first_lottery_endtime,
+        commit_slot: 0,
+        draw_request_id: 0,
+        resolved_request_id: 0,
+        lottery_state_bump,
+        pot_vault_bump
+    }
 }
\ No newline at end of file
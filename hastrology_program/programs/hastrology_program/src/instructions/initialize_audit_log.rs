@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED},
+    require_role,
+    state::{AuditLog, LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<AuditLog>(),
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeAuditLog<'info> {
+    pub fn initialize_audit_log_handler(&mut self) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.audit_log.load_init()?;
+
+        msg!("Audit log initialized");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_TREASURY_SEED, LOTTERY_STATE_SEED},
+    require_role,
+    state::{FeeTreasury, LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializeFeeTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeTreasury::INIT_SPACE,
+        seeds = [FEE_TREASURY_SEED],
+        bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeFeeTreasury<'info> {
+    pub fn initialize_fee_treasury_handler(&mut self, bumps: &InitializeFeeTreasuryBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        self.fee_treasury.set_inner(FeeTreasury {
+            accrued_fees: 0,
+            total_forwarded: 0,
+            last_forwarded_at: 0,
+            bump: bumps.fee_treasury
+        });
+
+        msg!("Fee treasury initialized");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED},
+    require_role,
+    state::{InsuranceReserve, LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceReserve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InsuranceReserve::INIT_SPACE,
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump
+    )]
+    pub insurance_reserve: Account<'info, InsuranceReserve>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeInsuranceReserve<'info> {
+    pub fn initialize_insurance_reserve_handler(&mut self, bumps: &InitializeInsuranceReserveBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        self.insurance_reserve.set_inner(InsuranceReserve {
+            balance: 0,
+            committed_lamports: 0,
+            total_compensated: 0,
+            compensation_count: 0,
+            bump: bumps.insurance_reserve
+        });
+
+        msg!("Insurance reserve initialized");
+
+        Ok(())
+    }
+}
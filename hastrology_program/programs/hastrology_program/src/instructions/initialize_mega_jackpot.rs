@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, MegaJackpot, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializeMegaJackpot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MegaJackpot::INIT_SPACE,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump
+    )]
+    pub mega_jackpot: Account<'info, MegaJackpot>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeMegaJackpot<'info> {
+    /// Creates the shared jackpot with its first draw `draw_interval` seconds from now.
+    pub fn initialize_mega_jackpot_handler(&mut self, draw_interval: i64, bumps: &InitializeMegaJackpotBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(draw_interval > 0, HashtrologyErrors::InvalidMegaJackpotInterval);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        self.mega_jackpot.set_inner(MegaJackpot {
+            epoch: 0,
+            draw_interval,
+            next_draw_at: now.checked_add(draw_interval).ok_or(HashtrologyErrors::Overflow)?,
+            jackpot_lamports: 0,
+            total_entries: 0,
+            segments: Vec::new(),
+            is_drawing: false,
+            request_slot: 0,
+            winning_entry: None,
+            bump: bumps.mega_jackpot
+        });
+
+        msg!("Mega jackpot initialized. First draw at {}", self.mega_jackpot.next_draw_at);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, METRICS_SEED},
+    require_role,
+    state::{LotteryState, Metrics, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializeMetrics<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Metrics::INIT_SPACE,
+        seeds = [METRICS_SEED],
+        bump
+    )]
+    pub metrics: Account<'info, Metrics>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeMetrics<'info> {
+    pub fn initialize_metrics_handler(&mut self, bumps: &InitializeMetricsBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.metrics.set_inner(Metrics {
+            total_entries: 0,
+            total_lamports_processed: 0,
+            draws_requested: 0,
+            failed_draws: 0,
+            retried_draws: 0,
+            paused_seconds: 0,
+            pause_started_at: 0,
+            vault_discrepancy: 0,
+            vault_checked_at: 0,
+            bump: bumps.metrics
+        });
+
+        msg!("Metrics initialized");
+
+        Ok(())
+    }
+}
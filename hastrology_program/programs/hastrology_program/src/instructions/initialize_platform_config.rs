@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_PLATFORM_FEE_BPS, PLATFORM_CONFIG_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{ConfigBounds, LotteryState, PlatformConfig, Role}
+};
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializePlatformConfig<'info> {
+    pub fn initialize_platform_config_handler(&mut self, protocol_fee_bps: u16, bumps: &InitializePlatformConfigBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            protocol_fee_bps <= MAX_PLATFORM_FEE_BPS,
+            HashtrologyErrors::InvalidPlatformFee
+        );
+
+        self.platform_config.set_inner(PlatformConfig {
+            protocol_fee_bps,
+            approved_hosts: Vec::new(),
+            bump: bumps.platform_config,
+            bounds: ConfigBounds::default()
+        });
+
+        msg!("Platform config initialized with a {} bps protocol fee", protocol_fee_bps);
+
+        Ok(())
+    }
+}
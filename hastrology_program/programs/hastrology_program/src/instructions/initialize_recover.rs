@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CLUSTER, LOTTERY_STATE_SEED, PLATFORM_CONFIG_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    instructions::initialize::initial_state,
+    state::{ConfigBounds, LotteryState},
+    ID
+};
+
+/// Completes setup when the pot vault already exists but the lottery state does not,
+/// e.g. after the state was closed for a layout change. `init` on `lottery_state` means
+/// live state can never be overwritten.
+#[derive(Accounts)]
+pub struct InitializeRecover<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LotteryState::INIT_SPACE,
+        seeds = [LOTTERY_STATE_SEED],
+        bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: The existing pot vault PDA; `initialize_recover_handler` checks it is empty.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump,
+        owner = ID
+    )]
+    pub pot_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Always required so tuned bounds can't be skipped by leaving it out. Until
+    /// `initialize_platform_config` creates it, the compiled defaults apply.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeRecover<'info> {
+    pub fn initialize_recover_handler(
+        &mut self,
+        platform_wallet_pubkey: Pubkey,
+        ticket_price: u64,
+        platform_fee_bps: u16,
+        first_lottery_endtime: i64,
+        bumps: &InitializeRecoverBumps
+    ) -> Result<()> {
+        // Only adopt a vault in the state `initialize` leaves it: 8 zeroed bytes and no pot,
+        // so lamports from an earlier deployment can't leak into the new first round
+        let vault = self.pot_vault.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(8);
+
+        require!(
+            vault.data_len() == 8
+                && vault.try_borrow_data()?.iter().all(|byte| *byte == 0)
+                && vault.lamports() <= rent_exempt,
+            HashtrologyErrors::VaultNotAdoptable
+        );
+
+        let lottery_state = initial_state(
+            &ConfigBounds::load(&self.platform_config)?,
+            self.authority.key(),
+            self.pot_vault.key(),
+            platform_wallet_pubkey,
+            ticket_price,
+            platform_fee_bps,
+            first_lottery_endtime,
+            bumps.lottery_state,
+            bumps.pot_vault
+        )?;
+
+        self.lottery_state.set_inner(lottery_state);
+        self.lottery_state.rebase_vault_ledger(self.pot_vault.lamports());
+
+        msg!("Initialized on {} with the existing pot vault", CLUSTER);
+
+        Ok(())
+    }
+}
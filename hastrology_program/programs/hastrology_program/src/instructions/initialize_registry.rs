@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED, METRICS_SEED, PRIZE_VAULT_SEED, REGISTRY_SEED, REGISTRY_VERSION, STAKE_STAGING_SEED},
+    require_role,
+    state::{LotteryState, Registry, Role},
+    ID
+};
+
+/// Also refreshes an existing registry, so it can be re-run after config changes or upgrades.
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Registry::INIT_SPACE,
+        seeds = [REGISTRY_SEED],
+        bump
+    )]
+    pub registry: Account<'info, Registry>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> InitializeRegistry<'info> {
+    pub fn initialize_registry_handler(&mut self, bumps: &InitializeRegistryBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &self.lottery_state;
+
+        let prize_vault = if lottery_state.prize_mint == Pubkey::default() {
+            Pubkey::default()
+        } else {
+            Pubkey::find_program_address(&[PRIZE_VAULT_SEED, lottery_state.prize_mint.as_ref()], &ID).0
+        };
+
+        self.registry.set_inner(Registry {
+            version: REGISTRY_VERSION,
+            lottery_state: lottery_state.key(),
+            pot_vault: lottery_state.pot_vault,
+            audit_log: Pubkey::find_program_address(&[AUDIT_LOG_SEED], &ID).0,
+            metrics: Pubkey::find_program_address(&[METRICS_SEED], &ID).0,
+            stake_staging: Pubkey::find_program_address(&[STAKE_STAGING_SEED], &ID).0,
+            prize_vault,
+            treasury: lottery_state.platform_wallet,
+            authority: lottery_state.authority,
+            bump: bumps.registry
+        });
+
+        msg!("Registry v{} written", REGISTRY_VERSION);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PLAYER_PROFILE_SEED, POOL_MEMBER_SEED},
+    errors::HashtrologyErrors,
+    state::{EntryPool, LotteryState, PlayerProfile, PoolMember}
+};
+
+#[derive(Accounts)]
+pub struct JoinPool<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(mut)]
+    pub entry_pool: Account<'info, EntryPool>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + PoolMember::INIT_SPACE,
+        seeds = [POOL_MEMBER_SEED, entry_pool.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub pool_member: Account<'info, PoolMember>,
+
+    /// CHECK: Always required so a spending cap or self-exclusion can't be skipped by leaving it out.
+    /// Only enforced once the member has created a profile.
+    #[account(
+        mut,
+        seeds = [PLAYER_PROFILE_SEED, member.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> JoinPool<'info> {
+    /// Escrows `amount` in the pool. Contributions stop at the ticket price.
+    pub fn join_pool_handler(&mut self, amount: u64, bumps: &JoinPoolBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        let entry_pool = &mut self.entry_pool;
+
+        require!(
+            !entry_pool.is_entered && entry_pool.lottery_id == lottery_state.current_lottery_id,
+            HashtrologyErrors::PoolClosed
+        );
+
+        let total_after = entry_pool.total_contributed
+            .checked_add(amount)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        require!(
+            amount > 0 && total_after <= lottery_state.ticket_price,
+            HashtrologyErrors::InvalidPoolContribution
+        );
+
+        PlayerProfile::charge(&self.player_profile, amount, Clock::get()?.unix_timestamp)?;
+
+        let pool_member = &mut self.pool_member;
+        if pool_member.member == Pubkey::default() {
+            pool_member.pool = entry_pool.key();
+            pool_member.member = self.member.key();
+            pool_member.bump = bumps.pool_member;
+            entry_pool.member_count = entry_pool.member_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        pool_member.contribution = pool_member.contribution.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+        entry_pool.total_contributed = total_after;
+
+        let accounts = Transfer {
+            from: self.member.to_account_info(),
+            to: entry_pool.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, amount)?;
+
+        msg!(
+            "{} added {} lamports to pool {} ({} of {})",
+            self.member.key(),
+            amount,
+            entry_pool.key(),
+            entry_pool.total_contributed,
+            lottery_state.ticket_price
+        );
+
+        Ok(())
+    }
+}
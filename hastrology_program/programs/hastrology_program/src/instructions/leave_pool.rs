@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::POOL_MEMBER_SEED,
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{EntryPool, PoolMember}
+};
+
+/// Refunds a member's contribution while the pool has not entered yet.
+#[derive(Accounts)]
+pub struct LeavePool<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(mut)]
+    pub entry_pool: Account<'info, EntryPool>,
+
+    #[account(
+        mut,
+        close = member,
+        seeds = [POOL_MEMBER_SEED, entry_pool.key().as_ref(), member.key().as_ref()],
+        bump = pool_member.bump
+    )]
+    pub pool_member: Account<'info, PoolMember>,
+}
+
+impl<'info> LeavePool<'info> {
+    pub fn leave_pool_handler(&mut self) -> Result<()> {
+        let entry_pool = &mut self.entry_pool;
+        let contribution = self.pool_member.contribution;
+
+        require!(
+            !entry_pool.is_entered,
+            HashtrologyErrors::PoolClosed
+        );
+
+        entry_pool.total_contributed = entry_pool.total_contributed
+            .checked_sub(contribution)
+            .ok_or(HashtrologyErrors::Overflow)?;
+        entry_pool.member_count = entry_pool.member_count.saturating_sub(1);
+
+        debit_lamports(&entry_pool.to_account_info(), contribution)?;
+        credit_lamports(&self.member, contribution)?;
+
+        msg!("{} left pool {} with {} lamports", self.member.key(), entry_pool.key(), contribution);
+
+        Ok(())
+    }
+}
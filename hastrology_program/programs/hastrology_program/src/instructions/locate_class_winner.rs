@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    events::ClassWinnerLocated,
+    math::weight_contains,
+    state::{LotteryState, RoundHistory, UserTicket}
+};
+
+/// Permissionless: when the draw lands on class weight, anyone may name the class ticket
+/// covering it, found off-chain from the tickets' `class_weight_start`.
+#[derive(Accounts)]
+#[instruction(ticket_index: u32)]
+pub struct LocateClassWinner<'info> {
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &lottery_state.current_lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub winning_ticket: Account<'info, UserTicket>,
+}
+
+impl<'info> LocateClassWinner<'info> {
+    pub fn locate_class_winner_handler(&mut self, ticket_index: u32) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let winning_ticket = &self.winning_ticket;
+
+        let winning_weight = lottery_state.class_winning_weight.ok_or(HashtrologyErrors::NoClassWeightDrawn)?;
+
+        require!(
+            Clock::get()?.unix_timestamp >= lottery_state.reveal_after,
+            HashtrologyErrors::RevealTooEarly
+        );
+
+        let ticket_class = winning_ticket.ticket_class
+            .and_then(|index| lottery_state.ticket_classes.get(index as usize))
+            .ok_or(HashtrologyErrors::ClassWeightNotCovered)?;
+
+        require!(
+            weight_contains(
+                winning_ticket.class_weight_start as u128,
+                ticket_class.extra_weight() as u128,
+                winning_weight as u128
+            ),
+            HashtrologyErrors::ClassWeightNotCovered
+        );
+
+        let winner = ticket_index as u64 + 1;
+
+        lottery_state.winner = winner;
+        lottery_state.class_winning_weight = None;
+        lottery_state.reveal_after = 0;
+        self.round_history.winner = winner;
+
+        emit!(ClassWinnerLocated {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            winner,
+            ticket_class: winning_ticket.ticket_class.unwrap_or_default(),
+            winning_weight,
+        });
+
+        msg!("Lottery #{} class weight {} landed on ticket {}", lottery_state.current_lottery_id, winning_weight, winner);
+
+        Ok(())
+    }
+}
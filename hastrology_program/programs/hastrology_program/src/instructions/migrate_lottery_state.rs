@@ -0,0 +1,82 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    state::{LegacyLotteryState, LotteryState},
+    ID
+};
+
+/// One-off: grows a lottery state still in its original layout to the current one. The
+/// round in progress carries over, every later field starts at its default and the vault
+/// ledger starts from the pot vault's balance. The authority pays the extra rent.
+#[derive(Accounts)]
+pub struct MigrateLotteryState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Read as a `LegacyLotteryState`, then grown and rewritten in place.
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump,
+        owner = ID
+    )]
+    pub lottery_state: UncheckedAccount<'info>,
+
+    /// CHECK: The pot vault PDA; only its balance is read.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump
+    )]
+    pub pot_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> MigrateLotteryState<'info> {
+    pub fn migrate_lottery_state_handler(&mut self) -> Result<()> {
+        let info = self.lottery_state.to_account_info();
+        let old_len = info.data_len();
+        let new_len = 8 + LotteryState::INIT_SPACE;
+
+        require!(old_len == LegacyLotteryState::LEN, HashtrologyErrors::LotteryStateAlreadyMigrated);
+
+        let legacy = LegacyLotteryState::load(&info)?;
+
+        require_keys_eq!(
+            self.authority.key(),
+            legacy.authority,
+            HashtrologyErrors::UnauthorizedAuthority
+        );
+
+        require!(!legacy.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        let rent = Rent::get()?;
+        let top_up = rent.minimum_balance(new_len).saturating_sub(info.lamports());
+
+        if top_up > 0 {
+            let accounts = Transfer {
+                from: self.authority.to_account_info(),
+                to: info.clone()
+            };
+
+            let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+            transfer(cpi_ctx, top_up)?;
+        }
+
+        info.realloc(new_len, true)?;
+
+        let mut lottery_state = legacy.into_current(&Clock::get()?);
+        lottery_state.rebase_vault_ledger(self.pot_vault.lamports());
+        lottery_state.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        msg!("Lottery state migrated to the current layout ({} bytes)", new_len);
+
+        Ok(())
+    }
+}
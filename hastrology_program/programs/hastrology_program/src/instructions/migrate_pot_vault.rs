@@ -0,0 +1,83 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, PotVault, Role},
+    ID
+};
+
+/// One-off: grows the untyped pot vault into a `PotVault`. The authority pays the extra rent,
+/// so the pot itself is unchanged.
+#[derive(Accounts)]
+pub struct MigratePotVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: The pot vault PDA, still in its 8-byte layout.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump,
+        owner = ID
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> MigratePotVault<'info> {
+    pub fn migrate_pot_vault_handler(&mut self) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        let vault = &self.pot_vault;
+        let old_len = vault.data_len();
+        let new_len = 8 + PotVault::INIT_SPACE;
+
+        require!(old_len < new_len, HashtrologyErrors::PotVaultAlreadyMigrated);
+
+        let rent = Rent::get()?;
+        let top_up = rent.minimum_balance(new_len).saturating_sub(rent.minimum_balance(old_len));
+
+        if top_up > 0 {
+            let accounts = Transfer {
+                from: self.authority.to_account_info(),
+                to: vault.clone()
+            };
+
+            let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+            transfer(cpi_ctx, top_up)?;
+            self.lottery_state.record_vault_inflow(top_up)?;
+        }
+
+        vault.realloc(new_len, true)?;
+
+        let lottery_state = &self.lottery_state;
+
+        PotVault {
+            ledger_base: lottery_state.vault_ledger_base,
+            total_inflow: lottery_state.vault_inflow_lamports,
+            total_outflow: lottery_state.vault_outflow_lamports,
+            migrated_at: Clock::get()?.unix_timestamp,
+            bump: lottery_state.pot_vault_bump
+        }.try_serialize(&mut &mut vault.try_borrow_mut_data()?[..])?;
+
+        msg!("Pot vault migrated to a typed account ({} bytes)", new_len);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::USER_TICKET_SEED,
+    errors::HashtrologyErrors,
+    lamports::close_program_account,
+    state::{LegacyUserTicket, UserTicket}
+};
+
+/// Moves a ticket created under the legacy 8-byte index seed to its u32 seed address.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct MigrateTicket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Original ticket owner; receives the legacy account's rent. Checked against
+    /// the legacy ticket in the handler.
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: Read as a `LegacyUserTicket`, whose layout predates every field `UserTicket`
+    /// has gained since, then closed.
+    #[account(
+        mut,
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &(ticket_index as u64).to_le_bytes()],
+        bump
+    )]
+    pub legacy_ticket: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UserTicket::INIT_SPACE,
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> MigrateTicket<'info> {
+    pub fn migrate_ticket_handler(&mut self, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        let legacy = LegacyUserTicket::load(&self.legacy_ticket)?;
+
+        require_keys_eq!(
+            self.user.key(),
+            legacy.user,
+            HashtrologyErrors::InvalidWinner
+        );
+
+        self.user_ticket.set_inner(legacy.into_current(self.payer.key()));
+        close_program_account(&self.legacy_ticket, &self.user)?;
+
+        msg!(
+            "Migrated ticket #{} of lottery #{} to compact seeds",
+            ticket_index,
+            lottery_id
+        );
+
+        Ok(())
+    }
+}
@@ -5,6 +5,150 @@ pub mod resolve_draw;
 pub mod payout;
 pub mod reset;
 pub mod update_config;
+pub mod configure_prize_mint;
+pub mod claim_prize;
+pub mod set_draw_operators;
+pub mod approve_draw;
+pub mod enter_next_round;
+pub mod process_queued_entry;
+pub mod cancel_queued_entry;
+pub mod configure_staking;
+pub mod stake_pot;
+pub mod unstake_pot;
+pub mod set_no_loss_mode;
+pub mod deposit_no_loss;
+pub mod withdraw_no_loss;
+pub mod payout_no_loss;
+pub mod initialize_audit_log;
+pub mod migrate_ticket;
+pub mod record_referral;
+pub mod distribute_referral_bonus;
+pub mod credit_deposit;
+pub mod refund_deposit;
+pub mod set_entry_eligibility;
+pub mod initialize_metrics;
+pub mod create_session;
+pub mod revoke_session;
+pub mod enter_with_session;
+pub mod retry_draw;
+pub mod set_payout_destination;
+pub mod set_merkle_entry_mode;
+pub mod init_merkle_tree;
+pub mod enter_merkle;
+pub mod claim_merkle_prize;
+pub mod set_anti_snipe;
+pub mod open_snipe_window;
+pub mod refund_late_entry;
+pub mod set_rate_limits;
+pub mod set_round_kind;
+pub mod set_keeper_reward;
+pub mod set_reveal_delay;
+pub mod reveal_winner;
+pub mod pick_lucky_sign;
+pub mod settle_lucky_sign;
+pub mod claim_co_winner_prizes;
+pub mod pack_round_tickets;
+pub mod open_round;
+pub mod set_round_duration;
+pub mod set_draw_window_queueing;
+pub mod initialize_registry;
+pub mod create_pool;
+pub mod join_pool;
+pub mod leave_pool;
+pub mod enter_pool;
+pub mod claim_pool_share;
+pub mod apply_config;
+pub mod set_season;
+pub mod preview_payout;
+pub mod set_spending_cap;
+pub mod self_exclude;
+pub mod pick_lotto_numbers;
+pub mod settle_lotto;
+pub mod register_lotto_ticket;
+pub mod finalize_lotto_tiers;
+pub mod claim_lotto_prize;
+pub mod initialize_fee_treasury;
+pub mod forward_fees;
+pub mod set_cpi_allowlist;
+pub mod validate_entry;
+pub mod enter_future_round;
+pub mod init_claim_bitmap;
+pub mod get_version;
+pub mod initialize_platform_config;
+pub mod set_host_approval;
+pub mod create_hosted_lottery;
+pub mod set_min_prize;
+pub mod set_entropy_mixing;
+pub mod commit_draw_nonce;
+pub mod reveal_draw_nonce;
+pub mod settle_unrevealed_draw;
+pub mod set_swap_program;
+pub mod request_prize_swap;
+pub mod cancel_prize_swap;
+pub mod claim_swapped_prize;
+pub mod reconcile;
+pub mod set_early_bird;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_entries_paused;
+pub mod initialize_recover;
+pub mod set_fee_governance;
+pub mod approve_fee_ceiling;
+pub mod set_kyc_verifier;
+pub mod enter_pending_kyc;
+pub mod approve_entry;
+pub mod refund_pending_entry;
+pub mod initialize_mega_jackpot;
+pub mod set_mega_jackpot_contribution;
+pub mod request_mega_draw;
+pub mod resolve_mega_draw;
+pub mod settle_mega_draw;
+pub mod set_endtime_grace;
+pub mod claim_held_prize;
+pub mod set_streak_bonus;
+pub mod reconcile_vault;
+pub mod set_winner_hook;
+pub mod set_ticket_classes;
+pub mod locate_class_winner;
+pub mod configure_loser_rewards;
+pub mod claim_loser_reward;
+pub mod set_prize_granularity;
+pub mod close_losing_tickets;
+pub mod set_config_bounds;
+pub mod announce_round;
+pub mod open_announced_round;
+pub mod place_conditional_entry;
+pub mod execute_conditional_entry;
+pub mod refund_conditional_entry;
+pub mod set_stake_payout;
+pub mod claim_prize_as_stake;
+pub mod set_trivial_resolution;
+pub mod resolve_trivial_round;
+pub mod quote_entry;
+pub mod initialize_insurance_reserve;
+pub mod set_insurance_bps;
+pub mod propose_compensation;
+pub mod compensate;
+pub mod cancel_compensation;
+pub mod set_dual_draw;
+pub mod prune_history;
+pub mod migrate_pot_vault;
+pub mod attach_nft_prize;
+pub mod claim_nft_prize;
+pub mod reclaim_nft_prize;
+pub mod set_fee_promotion;
+pub mod set_class_draws;
+pub mod claim_class_prize;
+pub mod sunset;
+pub mod refund_sunset_entry;
+pub mod close_sunset_accounts;
+pub mod set_slot_deadline;
+pub mod set_randomness_provider;
+pub mod set_device_entry_limit;
+pub mod snapshot_airdrop;
+pub mod crank_airdrop_snapshot;
+pub mod claim_held_token_prize;
+pub mod migrate_lottery_state;
 
 pub use initialize::*;
 pub use enter_lottery::*;
@@ -12,4 +156,146 @@ pub use request_draw::*;
 pub use resolve_draw::*;
 pub use payout::*;
 pub use reset::*;
-pub use update_config::*;
\ No newline at end of file
+pub use update_config::*;
+pub use configure_prize_mint::*;
+pub use claim_prize::*;
+pub use set_draw_operators::*;
+pub use approve_draw::*;
+pub use enter_next_round::*;
+pub use process_queued_entry::*;
+pub use cancel_queued_entry::*;
+pub use configure_staking::*;
+pub use stake_pot::*;
+pub use unstake_pot::*;
+pub use set_no_loss_mode::*;
+pub use deposit_no_loss::*;
+pub use withdraw_no_loss::*;
+pub use payout_no_loss::*;
+pub use initialize_audit_log::*;
+pub use migrate_ticket::*;
+pub use record_referral::*;
+pub use distribute_referral_bonus::*;
+pub use credit_deposit::*;
+pub use refund_deposit::*;
+pub use set_entry_eligibility::*;
+pub use initialize_metrics::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use enter_with_session::*;
+pub use set_payout_destination::*;
+pub use set_merkle_entry_mode::*;
+pub use init_merkle_tree::*;
+pub use enter_merkle::*;
+pub use claim_merkle_prize::*;
+pub use set_anti_snipe::*;
+pub use open_snipe_window::*;
+pub use refund_late_entry::*;
+pub use set_rate_limits::*;
+pub use set_round_kind::*;
+pub use set_keeper_reward::*;
+pub use set_reveal_delay::*;
+pub use reveal_winner::*;
+pub use pick_lucky_sign::*;
+pub use settle_lucky_sign::*;
+pub use claim_co_winner_prizes::*;
+pub use pack_round_tickets::*;
+pub use open_round::*;
+pub use set_round_duration::*;
+pub use set_draw_window_queueing::*;
+pub use initialize_registry::*;
+pub use create_pool::*;
+pub use join_pool::*;
+pub use leave_pool::*;
+pub use enter_pool::*;
+pub use claim_pool_share::*;
+pub use apply_config::*;
+pub use set_season::*;
+pub use preview_payout::*;
+pub use set_spending_cap::*;
+pub use self_exclude::*;
+pub use pick_lotto_numbers::*;
+pub use settle_lotto::*;
+pub use register_lotto_ticket::*;
+pub use finalize_lotto_tiers::*;
+pub use claim_lotto_prize::*;
+pub use initialize_fee_treasury::*;
+pub use forward_fees::*;
+pub use set_cpi_allowlist::*;
+pub use validate_entry::*;
+pub use enter_future_round::*;
+pub use init_claim_bitmap::*;
+pub use get_version::*;
+pub use initialize_platform_config::*;
+pub use set_host_approval::*;
+pub use create_hosted_lottery::*;
+pub use set_min_prize::*;
+pub use set_entropy_mixing::*;
+pub use commit_draw_nonce::*;
+pub use reveal_draw_nonce::*;
+pub use set_swap_program::*;
+pub use request_prize_swap::*;
+pub use cancel_prize_swap::*;
+pub use claim_swapped_prize::*;
+pub use reconcile::*;
+pub use set_early_bird::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_entries_paused::*;
+pub use initialize_recover::*;
+pub use set_fee_governance::*;
+pub use approve_fee_ceiling::*;
+pub use set_kyc_verifier::*;
+pub use enter_pending_kyc::*;
+pub use approve_entry::*;
+pub use refund_pending_entry::*;
+pub use initialize_mega_jackpot::*;
+pub use set_mega_jackpot_contribution::*;
+pub use request_mega_draw::*;
+pub use resolve_mega_draw::*;
+pub use settle_mega_draw::*;
+pub use set_endtime_grace::*;
+pub use claim_held_prize::*;
+pub use set_streak_bonus::*;
+pub use reconcile_vault::*;
+pub use set_winner_hook::*;
+pub use set_ticket_classes::*;
+pub use locate_class_winner::*;
+pub use configure_loser_rewards::*;
+pub use claim_loser_reward::*;
+pub use set_prize_granularity::*;
+pub use close_losing_tickets::*;
+pub use set_config_bounds::*;
+pub use announce_round::*;
+pub use open_announced_round::*;
+pub use place_conditional_entry::*;
+pub use execute_conditional_entry::*;
+pub use refund_conditional_entry::*;
+pub use set_stake_payout::*;
+pub use claim_prize_as_stake::*;
+pub use set_trivial_resolution::*;
+pub use resolve_trivial_round::*;
+pub use quote_entry::*;
+pub use initialize_insurance_reserve::*;
+pub use set_insurance_bps::*;
+pub use propose_compensation::*;
+pub use compensate::*;
+pub use cancel_compensation::*;
+pub use set_dual_draw::*;
+pub use prune_history::*;
+pub use migrate_pot_vault::*;
+pub use attach_nft_prize::*;
+pub use claim_nft_prize::*;
+pub use reclaim_nft_prize::*;
+pub use set_fee_promotion::*;
+pub use set_class_draws::*;
+pub use claim_class_prize::*;
+pub use sunset::*;
+pub use refund_sunset_entry::*;
+pub use close_sunset_accounts::*;
+pub use set_slot_deadline::*;
+pub use set_randomness_provider::*;
+pub use set_device_entry_limit::*;
+pub use snapshot_airdrop::*;
+pub use crank_airdrop_snapshot::*;
+pub use claim_held_token_prize::*;
+pub use migrate_lottery_state::*;
\ No newline at end of file
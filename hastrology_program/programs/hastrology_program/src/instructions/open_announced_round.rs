@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PROGRAM_VERSION, UPCOMING_ROUND_SEED},
+    errors::HashtrologyErrors,
+    events::RoundOpened,
+    state::{LotteryState, UpcomingRound}
+};
+
+/// Permissionless once the announced open time has passed and payout has finalized the
+/// current round. The announcement's rent goes back to whoever made it.
+#[derive(Accounts)]
+pub struct OpenAnnouncedRound<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        close = announced_by,
+        seeds = [UPCOMING_ROUND_SEED, &(lottery_state.current_lottery_id + 1).to_le_bytes()],
+        bump = upcoming_round.bump
+    )]
+    pub upcoming_round: Account<'info, UpcomingRound>,
+
+    /// CHECK: Receives the announcement's rent.
+    #[account(
+        mut,
+        address = upcoming_round.announced_by @ HashtrologyErrors::InvalidAnnouncer
+    )]
+    pub announced_by: UncheckedAccount<'info>,
+}
+
+impl<'info> OpenAnnouncedRound<'info> {
+    pub fn open_announced_round_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        lottery_state.open_next_round(&Clock::get()?)?;
+
+        emit!(RoundOpened {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            lottery_endtime: lottery_state.lottery_endtime,
+            ticket_price: lottery_state.ticket_price,
+        });
+
+        msg!(
+            "Announced lottery #{} \"{}\" opened until {}",
+            lottery_state.current_lottery_id,
+            self.upcoming_round.theme,
+            lottery_state.lottery_endtime
+        );
+
+        Ok(())
+    }
+}
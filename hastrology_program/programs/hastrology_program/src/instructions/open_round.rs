@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::RoundOpened,
+    state::LotteryState
+};
+
+/// Permissionless: once payout has finalized a round, anyone may open the next one
+/// with the current config. An announced round opens through `open_announced_round`.
+#[derive(Accounts)]
+pub struct OpenRound<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> OpenRound<'info> {
+    pub fn open_round_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.announced_opens_at == 0,
+            HashtrologyErrors::RoundWasAnnounced
+        );
+
+        lottery_state.open_next_round(&Clock::get()?)?;
+
+        emit!(RoundOpened {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            lottery_endtime: lottery_state.lottery_endtime,
+            ticket_price: lottery_state.ticket_price,
+        });
+
+        msg!(
+            "Lottery #{} opened until {}",
+            lottery_state.current_lottery_id,
+            lottery_state.lottery_endtime
+        );
+
+        Ok(())
+    }
+}
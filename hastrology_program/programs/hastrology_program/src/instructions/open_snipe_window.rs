@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, SNIPE_WINDOW_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, SnipeWindow}
+};
+
+/// Permissionless; creates the log that entries after `lottery_endtime` are recorded in.
+#[derive(Accounts)]
+pub struct OpenSnipeWindow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SnipeWindow::INIT_SPACE,
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub snipe_window: Account<'info, SnipeWindow>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> OpenSnipeWindow<'info> {
+    pub fn open_snipe_window_handler(&mut self, bumps: &OpenSnipeWindowBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            lottery_state.anti_snipe_max_extension > 0,
+            HashtrologyErrors::AntiSnipeDisabled
+        );
+
+        self.snipe_window.set_inner(SnipeWindow {
+            lottery_id: lottery_state.current_lottery_id,
+            ticket_price: lottery_state.ticket_price,
+            entries: Vec::new(),
+            is_resolved: false,
+            true_endtime: 0,
+            eligible_participants: 0,
+            bump: bumps.snipe_window
+        });
+
+        msg!("Anti-snipe window opened for lottery #{}", lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    system_program::{create_account, transfer, CreateAccount, Transfer},
+    Discriminator
+};
+
+use crate::{
+    constants::{PARTICIPANT_INDEX_SEED, TICKET_PACK_BATCH, TICKET_PAGE_SEED, TICKET_PAGE_SIZE},
+    state::{PackedTicket, ParticipantIndex, TicketPage},
+    ID
+};
+
+/// Permissionless crank; the caller pays the page's rent.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, page: u32)]
+pub struct PackRoundTickets<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Read as raw bytes; deserializing the whole index would exceed the heap on large rounds.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    /// CHECK: A full page exceeds the CPI allocation limit, so it is created and grown here
+    /// in `MAX_PERMITTED_DATA_INCREASE` steps before any tickets are packed.
+    #[account(
+        mut,
+        seeds = [TICKET_PAGE_SEED, &lottery_id.to_le_bytes(), &page.to_le_bytes()],
+        bump
+    )]
+    pub ticket_page: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> PackRoundTickets<'info> {
+    pub fn pack_round_tickets_handler(&mut self, lottery_id: u64, page: u32, bumps: &PackRoundTicketsBumps) -> Result<()> {
+        if !self.grow_page(lottery_id, page, bumps.ticket_page)? {
+            return Ok(());
+        }
+
+        let mut data = self.ticket_page.try_borrow_mut_data()?;
+        if data[..8] == [0u8; 8] {
+            data[..8].copy_from_slice(TicketPage::DISCRIMINATOR);
+        }
+
+        // The view `AccountLoader::load_mut` gives, without an `AccountLoader` that would have
+        // to borrow the page for `'info`
+        let ticket_page: &mut TicketPage = bytemuck::from_bytes_mut(&mut data[8..TicketPage::SPACE]);
+        ticket_page.lottery_id = lottery_id;
+        ticket_page.page = page;
+
+        let index_data = self.participant_index.try_borrow_data()?;
+        let first_ticket = page as usize * TICKET_PAGE_SIZE;
+        let page_total = ParticipantIndex::raw_len(&index_data)
+            .saturating_sub(first_ticket)
+            .min(TICKET_PAGE_SIZE);
+
+        let start = ticket_page.count as usize;
+        let end = page_total.min(start + TICKET_PACK_BATCH);
+
+        for slot in start..end {
+            let ticket_index = first_ticket + slot;
+            let Some(user) = ParticipantIndex::raw_participant(&index_data, ticket_index) else {
+                break;
+            };
+
+            ticket_page.entries[slot] = PackedTicket {
+                user,
+                ticket_number: (ticket_index + 1) as u32
+            };
+            ticket_page.count += 1;
+        }
+
+        msg!(
+            "Lottery #{} page {}: {} of {} tickets packed",
+            lottery_id,
+            page,
+            ticket_page.count,
+            page_total
+        );
+
+        Ok(())
+    }
+
+    /// Creates or grows the page by one step. Returns whether it has reached full size.
+    fn grow_page(&self, lottery_id: u64, page: u32, bump: u8) -> Result<bool> {
+        let page_info = self.ticket_page.to_account_info();
+        let current_len = page_info.data_len();
+
+        if current_len >= TicketPage::SPACE {
+            return Ok(true);
+        }
+
+        let new_len = TicketPage::SPACE.min(current_len + MAX_PERMITTED_DATA_INCREASE);
+        let required_lamports = Rent::get()?.minimum_balance(new_len);
+
+        if current_len == 0 {
+            let lottery_id_bytes = lottery_id.to_le_bytes();
+            let page_bytes = page.to_le_bytes();
+            let seeds: &[&[u8]] = &[TICKET_PAGE_SEED, &lottery_id_bytes, &page_bytes, &[bump]];
+
+            let accounts = CreateAccount {
+                from: self.cranker.to_account_info(),
+                to: page_info.clone()
+            };
+
+            let signer_seeds = &[seeds];
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+
+            create_account(cpi_ctx, required_lamports, new_len as u64, &ID)?;
+        } else {
+            let top_up = required_lamports.saturating_sub(page_info.lamports());
+
+            if top_up > 0 {
+                let accounts = Transfer {
+                    from: self.cranker.to_account_info(),
+                    to: page_info.clone()
+                };
+
+                let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+                transfer(cpi_ctx, top_up)?;
+            }
+
+            page_info.realloc(new_len, false)?;
+        }
+
+        msg!("Ticket page {} for lottery #{} grown to {} of {} bytes", page, lottery_id, new_len, TicketPage::SPACE);
+
+        Ok(new_len == TicketPage::SPACE)
+    }
+}
@@ -1,18 +1,37 @@
 use anchor_lang::{
     prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed
+    }
 };
 
 use crate::{
-    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, USER_TICKET_SEED}, errors::HashtrologyErrors, 
-    state::{LotteryState, UserTicket}
+    constants::{AUDIT_LOG_SEED, FEE_TREASURY_SEED, INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED, METRICS_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, PRIZE_SWAP_SEED, PROGRAM_VERSION, REFERRAL_EPOCH_SEED, ROUND_HISTORY_SEED, USER_TICKET_SEED, WINNER_HOOK_DISCRIMINATOR, WINNER_RECORD_SEED}, errors::HashtrologyErrors, 
+    events::RoundPaidOut,
+    lamports::{can_receive_lamports, credit_lamports, debit_lamports},
+    instructions::PayoutPreview,
+    require_role,
+    state::{AuditAction, AuditLog, FeeTreasury, InsuranceReserve, LotteryState, Metrics, PlayerProfile, PrizeSwap, ReferralEpoch, Role, RoundHistory, UserTicket, WinnerRecord},
+    ID
 };
 
+/// What the winner hook receives, after `WINNER_HOOK_DISCRIMINATOR`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RoundResult {
+    pub lottery_id: u64,
+    pub winning_ticket: u64,
+    pub winner: Pubkey,
+    pub winner_prize: u64,
+    pub pot_lamports: u64,
+    pub prize_held: bool // kept in the pot vault for a swap or a claim rather than sent
+}
+
+/// Remaining accounts: the registered winner hook program, when one is set, followed by
+/// whatever accounts that program takes.
 #[derive(Accounts)]
 pub struct Payout<'info> {
-    #[account(
-        mut,
-        constraint = authority.key() == lottery_state.authority @ HashtrologyErrors::UnauthorizedAuthority
-    )]
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
@@ -30,19 +49,19 @@ pub struct Payout<'info> {
     )]
     pub pot_vault: AccountInfo<'info>,
 
-    /// CHECK: This is the platform wallet
     #[account(
         mut,
-        address = lottery_state.platform_wallet
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
     )]
-    pub platform_wallet: AccountInfo<'info>,
+    pub fee_treasury: Account<'info, FeeTreasury>,
 
    #[account(
         mut,
         seeds = [
             USER_TICKET_SEED,
             &lottery_state.current_lottery_id.to_le_bytes(),
-            &(lottery_state.winner - 1).to_le_bytes() 
+            &((lottery_state.winner - 1) as u32).to_le_bytes()
         ],
         bump,
         constraint = winning_ticket.lottery_id == lottery_state.current_lottery_id @ HashtrologyErrors::InvalidWinner,
@@ -50,18 +69,81 @@ pub struct Payout<'info> {
     )]
     pub winning_ticket: Account<'info, UserTicket>,
 
-    /// CHECK: The wallet of winner
+    /// CHECK: The wallet of winner, or the payout destination they set on the ticket
     #[account(
         mut,
-        constraint = winner.key() == winning_ticket.user @ HashtrologyErrors::InvalidWinner
+        constraint = winner.key() == winning_ticket.payout_address() @ HashtrologyErrors::InvalidWinner
     )]
     pub winner: AccountInfo<'info>,
 
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    /// When provided, the top referrer's bonus is reserved out of the platform fee.
+    #[account(
+        mut,
+        seeds = [REFERRAL_EPOCH_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = referral_epoch.bump
+    )]
+    pub referral_epoch: Option<Account<'info, ReferralEpoch>>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// When the winner has a profile, the win is counted towards their season.
+    #[account(
+        mut,
+        seeds = [PLAYER_PROFILE_SEED, winning_ticket.user.as_ref()],
+        bump = winner_profile.bump
+    )]
+    pub winner_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Required while `insurance_bps` is set; receives the insurance cut.
+    #[account(
+        mut,
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump = insurance_reserve.bump
+    )]
+    pub insurance_reserve: Option<Account<'info, InsuranceReserve>>,
+
+    #[account(
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    /// CHECK: Always required so a requested prize swap can't be skipped by leaving it out.
+    /// Only used once the winner has opened it with `request_prize_swap`.
+    #[account(
+        mut,
+        seeds = [PRIZE_SWAP_SEED, winning_ticket.key().as_ref()],
+        bump
+    )]
+    pub prize_swap: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + WinnerRecord::INIT_SPACE,
+        seeds = [WINNER_RECORD_SEED, winning_ticket.user.as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub winner_record: Account<'info, WinnerRecord>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Payout<'info> {
-    pub fn payout_handler(&mut self) -> Result<()> { 
+    pub fn payout_handler(&mut self, bumps: &PayoutBumps, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> { 
+        require_role!(self.lottery_state, self.authority, Role::Operator);
 
         let lottery_state = &mut self.lottery_state;
 
@@ -69,41 +151,301 @@ impl<'info> Payout<'info> {
             lottery_state.is_drawing,
             HashtrologyErrors::DrawNotRequested
         );
-        let winning_ticket = &mut self.winning_ticket;
 
-        let total_pot_balance = self.pot_vault.lamports();
-    
-        let platform_fee_amount = (total_pot_balance * lottery_state.platform_fee_bps as u64) / 10_000;
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        require!(
+            !lottery_state.has_pending_reveal(),
+            HashtrologyErrors::WinnerNotRevealed
+        );
+
+        require!(
+            lottery_state.class_winning_weight.is_none(),
+            HashtrologyErrors::ClassWinnerNotLocated
+        );
+
+        require!(
+            lottery_state.round_kind.accepts_tickets(),
+            HashtrologyErrors::NoLossModeActive
+        );
+
+        require!(
+            lottery_state.round_kind.has_single_winner(),
+            HashtrologyErrors::CoWinnerRound
+        );
+
+        require!(
+            !lottery_state.merkle_entry_mode,
+            HashtrologyErrors::MerkleEntryModeActive
+        );
+
+        // Only the late-entry refunds set aside at resolve may have left the pot since the request
+        let round_history = &self.round_history;
+        round_history.verify_snapshot(lottery_state)?;
 
-        let winner_prize_amount = total_pot_balance
-            .checked_sub(platform_fee_amount)
+        let pot_with_refunds = lottery_state.round_pot(self.pot_vault.lamports())?
+            .checked_add(round_history.late_refunds)
             .ok_or(HashtrologyErrors::Overflow)?;
 
-        **self.pot_vault.try_borrow_mut_lamports()? -= platform_fee_amount;
-        **self.platform_wallet.try_borrow_mut_lamports()? += platform_fee_amount;
+        require!(
+            pot_with_refunds >= round_history.snapshot_pot,
+            HashtrologyErrors::RoundSnapshotMismatch
+        );
+
+        let winning_ticket = &mut self.winning_ticket;
+
+        let has_top_referrer = self.referral_epoch
+            .as_ref()
+            .is_some_and(|referral_epoch| referral_epoch.top_count > 0);
+
+        let PayoutPreview {
+            platform_fee: platform_fee_amount,
+            referral_bonus: referral_bonus_amount,
+            platform_share,
+            winner_prize,
+            prize_top_up,
+            pot_lamports,
+            rollover,
+            prize_dust,
+            insurance_cut,
+            ..
+        } = PayoutPreview::compute(lottery_state, self.pot_vault.lamports(), has_top_referrer, self.fee_treasury.accrued_fees)?;
+
+        if let Some(referral_epoch) = self.referral_epoch.as_mut().filter(|_| has_top_referrer) {
+            referral_epoch.bonus_lamports = referral_bonus_amount;
+            lottery_state.reserved_referral_lamports = lottery_state.reserved_referral_lamports
+                .checked_add(referral_bonus_amount)
+                .ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        debit_lamports(&self.pot_vault, platform_share)?;
+        credit_lamports(&self.fee_treasury.to_account_info(), platform_share)?;
+        self.fee_treasury.accrue(platform_share)?;
+        lottery_state.record_vault_outflow(platform_share)?;
         msg!("platform fee transferred");
 
-        **self.pot_vault.try_borrow_mut_lamports()? -= winner_prize_amount;
-        **self.winner.try_borrow_mut_lamports()? += winner_prize_amount;
-        msg!("winner prize transferred");
+        if insurance_cut > 0 {
+            let insurance_reserve = self.insurance_reserve
+                .as_mut()
+                .ok_or(HashtrologyErrors::InsuranceReserveRequired)?;
+
+            debit_lamports(&self.pot_vault, insurance_cut)?;
+            credit_lamports(&insurance_reserve.to_account_info(), insurance_cut)?;
+            insurance_reserve.deposit(insurance_cut)?;
+            lottery_state.record_vault_outflow(insurance_cut)?;
+            msg!("{} lamports set aside for the insurance reserve", insurance_cut);
+        }
+
+        // Consecutive wins earn an escalating bonus from whatever the treasury has left after any top-up
+        let win_streak = lottery_state.record_win_streak(winning_ticket.user);
+        let streak_bonus = lottery_state.streak_bonus(win_streak)
+            .min(self.fee_treasury.accrued_fees.saturating_sub(prize_top_up));
+        let treasury_share = prize_top_up.checked_add(streak_bonus).ok_or(HashtrologyErrors::Overflow)?;
+        let winner_prize_amount = winner_prize.checked_add(treasury_share).ok_or(HashtrologyErrors::Overflow)?;
+
+        // A prize the winner asked to swap stays in the vault until claim_swapped_prize
+        let hold_for_swap = self.prize_swap.owner == &ID;
+
+        // So does one the winner asked to stake, or one the address it would go to couldn't
+        // spend, whether their own wallet or the payout destination they set
+        let hold_for_stake = !hold_for_swap && winning_ticket.stake_vote_account.is_some();
+        let hold_for_claim = hold_for_stake || (!hold_for_swap && !can_receive_lamports(&self.winner));
+
+        let prize_recipient = if hold_for_swap {
+            let mut prize_swap = PrizeSwap::try_deserialize(&mut &self.prize_swap.try_borrow_data()?[..])?;
+            prize_swap.lamports = winner_prize_amount;
+            prize_swap.try_serialize(&mut &mut self.prize_swap.try_borrow_mut_data()?[..])?;
+
+            lottery_state.swap_prize_lamports = lottery_state.swap_prize_lamports
+                .checked_add(winner_prize_amount)
+                .ok_or(HashtrologyErrors::Overflow)?;
+            msg!("winner prize held for swap");
+
+            &self.pot_vault
+        } else if hold_for_claim {
+            winning_ticket.held_prize_lamports = winner_prize_amount;
+
+            lottery_state.held_prize_lamports = lottery_state.held_prize_lamports
+                .checked_add(winner_prize_amount)
+                .ok_or(HashtrologyErrors::Overflow)?;
+            if hold_for_stake {
+                msg!("winner prize held until claimed as a stake account");
+            } else {
+                msg!("winner prize held until claimed to a wallet");
+            }
+
+            &self.pot_vault
+        } else {
+            debit_lamports(&self.pot_vault, winner_prize)?;
+            credit_lamports(&self.winner, winner_prize)?;
+            lottery_state.record_vault_outflow(winner_prize)?;
+            msg!("winner prize transferred");
+
+            &self.winner
+        };
+
+        if treasury_share > 0 {
+            debit_lamports(&self.fee_treasury.to_account_info(), treasury_share)?;
+            credit_lamports(prize_recipient, treasury_share)?;
+            self.fee_treasury.spend(treasury_share)?;
+
+            if hold_for_swap || hold_for_claim {
+                lottery_state.record_vault_inflow(treasury_share)?;
+            }
+        }
+
+        if prize_top_up > 0 {
+            msg!("prize topped up by {} lamports from the fee treasury", prize_top_up);
+        }
+
+        if streak_bonus > 0 {
+            msg!("{}-round win streak bonus of {} lamports from the fee treasury", win_streak, streak_bonus);
+        }
+
+        if prize_dust > 0 {
+            msg!("{} lamports of prize dust sent to the fee treasury", prize_dust);
+        }
 
 
         winning_ticket.is_winner = true;
         winning_ticket.prize_amount = winner_prize_amount;  
 
-        lottery_state.total_participants = 0;
-        lottery_state.current_lottery_id = lottery_state.current_lottery_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
-        lottery_state.lottery_endtime = lottery_state.lottery_endtime.checked_add(86400).ok_or(HashtrologyErrors::Overflow)?;
-        lottery_state.is_drawing = false; 
-        lottery_state.commit_slot = 0;
+        if lottery_state.prize_mint != Pubkey::default() {
+            winning_ticket.token_prize_amount = lottery_state.token_prize_amount;
+        }
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::Payout,
+            self.authority.key(),
+            &[
+                &lottery_state.current_lottery_id.to_le_bytes(),
+                &lottery_state.winner.to_le_bytes(),
+                winning_ticket.user.as_ref(),
+                &winner_prize_amount.to_le_bytes(),
+                &platform_fee_amount.to_le_bytes(),
+            ]
+        )?;
+
+        let winning_ticket_index = lottery_state.winner;
+        lottery_state.reserve_class_pots()?;
+        lottery_state.finalize_round();
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.end_pause(now)?;
+        }
+
+        if let Some(winner_profile) = self.winner_profile.as_mut() {
+            winner_profile.record_win(lottery_state.current_season(now))?;
+        }
+
+        self.winner_record.record(
+            winning_ticket.user,
+            lottery_state.current_lottery_id,
+            winner_prize_amount,
+            now,
+            bumps.winner_record
+        )?;
+
+        let round_result = RoundResult {
+            lottery_id: lottery_state.current_lottery_id,
+            winning_ticket: winning_ticket_index,
+            winner: winning_ticket.user,
+            winner_prize: winner_prize_amount,
+            pot_lamports,
+            prize_held: hold_for_swap || hold_for_claim
+        };
+
+        emit!(RoundPaidOut {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            winning_ticket: winning_ticket_index,
+            winner: winning_ticket.user,
+            pot_lamports,
+            winner_prize: winner_prize_amount,
+            prize_top_up,
+            platform_fee: platform_fee_amount,
+            platform_fee_bps: lottery_state.platform_fee_bps,
+            referral_bonus: referral_bonus_amount,
+            rollover_lamports: rollover,
+            held_for_swap: hold_for_swap,
+            held_for_claim: hold_for_claim,
+            win_streak,
+            streak_bonus,
+            prize_dust,
+            insurance_cut,
+            fee_promo_lamports: lottery_state.round_promo_lamports().min(pot_lamports),
+            fee_promo_bps: lottery_state.fee_promo_bps,
+        });
 
         msg!(
             "Lottery #{} drawn! Winner: {}. Prize: {} lamports.",
-            lottery_state.current_lottery_id - 1,
+            lottery_state.current_lottery_id,
             winning_ticket.user,
             winner_prize_amount
         );
+
+        if self.lottery_state.winner_hook_program != Pubkey::default() {
+            self.notify_winner_hook(&round_result, remaining_accounts)?;
+        }
         
         Ok(())
     }
+
+    /// Calls the winner hook with the round's result, signed by the lottery state so the hook
+    /// can tell a real payout from a spoofed call. The state is written first, so the hook reads
+    /// the round as paid out.
+    fn notify_winner_hook(&mut self, round_result: &RoundResult, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let (hook_program, hook_accounts) = remaining_accounts
+            .split_first()
+            .ok_or(HashtrologyErrors::WinnerHookMissing)?;
+
+        require_keys_eq!(
+            hook_program.key(),
+            self.lottery_state.winner_hook_program,
+            HashtrologyErrors::WinnerHookMissing
+        );
+
+        require!(
+            hook_program.executable,
+            HashtrologyErrors::WinnerHookNotExecutable
+        );
+
+        self.lottery_state.exit(&ID)?;
+
+        let lottery_state_info = self.lottery_state.to_account_info();
+
+        let mut accounts = vec![AccountMeta::new_readonly(lottery_state_info.key(), true)];
+        accounts.extend(hook_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable
+        }));
+
+        let mut data = WINNER_HOOK_DISCRIMINATOR.to_vec();
+        round_result.serialize(&mut data)?;
+
+        let ix = Instruction {
+            program_id: hook_program.key(),
+            accounts,
+            data
+        };
+
+        let mut account_infos = vec![lottery_state_info];
+        account_infos.extend_from_slice(hook_accounts);
+        account_infos.push(hook_program.clone());
+
+        let seeds = &[LOTTERY_STATE_SEED, &[self.lottery_state.lottery_state_bump]];
+
+        invoke_signed(&ix, &account_infos, &[&seeds[..]])?;
+
+        msg!("Winner hook {} notified", hook_program.key());
+
+        Ok(())
+    }
 }
\ No newline at end of file
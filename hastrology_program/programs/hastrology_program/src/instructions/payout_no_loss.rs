@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_TREASURY_SEED, LOTTERY_STATE_SEED, METRICS_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    math::{available_balance, split_fee, weight_contains},
+    require_role,
+    state::{FeeTreasury, LotteryState, Metrics, NoLossDeposit, Role}
+};
+
+#[derive(Accounts)]
+pub struct PayoutNoLoss<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding deposits and accrued yield.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    /// Omitted when no weight was drawn (every deposit left before the draw).
+    pub winning_deposit: Option<Account<'info, NoLossDeposit>>,
+
+    /// CHECK: The wallet of the winning depositor
+    #[account(mut)]
+    pub winner: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+}
+
+impl<'info> PayoutNoLoss<'info> {
+    pub fn payout_no_loss_handler(&mut self) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.round_kind.accepts_deposits(),
+            HashtrologyErrors::NoLossModeDisabled
+        );
+
+        require!(
+            lottery_state.is_drawing,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        require!(
+            !lottery_state.has_pending_reveal(),
+            HashtrologyErrors::WinnerNotRevealed
+        );
+
+        let mut winner_prize_amount = 0;
+
+        if lottery_state.winner > 0 {
+            let deposit = self.winning_deposit.as_ref().ok_or(HashtrologyErrors::InvalidWinner)?;
+            let winner = self.winner.as_ref().ok_or(HashtrologyErrors::InvalidWinner)?;
+            let target = lottery_state.no_loss_winning_weight;
+
+            require!(
+                deposit.lottery_id == lottery_state.current_lottery_id
+                    && weight_contains(deposit.weight_start, deposit.weight, target),
+                HashtrologyErrors::InvalidWinner
+            );
+
+            require!(
+                winner.key() == deposit.user,
+                HashtrologyErrors::InvalidWinner
+            );
+
+            // A deposit withdrawn before the draw forfeits; the yield rolls over.
+            if !deposit.withdrawn {
+                let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+                let yield_pool = available_balance(
+                    self.pot_vault.lamports(),
+                    &[rent_reserve, lottery_state.total_deposits, lottery_state.reserved_referral_lamports]
+                );
+
+                let (platform_fee_amount, prize_amount) = split_fee(yield_pool, lottery_state.platform_fee_bps)?;
+                winner_prize_amount = prize_amount;
+
+                debit_lamports(&self.pot_vault, platform_fee_amount)?;
+                credit_lamports(&self.fee_treasury.to_account_info(), platform_fee_amount)?;
+                self.fee_treasury.accrue(platform_fee_amount)?;
+                lottery_state.record_vault_outflow(platform_fee_amount)?;
+
+                debit_lamports(&self.pot_vault, winner_prize_amount)?;
+                credit_lamports(winner, winner_prize_amount)?;
+                lottery_state.record_vault_outflow(winner_prize_amount)?;
+            }
+        }
+
+        lottery_state.no_loss_total_weight = 0;
+        lottery_state.no_loss_winning_weight = 0;
+        lottery_state.finalize_round();
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.end_pause(Clock::get()?.unix_timestamp)?;
+        }
+
+        msg!(
+            "No-loss lottery #{} settled. Yield prize: {} lamports.",
+            lottery_state.current_lottery_id,
+            winner_prize_amount
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use lottery_core::selection::numbers_mask;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, LOTTO_MAX_NUMBER, LOTTO_PICK_COUNT, LOTTO_PICK_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, LottoPick, RoundKind, UserTicket}
+};
+
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct PickLottoNumbers<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump,
+        has_one = user @ HashtrologyErrors::Unauthorized
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + LottoPick::INIT_SPACE,
+        seeds = [LOTTO_PICK_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub lotto_pick: Account<'info, LottoPick>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> PickLottoNumbers<'info> {
+    pub fn pick_lotto_numbers_handler(
+        &mut self,
+        lottery_id: u64,
+        ticket_index: u32,
+        numbers: Vec<u8>,
+        bumps: &PickLottoNumbersBumps
+    ) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            lottery_state.round_kind == RoundKind::LuckyNumbers,
+            HashtrologyErrors::NotLottoRound
+        );
+
+        require!(
+            numbers.len() == LOTTO_PICK_COUNT,
+            HashtrologyErrors::InvalidLottoNumbers
+        );
+
+        let mask = numbers_mask(&numbers, LOTTO_MAX_NUMBER).ok_or(HashtrologyErrors::InvalidLottoNumbers)?;
+
+        // Picks close with the round; tickets entered in an anti-snipe window cannot pick
+        require!(
+            lottery_id == lottery_state.current_lottery_id
+                && !lottery_state.is_drawing
+                && Clock::get()?.unix_timestamp < lottery_state.lottery_endtime,
+            HashtrologyErrors::LotteryIsOver
+        );
+
+        self.lotto_pick.set_inner(LottoPick {
+            lottery_id,
+            ticket_index,
+            numbers: mask,
+            tier: 0,
+            is_registered: false,
+            is_claimed: false,
+            bump: bumps.lotto_pick
+        });
+
+        msg!("Lottery #{} ticket {} picked numbers {:?}", lottery_id, ticket_index, numbers);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, LUCKY_SIGN_PICK_SEED, LUCKY_SIGN_ROUND_SEED, USER_TICKET_SEED, ZODIAC_SIGN_COUNT},
+    errors::HashtrologyErrors,
+    state::{LotteryState, LuckySignPick, LuckySignRound, RoundKind, UserTicket}
+};
+
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct PickLuckySign<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump,
+        has_one = user @ HashtrologyErrors::Unauthorized
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + LuckySignRound::INIT_SPACE,
+        seeds = [LUCKY_SIGN_ROUND_SEED, &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub lucky_sign_round: Account<'info, LuckySignRound>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + LuckySignPick::INIT_SPACE,
+        seeds = [LUCKY_SIGN_PICK_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump
+    )]
+    pub lucky_sign_pick: Account<'info, LuckySignPick>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> PickLuckySign<'info> {
+    pub fn pick_lucky_sign_handler(
+        &mut self,
+        lottery_id: u64,
+        ticket_index: u32,
+        sign: u8,
+        bumps: &PickLuckySignBumps
+    ) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            lottery_state.round_kind == RoundKind::LuckySign,
+            HashtrologyErrors::NotLuckySignRound
+        );
+
+        require!(
+            (sign as usize) < ZODIAC_SIGN_COUNT,
+            HashtrologyErrors::InvalidSign
+        );
+
+        // Picks close with the round; tickets entered in an anti-snipe window cannot pick
+        require!(
+            lottery_id == lottery_state.current_lottery_id
+                && !lottery_state.is_drawing
+                && Clock::get()?.unix_timestamp < lottery_state.lottery_endtime,
+            HashtrologyErrors::LotteryIsOver
+        );
+
+        let round = &mut self.lucky_sign_round;
+        if round.lottery_id == 0 {
+            round.lottery_id = lottery_id;
+            round.bump = bumps.lucky_sign_round;
+        }
+
+        round.sign_counts[sign as usize] = round.sign_counts[sign as usize]
+            .checked_add(1)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        self.lucky_sign_pick.set_inner(LuckySignPick {
+            lottery_id,
+            ticket_index,
+            sign,
+            is_claimed: false,
+            bump: bumps.lucky_sign_pick
+        });
+
+        msg!("Lottery #{} ticket {} picked sign {}", lottery_id, ticket_index, sign);
+
+        Ok(())
+    }
+}
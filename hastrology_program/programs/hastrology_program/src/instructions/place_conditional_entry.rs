@@ -0,0 +1,85 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{CONDITIONAL_ENTRY_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    state::{ConditionalEntry, LotteryState}
+};
+
+/// Escrows a ticket for the current round, to be entered only once the pot reaches
+/// `min_pot_lamports` while fewer than `max_participants` tickets are sold.
+#[derive(Accounts)]
+pub struct PlaceConditionalEntry<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ConditionalEntry::INIT_SPACE,
+        seeds = [CONDITIONAL_ENTRY_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub conditional_entry: Account<'info, ConditionalEntry>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> PlaceConditionalEntry<'info> {
+    pub fn place_conditional_entry_handler(
+        &mut self,
+        min_pot_lamports: u64,
+        max_participants: u64,
+        bumps: &PlaceConditionalEntryBumps
+    ) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        lottery_state.check_not_sunset()?;
+
+        require!(
+            !lottery_state.is_drawing && !lottery_state.round_finalized,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            !lottery_state.has_ended(lottery_state.lottery_endtime, &Clock::get()?),
+            HashtrologyErrors::LotteryIsOver
+        );
+
+        self.conditional_entry.set_inner(ConditionalEntry {
+            user: self.user.key(),
+            lottery_id: lottery_state.current_lottery_id,
+            amount: lottery_state.ticket_price,
+            min_pot_lamports,
+            max_participants,
+            bump: bumps.conditional_entry
+        });
+
+        let accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.conditional_entry.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), accounts);
+
+        transfer(cpi_ctx, lottery_state.ticket_price)?;
+
+        msg!(
+            "Conditional entry for lottery #{} placed by {}: pot at least {} lamports, under {} tickets",
+            lottery_state.current_lottery_id,
+            self.user.key(),
+            min_pot_lamports,
+            max_participants
+        );
+
+        Ok(())
+    }
+}
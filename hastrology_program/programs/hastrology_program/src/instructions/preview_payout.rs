@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEE_TREASURY_SEED, LOTTERY_STATE_SEED, POT_VAULT_SEED, REFERRAL_EPOCH_SEED},
+    errors::HashtrologyErrors,
+    math::{bps_of, rollover_amount, round_down},
+    state::{FeeTreasury, LotteryState, ReferralEpoch}
+};
+
+/// Lamport-exact split of the current pot, as `payout` would make it right now.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PayoutPreview {
+    pub lottery_id: u64,
+    pub pot_lamports: u64, // vault balance minus reserved referral bonuses and late-entry refunds
+    pub platform_fee: u64,
+    pub referral_bonus: u64, // reserved out of the platform fee for the top referrer
+    pub platform_share: u64,
+    pub winner_prize: u64,
+    pub token_prize_amount: u64, // 0 when prizes are SOL only
+    pub rollover: u64, // left in the vault for the next round
+    pub prize_top_up: u64, // paid from the fee treasury on top of winner_prize to reach min_prize_lamports
+    pub prize_dust: u64, // rounded off winner_prize to prize_granularity; included in platform_share
+    pub insurance_cut: u64, // taken out of the platform share for the insurance reserve
+}
+
+impl PayoutPreview {
+    /// Shared with `payout` so the preview can't drift from what is actually paid.
+    /// `treasury_fees` are the fee treasury's accrued fees before this payout's own fee.
+    pub fn compute(lottery_state: &LotteryState, pot_vault_lamports: u64, has_top_referrer: bool, treasury_fees: u64) -> Result<Self> {
+        let pot_lamports = lottery_state.round_pot(pot_vault_lamports)?;
+
+        let (platform_fee, unrounded_prize) = lottery_state.split_round_fee(pot_lamports)?;
+        let (winner_prize, prize_dust) = round_down(unrounded_prize, lottery_state.prize_granularity);
+
+        let referral_bonus = if has_top_referrer {
+            bps_of(platform_fee, lottery_state.referral_bonus_bps)?
+        } else {
+            0
+        };
+
+        // The dust joins the treasury's share after the referral cut, so it never inflates the bonus
+        let platform_share = platform_fee
+            .checked_sub(referral_bonus)
+            .and_then(|share| share.checked_add(prize_dust))
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        // The reserve is funded by the platform, never out of the prize
+        let insurance_cut = bps_of(pot_lamports, lottery_state.insurance_bps)?.min(platform_share);
+        let platform_share = platform_share - insurance_cut;
+
+        let token_prize_amount = if lottery_state.prize_mint != Pubkey::default() {
+            lottery_state.token_prize_amount
+        } else {
+            0
+        };
+
+        // Tops up as far as the treasury allows, so a drained treasury never blocks the payout
+        let prize_top_up = lottery_state.min_prize_lamports
+            .saturating_sub(winner_prize)
+            .min(treasury_fees.saturating_add(platform_share));
+
+        Ok(Self {
+            lottery_id: lottery_state.current_lottery_id,
+            pot_lamports,
+            platform_fee,
+            referral_bonus,
+            platform_share,
+            winner_prize,
+            token_prize_amount,
+            rollover: rollover_amount(pot_lamports, platform_fee + unrounded_prize)?,
+            prize_top_up,
+            prize_dust,
+            insurance_cut,
+        })
+    }
+}
+
+/// Read-only; meant to be simulated. Anchor returns the preview through `set_return_data`.
+#[derive(Accounts)]
+pub struct PreviewPayout<'info> {
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    /// Pass the same referral epoch payout would receive.
+    #[account(
+        seeds = [REFERRAL_EPOCH_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = referral_epoch.bump
+    )]
+    pub referral_epoch: Option<Account<'info, ReferralEpoch>>,
+
+    /// Needed to preview a top-up to the minimum prize.
+    #[account(
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Option<Account<'info, FeeTreasury>>,
+}
+
+impl<'info> PreviewPayout<'info> {
+    pub fn preview_payout_handler(&self) -> Result<PayoutPreview> {
+        let has_top_referrer = self.referral_epoch
+            .as_ref()
+            .is_some_and(|referral_epoch| referral_epoch.top_count > 0);
+
+        let treasury_fees = self.fee_treasury.as_ref().map_or(0, |fee_treasury| fee_treasury.accrued_fees);
+
+        let preview = PayoutPreview::compute(&self.lottery_state, self.pot_vault.lamports(), has_top_referrer, treasury_fees)?;
+
+        msg!(
+            "Lottery #{} preview: prize {} lamports, fee {} lamports",
+            preview.lottery_id,
+            preview.winner_prize,
+            preview.platform_fee
+        );
+
+        Ok(preview)
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{COMPENSATION_SEED, COMPENSATION_TIMELOCK, INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::CompensationProposed,
+    require_role,
+    state::{Compensation, InsuranceReserve, LotteryState, Role}
+};
+
+/// Proposes paying `amount` from the insurance reserve to `recipient`. The amount is set
+/// aside at once, but `compensate` can only pay it after `COMPENSATION_TIMELOCK`, so every
+/// payment from the reserve is public before it happens.
+#[derive(Accounts)]
+pub struct ProposeCompensation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump = insurance_reserve.bump
+    )]
+    pub insurance_reserve: Account<'info, InsuranceReserve>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Compensation::INIT_SPACE,
+        seeds = [COMPENSATION_SEED, &insurance_reserve.compensation_count.to_le_bytes()],
+        bump
+    )]
+    pub compensation: Account<'info, Compensation>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ProposeCompensation<'info> {
+    pub fn propose_compensation_handler(
+        &mut self,
+        recipient: Pubkey,
+        amount: u64,
+        lottery_id: u64,
+        reason_hash: [u8; 32],
+        bumps: &ProposeCompensationBumps
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(amount > 0, HashtrologyErrors::InvalidCompensation);
+
+        let id = self.insurance_reserve.commit(amount)?;
+        let activates_at = Clock::get()?.unix_timestamp
+            .checked_add(COMPENSATION_TIMELOCK)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        self.compensation.set_inner(Compensation {
+            id,
+            recipient,
+            amount,
+            lottery_id,
+            reason_hash,
+            proposed_by: self.authority.key(),
+            activates_at,
+            paid_at: 0,
+            bump: bumps.compensation
+        });
+
+        emit!(CompensationProposed {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            compensation_id: id,
+            lottery_id,
+            recipient,
+            amount,
+            reason_hash,
+            activates_at,
+        });
+
+        msg!("Compensation #{} of {} lamports proposed, payable after {}", id, amount, activates_at);
+
+        Ok(())
+    }
+}
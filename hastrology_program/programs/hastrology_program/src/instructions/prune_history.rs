@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{HISTORY_ARCHIVE_SEED, HISTORY_RETENTION_SLOTS, LOTTERY_STATE_SEED, MAX_ARCHIVED_ROUNDS, PROGRAM_VERSION, ROUND_HISTORY_SEED},
+    errors::HashtrologyErrors,
+    events::HistoryArchived,
+    require_role,
+    state::{HistoryArchive, LotteryState, Role, RoundHistory},
+    ID
+};
+
+/// Folds the round histories of lotteries `first_lottery_id..` into one `HistoryArchive` and
+/// closes them, returning their rent to the authority. Remaining accounts are the histories,
+/// writable and in lottery order; each must be past `HISTORY_RETENTION_SLOTS`. A round that
+/// was never drawn has no history and takes a zero leaf.
+#[derive(Accounts)]
+#[instruction(first_lottery_id: u64)]
+pub struct PruneHistory<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HistoryArchive::INIT_SPACE,
+        seeds = [HISTORY_ARCHIVE_SEED, &first_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub history_archive: Account<'info, HistoryArchive>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> PruneHistory<'info> {
+    pub fn prune_history_handler(
+        &mut self,
+        first_lottery_id: u64,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        bumps: &PruneHistoryBumps
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let round_count = remaining_accounts.len();
+
+        require!(
+            round_count > 0 && round_count <= MAX_ARCHIVED_ROUNDS,
+            HashtrologyErrors::InvalidHistoryPage
+        );
+
+        let clock = Clock::get()?;
+        let mut leaves = Vec::with_capacity(round_count);
+        let mut lamports_returned: u64 = 0;
+
+        for (offset, history_info) in remaining_accounts.iter().enumerate() {
+            let lottery_id = first_lottery_id.checked_add(offset as u64).ok_or(HashtrologyErrors::Overflow)?;
+
+            let (history_address, _) = Pubkey::find_program_address(
+                &[ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()],
+                &ID
+            );
+
+            require_keys_eq!(
+                history_info.key(),
+                history_address,
+                HashtrologyErrors::InvalidHistoryPage
+            );
+
+            if history_info.owner != &ID {
+                leaves.push([0u8; 32]);
+                continue;
+            }
+
+            let round_history: Account<RoundHistory> = Account::try_from(history_info)?;
+
+            require!(
+                lottery_id < self.lottery_state.current_lottery_id
+                    && clock.slot >= round_history.request_slot.saturating_add(HISTORY_RETENTION_SLOTS),
+                HashtrologyErrors::HistoryStillRetained
+            );
+
+            leaves.push(HistoryArchive::leaf(&history_info.try_borrow_data()?));
+
+            lamports_returned = lamports_returned
+                .checked_add(history_info.lamports())
+                .ok_or(HashtrologyErrors::Overflow)?;
+
+            round_history.close(self.authority.to_account_info())?;
+        }
+
+        require!(lamports_returned > 0, HashtrologyErrors::InvalidHistoryPage);
+
+        let root = HistoryArchive::root_of(&leaves);
+
+        self.history_archive.set_inner(HistoryArchive {
+            first_lottery_id,
+            round_count: round_count as u8,
+            root,
+            archived_at: clock.unix_timestamp,
+            bump: bumps.history_archive
+        });
+
+        emit!(HistoryArchived {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            first_lottery_id,
+            root,
+            leaves,
+            lamports_returned,
+        });
+
+        msg!(
+            "Archived {} round histories from lottery #{}, reclaiming {} lamports",
+            round_count,
+            first_lottery_id,
+            lamports_returned
+        );
+
+        Ok(())
+    }
+}
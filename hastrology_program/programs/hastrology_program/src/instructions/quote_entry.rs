@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    state::LotteryState
+};
+
+/// What `enter_lottery` would charge at `quoted_slot`. Pass `price` back as its `max_price`
+/// so the entry fails instead of charging more if the price moves before it lands.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct EntryQuote {
+    pub lottery_id: u64,
+    pub price: u64,
+    pub quoted_slot: u64,
+    pub bonus_tickets: u8, // early-bird tickets the entry would earn at this slot
+}
+
+/// Read-only; meant to be simulated. Anchor returns the quote through `set_return_data`.
+#[derive(Accounts)]
+pub struct QuoteEntry<'info> {
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> QuoteEntry<'info> {
+    pub fn quote_entry_handler(&self, ticket_class: Option<u8>) -> Result<EntryQuote> {
+        let lottery_state = &self.lottery_state;
+        let clock = Clock::get()?;
+
+        let quote = EntryQuote {
+            lottery_id: lottery_state.current_lottery_id,
+            price: lottery_state.ticket_price_for(ticket_class)?,
+            quoted_slot: clock.slot,
+            bonus_tickets: lottery_state.early_bird_bonus_at(clock.unix_timestamp),
+        };
+
+        msg!(
+            "Lottery #{} quote: {} lamports at slot {}",
+            quote.lottery_id,
+            quote.price,
+            quote.quoted_slot
+        );
+
+        Ok(quote)
+    }
+}
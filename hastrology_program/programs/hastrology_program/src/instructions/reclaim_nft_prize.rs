@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, NFT_PRIZE_ESCROW_SEED, NFT_PRIZE_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED},
+    errors::HashtrologyErrors,
+    events::NftPrizeReleased,
+    state::{LotteryState, NftPrize, RoundHistory},
+    ID
+};
+
+/// Returns an escrowed NFT prize to its depositor once its round is over without a single
+/// winner: reset with no entrants, drawn with none, or run in a mode with no single winner.
+/// A history closed by `prune_history` also counts, so an unclaimed NFT comes back after
+/// `HISTORY_RETENTION_SLOTS`.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct ReclaimNftPrize<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [NFT_PRIZE_SEED, &lottery_id.to_le_bytes()],
+        bump = nft_prize.bump,
+        has_one = depositor,
+        close = depositor
+    )]
+    pub nft_prize: Account<'info, NftPrize>,
+
+    #[account(
+        address = nft_prize.mint @ HashtrologyErrors::InvalidNftPrizeMint
+    )]
+    pub nft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [NFT_PRIZE_ESCROW_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        token::mint = nft_mint,
+        token::authority = lottery_state,
+        token::token_program = token_program
+    )]
+    pub nft_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The round's history PDA; may be uninitialized if the round was never drawn.
+    #[account(
+        seeds = [ROUND_HISTORY_SEED, &lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub round_history: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = nft_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ReclaimNftPrize<'info> {
+    pub fn reclaim_nft_prize_handler(&mut self, lottery_id: u64) -> Result<()> {
+        require!(
+            lottery_id < self.lottery_state.current_lottery_id,
+            HashtrologyErrors::NftPrizeNotReclaimable
+        );
+
+        if self.round_history.owner == &ID {
+            let data = self.round_history.try_borrow_data()?;
+            let history = RoundHistory::try_deserialize(&mut &data[..])?;
+
+            require!(
+                !history.round_kind.has_single_winner() || history.winner == 0,
+                HashtrologyErrors::NftPrizeNotReclaimable
+            );
+        }
+
+        let seeds = &[LOTTERY_STATE_SEED, &[self.lottery_state.lottery_state_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = TransferChecked {
+            from: self.nft_escrow.to_account_info(),
+            mint: self.nft_mint.to_account_info(),
+            to: self.depositor_token_account.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+        transfer_checked(
+            CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds),
+            1,
+            self.nft_mint.decimals
+        )?;
+
+        let accounts = CloseAccount {
+            account: self.nft_escrow.to_account_info(),
+            destination: self.depositor.to_account_info(),
+            authority: self.lottery_state.to_account_info()
+        };
+        close_account(CpiContext::new_with_signer(self.token_program.to_account_info(), accounts, signer_seeds))?;
+
+        emit!(NftPrizeReleased {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id,
+            mint: self.nft_mint.key(),
+            recipient: self.depositor.key(),
+            held_prize_lamports: 0,
+            reclaimed: true,
+        });
+
+        msg!("NFT prize {} for lottery #{} reclaimed by {}", self.nft_mint.key(), lottery_id, self.depositor.key());
+
+        Ok(())
+    }
+}
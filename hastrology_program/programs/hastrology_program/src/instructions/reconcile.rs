@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED, PARTICIPANT_INDEX_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{AuditAction, AuditLog, LotteryState, ParticipantIndex, Role},
+    ID
+};
+
+#[derive(Accounts)]
+pub struct Reconcile<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    /// CHECK: Read as raw bytes. When passed, `total_participants` is reset to its length.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+}
+
+impl<'info> Reconcile<'info> {
+    /// Repairs the counters `check_accounting` compares against the vault. Reservations are
+    /// trimmed to what the vault actually holds, swapped prizes first, then late-entry refunds,
+    /// then referral bonuses. The vault ledger `reconcile_vault` checks against restarts from the
+    /// balance left.
+    pub fn reconcile_handler(&mut self) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(!lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        if let Some(participant_index) = self.participant_index.as_ref() {
+            let recorded = ParticipantIndex::raw_len(&participant_index.try_borrow_data()?) as u64;
+
+            if recorded != lottery_state.total_participants {
+                msg!("total_participants {} -> {}", lottery_state.total_participants, recorded);
+                lottery_state.total_participants = recorded;
+            }
+        }
+
+        let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        let mut available = self.pot_vault.lamports().saturating_sub(rent_reserve);
+        let state: &mut LotteryState = lottery_state;
+
+        for (name, reserved) in [
+            ("swap_prize_lamports", &mut state.swap_prize_lamports),
+            ("held_prize_lamports", &mut state.held_prize_lamports),
+            ("late_entry_lamports", &mut state.late_entry_lamports),
+            ("reserved_referral_lamports", &mut state.reserved_referral_lamports)
+        ] {
+            let kept = (*reserved).min(available);
+
+            if kept != *reserved {
+                msg!("{} {} -> {}", name, reserved, kept);
+                *reserved = kept;
+            }
+
+            available -= kept;
+        }
+
+        if state.needs_review {
+            msg!("vault ledger rebased, review flag cleared");
+        }
+
+        state.rebase_vault_ledger(self.pot_vault.lamports());
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::Reconcile,
+            self.authority.key(),
+            &[
+                &lottery_state.current_lottery_id.to_le_bytes(),
+                &lottery_state.total_participants.to_le_bytes(),
+                &lottery_state.reserved_lamports()?.to_le_bytes()
+            ]
+        )?;
+
+        msg!("Lottery #{} reconciled", lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, METRICS_SEED, POT_VAULT_SEED, PROGRAM_VERSION, VAULT_REVIEW_THRESHOLD},
+    errors::HashtrologyErrors,
+    events::VaultReconciled,
+    state::{LotteryState, Metrics}
+};
+
+/// Compares the pot vault against its ledger of tracked entries, deposits, rollovers, fees and
+/// payouts. Anyone may crank it; drift past `VAULT_REVIEW_THRESHOLD` flags the lottery for review
+/// until the admin `reconcile` rebases the ledger.
+#[derive(Accounts)]
+pub struct ReconcileVault<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Account<'info, Metrics>,
+}
+
+impl<'info> ReconcileVault<'info> {
+    pub fn reconcile_vault_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let vault_lamports = self.pot_vault.lamports();
+        let expected = lottery_state.expected_vault_lamports();
+
+        let discrepancy = i64::try_from(vault_lamports as i128 - expected).map_err(|_| HashtrologyErrors::Overflow)?;
+        let expected_lamports = i64::try_from(expected).map_err(|_| HashtrologyErrors::Overflow)?;
+
+        if discrepancy.unsigned_abs() > VAULT_REVIEW_THRESHOLD {
+            lottery_state.needs_review = true;
+        }
+
+        self.metrics.record_vault_check(discrepancy, Clock::get()?.unix_timestamp);
+
+        emit!(VaultReconciled {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            vault_lamports,
+            expected_lamports,
+            discrepancy,
+            needs_review: lottery_state.needs_review
+        });
+
+        msg!(
+            "Pot vault holds {} lamports, ledger expects {} ({:+})",
+            vault_lamports,
+            expected_lamports,
+            discrepancy
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, REFERRAL_EPOCH_SEED, REFERRAL_STATS_SEED, USER_RECEIPT_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, ReferralEpoch, ReferralStats, UserEntryReceipt}
+};
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct RecordReferral<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [USER_RECEIPT_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump,
+        constraint = !user_entry_receipt.referral_recorded @ HashtrologyErrors::ReferralAlreadyRecorded
+    )]
+    pub user_entry_receipt: Account<'info, UserEntryReceipt>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ReferralStats::INIT_SPACE,
+        seeds = [REFERRAL_STATS_SEED, referrer.as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ReferralEpoch::INIT_SPACE,
+        seeds = [REFERRAL_EPOCH_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub referral_epoch: Account<'info, ReferralEpoch>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> RecordReferral<'info> {
+    pub fn record_referral_handler(&mut self, referrer: Pubkey, bumps: &RecordReferralBumps) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            referrer != self.user.key(),
+            HashtrologyErrors::SelfReferral
+        );
+
+        let stats = &mut self.referral_stats;
+        stats.referrer = referrer;
+        stats.lottery_id = lottery_state.current_lottery_id;
+        stats.count = stats.count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        stats.bump = bumps.referral_stats;
+
+        let epoch = &mut self.referral_epoch;
+        epoch.lottery_id = lottery_state.current_lottery_id;
+        epoch.bump = bumps.referral_epoch;
+
+        if stats.count > epoch.top_count {
+            epoch.top_referrer = referrer;
+            epoch.top_count = stats.count;
+        }
+
+        self.user_entry_receipt.referral_recorded = true;
+
+        msg!(
+            "Referral by {} recorded for lottery #{} ({} this round)",
+            referrer,
+            lottery_state.current_lottery_id,
+            stats.count
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONDITIONAL_ENTRY_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    state::{ConditionalEntry, LotteryState}
+};
+
+/// The user may cancel their conditional entry at any time; once its round is over,
+/// anyone may refund it.
+#[derive(Accounts)]
+pub struct RefundConditionalEntry<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: Owner of the conditional entry; receives the escrow and its rent.
+    #[account(
+        mut,
+        address = conditional_entry.user @ HashtrologyErrors::InvalidConditionalEntry
+    )]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [CONDITIONAL_ENTRY_SEED, user.key().as_ref(), &conditional_entry.lottery_id.to_le_bytes()],
+        bump = conditional_entry.bump
+    )]
+    pub conditional_entry: Account<'info, ConditionalEntry>,
+}
+
+impl<'info> RefundConditionalEntry<'info> {
+    pub fn refund_conditional_entry_handler(&mut self) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+        let conditional_entry = &self.conditional_entry;
+
+        let round_over = conditional_entry.lottery_id < lottery_state.current_lottery_id
+            || lottery_state.is_drawing
+            || lottery_state.round_finalized
+            || lottery_state.has_ended(lottery_state.lottery_endtime, &Clock::get()?);
+
+        require!(
+            round_over || self.signer.key() == conditional_entry.user,
+            HashtrologyErrors::ConditionalEntryStillLive
+        );
+
+        msg!(
+            "Conditional entry for lottery #{} refunded. {} lamports returned to {}.",
+            conditional_entry.lottery_id,
+            conditional_entry.amount,
+            conditional_entry.user
+        );
+
+        Ok(())
+    }
+}
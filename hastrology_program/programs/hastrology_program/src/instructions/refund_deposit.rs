@@ -0,0 +1,62 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer}
+};
+
+use crate::{
+    constants::{DEPOSIT_ADDRESS_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    state::LotteryState
+};
+
+#[derive(Accounts)]
+pub struct RefundDeposit<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: Wallet the deposit address belongs to; receives the refund.
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [DEPOSIT_ADDRESS_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub deposit_address: SystemAccount<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> RefundDeposit<'info> {
+    pub fn refund_deposit_handler(&mut self, bumps: &RefundDepositBumps) -> Result<()> {
+        let balance = self.deposit_address.lamports();
+
+        require!(
+            balance > 0 && balance != self.lottery_state.ticket_price,
+            HashtrologyErrors::DepositNotRefundable
+        );
+
+        let user_key = self.user.key();
+        let seeds = &[DEPOSIT_ADDRESS_SEED, user_key.as_ref(), &[bumps.deposit_address]];
+        let signer_seeds = &[&seeds[..]];
+
+        let accounts = Transfer {
+            from: self.deposit_address.to_account_info(),
+            to: self.user.to_account_info()
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+
+        transfer(cpi_ctx, balance)?;
+
+        msg!("Refunded off-size deposit of {} lamports to {}", balance, user_key);
+
+        Ok(())
+    }
+}
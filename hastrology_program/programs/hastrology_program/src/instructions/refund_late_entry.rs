@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CLAIM_BITMAP_SEED, LOTTERY_STATE_SEED, POT_VAULT_SEED, SNIPE_WINDOW_SEED, USER_TICKET_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{ClaimBitmap, LotteryState, SnipeWindow, UserTicket}
+};
+
+/// Permissionless; returns the ticket price of an entry made after the round's hidden true end.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct RefundLateEntry<'info> {
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [SNIPE_WINDOW_SEED, &lottery_id.to_le_bytes()],
+        bump = snipe_window.bump,
+        constraint = snipe_window.is_resolved @ HashtrologyErrors::SnipeWindowNotResolved
+    )]
+    pub snipe_window: Account<'info, SnipeWindow>,
+
+    #[account(
+        mut,
+        seeds = [USER_TICKET_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump,
+        constraint = !user_ticket.is_claimed @ HashtrologyErrors::PrizeAlreadyClaimed
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    /// CHECK: The ticket owner receiving the refund
+    #[account(
+        mut,
+        address = user_ticket.user @ HashtrologyErrors::InvalidWinner
+    )]
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_BITMAP_SEED, &lottery_id.to_le_bytes()],
+        bump = claim_bitmap.bump
+    )]
+    pub claim_bitmap: Account<'info, ClaimBitmap>,
+}
+
+impl<'info> RefundLateEntry<'info> {
+    pub fn refund_late_entry_handler(&mut self, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        let snipe_window = &self.snipe_window;
+
+        require!(
+            ticket_index as u64 >= snipe_window.eligible_participants,
+            HashtrologyErrors::NotLateEntry
+        );
+
+        let refund = snipe_window.ticket_price;
+
+        debit_lamports(&self.pot_vault, refund)?;
+        credit_lamports(&self.user, refund)?;
+        self.lottery_state.record_vault_outflow(refund)?;
+
+        self.lottery_state.late_entry_lamports = self.lottery_state.late_entry_lamports
+            .checked_sub(refund)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        ClaimBitmap::mark_claimed(&mut self.claim_bitmap, ticket_index)?;
+
+        self.user_ticket.is_claimed = true;
+
+        msg!(
+            "Late entry #{} of lottery #{} refunded {} lamports",
+            ticket_index + 1,
+            lottery_id,
+            refund
+        );
+
+        Ok(())
+    }
+}
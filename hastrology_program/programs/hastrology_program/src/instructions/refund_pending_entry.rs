@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PENDING_KYC_SEED,
+    state::PendingKycEntry
+};
+
+/// Returns an escrowed entry that was never approved, including after its round has passed.
+#[derive(Accounts)]
+pub struct RefundPendingEntry<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [PENDING_KYC_SEED, user.key().as_ref(), &pending_entry.lottery_id.to_le_bytes()],
+        bump = pending_entry.bump
+    )]
+    pub pending_entry: Account<'info, PendingKycEntry>,
+}
+
+impl<'info> RefundPendingEntry<'info> {
+    pub fn refund_pending_entry_handler(&mut self) -> Result<()> {
+        msg!(
+            "Pending entry for lottery #{} refunded. {} lamports returned.",
+            self.pending_entry.lottery_id,
+            self.pending_entry.amount
+        );
+
+        Ok(())
+    }
+}
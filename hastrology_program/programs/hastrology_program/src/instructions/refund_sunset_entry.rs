@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, PROGRAM_VERSION, USER_RECEIPT_SEED},
+    errors::HashtrologyErrors,
+    events::SunsetEntryRefunded,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, UserEntryReceipt}
+};
+
+#[derive(Accounts)]
+pub struct RefundSunsetEntry<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [USER_RECEIPT_SEED, user.key().as_ref(), &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub user_entry_receipt: Account<'info, UserEntryReceipt>,
+}
+
+impl<'info> RefundSunsetEntry<'info> {
+    /// Refunds everything a wallet paid into the round open at sunset, as totalled on its
+    /// receipt. Closing the receipt makes it a one-time refund.
+    pub fn refund_sunset_entry_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let receipt = &self.user_entry_receipt;
+
+        require!(
+            lottery_state.sunset_refunds_open(),
+            HashtrologyErrors::SunsetRefundsClosed
+        );
+
+        let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        let refundable = lottery_state.round_pot(self.pot_vault.lamports().saturating_sub(rent_reserve))?;
+
+        let amount = receipt.total_paid.min(refundable);
+
+        debit_lamports(&self.pot_vault, amount)?;
+        credit_lamports(&self.user, amount)?;
+        lottery_state.record_vault_outflow(amount)?;
+
+        emit!(SunsetEntryRefunded {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            user: self.user.key(),
+            entry_count: receipt.entry_count,
+            amount,
+        });
+
+        msg!("Refunded {} lamports for {} entries in lottery #{}", amount, receipt.entry_count, lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use lottery_core::selection::count_matches;
+
+use crate::{
+    constants::{LOTTO_PICK_SEED, LOTTO_ROUND_SEED},
+    errors::HashtrologyErrors,
+    state::{LottoPick, LottoRound}
+};
+
+/// Permissionless: anyone may register a winning pick during the registration window.
+/// Only registered tickets count towards their tier's split, and only they can claim.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64, ticket_index: u32)]
+pub struct RegisterLottoTicket<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTO_ROUND_SEED, &lottery_id.to_le_bytes()],
+        bump = lotto_round.bump
+    )]
+    pub lotto_round: Account<'info, LottoRound>,
+
+    #[account(
+        mut,
+        seeds = [LOTTO_PICK_SEED, &lottery_id.to_le_bytes(), &ticket_index.to_le_bytes()],
+        bump = lotto_pick.bump
+    )]
+    pub lotto_pick: Account<'info, LottoPick>,
+}
+
+impl<'info> RegisterLottoTicket<'info> {
+    pub fn register_lotto_ticket_handler(&mut self, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        let round = &mut self.lotto_round;
+        let pick = &mut self.lotto_pick;
+
+        require!(
+            Clock::get()?.unix_timestamp < round.registration_ends,
+            HashtrologyErrors::LottoRegistrationClosed
+        );
+
+        require!(
+            !pick.is_registered,
+            HashtrologyErrors::LottoTicketAlreadyRegistered
+        );
+
+        let matches = count_matches(pick.numbers, round.drawn_numbers);
+        let tier = LottoRound::tier_of(matches).ok_or(HashtrologyErrors::LottoTicketNotWinning)?;
+
+        round.tier_counts[tier] = round.tier_counts[tier]
+            .checked_add(1)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        pick.tier = tier as u8;
+        pick.is_registered = true;
+
+        msg!("Lottery #{} ticket {} registered with {} matches", lottery_id, ticket_index, matches);
+
+        Ok(())
+    }
+}
@@ -1,20 +1,26 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::{instruction::AccountMeta, sysvar::slot_hashes}};
 
 use crate::{instruction, ID};
-use crate::{constants::LOTTERY_STATE_SEED, errors::HashtrologyErrors, state::LotteryState};
+use crate::{
+    constants::{
+        DRAW_ATTESTATION_SEED, FEE_TREASURY_SEED, LOTTERY_STATE_SEED, METRICS_SEED, PARTICIPANT_INDEX_SEED, POT_VAULT_SEED,
+        PROGRAM_VERSION, RANDOMNESS_IDENTITY_SEED, ROUND_HISTORY_SEED, SEASON_WINNERS_SEED, SNIPE_WINDOW_SEED
+    },
+    errors::HashtrologyErrors,
+    require_ctx,
+    events::{KeeperRewarded, PrizeFloorRollover},
+    lamports::{credit_lamports, debit_lamports},
+    instructions::PayoutPreview,
+    math::available_balance,
+    randomness::{ProviderAccounts, RandomnessRequest},
+    state::{DrawAttestation, FeeTreasury, LotteryState, Metrics, RoundHistory, SnipeWindow}
+};
 
-use ephemeral_vrf_sdk::anchor::vrf;
-use ephemeral_vrf_sdk::consts::DEFAULT_QUEUE;
-use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
-use ephemeral_vrf_sdk::types::SerializableAccountMeta;
-
-#[vrf]
 #[derive(Accounts)]
 pub struct RequestDraw<'info> {
-    #[account(
-        mut,
-        constraint = authority.key() == lottery_state.authority @ HashtrologyErrors::UnauthorizedAuthority
-    )]
+    /// Any keeper may trigger the draw once the round is over; they pay the VRF request
+    /// and earn `keeper_reward_lamports` from the pot.
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
@@ -23,46 +29,381 @@ pub struct RequestDraw<'info> {
         bump = lottery_state.lottery_state_bump
     )]
     pub lottery_state: Account<'info, LotteryState>,
-    /// CHECK: MagicBlock default queue
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
     #[account(
         mut,
-        address = DEFAULT_QUEUE @ HashtrologyErrors::Overflow
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    /// Required only when the pot reaches `attestation_min_pot`.
+    #[account(
+        seeds = [DRAW_ATTESTATION_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = draw_attestation.bump
+    )]
+    pub draw_attestation: Option<Account<'info, DrawAttestation>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoundHistory::INIT_SPACE,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    /// Forwarded to the VRF callback so the hidden end time can be applied.
+    #[account(
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = snipe_window.bump
+    )]
+    pub snipe_window: Option<Account<'info, SnipeWindow>>,
+
+    /// Required while `min_prize_lamports` is set, to check the floor can be met.
+    #[account(
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Option<Account<'info, FeeTreasury>>,
+
+    /// CHECK: The randomness provider's queue the lottery is configured with
+    #[account(
+        mut,
+        address = lottery_state.randomness_queue @ HashtrologyErrors::InvalidRandomnessProvider
     )]
     pub oracle_queue: UncheckedAccount<'info>,
+
+    /// CHECK: Required when the pot reaches `dual_draw_min_pot`; must be `second_oracle_queue`.
+    #[account(mut)]
+    pub second_oracle_queue: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The PDA randomness requests are signed as.
+    #[account(seeds = [RANDOMNESS_IDENTITY_SEED], bump)]
+    pub program_identity: UncheckedAccount<'info>,
+
+    /// CHECK: The configured randomness provider.
+    #[account(address = lottery_state.randomness_provider @ HashtrologyErrors::InvalidRandomnessProvider)]
+    pub vrf_program: UncheckedAccount<'info>,
+
+    /// CHECK: The slot hashes sysvar.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> RequestDraw<'info> {
-    pub fn request_draw_handler(&mut self) -> Result<()> {  
+    pub fn request_draw_handler(&mut self, bumps: &RequestDrawBumps) -> Result<()> {  
         
         let clock = Clock::get()?;
         
         let lottery_state = &mut self.lottery_state;
+        lottery_state.check_not_sunset()?;
         
-        require!(clock.unix_timestamp >= lottery_state.lottery_endtime, HashtrologyErrors::LotteryNotOver);
+        // With anti-snipe the whole window must pass, since the true end is only revealed by the VRF
+        let draw_endtime = lottery_state.lottery_endtime.saturating_add(lottery_state.anti_snipe_max_extension);
+
+        require_ctx!(
+            lottery_state.round_kind.draw_ready(lottery_state.has_ended(draw_endtime, &clock), lottery_state.total_participants),
+            HashtrologyErrors::LotteryNotOver,
+            "expected_end={} now={} end_slot={} slot={} participants={}",
+            draw_endtime, clock.unix_timestamp, lottery_state.end_slot, clock.slot, lottery_state.total_participants
+        );
+        require_ctx!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::DrawAlreadyRequested,
+            "lottery_id={} phase={} draw_request_id={}", lottery_state.current_lottery_id, lottery_state.phase(), lottery_state.draw_request_id
+        );
+        require_ctx!(
+            !lottery_state.round_finalized,
+            HashtrologyErrors::RoundNotOpen,
+            "lottery_id={} phase={}", lottery_state.current_lottery_id, lottery_state.phase()
+        );
+
+        let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        lottery_state.check_accounting(self.pot_vault.lamports().saturating_sub(rent_reserve))?;
+
+        if lottery_state.draw_threshold > 0 && self.pot_vault.lamports() >= lottery_state.attestation_min_pot {
+            let attestation = self.draw_attestation
+                .as_ref()
+                .ok_or(HashtrologyErrors::DrawAttestationRequired)?;
+
+            require!(
+                attestation.approval_count >= lottery_state.draw_threshold,
+                HashtrologyErrors::InsufficientDrawApprovals
+            );
+        }
+
+        if let Some(projected_prize) = self.prize_below_floor()? {
+            return self.roll_over_for_prize_floor(projected_prize, &clock);
+        }
+
+        let lottery_state = &self.lottery_state;
+
+        if lottery_state.entropy_mix_min_pot > 0 && self.pot_vault.lamports() >= lottery_state.entropy_mix_min_pot {
+            require!(
+                lottery_state.has_entropy_commitment(),
+                HashtrologyErrors::EntropyCommitmentRequired
+            );
+        }
+
+        self.lottery_state.draw_retries = 0;
+
+        self.pay_keeper_reward()?;
+
+        self.request_randomness(bumps, &clock)
+    }
+
+    /// The projected winner prize, topped up from the fee treasury, when it falls short of `min_prize_lamports`.
+    fn prize_below_floor(&self) -> Result<Option<u64>> {
+        let lottery_state = &self.lottery_state;
+
+        if lottery_state.min_prize_lamports == 0
+            || !lottery_state.round_kind.has_single_winner()
+            || lottery_state.total_participants == 0
+        {
+            return Ok(None);
+        }
+
+        let fee_treasury = self.fee_treasury
+            .as_ref()
+            .ok_or(HashtrologyErrors::FeeTreasuryRequired)?;
+
+        // Assume the full keeper reward is paid so the floor still holds at payout
+        let pot_lamports = self.pot_vault.lamports().saturating_sub(lottery_state.keeper_reward_lamports);
+        let preview = PayoutPreview::compute(lottery_state, pot_lamports, false, fee_treasury.accrued_fees)?;
+        let projected_prize = preview.winner_prize.checked_add(preview.prize_top_up).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok((projected_prize < lottery_state.min_prize_lamports).then_some(projected_prize))
+    }
+
+    /// Extends the round by a full `round_duration` instead of drawing below the prize floor.
+    fn roll_over_for_prize_floor(&mut self, projected_prize: u64, clock: &Clock) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let endtime = clock.unix_timestamp.checked_add(lottery_state.round_duration).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.set_endtime(endtime, clock);
+        let rollover_lamports = lottery_state.round_pot(self.pot_vault.lamports())?;
+
+        emit!(PrizeFloorRollover {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            projected_prize,
+            min_prize_lamports: lottery_state.min_prize_lamports,
+            new_endtime: lottery_state.lottery_endtime,
+            rollover_lamports,
+        });
+
+        msg!(
+            "Lottery #{} rolled over: prize below the {} lamport floor",
+            lottery_state.current_lottery_id,
+            lottery_state.min_prize_lamports
+        );
+
+        Ok(())
+    }
+
+    /// Pays the configured keeper reward out of the round's pot, never touching deposits or reserved lamports.
+    pub(crate) fn pay_keeper_reward(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        if lottery_state.keeper_reward_lamports == 0 {
+            return Ok(());
+        }
+
+        // The round's own pot, so the reward never comes out of prizes held for a swap, a
+        // claim or a class draw
+        let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        let round_pot = lottery_state.round_pot(self.pot_vault.lamports().saturating_sub(rent_reserve))?;
+        let spendable = available_balance(round_pot, &[lottery_state.total_deposits]);
+        let reward = lottery_state.keeper_reward_lamports.min(spendable);
+
+        if reward == 0 {
+            return Ok(());
+        }
+
+        debit_lamports(&self.pot_vault, reward)?;
+        credit_lamports(&self.authority, reward)?;
+        lottery_state.record_vault_outflow(reward)?;
+
+        emit!(KeeperRewarded {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            keeper: self.authority.key(),
+            amount: reward,
+        });
+
+        Ok(())
+    }
+
+    /// Opens a new VRF request for the current round. Shared by `request_draw` and `retry_draw`.
+    pub(crate) fn request_randomness(&mut self, bumps: &RequestDrawBumps, clock: &Clock) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            // A populated history for this round means an earlier request was made
+            let is_retry = self.round_history.lottery_id == lottery_state.current_lottery_id
+                && self.round_history.draw_request_id != 0;
+            let previous_unresolved = is_retry && self.round_history.resolve_slot == 0;
+            metrics.record_draw_request(is_retry, previous_unresolved, clock.unix_timestamp)?;
+        }
+
         lottery_state.is_drawing = true;
+        lottery_state.draw_request_id = lottery_state.draw_request_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.commit_slot = clock.slot;
 
-        msg!("Randomness requested for Lottery #{} and {}", lottery_state.current_lottery_id, lottery_state.is_drawing);
+        msg!(
+            "Randomness requested for Lottery #{} (request #{})",
+            lottery_state.current_lottery_id,
+            lottery_state.draw_request_id
+        );
 
-        let accounts_metas = vec![
-            SerializableAccountMeta {
-                pubkey: lottery_state.key(),
-                is_signer: false,
-                is_writable: true,
-            },
-        ];
+        // Bind the VRF request to this round and request id so each one is unique.
+        let mut caller_seed = [0u8; 32];
+        caller_seed[..8].copy_from_slice(&lottery_state.current_lottery_id.to_le_bytes());
+        caller_seed[8..16].copy_from_slice(&lottery_state.draw_request_id.to_le_bytes());
+
+        let snapshot_pot = lottery_state.round_pot(self.pot_vault.lamports())?;
+        let snapshot_hash = lottery_state.round_snapshot_hash(snapshot_pot)?;
+
+        // High-value rounds also ask a second queue, and only draw once both have answered
+        let second_oracle_queue = if lottery_state.dual_draw_min_pot > 0 && snapshot_pot >= lottery_state.dual_draw_min_pot {
+            let second_oracle_queue = self.second_oracle_queue
+                .as_ref()
+                .filter(|queue| queue.key() == lottery_state.second_oracle_queue)
+                .ok_or(HashtrologyErrors::SecondOracleQueueRequired)?;
 
-        let ix = create_request_randomness_ix( RequestRandomnessParams {
-            payer: self.authority.key(),
-            oracle_queue:  self.oracle_queue.key(),
-            callback_program_id: ID,
-            callback_discriminator: instruction::ResolveDraw::DISCRIMINATOR.to_vec(),
-            accounts_metas: Some(accounts_metas),
-            ..Default::default()
+            Some(second_oracle_queue.to_account_info())
+        } else {
+            None
+        };
+
+        self.round_history.set_inner(RoundHistory {
+            lottery_id: lottery_state.current_lottery_id,
+            draw_request_id: lottery_state.draw_request_id,
+            caller_seed,
+            oracle_queue: self.oracle_queue.key(),
+            request_slot: clock.slot,
+            randomness: [0u8; 32],
+            vrf_signer: Pubkey::default(),
+            resolve_slot: 0,
+            total_participants: lottery_state.total_participants,
+            winner: 0,
+            bump: bumps.round_history,
+            payment_currency: lottery_state.payment_currency.clone(),
+            token_prize_currency: lottery_state.token_prize_currency.clone(),
+            token_prize_amount: lottery_state.token_prize_amount,
+            round_kind: lottery_state.round_kind,
+            winner_rerolls: 0,
+            entropy_mixed: lottery_state.has_entropy_commitment(),
+            snipe_window_forwarded: self.snipe_window.is_some(),
+            entropy_blockhash: [0u8; 32],
+            entropy_nonce: [0u8; 32],
+            snapshot_hash,
+            snapshot_pot,
+            late_refunds: 0,
+            class_tickets_sold: lottery_state.class_tickets_sold(),
+            class_weight_total: lottery_state.class_weight_total,
+            reward_mint: lottery_state.reward_mint,
+            loser_reward_amount: lottery_state.loser_reward_for_round(),
+            second_oracle_queue: second_oracle_queue.as_ref().map(|queue| queue.key()),
+            second_randomness: [0u8; 32],
+            draw_sources: 0,
+            class_pots: lottery_state.class_pots,
+            class_prizes_paid: 0,
         });
 
-        self.invoke_signed_vrf(&self.authority.to_account_info(), &ix)?;
+        let mut accounts_metas = vec![
+            AccountMeta::new(lottery_state.key(), false),
+            AccountMeta::new(self.round_history.key(), false),
+        ];
+
+        // The program id stands in for an absent optional account when later ones follow
+        let season_rule = lottery_state.season_length > 0
+            && lottery_state.round_kind.has_single_winner()
+            && !lottery_state.merkle_entry_mode;
+
+        let mix_entropy = self.round_history.entropy_mixed;
+
+        if self.snipe_window.is_some() || season_rule || mix_entropy {
+            let snipe_window = self.snipe_window.as_ref().map_or(ID, |snipe_window| snipe_window.key());
+
+            accounts_metas.push(match self.snipe_window {
+                Some(_) => AccountMeta::new(snipe_window, false),
+                None => AccountMeta::new_readonly(snipe_window, false),
+            });
+        }
+
+        if season_rule || mix_entropy {
+            let lottery_id_bytes = lottery_state.current_lottery_id.to_le_bytes();
+            let (participant_index, _) = Pubkey::find_program_address(&[PARTICIPANT_INDEX_SEED, &lottery_id_bytes], &ID);
+            let (season_winners, _) = Pubkey::find_program_address(&[SEASON_WINNERS_SEED], &ID);
+
+            accounts_metas.push(AccountMeta::new_readonly(if season_rule { participant_index } else { ID }, false));
+            accounts_metas.push(match season_rule {
+                true => AccountMeta::new(season_winners, false),
+                false => AccountMeta::new_readonly(ID, false),
+            });
+        }
+
+        if mix_entropy {
+            accounts_metas.push(AccountMeta::new_readonly(slot_hashes::ID, false));
+        }
+
+        let provider = lottery_state.provider()?;
+
+        provider.request(
+            RandomnessRequest {
+                payer: self.authority.key(),
+                queue: self.oracle_queue.key(),
+                callback_discriminator: instruction::ResolveDraw::DISCRIMINATOR.to_vec(),
+                caller_seed,
+                accounts: accounts_metas.clone(),
+            },
+            ProviderAccounts {
+                payer: &self.authority.to_account_info(),
+                program_identity: &self.program_identity.to_account_info(),
+                queue: &self.oracle_queue.to_account_info(),
+                slot_hashes: &self.slot_hashes.to_account_info(),
+                provider_program: &self.vrf_program.to_account_info(),
+            }
+        )?;
+
+        if let Some(second_oracle_queue) = second_oracle_queue {
+            // A distinct seed keeps the two requests apart; the callback names the queue
+            let mut second_seed = caller_seed;
+            second_seed[16] = 1;
+
+            provider.request(
+                RandomnessRequest {
+                    payer: self.authority.key(),
+                    queue: second_oracle_queue.key(),
+                    callback_discriminator: instruction::ResolveSecondDraw::DISCRIMINATOR.to_vec(),
+                    caller_seed: second_seed,
+                    accounts: accounts_metas,
+                },
+                ProviderAccounts {
+                    payer: &self.authority.to_account_info(),
+                    program_identity: &self.program_identity.to_account_info(),
+                    queue: &second_oracle_queue,
+                    slot_hashes: &self.slot_hashes.to_account_info(),
+                    provider_program: &self.vrf_program.to_account_info(),
+                }
+            )?;
+
+            msg!("Randomness also requested from second queue {}", second_oracle_queue.key());
+        }
 
         Ok(())
-        
     }
 }
\ No newline at end of file
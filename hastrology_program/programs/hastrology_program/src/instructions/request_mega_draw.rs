@@ -0,0 +1,123 @@
+use anchor_lang::{prelude::*, solana_program::{instruction::AccountMeta, sysvar::slot_hashes}};
+
+use crate::instruction;
+use crate::{
+    constants::{DRAW_RETRY_TIMEOUT_SLOTS, LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, RANDOMNESS_IDENTITY_SEED},
+    errors::HashtrologyErrors,
+    randomness::{ProviderAccounts, RandomnessRequest},
+    state::{LotteryState, MegaJackpot}
+};
+
+// Keeps mega jackpot requests apart from round draws that share a lottery id and request id
+const MEGA_DRAW_TAG: &[u8; 4] = b"mega";
+
+#[derive(Accounts)]
+pub struct RequestMegaDraw<'info> {
+    /// Any keeper may trigger the draw once it is due; they pay the VRF request.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Account<'info, MegaJackpot>,
+
+    /// Read for the randomness provider the lottery is configured with.
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: The randomness provider's queue the lottery is configured with
+    #[account(
+        mut,
+        address = lottery_state.randomness_queue @ HashtrologyErrors::InvalidRandomnessProvider
+    )]
+    pub oracle_queue: UncheckedAccount<'info>,
+
+    /// CHECK: The PDA randomness requests are signed as.
+    #[account(seeds = [RANDOMNESS_IDENTITY_SEED], bump)]
+    pub program_identity: UncheckedAccount<'info>,
+
+    /// CHECK: The configured randomness provider.
+    #[account(address = lottery_state.randomness_provider @ HashtrologyErrors::InvalidRandomnessProvider)]
+    pub vrf_program: UncheckedAccount<'info>,
+
+    /// CHECK: The slot hashes sysvar.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestMegaDraw<'info> {
+    /// Requests randomness over every entry linked this epoch. An epoch without entries just
+    /// rolls over, and a request the oracle never answered can be replaced after a timeout.
+    pub fn request_mega_draw_handler(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let mega_jackpot = &mut self.mega_jackpot;
+
+        require!(
+            clock.unix_timestamp >= mega_jackpot.next_draw_at,
+            HashtrologyErrors::MegaJackpotNotDue
+        );
+
+        require!(
+            mega_jackpot.winning_entry.is_none(),
+            HashtrologyErrors::MegaJackpotDrawPending
+        );
+
+        require!(
+            !mega_jackpot.is_drawing
+                || clock.slot >= mega_jackpot.request_slot.saturating_add(DRAW_RETRY_TIMEOUT_SLOTS),
+            HashtrologyErrors::MegaJackpotDrawPending
+        );
+
+        if mega_jackpot.total_entries == 0 {
+            mega_jackpot.start_next_epoch(clock.unix_timestamp)?;
+
+            msg!("No entries linked to the mega jackpot. Rolled over to epoch #{}", mega_jackpot.epoch);
+
+            return Ok(());
+        }
+
+        mega_jackpot.is_drawing = true;
+        mega_jackpot.request_slot = clock.slot;
+
+        let mut caller_seed = [0u8; 32];
+        caller_seed[..8].copy_from_slice(&mega_jackpot.epoch.to_le_bytes());
+        caller_seed[8..16].copy_from_slice(&clock.slot.to_le_bytes());
+        caller_seed[16..20].copy_from_slice(MEGA_DRAW_TAG);
+
+        msg!(
+            "Randomness requested for mega jackpot epoch #{} over {} entries",
+            mega_jackpot.epoch,
+            mega_jackpot.total_entries
+        );
+
+        self.lottery_state.provider()?.request(
+            RandomnessRequest {
+                payer: self.payer.key(),
+                queue: self.oracle_queue.key(),
+                callback_discriminator: instruction::ResolveMegaDraw::DISCRIMINATOR.to_vec(),
+                caller_seed,
+                accounts: vec![
+                    AccountMeta::new(mega_jackpot.key(), false),
+                    AccountMeta::new_readonly(self.lottery_state.key(), false),
+                ],
+            },
+            ProviderAccounts {
+                payer: &self.payer.to_account_info(),
+                program_identity: &self.program_identity.to_account_info(),
+                queue: &self.oracle_queue.to_account_info(),
+                slot_hashes: &self.slot_hashes.to_account_info(),
+                provider_program: &self.vrf_program.to_account_info(),
+            }
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PRIZE_SWAP_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, PrizeSwap, UserTicket}
+};
+
+#[derive(Accounts)]
+pub struct RequestPrizeSwap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump,
+        constraint = lottery_state.swap_program != Pubkey::default() @ HashtrologyErrors::SwapNotEnabled
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        has_one = user @ HashtrologyErrors::Unauthorized,
+        constraint = !user_ticket.is_claimed && user_ticket.prize_amount == 0 @ HashtrologyErrors::PrizeAlreadyPaid
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+
+    pub output_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + PrizeSwap::INIT_SPACE,
+        seeds = [PRIZE_SWAP_SEED, user_ticket.key().as_ref()],
+        bump
+    )]
+    pub prize_swap: Account<'info, PrizeSwap>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> RequestPrizeSwap<'info> {
+    /// Asks for this ticket's SOL prize, should it win, to be swapped into `output_mint`.
+    pub fn request_prize_swap_handler(&mut self, bumps: &RequestPrizeSwapBumps) -> Result<()> {
+        self.prize_swap.set_inner(PrizeSwap {
+            ticket: self.user_ticket.key(),
+            output_mint: self.output_mint.key(),
+            lamports: 0,
+            bump: bumps.prize_swap
+        });
+
+        msg!(
+            "Prize for lottery #{} ticket will be swapped into {}",
+            self.user_ticket.lottery_id,
+            self.output_mint.key()
+        );
+
+        Ok(())
+    }
+}
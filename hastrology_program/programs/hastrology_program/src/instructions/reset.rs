@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::LOTTERY_STATE_SEED, errors::HashtrologyErrors, state::LotteryState
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED, METRICS_SEED}, errors::HashtrologyErrors, require_ctx, state::{AuditAction, AuditLog, LotteryState, Metrics}
 };
 
 #[derive(Accounts)]
@@ -16,6 +16,20 @@ pub struct Reset<'info> {
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
     pub system_program: Program<'info, System> 
 }
 
@@ -27,9 +41,11 @@ impl<'info> Reset<'info> {
         let lottery_state = &mut self.lottery_state;
         let clock = Clock::get()?;
 
-        require!(
-            clock.unix_timestamp >= lottery_state.lottery_endtime,
-            HashtrologyErrors::LotteryNotOver
+        require_ctx!(
+            lottery_state.has_ended(lottery_state.lottery_endtime, &clock),
+            HashtrologyErrors::LotteryNotOver,
+            "expected_end={} now={} end_slot={} slot={}",
+            lottery_state.lottery_endtime, clock.unix_timestamp, lottery_state.end_slot, clock.slot
         );
 
         require!(
@@ -37,14 +53,30 @@ impl<'info> Reset<'info> {
             HashtrologyErrors::CannotRolloverWithPlayers
         );
 
+        require!(
+            !lottery_state.round_finalized,
+            HashtrologyErrors::RoundNotOpen
+        );
+
         lottery_state.winner = 0;
+        lottery_state.winner_commitment = [0u8; 32];
         lottery_state.total_participants = 0;
         lottery_state.current_lottery_id = lottery_state.current_lottery_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
-        lottery_state.lottery_endtime = lottery_state.lottery_endtime.checked_add(100).ok_or(HashtrologyErrors::Overflow)?;
+        let endtime = lottery_state.lottery_endtime.checked_add(100).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.set_endtime(endtime, &clock);
+        lottery_state.round_started_at = clock.unix_timestamp;
         lottery_state.is_drawing = false; 
         lottery_state.commit_slot = 0;
 
-        
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.end_pause(clock.unix_timestamp)?;
+        }
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::Reset,
+            self.authority.key(),
+            &[&lottery_state.current_lottery_id.to_le_bytes()]
+        )?;
 
         msg!("Initialized...");
         
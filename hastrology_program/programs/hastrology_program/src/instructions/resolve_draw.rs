@@ -1,10 +1,32 @@
-use anchor_lang::prelude::*;
-use crate::{constants::LOTTERY_STATE_SEED, errors::HashtrologyErrors, state::LotteryState};
-use ephemeral_vrf_sdk::{rnd::random_u64, consts::VRF_PROGRAM_IDENTITY};
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
+use crate::{
+    constants::{DRAW_SOURCE_PRIMARY, DRAW_SOURCE_SECOND, LOTTERY_STATE_SEED, LOTTO_MAX_NUMBER, LOTTO_PICK_COUNT, PARTICIPANT_INDEX_SEED, ROUND_HISTORY_SEED, SEASON_WINNERS_SEED, SNIPE_WINDOW_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, ParticipantIndex, RoundHistory, RoundKind, SeasonWinners, SnipeWindow},
+    ID
+};
+use ephemeral_vrf_sdk::rnd::random_u64;
+use lottery_core::{
+    selection::{select_numbers, select_sign, select_ticket_excluding, select_weight, select_weighted_ticket, snipe_extension, WeightedPick},
+    RandomnessSource
+};
+
+/// Feeds the VRF callback's randomness into `lottery_core`.
+pub struct VrfRandomness(pub [u8; 32]);
+
+impl RandomnessSource for VrfRandomness {
+    fn random_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    fn random_u64(&self) -> u64 {
+        random_u64(&self.0)
+    }
+}
 
 #[derive(Accounts)]
 pub struct ResolveDraw<'info> {
-    #[account(address = VRF_PROGRAM_IDENTITY)]
+    #[account(address = lottery_state.randomness_callback_signer() @ HashtrologyErrors::InvalidRandomnessProvider)]
     pub vrf_program: Signer<'info>,
 
     #[account(
@@ -14,29 +36,291 @@ pub struct ResolveDraw<'info> {
     )]
     pub lottery_state: Account<'info, LotteryState>,
 
+    #[account(
+        mut,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    /// Only forwarded when the round had entries inside the anti-snipe window.
+    #[account(
+        mut,
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = snipe_window.bump
+    )]
+    pub snipe_window: Option<Account<'info, SnipeWindow>>,
+
+    /// CHECK: Read as raw bytes to look up the drawn wallets. Forwarded with `season_winners`
+    /// while the one-win-per-season rule is on.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_WINNERS_SEED],
+        bump = season_winners.bump
+    )]
+    pub season_winners: Option<Account<'info, SeasonWinners>>,
+
+    /// CHECK: SlotHashes sysvar, forwarded when the round mixes in an operator nonce.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> ResolveDraw<'info> {
-    pub fn resolve_draw_handler(&mut self, randomness: [u8; 32]) -> Result<()> {  
+    pub fn resolve_draw_handler(&mut self, randomness: [u8; 32]) -> Result<()> {
+        self.resolve(DRAW_SOURCE_PRIMARY, randomness)
+    }
+
+    /// Callback of the second queue in a dual draw. It takes the same accounts as
+    /// `resolve_draw`, since whichever queue answers last resolves the round.
+    pub fn resolve_second_draw_handler(&mut self, randomness: [u8; 32]) -> Result<()> {
+        self.resolve(DRAW_SOURCE_SECOND, randomness)
+    }
+
+    fn resolve(&mut self, source: u8, randomness: [u8; 32]) -> Result<()> {
         let lottery_state = &mut self.lottery_state;
-        let total_participants = lottery_state.total_participants;
-
-        let raw_random_value = random_u64(&randomness);
-
-        if total_participants == 0 {
-            msg!("No participants. No winner selected.");
-            lottery_state.winner = 0;
-        } else {
-            let winning_index = raw_random_value % total_participants;
-            lottery_state.winner = winning_index.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
-            msg!(
-                "Lottery Resolved! Raw: {}, Participants: {}, Winner Index: {}", 
-                raw_random_value,
-                lottery_state.total_participants,
-                winning_index
-            );
+
+        require!(
+            lottery_state.is_drawing,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.resolved_request_id != lottery_state.draw_request_id,
+            HashtrologyErrors::DrawAlreadyResolved
+        );
+
+        let clock = Clock::get()?;
+        let round_history = &mut self.round_history;
+        round_history.verify_snapshot(lottery_state)?;
+
+        // Until every queue has answered the request stays open, so retry_draw still applies
+        let Some(randomness) = round_history.record_vrf_output(source, randomness)? else {
+            msg!("VRF output recorded for Lottery #{}. Waiting for the other queue", lottery_state.current_lottery_id);
+
+            return Ok(());
+        };
+
+        lottery_state.resolved_request_id = lottery_state.draw_request_id;
+        round_history.vrf_signer = self.vrf_program.key();
+        round_history.resolve_slot = clock.slot;
+
+        // The winner waits for the operator nonce; the slot hash is pinned now, before it is revealed
+        if round_history.entropy_mixed {
+            let slot_hashes = self.slot_hashes
+                .as_ref()
+                .ok_or(HashtrologyErrors::SlotHashesRequired)?;
+
+            round_history.entropy_blockhash = latest_slot_hash(&slot_hashes.try_borrow_data()?)?;
+            lottery_state.entropy_mix_pending = true;
+
+            msg!("VRF output recorded for Lottery #{}. Waiting for the operator nonce", lottery_state.current_lottery_id);
+
+            return Ok(());
+        }
+
+        draw_winner(
+            lottery_state,
+            round_history,
+            self.snipe_window.as_mut(),
+            self.participant_index.as_ref(),
+            self.season_winners.as_mut(),
+            randomness
+        )
+    }
+}
+
+/// Most recent entry of the SlotHashes sysvar: a `u64` length, then `(slot, hash)` pairs newest first.
+fn latest_slot_hash(data: &[u8]) -> Result<[u8; 32]> {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(data.get(16..48).ok_or(HashtrologyErrors::SlotHashesRequired)?);
+
+    Ok(hash)
+}
+
+/// Applies `randomness` to the round: resolves the anti-snipe window and picks the winner.
+/// Shared by `resolve_draw` and `reveal_draw_nonce`.
+pub(crate) fn draw_winner<'info>(
+    lottery_state: &mut Account<'info, LotteryState>,
+    round_history: &mut Account<'info, RoundHistory>,
+    snipe_window: Option<&mut Account<'info, SnipeWindow>>,
+    participant_index: Option<&UncheckedAccount<'info>>,
+    season_winners: Option<&mut Account<'info, SeasonWinners>>,
+    randomness: [u8; 32]
+) -> Result<()> {
+    let mut total_participants = lottery_state.total_participants;
+
+    let vrf_randomness = VrfRandomness(randomness);
+    let raw_random_value = vrf_randomness.random_u64();
+
+    if let Some(snipe_window) = snipe_window {
+        let extension = snipe_extension(&randomness, lottery_state.anti_snipe_max_extension);
+
+        let true_endtime = lottery_state.lottery_endtime.saturating_add(extension);
+        let eligible_participants = snipe_window.eligible_count(true_endtime, total_participants);
+        let late_refunds = (total_participants - eligible_participants)
+            .checked_mul(snipe_window.ticket_price)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        lottery_state.late_entry_lamports = lottery_state.late_entry_lamports
+            .checked_add(late_refunds)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        round_history.late_refunds = late_refunds;
+        snipe_window.is_resolved = true;
+        snipe_window.true_endtime = true_endtime;
+        snipe_window.eligible_participants = eligible_participants;
+
+        msg!(
+            "True end revealed at {}: {} of {} tickets eligible",
+            true_endtime,
+            eligible_participants,
+            total_participants
+        );
+
+        total_participants = eligible_participants;
+    }
+
+    match lottery_state.round_kind {
+        RoundKind::NoLoss => {
+            // Draw a point in the cumulative deposit weight; `winner = 1` flags that one was drawn.
+            if let Some(winning_weight) = select_weight(&vrf_randomness, lottery_state.no_loss_total_weight) {
+                lottery_state.no_loss_winning_weight = winning_weight;
+                lottery_state.winner = 1;
+                msg!(
+                    "No-loss lottery resolved! Winning weight: {} of {}",
+                    lottery_state.no_loss_winning_weight,
+                    lottery_state.no_loss_total_weight
+                );
+            } else {
+                msg!("No deposit weight. No winner selected.");
+                lottery_state.winner = 0;
+            }
+        }
+        RoundKind::LuckySign => {
+            // `winner` holds the drawn sign + 1; every ticket that picked it shares the prize.
+            if let Some(winning_sign) = select_sign(&vrf_randomness, total_participants) {
+                lottery_state.winner = winning_sign as u64 + 1;
+                msg!("Lucky sign lottery resolved! Winning sign: {}", winning_sign);
+            } else {
+                msg!("No participants. No winner selected.");
+                lottery_state.winner = 0;
+            }
+        }
+        RoundKind::LuckyNumbers => {
+            // `winner` holds the drawn numbers as a bitmask; tickets win by how many they match.
+            if let Some(drawn) = select_numbers(&vrf_randomness, total_participants, LOTTO_PICK_COUNT as u8, LOTTO_MAX_NUMBER) {
+                lottery_state.winner = drawn;
+                msg!("Lucky numbers lottery resolved! Drawn mask: {:#x}", drawn);
+            } else {
+                msg!("No participants. No numbers drawn.");
+                lottery_state.winner = 0;
+            }
         }
-        
-        Ok(())
+        // Class tickets carry weight on top of every ticket's 1. A draw landing on it waits for
+        // locate_class_winner to name the ticket; the season rule doesn't apply to these rounds.
+        RoundKind::Standard | RoundKind::Raffle { .. } if lottery_state.class_weight_total > 0 => {
+            match select_weighted_ticket(&vrf_randomness, total_participants, lottery_state.class_weight_total) {
+                Some(WeightedPick::Ticket(winning_ticket)) => {
+                    lottery_state.winner = winning_ticket;
+                    msg!(
+                        "Lottery Resolved! Raw: {}, Participants: {}, Winner Index: {}",
+                        raw_random_value,
+                        total_participants,
+                        winning_ticket - 1
+                    );
+                }
+                Some(WeightedPick::ClassWeight(winning_weight)) => {
+                    lottery_state.winner = 0;
+                    lottery_state.class_winning_weight = Some(winning_weight);
+
+                    if lottery_state.reveal_delay > 0 {
+                        lottery_state.reveal_after = Clock::get()?.unix_timestamp
+                            .checked_add(lottery_state.reveal_delay)
+                            .ok_or(HashtrologyErrors::Overflow)?;
+                    }
+
+                    msg!(
+                        "Class weight {} of {} drawn. Waiting for locate_class_winner",
+                        winning_weight,
+                        lottery_state.class_weight_total
+                    );
+                }
+                None => {
+                    msg!("No participants. No winner selected.");
+                    lottery_state.winner = 0;
+                }
+            }
+        }
+        RoundKind::Standard | RoundKind::Raffle { .. } => {
+            let season = lottery_state.current_season(Clock::get()?.unix_timestamp);
+            let season_rule = match (participant_index, season_winners) {
+                (Some(index), Some(season_winners)) if lottery_state.season_length > 0 => Some((index, season_winners)),
+                _ => None
+            };
+
+            let selection = match season_rule {
+                Some((index, season_winners)) => {
+                    let index_data = index.try_borrow_data()?;
+                    let wallet_of = |ticket: u64| ParticipantIndex::raw_participant(&index_data, ticket as usize - 1);
+
+                    // Re-roll past wallets that already won this season
+                    let selection = select_ticket_excluding(&vrf_randomness, total_participants, |ticket| {
+                        wallet_of(ticket).is_some_and(|wallet| season_winners.has_won(season, &wallet))
+                    });
+
+                    if let Some(wallet) = selection.and_then(|(ticket, _)| wallet_of(ticket)) {
+                        season_winners.record(season, wallet);
+                    }
+
+                    selection
+                }
+                None => select_ticket_excluding(&vrf_randomness, total_participants, |_| false)
+            };
+
+            if let Some((winning_ticket, rerolls)) = selection {
+                lottery_state.winner = winning_ticket;
+                round_history.winner_rerolls = rerolls;
+                msg!(
+                    "Lottery Resolved! Raw: {}, Participants: {}, Winner Index: {}", 
+                    raw_random_value,
+                    lottery_state.total_participants,
+                    winning_ticket - 1
+                );
+                if rerolls > 0 {
+                    msg!("Re-rolled {} time(s) past earlier season winners", rerolls);
+                }
+            } else {
+                msg!("No participants. No winner selected.");
+                lottery_state.winner = 0;
+            }
+        }
+    }
+
+    // In delayed mode only a commitment is stored; reveal_winner publishes the result later
+    if lottery_state.reveal_delay > 0 && lottery_state.winner > 0 {
+        lottery_state.winner_commitment = lottery_state.winner_commitment_for(
+            lottery_state.winner,
+            lottery_state.no_loss_winning_weight,
+            &randomness
+        );
+        lottery_state.reveal_after = Clock::get()?.unix_timestamp
+            .checked_add(lottery_state.reveal_delay)
+            .ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.winner = 0;
+        lottery_state.no_loss_winning_weight = 0;
+
+        msg!("Winner committed. Reveal after {}", lottery_state.reveal_after);
     }
+
+    round_history.total_participants = total_participants;
+    round_history.winner = lottery_state.winner;
+
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use lottery_core::selection::select_ticket;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED},
+    errors::HashtrologyErrors,
+    instructions::VrfRandomness,
+    state::{LotteryState, MegaJackpot}
+};
+
+#[derive(Accounts)]
+pub struct ResolveMegaDraw<'info> {
+    #[account(address = lottery_state.randomness_callback_signer() @ HashtrologyErrors::InvalidRandomnessProvider)]
+    pub vrf_program: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Account<'info, MegaJackpot>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> ResolveMegaDraw<'info> {
+    /// VRF callback: picks the winning entry; `settle_mega_draw` then pays its ticket holder.
+    pub fn resolve_mega_draw_handler(&mut self, randomness: [u8; 32]) -> Result<()> {
+        let mega_jackpot = &mut self.mega_jackpot;
+
+        require!(
+            mega_jackpot.is_drawing && mega_jackpot.winning_entry.is_none(),
+            HashtrologyErrors::MegaJackpotNotDrawing
+        );
+
+        // `select_ticket` numbers from 1; jackpot entries are indexed from 0
+        let winning_entry = select_ticket(&VrfRandomness(randomness), mega_jackpot.total_entries)
+            .ok_or(HashtrologyErrors::MegaJackpotNotDrawing)? - 1;
+
+        mega_jackpot.winning_entry = Some(winning_entry);
+        mega_jackpot.is_drawing = false;
+
+        msg!(
+            "Mega jackpot epoch #{} resolved! Winning entry: {} of {}",
+            mega_jackpot.epoch,
+            winning_entry,
+            mega_jackpot.total_entries
+        );
+
+        Ok(())
+    }
+}
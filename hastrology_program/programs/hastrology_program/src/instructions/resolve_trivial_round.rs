@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, ROUND_HISTORY_SEED, SNIPE_WINDOW_SEED},
+    errors::HashtrologyErrors,
+    require_ctx,
+    state::{LotteryState, RoundHistory},
+    ID
+};
+
+/// Permissionless fast path for a round with a single ticket: its winner is certain, so the
+/// round is resolved here without a VRF request and can go straight to `payout`. Anything
+/// that needs the randomness (late entries, the season rule, a delayed reveal), the prize
+/// floor check or a draw attestation still goes through `request_draw`.
+#[derive(Accounts)]
+pub struct ResolveTrivialRound<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoundHistory::INIT_SPACE,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    /// CHECK: Always required so a round with anti-snipe entries can't skip the VRF by
+    /// leaving it out; it must not exist.
+    #[account(
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub snipe_window: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ResolveTrivialRound<'info> {
+    pub fn resolve_trivial_round_handler(&mut self, bumps: &ResolveTrivialRoundBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        let lottery_state = &mut self.lottery_state;
+        lottery_state.check_not_sunset()?;
+
+        require!(
+            lottery_state.trivial_resolution,
+            HashtrologyErrors::TrivialResolutionDisabled
+        );
+
+        let draw_endtime = lottery_state.lottery_endtime.saturating_add(lottery_state.anti_snipe_max_extension);
+
+        require_ctx!(
+            lottery_state.round_kind.draw_ready(lottery_state.has_ended(draw_endtime, &clock), lottery_state.total_participants),
+            HashtrologyErrors::LotteryNotOver,
+            "expected_end={} now={} end_slot={} slot={} participants={}",
+            draw_endtime, clock.unix_timestamp, lottery_state.end_slot, clock.slot, lottery_state.total_participants
+        );
+        require_ctx!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::DrawAlreadyRequested,
+            "lottery_id={} phase={} draw_request_id={}", lottery_state.current_lottery_id, lottery_state.phase(), lottery_state.draw_request_id
+        );
+        require_ctx!(
+            !lottery_state.round_finalized,
+            HashtrologyErrors::RoundNotOpen,
+            "lottery_id={} phase={}", lottery_state.current_lottery_id, lottery_state.phase()
+        );
+
+        require!(
+            lottery_state.total_participants == 1
+                && lottery_state.round_kind.has_single_winner()
+                && lottery_state.season_length == 0
+                && lottery_state.reveal_delay == 0
+                && lottery_state.min_prize_lamports == 0
+                && lottery_state.draw_threshold == 0
+                && self.snipe_window.owner != &ID,
+            HashtrologyErrors::TrivialRoundNeedsDraw
+        );
+
+        let rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        lottery_state.check_accounting(self.pot_vault.lamports().saturating_sub(rent_reserve))?;
+
+        lottery_state.is_drawing = true;
+        lottery_state.draw_request_id = lottery_state.draw_request_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        lottery_state.resolved_request_id = lottery_state.draw_request_id;
+        lottery_state.commit_slot = clock.slot;
+        lottery_state.winner = 1;
+
+        let snapshot_pot = lottery_state.round_pot(self.pot_vault.lamports())?;
+        let snapshot_hash = lottery_state.round_snapshot_hash(snapshot_pot)?;
+
+        // No caller seed, oracle or randomness: nothing was requested
+        self.round_history.set_inner(RoundHistory {
+            lottery_id: lottery_state.current_lottery_id,
+            draw_request_id: lottery_state.draw_request_id,
+            caller_seed: [0u8; 32],
+            oracle_queue: Pubkey::default(),
+            request_slot: clock.slot,
+            randomness: [0u8; 32],
+            vrf_signer: Pubkey::default(),
+            resolve_slot: clock.slot,
+            total_participants: 1,
+            winner: 1,
+            bump: bumps.round_history,
+            payment_currency: lottery_state.payment_currency.clone(),
+            token_prize_currency: lottery_state.token_prize_currency.clone(),
+            token_prize_amount: lottery_state.token_prize_amount,
+            round_kind: lottery_state.round_kind,
+            winner_rerolls: 0,
+            entropy_mixed: false,
+            snipe_window_forwarded: false,
+            entropy_blockhash: [0u8; 32],
+            entropy_nonce: [0u8; 32],
+            snapshot_hash,
+            snapshot_pot,
+            late_refunds: 0,
+            class_tickets_sold: lottery_state.class_tickets_sold(),
+            class_weight_total: lottery_state.class_weight_total,
+            reward_mint: lottery_state.reward_mint,
+            loser_reward_amount: lottery_state.loser_reward_for_round(),
+            second_oracle_queue: None,
+            second_randomness: [0u8; 32],
+            draw_sources: 0,
+            class_pots: lottery_state.class_pots,
+            class_prizes_paid: 0,
+        });
+
+        msg!(
+            "Lottery #{} resolved without a draw: its only ticket wins",
+            lottery_state.current_lottery_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DRAW_RETRY_TIMEOUT_SLOTS, MAX_DRAW_RETRIES, PROGRAM_VERSION, ROUND_REOPEN_EXTENSION},
+    errors::HashtrologyErrors,
+    events::RoundReopened,
+    instructions::{RequestDraw, RequestDrawBumps}
+};
+
+impl<'info> RequestDraw<'info> {
+    /// Re-requests randomness when the oracle never called back. After `MAX_DRAW_RETRIES`
+    /// failed requests the round is reopened for entries instead of staying stuck. A draw
+    /// whose operator nonce was never revealed is not retried: its VRF output is in, so
+    /// `settle_unrevealed_draw` draws from that instead.
+    pub fn retry_draw_handler(&mut self, bumps: &RequestDrawBumps) -> Result<()> {
+        let clock = Clock::get()?;
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.is_drawing,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.resolved_request_id != lottery_state.draw_request_id,
+            HashtrologyErrors::DrawAlreadyResolved
+        );
+
+        require!(
+            clock.slot >= lottery_state.commit_slot.saturating_add(DRAW_RETRY_TIMEOUT_SLOTS),
+            HashtrologyErrors::DrawNotTimedOut
+        );
+
+        self.pay_keeper_reward()?;
+
+        let lottery_state = &mut self.lottery_state;
+
+        if lottery_state.draw_retries >= MAX_DRAW_RETRIES {
+            let failed_requests = lottery_state.draw_retries + 1;
+
+            // Drop the pending request so a late callback cannot land on the reopened round
+            lottery_state.resolved_request_id = lottery_state.draw_request_id;
+            lottery_state.is_drawing = false;
+            lottery_state.commit_slot = 0;
+            lottery_state.draw_retries = 0;
+            let endtime = clock.unix_timestamp.checked_add(ROUND_REOPEN_EXTENSION).ok_or(HashtrologyErrors::Overflow)?;
+            lottery_state.set_endtime(endtime, &clock);
+            let rollover_lamports = lottery_state.round_pot(self.pot_vault.lamports())?;
+
+            if let Some(metrics) = self.metrics.as_mut() {
+                metrics.end_pause(clock.unix_timestamp)?;
+            }
+
+            emit!(RoundReopened {
+                event_seq: lottery_state.next_event_seq(),
+                program_version: PROGRAM_VERSION.to_string(),
+                lottery_id: lottery_state.current_lottery_id,
+                failed_requests,
+                new_endtime: lottery_state.lottery_endtime,
+                rollover_lamports,
+            });
+
+            msg!(
+                "Draw for lottery #{} failed {} times. Round reopened until {}",
+                lottery_state.current_lottery_id,
+                failed_requests,
+                lottery_state.lottery_endtime
+            );
+
+            return Ok(());
+        }
+
+        lottery_state.draw_retries += 1;
+
+        self.request_randomness(bumps, &clock)
+    }
+}
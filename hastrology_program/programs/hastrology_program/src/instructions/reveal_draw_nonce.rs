@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PARTICIPANT_INDEX_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED, SEASON_WINNERS_SEED, SNIPE_WINDOW_SEED},
+    errors::HashtrologyErrors,
+    events::DrawNonceRevealed,
+    instructions::resolve_draw::draw_winner,
+    state::{LotteryState, RoundHistory, SeasonWinners, SnipeWindow},
+    ID
+};
+
+/// Permissionless: the nonce is checked against the committed hash, so whoever holds it can
+/// finish the draw. Takes the same optional accounts as `resolve_draw`.
+#[derive(Accounts)]
+pub struct RevealDrawNonce<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+
+    #[account(
+        mut,
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = snipe_window.bump
+    )]
+    pub snipe_window: Option<Account<'info, SnipeWindow>>,
+
+    /// CHECK: Read as raw bytes to look up the drawn wallets.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_WINNERS_SEED],
+        bump = season_winners.bump
+    )]
+    pub season_winners: Option<Account<'info, SeasonWinners>>,
+}
+
+impl<'info> RevealDrawNonce<'info> {
+    pub fn reveal_draw_nonce_handler(&mut self, nonce: [u8; 32]) -> Result<()> {
+        require!(
+            self.lottery_state.entropy_mix_pending,
+            HashtrologyErrors::NoEntropyRevealPending
+        );
+
+        require!(
+            nonce != [0u8; 32] && self.lottery_state.entropy_commitment_for(&nonce) == self.lottery_state.entropy_commitment,
+            HashtrologyErrors::InvalidEntropyNonce
+        );
+
+        self.check_draw_accounts()?;
+
+        let lottery_state = &mut self.lottery_state;
+        let round_history = &mut self.round_history;
+
+        round_history.entropy_nonce = nonce;
+        lottery_state.entropy_mix_pending = false;
+        lottery_state.entropy_commitment = [0u8; 32];
+
+        emit!(DrawNonceRevealed {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            nonce,
+            blockhash: round_history.entropy_blockhash,
+        });
+
+        let randomness = round_history.mixed_randomness();
+
+        draw_winner(
+            lottery_state,
+            round_history,
+            self.snipe_window.as_mut(),
+            self.participant_index.as_ref(),
+            self.season_winners.as_mut(),
+            randomness
+        )
+    }
+
+    /// The caller picks the accounts, so they must match what the plain VRF draw would have
+    /// used, and the round must not have changed since the draw was requested.
+    pub(crate) fn check_draw_accounts(&self) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        let season_rule = lottery_state.season_length > 0
+            && lottery_state.round_kind.has_single_winner()
+            && !lottery_state.merkle_entry_mode;
+
+        require!(
+            self.snipe_window.is_some() == self.round_history.snipe_window_forwarded
+                && (!season_rule || (self.participant_index.is_some() && self.season_winners.is_some())),
+            HashtrologyErrors::DrawAccountsMismatch
+        );
+
+        self.round_history.verify_snapshot(lottery_state)
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PROGRAM_VERSION, ROUND_HISTORY_SEED},
+    errors::HashtrologyErrors,
+    events::WinnerRevealed,
+    state::{LotteryState, RoundHistory}
+};
+
+/// Permissionless: the winner follows from the randomness stored in `round_history`,
+/// so anyone may publish it once the delay has passed.
+#[derive(Accounts)]
+pub struct RevealWinner<'info> {
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [ROUND_HISTORY_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = round_history.bump
+    )]
+    pub round_history: Account<'info, RoundHistory>,
+}
+
+impl<'info> RevealWinner<'info> {
+    pub fn reveal_winner_handler(&mut self, winner: u64, winning_weight: u128) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let round_history = &mut self.round_history;
+
+        require!(
+            lottery_state.has_pending_reveal(),
+            HashtrologyErrors::NoPendingReveal
+        );
+
+        require!(
+            Clock::get()?.unix_timestamp >= lottery_state.reveal_after,
+            HashtrologyErrors::RevealTooEarly
+        );
+
+        let randomness = round_history
+            .draw_randomness()
+            .ok_or(HashtrologyErrors::EntropyRevealPending)?;
+
+        require!(
+            lottery_state.winner_commitment_for(winner, winning_weight, &randomness)
+                == lottery_state.winner_commitment,
+            HashtrologyErrors::InvalidWinnerReveal
+        );
+
+        lottery_state.winner = winner;
+        lottery_state.no_loss_winning_weight = winning_weight;
+        lottery_state.winner_commitment = [0u8; 32];
+        lottery_state.reveal_after = 0;
+        round_history.winner = winner;
+
+        emit!(WinnerRevealed {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            winner,
+        });
+
+        msg!("Lottery #{} winner revealed: ticket {}", lottery_state.current_lottery_id, winner);
+
+        Ok(())
+    }
+}
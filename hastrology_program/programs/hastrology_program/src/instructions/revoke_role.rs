@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, LOTTERY_STATE_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{AuditAction, AuditLog, LotteryState, Role, RoleGrant}
+};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+}
+
+impl<'info> RevokeRole<'info> {
+    /// The authority's own roles are implicit and can't be revoked.
+    pub fn revoke_role_handler(&mut self, member: Pubkey, role: Role) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+        let grant = RoleGrant { member, role };
+
+        let position = lottery_state.role_grants
+            .iter()
+            .position(|existing| *existing == grant)
+            .ok_or(HashtrologyErrors::RoleNotGranted)?;
+
+        lottery_state.role_grants.remove(position);
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::RevokeRole,
+            self.authority.key(),
+            &[member.as_ref(), &[role as u8]]
+        )?;
+
+        msg!("Revoked {:?} from {}", role, member);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SESSION_AUTHORITY_SEED,
+    state::SessionAuthority
+};
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [SESSION_AUTHORITY_SEED, user.key().as_ref()],
+        bump = session_authority.bump
+    )]
+    pub session_authority: Account<'info, SessionAuthority>,
+}
+
+impl<'info> RevokeSession<'info> {
+    pub fn revoke_session_handler(&mut self) -> Result<()> {
+        let session_authority = &self.session_authority;
+
+        msg!(
+            "Session key {} revoked. {} of {} lamports unspent returned.",
+            session_authority.session_key,
+            session_authority.spend_cap - session_authority.spent,
+            session_authority.spend_cap
+        );
+
+        Ok(())
+    }
+}
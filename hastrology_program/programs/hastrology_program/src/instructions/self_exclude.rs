@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PLAYER_PROFILE_SEED,
+    state::PlayerProfile
+};
+
+/// The player locks themselves out of entries. There is deliberately no authority
+/// instruction to lift it; the exclusion only ends when its period lapses.
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> SelfExclude<'info> {
+    pub fn self_exclude_handler(&mut self, duration: i64, bumps: &SelfExcludeBumps) -> Result<()> {
+        let player_profile = &mut self.player_profile;
+
+        if player_profile.user == Pubkey::default() {
+            player_profile.user = self.user.key();
+            player_profile.bump = bumps.player_profile;
+        }
+
+        let excluded_until = player_profile.self_exclude(duration, Clock::get()?.unix_timestamp)?;
+
+        msg!("{} excluded from entries until {}", self.user.key(), excluded_until);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_ANTI_SNIPE_EXTENSION},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetAntiSnipe<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetAntiSnipe<'info> {
+    pub fn set_anti_snipe_handler(&mut self, max_extension: i64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            (0..=MAX_ANTI_SNIPE_EXTENSION).contains(&max_extension),
+            HashtrologyErrors::InvalidAntiSnipeExtension
+        );
+
+        // Changing the window after the advertised end would move a deadline players already rely on
+        require!(
+            Clock::get()?.unix_timestamp < lottery_state.lottery_endtime && !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsOver
+        );
+
+        lottery_state.anti_snipe_max_extension = max_extension;
+
+        msg!("Anti-snipe extension set to up to {} seconds", max_extension);
+
+        Ok(())
+    }
+}
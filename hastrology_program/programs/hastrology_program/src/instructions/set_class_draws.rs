@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetClassDraws<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetClassDraws<'info> {
+    /// With class draws on, class tickets enter the main draw like regular ones, and what
+    /// they pay above the regular price goes to a pot their class draws for on its own, so
+    /// sun tickets only compete against sun tickets. Only between rounds.
+    pub fn set_class_draws_handler(&mut self, enabled: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.set_class_draws(enabled)?;
+
+        msg!("Class draws {} from lottery #{}", if enabled { "enabled" } else { "disabled" }, self.lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
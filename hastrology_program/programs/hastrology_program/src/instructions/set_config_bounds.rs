@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PLATFORM_CONFIG_SEED},
+    require_role,
+    state::{ConfigBounds, LotteryState, PlatformConfig, Role}
+};
+
+/// Only affects later `initialize` and `update_config` calls; the live config isn't rechecked.
+#[derive(Accounts)]
+pub struct SetConfigBounds<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> SetConfigBounds<'info> {
+    pub fn set_config_bounds_handler(&mut self, bounds: ConfigBounds) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        bounds.validate()?;
+
+        self.platform_config.bounds = bounds;
+
+        msg!(
+            "Config bounds set: rounds of {}-{} seconds, fee up to {} bps, ticket price from {} lamports",
+            bounds.min_round_duration,
+            bounds.max_round_duration,
+            bounds.max_fee_bps,
+            bounds.min_ticket_price
+        );
+
+        Ok(())
+    }
+}
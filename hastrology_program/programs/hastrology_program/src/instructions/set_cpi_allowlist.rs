@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_CPI_ALLOWLIST},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetCpiAllowlist<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetCpiAllowlist<'info> {
+    pub fn set_cpi_allowlist_handler(&mut self, programs: Vec<Pubkey>) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            programs.len() <= MAX_CPI_ALLOWLIST,
+            HashtrologyErrors::TooManyAllowlistedPrograms
+        );
+
+        for (i, program) in programs.iter().enumerate() {
+            require!(
+                !programs[..i].contains(program),
+                HashtrologyErrors::DuplicateAllowlistedProgram
+            );
+        }
+
+        self.lottery_state.cpi_allowlist = programs;
+
+        msg!("CPI entry allowlist set to {} programs", self.lottery_state.cpi_allowlist.len());
+
+        Ok(())
+    }
+}
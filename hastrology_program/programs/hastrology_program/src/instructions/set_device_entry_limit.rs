@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetDeviceEntryLimit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetDeviceEntryLimit<'info> {
+    /// Caps `enter_lottery` entries per device commitment per round, requiring every entry to
+    /// carry one; 0 lifts the cap and makes commitments optional again. No other entry path
+    /// can carry a commitment, so sessions, deposits, pools, queued, conditional, Merkle,
+    /// no-loss and KYC-approved entries are refused while a cap is set.
+    pub fn set_device_entry_limit_handler(&mut self, max_entries_per_device: u32) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.max_entries_per_device = max_entries_per_device;
+
+        msg!("Entries per device set to {} per round", max_entries_per_device);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_DRAW_OPERATORS},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetDrawOperators<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetDrawOperators<'info> {
+    pub fn set_draw_operators_handler(
+        &mut self,
+        operators: Vec<Pubkey>,
+        threshold: u8,
+        attestation_min_pot: u64,
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            operators.len() <= MAX_DRAW_OPERATORS,
+            HashtrologyErrors::TooManyDrawOperators
+        );
+
+        require!(
+            threshold as usize <= operators.len(),
+            HashtrologyErrors::InvalidDrawThreshold
+        );
+
+        for (i, operator) in operators.iter().enumerate() {
+            require!(
+                !operators[..i].contains(operator),
+                HashtrologyErrors::DuplicateDrawOperator
+            );
+        }
+
+        lottery_state.draw_operators = operators;
+        lottery_state.draw_threshold = threshold;
+        lottery_state.attestation_min_pot = attestation_min_pot;
+
+        msg!(
+            "Draw attestation set to {}-of-{} for pots of at least {} lamports",
+            threshold,
+            lottery_state.draw_operators.len(),
+            attestation_min_pot
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetDrawWindowQueueing<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetDrawWindowQueueing<'info> {
+    pub fn set_draw_window_queueing_handler(&mut self, enabled: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.queue_entries_while_drawing = enabled;
+
+        msg!("Draw window queueing set to {}", enabled);
+
+        Ok(())
+    }
+}
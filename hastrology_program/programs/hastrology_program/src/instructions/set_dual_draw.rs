@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetDualDraw<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetDualDraw<'info> {
+    /// Draws for pots of at least `min_pot_lamports` also request randomness from
+    /// `second_oracle_queue` and use the hash of both outputs; 0 turns it off.
+    pub fn set_dual_draw_handler(&mut self, min_pot_lamports: u64, second_oracle_queue: Pubkey) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        require!(
+            min_pot_lamports == 0 || (second_oracle_queue != Pubkey::default() && second_oracle_queue != self.lottery_state.randomness_queue),
+            HashtrologyErrors::InvalidSecondOracleQueue
+        );
+
+        self.lottery_state.dual_draw_min_pot = min_pot_lamports;
+        self.lottery_state.second_oracle_queue = second_oracle_queue;
+
+        msg!("Dual draw set for pots of {} lamports or more, second queue {}", min_pot_lamports, second_oracle_queue);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_EARLY_BIRD_BONUS},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetEarlyBird<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetEarlyBird<'info> {
+    /// Entries within `window` seconds of a round opening get `bonus` free tickets.
+    /// A bonus of 0 disables it.
+    pub fn set_early_bird_handler(&mut self, window: i64, bonus: u8) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        require!(
+            window >= 0 && bonus <= MAX_EARLY_BIRD_BONUS,
+            HashtrologyErrors::InvalidEarlyBirdBonus
+        );
+
+        self.lottery_state.early_bird_window = window;
+        self.lottery_state.early_bird_bonus = bonus;
+
+        msg!("Early-bird bonus set to {} ticket(s) for the first {}s", bonus, window);
+
+        Ok(())
+    }
+}
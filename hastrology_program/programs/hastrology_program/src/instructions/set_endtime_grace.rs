@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_ENDTIME_GRACE},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetEndtimeGrace<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetEndtimeGrace<'info> {
+    /// Lets end-of-round checks pass up to `grace` seconds before the cluster clock reaches `lottery_endtime`.
+    pub fn set_endtime_grace_handler(&mut self, grace: i64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            (0..=MAX_ENDTIME_GRACE).contains(&grace),
+            HashtrologyErrors::InvalidEndtimeGrace
+        );
+
+        self.lottery_state.endtime_grace = grace;
+
+        msg!("Endtime grace set to {} seconds", grace);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetEntriesPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetEntriesPaused<'info> {
+    /// Stops every path that issues a ticket. Draws, payouts and claims carry on.
+    pub fn set_entries_paused_handler(&mut self, paused: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Pauser);
+
+        self.lottery_state.entries_paused = paused;
+
+        msg!("Entries paused set to {}", paused);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetEntropyMixing<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetEntropyMixing<'info> {
+    /// Pots of at least `min_pot` lamports can only be drawn once a draw operator has committed
+    /// a nonce for the round. 0 disables the requirement.
+    pub fn set_entropy_mixing_handler(&mut self, min_pot: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        self.lottery_state.entropy_mix_min_pot = min_pot;
+
+        msg!("Entropy mixing required from {} lamports", min_pot);
+
+        Ok(())
+    }
+}
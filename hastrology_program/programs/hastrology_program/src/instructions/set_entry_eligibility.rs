@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{EntryEligibility, LotteryState, Role},
+    ID
+};
+
+#[derive(Accounts)]
+pub struct SetEntryEligibility<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetEntryEligibility<'info> {
+    pub fn set_entry_eligibility_handler(&mut self, eligibility: EntryEligibility) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        if let EntryEligibility::TokenBalance { amount, .. } = eligibility {
+            require!(
+                amount > 0,
+                HashtrologyErrors::InvalidEligibilityRule
+            );
+        }
+
+        if let EntryEligibility::Attestation { program, .. } = eligibility {
+            require!(
+                program != Pubkey::default() && program != ID,
+                HashtrologyErrors::InvalidEligibilityRule
+            );
+        }
+
+        self.lottery_state.entry_eligibility = eligibility;
+
+        msg!("Entry eligibility updated for lottery #{}", self.lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetFeeGovernance<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetFeeGovernance<'info> {
+    /// An admin may name the first fee governance key; after that only the current key can
+    /// hand it on, so a compromised admin can't take over fee approvals.
+    pub fn set_fee_governance_handler(&mut self, fee_governance: Pubkey) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let signer = self.signer.key();
+
+        let authorized = if lottery_state.fee_governance == Pubkey::default() {
+            lottery_state.has_role(&signer, Role::Admin)
+        } else {
+            signer == lottery_state.fee_governance
+        };
+
+        require!(authorized, HashtrologyErrors::UnauthorizedFeeGovernance);
+
+        lottery_state.fee_governance = fee_governance;
+
+        msg!("Fee governance set to {}", fee_governance);
+
+        Ok(())
+    }
+}
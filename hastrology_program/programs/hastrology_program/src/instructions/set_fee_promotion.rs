@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetFeePromotion<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetFeePromotion<'info> {
+    /// Charges entries paid into round `lottery_id` between `starts_at` and `ends_at`
+    /// `fee_bps` at payout instead of the platform fee; 0 waives it. Replaces any earlier
+    /// promotion, unless the current round already has entries under it.
+    pub fn set_fee_promotion_handler(&mut self, lottery_id: u64, starts_at: i64, ends_at: i64, fee_bps: u16) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(
+            starts_at < ends_at
+                && ends_at > now
+                && fee_bps <= lottery_state.platform_fee_bps
+                && lottery_id >= lottery_state.current_lottery_id,
+            HashtrologyErrors::InvalidFeePromotion
+        );
+
+        if lottery_id == lottery_state.current_lottery_id {
+            require!(
+                !lottery_state.is_drawing && !lottery_state.round_finalized,
+                HashtrologyErrors::RoundNotOpen
+            );
+        }
+
+        require!(
+            lottery_state.round_promo_lamports() == 0,
+            HashtrologyErrors::FeePromotionInUse
+        );
+
+        lottery_state.fee_promo_lottery_id = lottery_id;
+        lottery_state.fee_promo_start = starts_at;
+        lottery_state.fee_promo_end = ends_at;
+        lottery_state.fee_promo_bps = fee_bps;
+        lottery_state.fee_promo_lamports = 0;
+
+        msg!(
+            "Lottery #{} entries between {} and {} pay a {} bps platform fee",
+            lottery_id,
+            starts_at,
+            ends_at,
+            fee_bps
+        );
+
+        Ok(())
+    }
+}
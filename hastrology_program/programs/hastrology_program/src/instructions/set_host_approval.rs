@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_APPROVED_HOSTS, PLATFORM_CONFIG_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, PlatformConfig, Role}
+};
+
+/// Revoking a host stops new lotteries; the host's existing lotteries keep running.
+#[derive(Accounts)]
+pub struct SetHostApproval<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+impl<'info> SetHostApproval<'info> {
+    pub fn set_host_approval_handler(&mut self, host: Pubkey, approved: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let hosts = &mut self.platform_config.approved_hosts;
+
+        if approved {
+            require!(
+                !hosts.contains(&host),
+                HashtrologyErrors::HostAlreadyApproved
+            );
+
+            require!(
+                hosts.len() < MAX_APPROVED_HOSTS,
+                HashtrologyErrors::TooManyHosts
+            );
+
+            hosts.push(host);
+        } else {
+            hosts.retain(|approved_host| approved_host != &host);
+        }
+
+        msg!("Host {} approval set to {}", host, approved);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{INSURANCE_RESERVE_SEED, LOTTERY_STATE_SEED, MAX_INSURANCE_BPS},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{InsuranceReserve, LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetInsuranceBps<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Must exist before payouts can fund it.
+    #[account(
+        seeds = [INSURANCE_RESERVE_SEED],
+        bump = insurance_reserve.bump
+    )]
+    pub insurance_reserve: Account<'info, InsuranceReserve>,
+}
+
+impl<'info> SetInsuranceBps<'info> {
+    /// Moves `insurance_bps` of each paid-out pot from the platform share into the insurance
+    /// reserve; the winner's prize is unchanged. 0 stops funding it.
+    pub fn set_insurance_bps_handler(&mut self, insurance_bps: u16) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        require!(
+            insurance_bps <= MAX_INSURANCE_BPS,
+            HashtrologyErrors::InsuranceBpsTooHigh
+        );
+
+        self.lottery_state.insurance_bps = insurance_bps;
+
+        msg!("Insurance reserve cut set to {} bps", insurance_bps);
+
+        Ok(())
+    }
+}
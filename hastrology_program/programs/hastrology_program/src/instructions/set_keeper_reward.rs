@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_KEEPER_REWARD},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetKeeperReward<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetKeeperReward<'info> {
+    pub fn set_keeper_reward_handler(&mut self, reward_lamports: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        require!(
+            reward_lamports <= MAX_KEEPER_REWARD,
+            HashtrologyErrors::InvalidKeeperReward
+        );
+
+        self.lottery_state.keeper_reward_lamports = reward_lamports;
+
+        msg!("Keeper reward set to {} lamports", reward_lamports);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetKycVerifier<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetKycVerifier<'info> {
+    /// Enables KYC escrow with `verifier` as the approving key, or disables it with `Pubkey::default()`.
+    /// Entries already escrowed stay refundable either way.
+    pub fn set_kyc_verifier_handler(&mut self, verifier: Pubkey) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        self.lottery_state.kyc_verifier = verifier;
+
+        msg!("KYC verifier set to {}", verifier);
+
+        Ok(())
+    }
+}
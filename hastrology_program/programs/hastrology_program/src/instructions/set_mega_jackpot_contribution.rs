@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_MEGA_JACKPOT_BPS, MEGA_JACKPOT_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, MegaJackpot, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetMegaJackpotContribution<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// Must exist before a lottery can link to it.
+    #[account(
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Account<'info, MegaJackpot>,
+}
+
+impl<'info> SetMegaJackpotContribution<'info> {
+    /// Sends `contribution_bps` of each `enter_lottery` sale to the mega jackpot; 0 unlinks the lottery.
+    pub fn set_mega_jackpot_contribution_handler(&mut self, contribution_bps: u16) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            contribution_bps <= MAX_MEGA_JACKPOT_BPS,
+            HashtrologyErrors::MegaJackpotBpsTooHigh
+        );
+
+        self.lottery_state.mega_jackpot_bps = contribution_bps;
+
+        msg!("Mega jackpot contribution set to {} bps", contribution_bps);
+
+        Ok(())
+    }
+}
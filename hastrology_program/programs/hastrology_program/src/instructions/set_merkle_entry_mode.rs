@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetMerkleEntryMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetMerkleEntryMode<'info> {
+    pub fn set_merkle_entry_mode_handler(&mut self, enabled: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        // Ticket indices are shared, so a round cannot mix ticket PDAs and Merkle leaves
+        require!(
+            !lottery_state.is_drawing && lottery_state.total_participants == 0,
+            HashtrologyErrors::CannotSwitchModeMidRound
+        );
+
+        require!(
+            !enabled || lottery_state.round_kind.has_single_winner(),
+            HashtrologyErrors::MerkleEntryNeedsSingleWinner
+        );
+
+        lottery_state.merkle_entry_mode = enabled;
+
+        msg!("Merkle entry mode set to {}", enabled);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetMinPrize<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetMinPrize<'info> {
+    /// Rounds whose prize can't reach `min_prize_lamports`, even with the fee treasury, roll over
+    /// instead of drawing. 0 disables the floor.
+    pub fn set_min_prize_handler(&mut self, min_prize_lamports: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        self.lottery_state.min_prize_lamports = min_prize_lamports;
+
+        msg!("Minimum prize set to {} lamports", min_prize_lamports);
+
+        Ok(())
+    }
+}
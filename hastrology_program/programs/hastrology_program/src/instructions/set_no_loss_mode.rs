@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role, RoundKind}
+};
+
+#[derive(Accounts)]
+pub struct SetNoLossMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetNoLossMode<'info> {
+    pub fn set_no_loss_mode_handler(&mut self, enabled: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let round_kind = if enabled { RoundKind::NoLoss } else { RoundKind::Standard };
+
+        self.lottery_state.set_round_kind(round_kind)?;
+
+        msg!("No-loss mode set to {}", enabled);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::HashtrologyErrors,
+    state::UserTicket
+};
+
+#[derive(Accounts)]
+pub struct SetPayoutDestination<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = user @ HashtrologyErrors::Unauthorized,
+        constraint = !user_ticket.is_claimed && user_ticket.prize_amount == 0 @ HashtrologyErrors::PrizeAlreadyPaid
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+}
+
+impl<'info> SetPayoutDestination<'info> {
+    /// The destination account isn't passed here, so it's checked at payout: a prize it
+    /// couldn't spend is held for `claim_held_prize` instead of being sent.
+    pub fn set_payout_destination_handler(&mut self, destination: Option<Pubkey>) -> Result<()> {
+        if let Some(destination) = destination {
+            require!(
+                destination != Pubkey::default(),
+                HashtrologyErrors::InvalidPayoutDestination
+            );
+        }
+
+        self.user_ticket.payout_destination = destination;
+
+        msg!(
+            "Payout destination for lottery #{} ticket set to {}",
+            self.user_ticket.lottery_id,
+            self.user_ticket.payout_address()
+        );
+
+        Ok(())
+    }
+}
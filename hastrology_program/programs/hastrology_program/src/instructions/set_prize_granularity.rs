@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_PRIZE_GRANULARITY},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetPrizeGranularity<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetPrizeGranularity<'info> {
+    /// Winner prizes are rounded down to a multiple of `prize_granularity` lamports and the dust
+    /// is sent to the fee treasury with the platform fee. 0 disables rounding.
+    pub fn set_prize_granularity_handler(&mut self, prize_granularity: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        require!(
+            prize_granularity <= MAX_PRIZE_GRANULARITY,
+            HashtrologyErrors::PrizeGranularityTooHigh
+        );
+
+        self.lottery_state.prize_granularity = prize_granularity;
+
+        msg!("Prize granularity set to {} lamports", prize_granularity);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetRandomnessProvider<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetRandomnessProvider<'info> {
+    /// Points draws at another randomness provider, which needs an adapter in `randomness`,
+    /// and its `queue`, or the provider's default queue for `None`. A mega jackpot draw
+    /// pending with the old provider has to be re-requested once it times out.
+    pub fn set_randomness_provider_handler(&mut self, program_id: Pubkey, queue: Option<Pubkey>) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.set_randomness_provider(program_id, queue)?;
+
+        msg!("Randomness provider set to {} with queue {}", program_id, self.lottery_state.randomness_queue);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetRateLimits<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetRateLimits<'info> {
+    pub fn set_rate_limits_handler(&mut self, max_entries_per_wallet: u32, max_entries_per_slot: u32) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+
+        lottery_state.max_entries_per_wallet = max_entries_per_wallet;
+        lottery_state.max_entries_per_slot = max_entries_per_slot;
+
+        msg!(
+            "Entry rate limits set: {} per wallet per round, {} per slot",
+            max_entries_per_wallet,
+            max_entries_per_slot
+        );
+
+        Ok(())
+    }
+}
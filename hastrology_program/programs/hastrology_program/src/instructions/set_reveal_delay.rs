@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_REVEAL_DELAY},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetRevealDelay<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetRevealDelay<'info> {
+    pub fn set_reveal_delay_handler(&mut self, reveal_delay: i64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            (0..=MAX_REVEAL_DELAY).contains(&reveal_delay),
+            HashtrologyErrors::InvalidRevealDelay
+        );
+
+        // Changing the delay mid-draw would strand or rush a committed winner
+        require!(
+            !self.lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        self.lottery_state.reveal_delay = reveal_delay;
+
+        msg!("Winner reveal delay set to {} seconds", reveal_delay);
+
+        Ok(())
+    }
+}
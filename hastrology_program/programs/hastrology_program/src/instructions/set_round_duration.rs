@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MIN_ROUND_DURATION},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetRoundDuration<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetRoundDuration<'info> {
+    pub fn set_round_duration_handler(&mut self, round_duration: i64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            round_duration >= MIN_ROUND_DURATION,
+            HashtrologyErrors::InvalidRoundDuration
+        );
+
+        self.lottery_state.round_duration = round_duration;
+
+        msg!("Round duration set to {} seconds", round_duration);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_TICKETS_PER_ROUND},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role, RoundKind}
+};
+
+#[derive(Accounts)]
+pub struct SetRoundKind<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetRoundKind<'info> {
+    pub fn set_round_kind_handler(&mut self, round_kind: RoundKind) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        round_kind.validate()?;
+
+        if let RoundKind::Raffle { ticket_supply } = round_kind {
+            require!(
+                ticket_supply <= MAX_TICKETS_PER_ROUND,
+                HashtrologyErrors::InvalidRoundKind
+            );
+        }
+
+        self.lottery_state.set_round_kind(round_kind)?;
+
+        msg!("Round kind updated for lottery #{}", self.lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
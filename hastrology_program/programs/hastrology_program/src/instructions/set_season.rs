@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, SEASON_WINNERS_SEED},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role, SeasonWinners}
+};
+
+#[derive(Accounts)]
+pub struct SetSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SeasonWinners::INIT_SPACE,
+        seeds = [SEASON_WINNERS_SEED],
+        bump
+    )]
+    pub season_winners: Account<'info, SeasonWinners>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetSeason<'info> {
+    /// Starts a new season of `season_length` seconds now; 0 turns the one-win-per-season rule off.
+    pub fn set_season_handler(&mut self, season_length: i64, bumps: &SetSeasonBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        require!(
+            season_length >= 0,
+            HashtrologyErrors::InvalidSeasonLength
+        );
+
+        let lottery_state = &mut self.lottery_state;
+        lottery_state.season_length = season_length;
+        lottery_state.season_start = Clock::get()?.unix_timestamp;
+
+        // Restarting the season forgets its winners
+        self.season_winners.set_inner(SeasonWinners {
+            season: 0,
+            winners: Vec::new(),
+            bump: bumps.season_winners
+        });
+
+        msg!("Season length set to {} seconds", season_length);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetSlotDeadline<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetSlotDeadline<'info> {
+    /// Ends rounds by slot instead of the cluster clock, and no sooner than `min_round_slots`
+    /// after they open. Only between rounds; `lottery_endtime` stays as the displayed estimate.
+    pub fn set_slot_deadline_handler(&mut self, enabled: bool, min_round_slots: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.set_slot_deadline(enabled, min_round_slots)?;
+
+        msg!("Slot deadline set to {} with a minimum of {} slots", enabled, min_round_slots);
+
+        Ok(())
+    }
+}
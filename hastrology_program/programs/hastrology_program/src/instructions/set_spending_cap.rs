@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PLAYER_PROFILE_SEED,
+    state::PlayerProfile
+};
+
+/// Set by the player for themselves; nobody else can loosen it.
+#[derive(Accounts)]
+pub struct SetSpendingCap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> SetSpendingCap<'info> {
+    pub fn set_spending_cap_handler(&mut self, cap: u64, bumps: &SetSpendingCapBumps) -> Result<()> {
+        let player_profile = &mut self.player_profile;
+
+        if player_profile.user == Pubkey::default() {
+            player_profile.user = self.user.key();
+            player_profile.bump = bumps.player_profile;
+        }
+
+        let effective_at = player_profile.request_spending_cap(cap, Clock::get()?.unix_timestamp)?;
+
+        msg!("Spending cap of {} lamports takes effect at {}", cap, effective_at);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::HashtrologyErrors,
+    state::UserTicket
+};
+
+/// Opts a ticket into receiving its prize as a stake account delegated to `vote_account`.
+/// `payout` then holds the prize until the winner claims it with `claim_prize_as_stake`.
+#[derive(Accounts)]
+pub struct SetStakePayout<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = user @ HashtrologyErrors::Unauthorized,
+        constraint = !user_ticket.is_claimed && user_ticket.prize_amount == 0 @ HashtrologyErrors::PrizeAlreadyPaid
+    )]
+    pub user_ticket: Account<'info, UserTicket>,
+}
+
+impl<'info> SetStakePayout<'info> {
+    pub fn set_stake_payout_handler(&mut self, vote_account: Option<Pubkey>) -> Result<()> {
+        if let Some(vote_account) = vote_account {
+            require!(
+                vote_account != Pubkey::default(),
+                HashtrologyErrors::InvalidVoteAccount
+            );
+        }
+
+        self.user_ticket.stake_vote_account = vote_account;
+
+        match vote_account {
+            Some(vote_account) => msg!(
+                "Prize for lottery #{} ticket will be staked with {}",
+                self.user_ticket.lottery_id,
+                vote_account
+            ),
+            None => msg!("Prize for lottery #{} ticket will be paid in SOL", self.user_ticket.lottery_id)
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_STREAK_BONUS},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetStreakBonus<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetStreakBonus<'info> {
+    /// A wallet winning consecutive rounds gets `streak_bonus_lamports` from the fee treasury for
+    /// each win after the first, escalating up to `MAX_STREAK_BONUS_STEPS`. 0 disables the bonus.
+    pub fn set_streak_bonus_handler(&mut self, streak_bonus_lamports: u64) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::FeeManager);
+
+        require!(!self.lottery_state.is_drawing, HashtrologyErrors::LotteryIsDrawing);
+
+        require!(
+            streak_bonus_lamports <= MAX_STREAK_BONUS,
+            HashtrologyErrors::StreakBonusTooHigh
+        );
+
+        self.lottery_state.streak_bonus_lamports = streak_bonus_lamports;
+
+        msg!("Streak bonus set to {} lamports per consecutive win", streak_bonus_lamports);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetSwapProgram<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetSwapProgram<'info> {
+    /// Sets the DEX (e.g. Jupiter) winners' prizes are swapped through. `Pubkey::default()`
+    /// stops new swap requests; prizes already held can still be claimed as raw SOL.
+    pub fn set_swap_program_handler(&mut self, swap_program: Pubkey) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.swap_program = swap_program;
+
+        msg!("Swap program set to {}", swap_program);
+
+        Ok(())
+    }
+}
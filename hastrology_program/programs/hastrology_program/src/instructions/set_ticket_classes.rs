@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role, TicketClass, TicketClassConfig}
+};
+
+#[derive(Accounts)]
+pub struct SetTicketClasses<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetTicketClasses<'info> {
+    /// Sets the star, moon and sun tickets (by index) the next rounds sell next to the regular
+    /// one. Only between rounds; an empty list turns classes off.
+    pub fn set_ticket_classes_handler(&mut self, ticket_classes: Vec<TicketClassConfig>) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let ticket_classes: Vec<TicketClass> = ticket_classes
+            .into_iter()
+            .map(|config| TicketClass { price: config.price, weight: config.weight, tickets_sold: 0 })
+            .collect();
+
+        self.lottery_state.set_ticket_classes(ticket_classes)?;
+
+        msg!("{} ticket classes set for lottery #{}", self.lottery_state.ticket_classes.len(), self.lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
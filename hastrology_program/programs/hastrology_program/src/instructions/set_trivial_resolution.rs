@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetTrivialResolution<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetTrivialResolution<'info> {
+    /// While enabled, single-ticket rounds may be resolved by `resolve_trivial_round`
+    /// instead of paying for a VRF request.
+    pub fn set_trivial_resolution_handler(&mut self, enabled: bool) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.trivial_resolution = enabled;
+
+        msg!("Trivial round resolution set to {}", enabled);
+
+        Ok(())
+    }
+}
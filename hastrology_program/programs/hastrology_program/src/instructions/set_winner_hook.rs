@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LOTTERY_STATE_SEED,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct SetWinnerHook<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> SetWinnerHook<'info> {
+    /// Registers the program (e.g. a bridge or notification relay) `payout` calls with each
+    /// round's result. Once set, payouts fail without it, so the call is atomic with the prize.
+    /// `Pubkey::default()` turns the hook off.
+    pub fn set_winner_hook_handler(&mut self, winner_hook_program: Pubkey) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        self.lottery_state.winner_hook_program = winner_hook_program;
+
+        msg!("Winner hook set to {}", winner_hook_program);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, FEE_TREASURY_SEED, LOTTERY_STATE_SEED, LOTTO_REGISTRATION_WINDOW, LOTTO_ROUND_SEED, LOTTO_TIER_BPS, METRICS_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    math::split_prize_tiers,
+    require_role,
+    state::{AuditAction, AuditLog, FeeTreasury, LotteryState, LottoRound, Metrics, Role, RoundKind}
+};
+
+#[derive(Accounts)]
+pub struct SettleLotto<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LottoRound::INIT_SPACE,
+        seeds = [LOTTO_ROUND_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub lotto_round: Account<'info, LottoRound>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleLotto<'info> {
+    /// Splits the prize pool into tier pools held by the round account, opens ticket
+    /// registration and finalizes the round.
+    pub fn settle_lotto_handler(&mut self, bumps: &SettleLottoBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.round_kind == RoundKind::LuckyNumbers,
+            HashtrologyErrors::NotLottoRound
+        );
+
+        require!(
+            lottery_state.is_drawing,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        require!(
+            !lottery_state.has_pending_reveal(),
+            HashtrologyErrors::WinnerNotRevealed
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut platform_fee_amount = 0;
+        let mut prize_pool = 0;
+        let mut tier_pools = [0u64; LOTTO_TIER_BPS.len()];
+
+        if lottery_state.winner > 0 {
+            let total_pot_balance = self.pot_vault
+                .lamports()
+                .checked_sub(lottery_state.reserved_referral_lamports)
+                .and_then(|balance| balance.checked_sub(lottery_state.late_entry_lamports))
+                .ok_or(HashtrologyErrors::Overflow)?;
+
+            let (fee, pool) = lottery_state.split_round_fee(total_pot_balance)?;
+            platform_fee_amount = fee;
+            prize_pool = pool;
+            tier_pools.copy_from_slice(&split_prize_tiers(prize_pool, &LOTTO_TIER_BPS)?);
+
+            debit_lamports(&self.pot_vault, platform_fee_amount)?;
+            credit_lamports(&self.fee_treasury.to_account_info(), platform_fee_amount)?;
+            self.fee_treasury.accrue(platform_fee_amount)?;
+            lottery_state.record_vault_outflow(platform_fee_amount)?;
+
+            debit_lamports(&self.pot_vault, prize_pool)?;
+            credit_lamports(&self.lotto_round.to_account_info(), prize_pool)?;
+            lottery_state.record_vault_outflow(prize_pool)?;
+        }
+
+        // With nothing drawn there is nothing to register, so registration closes at once
+        let registration_ends = if lottery_state.winner > 0 {
+            now.checked_add(LOTTO_REGISTRATION_WINDOW).ok_or(HashtrologyErrors::Overflow)?
+        } else {
+            now
+        };
+
+        self.lotto_round.set_inner(LottoRound {
+            lottery_id: lottery_state.current_lottery_id,
+            drawn_numbers: lottery_state.winner,
+            registration_ends,
+            tier_pools,
+            tier_counts: [0; LOTTO_TIER_BPS.len()],
+            is_finalized: false,
+            tier_shares: [0; LOTTO_TIER_BPS.len()],
+            claimed_count: 0,
+            bump: bumps.lotto_round
+        });
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::Payout,
+            self.authority.key(),
+            &[
+                &lottery_state.current_lottery_id.to_le_bytes(),
+                &lottery_state.winner.to_le_bytes(),
+                &prize_pool.to_le_bytes(),
+                &platform_fee_amount.to_le_bytes(),
+            ]
+        )?;
+
+        lottery_state.finalize_round();
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.end_pause(now)?;
+        }
+
+        msg!(
+            "Lucky numbers lottery #{} settled. Prize pool {} lamports, registration until {}.",
+            self.lotto_round.lottery_id,
+            prize_pool,
+            registration_ends
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AUDIT_LOG_SEED, FEE_TREASURY_SEED, LOTTERY_STATE_SEED, LUCKY_SIGN_ROUND_SEED, METRICS_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    require_role,
+    state::{AuditAction, AuditLog, FeeTreasury, LotteryState, LuckySignRound, Metrics, Role, RoundKind}
+};
+
+#[derive(Accounts)]
+pub struct SettleLuckySign<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [FEE_TREASURY_SEED],
+        bump = fee_treasury.bump
+    )]
+    pub fee_treasury: Account<'info, FeeTreasury>,
+
+    /// Created empty when nobody picked a sign this round; the pot then rolls over.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + LuckySignRound::INIT_SPACE,
+        seeds = [LUCKY_SIGN_ROUND_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump
+    )]
+    pub lucky_sign_round: Account<'info, LuckySignRound>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump
+    )]
+    pub metrics: Option<Account<'info, Metrics>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleLuckySign<'info> {
+    /// Moves the prize pool into the round's tally account, split evenly across
+    /// every ticket that picked the drawn sign, and finalizes the round.
+    pub fn settle_lucky_sign_handler(&mut self, bumps: &SettleLuckySignBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            lottery_state.round_kind == RoundKind::LuckySign,
+            HashtrologyErrors::NotLuckySignRound
+        );
+
+        require!(
+            lottery_state.is_drawing,
+            HashtrologyErrors::DrawNotRequested
+        );
+
+        require!(
+            lottery_state.staked_lamports == 0,
+            HashtrologyErrors::PotStillStaked
+        );
+
+        require!(
+            !lottery_state.has_pending_reveal(),
+            HashtrologyErrors::WinnerNotRevealed
+        );
+
+        let round = &mut self.lucky_sign_round;
+        if round.lottery_id == 0 {
+            round.lottery_id = lottery_state.current_lottery_id;
+            round.bump = bumps.lucky_sign_round;
+        }
+
+        let mut share_lamports = 0;
+        let mut winner_count = 0;
+        let mut platform_fee_amount = 0;
+
+        if lottery_state.winner > 0 {
+            round.winning_sign = (lottery_state.winner - 1) as u8;
+            winner_count = round.winner_count();
+
+            if winner_count > 0 {
+                let total_pot_balance = self.pot_vault
+                    .lamports()
+                    .checked_sub(lottery_state.reserved_referral_lamports)
+                    .and_then(|balance| balance.checked_sub(lottery_state.late_entry_lamports))
+                    .ok_or(HashtrologyErrors::Overflow)?;
+
+                let (fee, prize_pool) = lottery_state.split_round_fee(total_pot_balance)?;
+                platform_fee_amount = fee;
+
+                // Any remainder of the even split stays in the pot for the next round
+                share_lamports = prize_pool / winner_count as u64;
+                let co_winner_pool = share_lamports
+                    .checked_mul(winner_count as u64)
+                    .ok_or(HashtrologyErrors::Overflow)?;
+
+                debit_lamports(&self.pot_vault, platform_fee_amount)?;
+                credit_lamports(&self.fee_treasury.to_account_info(), platform_fee_amount)?;
+                self.fee_treasury.accrue(platform_fee_amount)?;
+                lottery_state.record_vault_outflow(platform_fee_amount)?;
+
+                debit_lamports(&self.pot_vault, co_winner_pool)?;
+                credit_lamports(&round.to_account_info(), co_winner_pool)?;
+                lottery_state.record_vault_outflow(co_winner_pool)?;
+            }
+
+            round.share_lamports = share_lamports;
+            round.is_settled = true;
+        }
+
+        self.audit_log.load_mut()?.record(
+            AuditAction::Payout,
+            self.authority.key(),
+            &[
+                &lottery_state.current_lottery_id.to_le_bytes(),
+                &lottery_state.winner.to_le_bytes(),
+                &winner_count.to_le_bytes(),
+                &share_lamports.to_le_bytes(),
+                &platform_fee_amount.to_le_bytes(),
+            ]
+        )?;
+
+        lottery_state.finalize_round();
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.end_pause(Clock::get()?.unix_timestamp)?;
+        }
+
+        msg!(
+            "Lucky sign lottery #{} settled. {} co-winners, {} lamports each.",
+            lottery_state.current_lottery_id,
+            winner_count,
+            share_lamports
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MEGA_JACKPOT_SEED, PARTICIPANT_INDEX_SEED, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::MegaJackpotPaid,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, MegaJackpot, ParticipantIndex},
+    ID
+};
+
+/// Pays the drawn mega jackpot entry to the wallet holding that ticket. Anyone may crank it,
+/// naming the round and ticket the winning entry maps to.
+#[derive(Accounts)]
+#[instruction(lottery_id: u64)]
+pub struct SettleMegaDraw<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [MEGA_JACKPOT_SEED],
+        bump = mega_jackpot.bump
+    )]
+    pub mega_jackpot: Account<'info, MegaJackpot>,
+
+    /// CHECK: Read as raw bytes to look up the wallet holding the winning ticket.
+    #[account(
+        seeds = [PARTICIPANT_INDEX_SEED, &lottery_id.to_le_bytes()],
+        bump,
+        owner = ID
+    )]
+    pub participant_index: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the participant index in the handler.
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+}
+
+impl<'info> SettleMegaDraw<'info> {
+    pub fn settle_mega_draw_handler(&mut self, lottery_id: u64, ticket_index: u64) -> Result<()> {
+        let mega_jackpot = &mut self.mega_jackpot;
+
+        let winning_entry = mega_jackpot.winning_entry.ok_or(HashtrologyErrors::MegaJackpotNotDrawn)?;
+
+        require!(
+            mega_jackpot.ticket_for_entry(winning_entry) == Some((lottery_id, ticket_index)),
+            HashtrologyErrors::InvalidMegaJackpotTicket
+        );
+
+        let wallet = ParticipantIndex::raw_participant(&self.participant_index.try_borrow_data()?, ticket_index as usize)
+            .ok_or(HashtrologyErrors::InvalidMegaJackpotTicket)?;
+
+        require_keys_eq!(self.winner.key(), wallet, HashtrologyErrors::InvalidWinner);
+
+        let prize_lamports = mega_jackpot.jackpot_lamports;
+        let total_entries = mega_jackpot.total_entries;
+        let epoch = mega_jackpot.epoch;
+
+        debit_lamports(&mega_jackpot.to_account_info(), prize_lamports)?;
+        credit_lamports(&self.winner, prize_lamports)?;
+
+        mega_jackpot.jackpot_lamports = 0;
+        mega_jackpot.start_next_epoch(Clock::get()?.unix_timestamp)?;
+
+        emit!(MegaJackpotPaid {
+            event_seq: self.lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            epoch,
+            lottery_id,
+            ticket_index,
+            winner: wallet,
+            prize_lamports,
+            total_entries,
+        });
+
+        msg!(
+            "Mega jackpot epoch #{} paid {} lamports to {} (lottery #{}, ticket #{})",
+            epoch,
+            prize_lamports,
+            wallet,
+            lottery_id,
+            ticket_index + 1
+        );
+
+        Ok(())
+    }
+}
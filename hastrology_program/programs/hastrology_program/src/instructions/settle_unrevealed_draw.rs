@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DRAW_RETRY_TIMEOUT_SLOTS, PROGRAM_VERSION},
+    errors::HashtrologyErrors,
+    events::DrawNonceWithheld,
+    instructions::{resolve_draw::draw_winner, RevealDrawNonce}
+};
+
+impl<'info> RevealDrawNonce<'info> {
+    /// Settles a draw whose operator nonce was never revealed from the VRF output pinned when
+    /// the oracle called back. The operator already knows that output, so requesting a fresh
+    /// one would let them re-roll by withholding; the round is drawn from it instead.
+    pub fn settle_unrevealed_draw_handler(&mut self) -> Result<()> {
+        require!(
+            self.lottery_state.entropy_mix_pending,
+            HashtrologyErrors::NoEntropyRevealPending
+        );
+
+        require!(
+            Clock::get()?.slot >= self.round_history.resolve_slot.saturating_add(DRAW_RETRY_TIMEOUT_SLOTS),
+            HashtrologyErrors::DrawNotTimedOut
+        );
+
+        self.check_draw_accounts()?;
+
+        let lottery_state = &mut self.lottery_state;
+        let round_history = &mut self.round_history;
+
+        // Drawn and later verified from the raw VRF output
+        round_history.entropy_mixed = false;
+        lottery_state.entropy_mix_pending = false;
+        lottery_state.entropy_commitment = [0u8; 32];
+
+        emit!(DrawNonceWithheld {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+        });
+
+        msg!(
+            "Operator nonce for lottery #{} was never revealed; drawing from the VRF output alone",
+            lottery_state.current_lottery_id
+        );
+
+        let randomness = round_history.vrf_output();
+
+        draw_winner(
+            lottery_state,
+            round_history,
+            self.snipe_window.as_mut(),
+            self.participant_index.as_ref(),
+            self.season_winners.as_mut(),
+            randomness
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{AIRDROP_SNAPSHOT_SEED, LOTTERY_STATE_SEED, MAX_SNAPSHOT_ROUNDS, MERKLE_TREE_DEPTH},
+    errors::HashtrologyErrors,
+    require_role,
+    state::{AirdropSnapshot, LotteryState, Role}
+};
+
+/// Opens an `AirdropSnapshot` of lotteries `first_lottery_id..`, which
+/// `crank_airdrop_snapshot` then fills round by round.
+#[derive(Accounts)]
+#[instruction(first_lottery_id: u64, round_count: u8)]
+pub struct SnapshotAirdrop<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AirdropSnapshot::INIT_SPACE,
+        seeds = [AIRDROP_SNAPSHOT_SEED, &first_lottery_id.to_le_bytes(), &[round_count]],
+        bump
+    )]
+    pub airdrop_snapshot: Account<'info, AirdropSnapshot>,
+
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> SnapshotAirdrop<'info> {
+    pub fn snapshot_airdrop_handler(
+        &mut self,
+        first_lottery_id: u64,
+        round_count: u8,
+        bumps: &SnapshotAirdropBumps
+    ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let last_lottery_id = first_lottery_id
+            .checked_add(round_count as u64)
+            .and_then(|end| end.checked_sub(1))
+            .ok_or(HashtrologyErrors::InvalidSnapshotRange)?;
+
+        require!(
+            round_count > 0
+                && round_count as usize <= MAX_SNAPSHOT_ROUNDS
+                && last_lottery_id <= self.lottery_state.current_lottery_id,
+            HashtrologyErrors::InvalidSnapshotRange
+        );
+
+        self.airdrop_snapshot.set_inner(AirdropSnapshot {
+            first_lottery_id,
+            round_count,
+            rounds_read: 0,
+            next_ticket: 0,
+            filled_subtrees: [[0u8; 32]; MERKLE_TREE_DEPTH],
+            round_root: [0u8; 32],
+            round_roots: [[0u8; 32]; MAX_SNAPSHOT_ROUNDS],
+            ticket_count: 0,
+            depth: 0,
+            root: [0u8; 32],
+            snapshotted_at: 0,
+            bump: bumps.airdrop_snapshot
+        });
+
+        msg!(
+            "Opened airdrop snapshot of {} rounds from lottery #{}",
+            round_count,
+            first_lottery_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed
+    }
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, STAKE_POOL_DEPOSIT_SOL_IX, STAKE_STAGING_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct StakePot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    /// System-owned PDA that signs the deposit, since the stake pool only accepts SOL from system accounts.
+    #[account(
+        mut,
+        seeds = [STAKE_STAGING_SEED],
+        bump
+    )]
+    pub stake_staging: SystemAccount<'info>,
+
+    /// CHECK: Must be the configured stake pool; validated by the stake pool program.
+    #[account(
+        mut,
+        address = lottery_state.stake_pool @ HashtrologyErrors::InvalidStakePool
+    )]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = pool_mint,
+        associated_token::authority = stake_staging,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Must be the configured stake pool program.
+    #[account(
+        address = lottery_state.stake_pool_program @ HashtrologyErrors::InvalidStakePool
+    )]
+    pub stake_pool_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> StakePot<'info> {
+    pub fn stake_pot_handler(&mut self, amount: u64, bumps: &StakePotBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let lottery_state = &mut self.lottery_state;
+
+        require!(
+            !lottery_state.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        require!(
+            lottery_state.stake_pool_program != Pubkey::default(),
+            HashtrologyErrors::InvalidStakePool
+        );
+
+        require!(
+            amount > 0,
+            HashtrologyErrors::InvalidStakeAmount
+        );
+
+        // Only the round's own pot; lamports held for referrers, late entries, swaps, claims
+        // and class draws must stay on hand
+        let pot_rent_reserve = Rent::get()?.minimum_balance(self.pot_vault.data_len());
+        let stakeable = lottery_state.round_pot(self.pot_vault.lamports().saturating_sub(pot_rent_reserve))?;
+
+        require!(
+            amount <= stakeable,
+            HashtrologyErrors::InsufficientPotBalance
+        );
+
+        debit_lamports(&self.pot_vault, amount)?;
+        credit_lamports(&self.stake_staging.to_account_info(), amount)?;
+        lottery_state.record_vault_outflow(amount)?;
+
+        let mut data = Vec::with_capacity(9);
+        data.push(STAKE_POOL_DEPOSIT_SOL_IX);
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.stake_pool_program.key(),
+            accounts: vec![
+                AccountMeta::new(self.stake_pool.key(), false),
+                AccountMeta::new_readonly(self.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new(self.reserve_stake.key(), false),
+                AccountMeta::new(self.stake_staging.key(), true),
+                AccountMeta::new(self.pool_token_account.key(), false),
+                AccountMeta::new(self.manager_fee_account.key(), false),
+                // Referral fees come back to our own pool token account
+                AccountMeta::new(self.pool_token_account.key(), false),
+                AccountMeta::new(self.pool_mint.key(), false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(self.token_program.key(), false),
+            ],
+            data
+        };
+
+        let seeds = &[STAKE_STAGING_SEED, &[bumps.stake_staging]];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(
+            &ix,
+            &[
+                self.stake_pool.to_account_info(),
+                self.stake_pool_withdraw_authority.to_account_info(),
+                self.reserve_stake.to_account_info(),
+                self.stake_staging.to_account_info(),
+                self.pool_token_account.to_account_info(),
+                self.manager_fee_account.to_account_info(),
+                self.pool_mint.to_account_info(),
+                self.system_program.to_account_info(),
+                self.token_program.to_account_info(),
+            ],
+            signer_seeds
+        )?;
+
+        lottery_state.staked_lamports = lottery_state.staked_lamports.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+
+        msg!(
+            "Staked {} lamports from the pot. Total staked: {}",
+            amount,
+            lottery_state.staked_lamports
+        );
+
+        Ok(())
+    }
+}
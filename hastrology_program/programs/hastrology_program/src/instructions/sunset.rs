@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, MAX_TICKET_CLASSES, PROGRAM_VERSION},
+    events::ProgramSunset,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct Sunset<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+}
+
+impl<'info> Sunset<'info> {
+    /// Winds the program down for good. Unlike pausing there's no way back: no new tickets,
+    /// rounds or draws. A draw already underway still resolves and pays out; an open round's
+    /// tickets are refunded through `refund_sunset_entry` instead. Claims and refunds stay
+    /// live, and `close_sunset_accounts` closes accounts once they hold only rent.
+    pub fn sunset_handler(&mut self) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let lottery_state = &mut self.lottery_state;
+        lottery_state.check_not_sunset()?;
+
+        let sunset_at = Clock::get()?.unix_timestamp;
+        lottery_state.sunset_at = sunset_at;
+
+        // The open round won't be drawn, so class premiums go back with the rest of its pot
+        let refundable_participants = if lottery_state.sunset_refunds_open() {
+            lottery_state.class_pots = [0; MAX_TICKET_CLASSES];
+            lottery_state.total_participants
+        } else {
+            0
+        };
+
+        emit!(ProgramSunset {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            lottery_id: lottery_state.current_lottery_id,
+            sunset_at,
+            refundable_participants,
+        });
+
+        msg!("Program sunset at lottery #{}; {} tickets to refund", lottery_state.current_lottery_id, refundable_participants);
+
+        Ok(())
+    }
+}
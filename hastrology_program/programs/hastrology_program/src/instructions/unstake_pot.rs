@@ -0,0 +1,224 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+        stake,
+        sysvar
+    },
+    system_program::{Transfer, transfer}
+};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED, PROGRAM_VERSION, STAKE_POOL_WITHDRAW_SOL_IX, STAKE_STAGING_SEED},
+    errors::HashtrologyErrors,
+    events::StakeShortfall,
+    require_role,
+    state::{LotteryState, Role}
+};
+
+#[derive(Accounts)]
+pub struct UnstakePot<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    /// CHECK: This is the platform wallet
+    #[account(
+        mut,
+        address = lottery_state.platform_wallet
+    )]
+    pub platform_wallet: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_STAGING_SEED],
+        bump
+    )]
+    pub stake_staging: SystemAccount<'info>,
+
+    /// CHECK: Must be the configured stake pool; validated by the stake pool program.
+    #[account(
+        mut,
+        address = lottery_state.stake_pool @ HashtrologyErrors::InvalidStakePool
+    )]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    pub stake_pool_withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the stake pool program.
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub pool_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_mint,
+        associated_token::authority = stake_staging,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Clock sysvar
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the configured stake pool program.
+    #[account(
+        address = lottery_state.stake_pool_program @ HashtrologyErrors::InvalidStakePool
+    )]
+    pub stake_pool_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> UnstakePot<'info> {
+    pub fn unstake_pot_handler(&mut self, bumps: &UnstakePotBumps) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Operator);
+
+        let pool_tokens = self.pool_token_account.amount;
+
+        require!(
+            pool_tokens > 0,
+            HashtrologyErrors::NothingStaked
+        );
+
+        let mut data = Vec::with_capacity(9);
+        data.push(STAKE_POOL_WITHDRAW_SOL_IX);
+        data.extend_from_slice(&pool_tokens.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.stake_pool_program.key(),
+            accounts: vec![
+                AccountMeta::new(self.stake_pool.key(), false),
+                AccountMeta::new_readonly(self.stake_pool_withdraw_authority.key(), false),
+                AccountMeta::new_readonly(self.stake_staging.key(), true),
+                AccountMeta::new(self.pool_token_account.key(), false),
+                AccountMeta::new(self.reserve_stake.key(), false),
+                AccountMeta::new(self.stake_staging.key(), false),
+                AccountMeta::new(self.manager_fee_account.key(), false),
+                AccountMeta::new(self.pool_mint.key(), false),
+                AccountMeta::new_readonly(self.clock.key(), false),
+                AccountMeta::new_readonly(self.stake_history.key(), false),
+                AccountMeta::new_readonly(self.stake_program.key(), false),
+                AccountMeta::new_readonly(self.token_program.key(), false),
+            ],
+            data
+        };
+
+        let seeds = &[STAKE_STAGING_SEED, &[bumps.stake_staging]];
+        let signer_seeds = &[&seeds[..]];
+
+        let staging_before = self.stake_staging.lamports();
+
+        invoke_signed(
+            &ix,
+            &[
+                self.stake_pool.to_account_info(),
+                self.stake_pool_withdraw_authority.to_account_info(),
+                self.stake_staging.to_account_info(),
+                self.pool_token_account.to_account_info(),
+                self.reserve_stake.to_account_info(),
+                self.manager_fee_account.to_account_info(),
+                self.pool_mint.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_program.to_account_info(),
+                self.token_program.to_account_info(),
+            ],
+            signer_seeds
+        )?;
+
+        let received = self.stake_staging
+            .lamports()
+            .checked_sub(staging_before)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        let lottery_state = &mut self.lottery_state;
+        let principal = lottery_state.staked_lamports;
+        let yield_amount = received.saturating_sub(principal);
+        let shortfall = principal.saturating_sub(received);
+
+        let (to_pot, to_platform) = if lottery_state.stake_yield_to_prize {
+            (received, 0)
+        } else {
+            (received - yield_amount, yield_amount)
+        };
+
+        if to_pot > 0 {
+            let accounts = Transfer {
+                from: self.stake_staging.to_account_info(),
+                to: self.pot_vault.to_account_info()
+            };
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+            transfer(cpi_ctx, to_pot)?;
+            lottery_state.record_vault_inflow(to_pot)?;
+        }
+
+        if to_platform > 0 {
+            let accounts = Transfer {
+                from: self.stake_staging.to_account_info(),
+                to: self.platform_wallet.to_account_info()
+            };
+            let cpi_ctx = CpiContext::new_with_signer(self.system_program.to_account_info(), accounts, signer_seeds);
+            transfer(cpi_ctx, to_platform)?;
+        }
+
+        lottery_state.staked_lamports = 0;
+
+        // Pool fees or slashing returned less than was staked; the pot is short by that much
+        if shortfall > 0 {
+            emit!(StakeShortfall {
+                event_seq: lottery_state.next_event_seq(),
+                program_version: PROGRAM_VERSION.to_string(),
+                lottery_id: lottery_state.current_lottery_id,
+                staked_lamports: principal,
+                received_lamports: received,
+                shortfall,
+            });
+
+            msg!("Stake pool returned {} lamports less than was staked", shortfall);
+        }
+
+        msg!(
+            "Unstaked {} lamports (principal: {}, yield: {}). To pot: {}, To platform: {}",
+            received,
+            principal,
+            yield_amount,
+            to_pot,
+            to_platform
+        );
+
+        Ok(())
+    }
+}
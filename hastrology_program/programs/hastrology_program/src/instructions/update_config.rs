@@ -1,16 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::LOTTERY_STATE_SEED, 
+    constants::{AUDIT_LOG_SEED, CONFIG_TIMELOCK, LOTTERY_STATE_SEED, PLATFORM_CONFIG_SEED, PROGRAM_VERSION}, 
     errors::HashtrologyErrors, 
-    state::LotteryState
+    events::ConfigProposed,
+    require_role,
+    state::{AuditAction, AuditLog, ConfigBounds, LotteryState, PendingConfig, Role}
 };
 
+/// Proposes a config change. It is only stored here; `apply_config` applies it once
+/// `CONFIG_TIMELOCK` has passed. A new proposal replaces the pending one and restarts the timer.
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
-    #[account(
-        constraint = authority.key() == lottery_state.authority @ HashtrologyErrors::Unauthorized
-    )]
     pub authority: Signer<'info>,
 
     #[account(
@@ -19,6 +20,21 @@ pub struct UpdateConfig<'info> {
         bump = lottery_state.lottery_state_bump
     )]
     pub lottery_state: Account<'info, LotteryState>,
+
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+
+    /// CHECK: Always required so tuned bounds can't be skipped by leaving it out. Until
+    /// `initialize_platform_config` creates it, the compiled defaults apply.
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: UncheckedAccount<'info>,
 }
 
 impl<'info> UpdateConfig<'info> {
@@ -28,47 +44,87 @@ impl<'info> UpdateConfig<'info> {
         new_platform_fee_bps: Option<u16>,
         new_platform_wallet: Option<Pubkey>,
         new_lottery_endtime: Option<i64>,
+        new_max_pot_lamports: Option<u64>,
+        new_referral_bonus_bps: Option<u16>,
     ) -> Result<()> {
+        require_role!(self.lottery_state, self.authority, Role::Admin);
+
+        let bounds = ConfigBounds::load(&self.platform_config)?;
         let lottery_state = &mut self.lottery_state;
 
-        // Update ticket price if provided
+        let now = Clock::get()?.unix_timestamp;
+        let activates_at = now
+            .checked_add(CONFIG_TIMELOCK)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
         if let Some(price) = new_ticket_price {
-            require!(
-                price > 0, 
-                HashtrologyErrors::InvalidTicketPrice
-            );
-            msg!("Updating ticket price from {} to {}", lottery_state.ticket_price, price);
-            lottery_state.ticket_price = price;
+            bounds.check_ticket_price(price)?;
         }
 
-        // Update platform fee if provided
         if let Some(fee_bps) = new_platform_fee_bps {
+            bounds.check_fee(fee_bps)?;
+
             require!(
-                fee_bps <= 10_000, 
-                HashtrologyErrors::InvalidPlatformFee
+                fee_bps <= lottery_state.max_platform_fee_bps(now),
+                HashtrologyErrors::FeeAboveCeiling
             );
-            msg!("Updating platform fee from {} to {} bps", lottery_state.platform_fee_bps, fee_bps);
-            lottery_state.platform_fee_bps = fee_bps;
-        }
-
-        // Update platform wallet if provided
-        if let Some(wallet) = new_platform_wallet {
-            msg!("Updating platform wallet to {}", wallet);
-            lottery_state.platform_wallet = wallet;
         }
 
-        // Update lottery endtime if provided
+        // The new endtime must still be in the future when the change can first apply
         if let Some(endtime) = new_lottery_endtime {
-            let clock = Clock::get()?;
             require!(
-                endtime > clock.unix_timestamp,
+                endtime > activates_at,
                 HashtrologyErrors::InvalidEndtime
             );
-            msg!("Updating lottery endtime from {} to {}", lottery_state.lottery_endtime, endtime);
-            lottery_state.lottery_endtime = endtime;
+
+            bounds.check_endtime(now, endtime)?;
         }
 
-        msg!("Config updated successfully");
+        if let Some(bonus_bps) = new_referral_bonus_bps {
+            require!(
+                bonus_bps <= 10_000, 
+                HashtrologyErrors::InvalidPlatformFee
+            );
+        }
+
+        lottery_state.pending_config = Some(PendingConfig {
+            ticket_price: new_ticket_price,
+            platform_fee_bps: new_platform_fee_bps,
+            platform_wallet: new_platform_wallet,
+            lottery_endtime: new_lottery_endtime,
+            max_pot_lamports: new_max_pot_lamports,
+            referral_bonus_bps: new_referral_bonus_bps,
+            activates_at
+        });
+
+        // Unset fields hash as all ones so they can't collide with a real value
+        self.audit_log.load_mut()?.record(
+            AuditAction::ProposeConfig,
+            self.authority.key(),
+            &[
+                &new_ticket_price.map_or([0xff; 8], u64::to_le_bytes),
+                &new_platform_fee_bps.map_or([0xff; 2], u16::to_le_bytes),
+                &new_platform_wallet.map_or([0xff; 32], |wallet| wallet.to_bytes()),
+                &new_lottery_endtime.map_or([0xff; 8], i64::to_le_bytes),
+                &new_max_pot_lamports.map_or([0xff; 8], u64::to_le_bytes),
+                &new_referral_bonus_bps.map_or([0xff; 2], u16::to_le_bytes),
+                &activates_at.to_le_bytes(),
+            ]
+        )?;
+
+        emit!(ConfigProposed {
+            event_seq: lottery_state.next_event_seq(),
+            program_version: PROGRAM_VERSION.to_string(),
+            ticket_price: new_ticket_price,
+            platform_fee_bps: new_platform_fee_bps,
+            platform_wallet: new_platform_wallet,
+            lottery_endtime: new_lottery_endtime,
+            max_pot_lamports: new_max_pot_lamports,
+            referral_bonus_bps: new_referral_bonus_bps,
+            activates_at,
+        });
+
+        msg!("Config change proposed, applies after {}", activates_at);
         
         Ok(())
     }
@@ -0,0 +1,92 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions as instructions_sysvar
+};
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, PLAYER_PROFILE_SEED, POT_VAULT_SEED, SNIPE_WINDOW_SEED},
+    errors::HashtrologyErrors,
+    state::{LotteryState, PlayerProfile, SnipeWindow}
+};
+
+/// Takes the same accounts as `enter_lottery`, minus the ones it would create, and
+/// fails with the error the real entry would hit. Meant to be simulated, never sent.
+#[derive(Accounts)]
+pub struct ValidateEntry<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding the SOL prize pot.
+    #[account(
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    pub eligibility_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Checked by `EntryEligibility::check`, as in `enter_lottery`.
+    pub eligibility_attestation: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [SNIPE_WINDOW_SEED, &lottery_state.current_lottery_id.to_le_bytes()],
+        bump = snipe_window.bump
+    )]
+    pub snipe_window: Option<Account<'info, SnipeWindow>>,
+
+    /// CHECK: Only read once the wallet has created its profile.
+    #[account(
+        seeds = [PLAYER_PROFILE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    /// CHECK: The instructions sysvar, as in `enter_lottery`.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> ValidateEntry<'info> {
+    /// Runs every check `enter_lottery` makes, in the same order, without writing anything.
+    pub fn validate_entry_handler(&self) -> Result<()> {
+        let lottery_state = &self.lottery_state;
+
+        lottery_state.check_ticket_entry()?;
+        lottery_state.check_entry_caller(&self.instructions_sysvar)?;
+
+        lottery_state.entry_eligibility.check(
+            &self.user.to_account_info(),
+            self.eligibility_token_account.as_ref(),
+            self.eligibility_attestation.as_deref()
+        )?;
+
+        lottery_state.check_pot_cap(self.pot_vault.lamports(), lottery_state.ticket_price)?;
+        lottery_state.next_ticket_number()?;
+
+        let clock = Clock::get()?;
+        lottery_state.check_slot_entry(clock.slot)?;
+
+        // Deserialized copy; the spend is recorded on it only to run the cap check
+        if let Some(mut player_profile) = PlayerProfile::load(&self.player_profile)? {
+            player_profile.check_not_excluded(clock.unix_timestamp)?;
+            player_profile.record_spend(lottery_state.ticket_price, clock.unix_timestamp)?;
+        }
+
+        lottery_state.check_snipe_entry(clock.unix_timestamp, self.snipe_window.as_deref())?;
+
+        require!(
+            self.user.lamports() >= lottery_state.ticket_price,
+            HashtrologyErrors::InsufficientLamports
+        );
+
+        msg!("Entry into lottery #{} is valid", lottery_state.current_lottery_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{LOTTERY_STATE_SEED, POT_VAULT_SEED},
+    errors::HashtrologyErrors,
+    lamports::{credit_lamports, debit_lamports},
+    state::{LotteryState, NoLossDeposit}
+};
+
+#[derive(Accounts)]
+pub struct WithdrawNoLoss<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOTTERY_STATE_SEED],
+        bump = lottery_state.lottery_state_bump
+    )]
+    pub lottery_state: Account<'info, LotteryState>,
+
+    /// CHECK: This is the PDA vault holding deposits and accrued yield.
+    #[account(
+        mut,
+        seeds = [POT_VAULT_SEED],
+        bump = lottery_state.pot_vault_bump
+    )]
+    pub pot_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = deposit.user == user.key() @ HashtrologyErrors::InvalidDeposit
+    )]
+    pub deposit: Account<'info, NoLossDeposit>,
+}
+
+impl<'info> WithdrawNoLoss<'info> {
+    pub fn withdraw_no_loss_handler(&mut self) -> Result<()> {
+        let lottery_state = &mut self.lottery_state;
+        let deposit = &mut self.deposit;
+
+        let round_over = deposit.lottery_id < lottery_state.current_lottery_id;
+
+        // Deposits withdrawn mid-round keep their account (and weight range) until
+        // the round is paid out, so the draw can still land on them and roll over.
+        require!(
+            !deposit.withdrawn || round_over,
+            HashtrologyErrors::DepositAlreadyWithdrawn
+        );
+
+        if !deposit.withdrawn {
+            require!(
+                round_over || !lottery_state.is_drawing,
+                HashtrologyErrors::LotteryIsDrawing
+            );
+
+            debit_lamports(&self.pot_vault, deposit.amount)?;
+            credit_lamports(&self.user, deposit.amount)?;
+            lottery_state.record_vault_outflow(deposit.amount)?;
+
+            lottery_state.total_deposits = lottery_state.total_deposits.checked_sub(deposit.amount).ok_or(HashtrologyErrors::Overflow)?;
+            deposit.withdrawn = true;
+
+            msg!(
+                "Withdrew {} lamports deposited in lottery #{}",
+                deposit.amount,
+                deposit.lottery_id
+            );
+        }
+
+        if round_over {
+            deposit.close(self.user.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer}
+};
+
+use crate::{errors::HashtrologyErrors, ID};
+
+/// Removes `amount` lamports from a program-owned account, failing instead of panicking on underflow.
+pub fn debit_lamports(account: &AccountInfo, amount: u64) -> Result<()> {
+    let mut lamports = account.try_borrow_mut_lamports()?;
+
+    **lamports = (**lamports)
+        .checked_sub(amount)
+        .ok_or(HashtrologyErrors::InsufficientLamports)?;
+
+    Ok(())
+}
+
+/// Adds `amount` lamports to any writable account, failing instead of wrapping on overflow.
+pub fn credit_lamports(account: &AccountInfo, amount: u64) -> Result<()> {
+    let mut lamports = account.try_borrow_mut_lamports()?;
+
+    **lamports = (**lamports)
+        .checked_add(amount)
+        .ok_or(HashtrologyErrors::Overflow)?;
+
+    Ok(())
+}
+
+/// Whether lamports credited to `account` stay spendable by its holder: a plain wallet,
+/// owned by the system program and holding no data. PDAs of other programs and data
+/// accounts may have no way to move lamports back out.
+pub fn can_receive_lamports(account: &AccountInfo) -> bool {
+    account.owner == &system_program::ID && account.data_is_empty()
+}
+
+/// Closes a program-owned account of any type into `destination`, the way Anchor's `close`
+/// does for typed accounts: moves its lamports, hands it back to the system program and
+/// drops its data.
+pub fn close_program_account(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    let lamports = account.lamports();
+
+    debit_lamports(account, lamports)?;
+    credit_lamports(destination, lamports)?;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Creates a program-owned PDA of `space` bytes with `payer` funding its rent, the way
+/// Anchor's `init` does, including when someone pre-funded the address.
+pub fn create_program_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        let accounts = CreateAccount { from: payer.clone(), to: account.clone() };
+        create_account(
+            CpiContext::new_with_signer(system_program.to_account_info(), accounts, signer_seeds),
+            rent,
+            space as u64,
+            &ID
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(account.lamports());
+
+        if top_up > 0 {
+            let accounts = Transfer { from: payer.clone(), to: account.clone() };
+            transfer(CpiContext::new(system_program.to_account_info(), accounts), top_up)?;
+        }
+
+        let accounts = Allocate { account_to_allocate: account.clone() };
+        allocate(CpiContext::new_with_signer(system_program.to_account_info(), accounts, signer_seeds), space as u64)?;
+
+        let accounts = Assign { account_to_assign: account.clone() };
+        assign(CpiContext::new_with_signer(system_program.to_account_info(), accounts, signer_seeds), &ID)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_account<F: FnOnce(&AccountInfo)>(balance: u64, f: F) {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = balance;
+        let mut data = [];
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&account);
+    }
+
+    fn with_owned_account<F: FnOnce(&AccountInfo)>(owner: Pubkey, data_len: usize, f: F) {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0u8; data_len];
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&account);
+    }
+
+    #[test]
+    fn debit_reduces_balance() {
+        with_account(1_000, |account| {
+            debit_lamports(account, 400).unwrap();
+            assert_eq!(account.lamports(), 600);
+        });
+    }
+
+    #[test]
+    fn debit_entire_balance() {
+        with_account(1_000, |account| {
+            debit_lamports(account, 1_000).unwrap();
+            assert_eq!(account.lamports(), 0);
+        });
+    }
+
+    #[test]
+    fn debit_insufficient_balance_errors_and_leaves_balance() {
+        with_account(999, |account| {
+            let err = debit_lamports(account, 1_000).unwrap_err();
+            assert_eq!(err, HashtrologyErrors::InsufficientLamports.into());
+            assert_eq!(account.lamports(), 999);
+        });
+    }
+
+    #[test]
+    fn debit_from_empty_account_errors() {
+        with_account(0, |account| {
+            assert!(debit_lamports(account, 1).is_err());
+            assert_eq!(account.lamports(), 0);
+        });
+    }
+
+    #[test]
+    fn credit_increases_balance() {
+        with_account(1_000, |account| {
+            credit_lamports(account, 250).unwrap();
+            assert_eq!(account.lamports(), 1_250);
+        });
+    }
+
+    #[test]
+    fn credit_overflow_errors_and_leaves_balance() {
+        with_account(u64::MAX, |account| {
+            let err = credit_lamports(account, 1).unwrap_err();
+            assert_eq!(err, HashtrologyErrors::Overflow.into());
+            assert_eq!(account.lamports(), u64::MAX);
+        });
+    }
+
+    #[test]
+    fn wallet_can_receive_lamports() {
+        with_owned_account(system_program::ID, 0, |account| {
+            assert!(can_receive_lamports(account));
+        });
+    }
+
+    #[test]
+    fn program_owned_account_cannot_receive_lamports() {
+        with_owned_account(Pubkey::new_unique(), 0, |account| {
+            assert!(!can_receive_lamports(account));
+        });
+    }
+
+    #[test]
+    fn system_owned_account_with_data_cannot_receive_lamports() {
+        with_owned_account(system_program::ID, 8, |account| {
+            assert!(!can_receive_lamports(account));
+        });
+    }
+}
@@ -1,14 +1,27 @@
 #![allow(unexpected_cfgs, deprecated)]
 use anchor_lang::prelude::*;
 
+#[cfg(all(feature = "devnet", feature = "mainnet"))]
+compile_error!("Enable only one of the `devnet` and `mainnet` features.");
+
+#[cfg(not(any(feature = "devnet", feature = "mainnet")))]
+compile_error!("Enable one of the `devnet` and `mainnet` features; build mainnet with `--no-default-features --features mainnet`.");
+
+// Anchor.toml deploys the same program keypair to every cluster, so the ID isn't per-cluster
 declare_id!("A3voJRWMzoy1118ZmTjsoYAGXrM9zPySUPwcgUQ3PV76");
 
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
 pub  mod constants;
+pub mod entry;
+pub mod lamports;
+pub mod math;
+pub mod randomness;
 
 pub use instructions::*;
+use state::{ConfigBounds, EntryEligibility, Role, RoundKind, TicketClassConfig};
 
 #[program]
 pub mod hastrology_program {
@@ -36,13 +49,19 @@ pub mod hastrology_program {
         ctx.accounts.reset_handle()
     }
 
-    pub fn enter_lottery(ctx: Context<EnterLottery>) -> Result<()> {
+    pub fn enter_lottery<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EnterLottery<'info>>,
+        entry_nonce: Option<u64>,
+        ticket_class: Option<u8>,
+        max_price: Option<u64>,
+        device_commitment: Option<[u8; 32]>
+    ) -> Result<()> {
 
-        ctx.accounts.enter_lottery_handler()
+        ctx.accounts.enter_lottery_handler(entry_nonce, ticket_class, max_price, device_commitment, &ctx.bumps, ctx.remaining_accounts)
     }
 
     pub fn request_draw(ctx: Context<RequestDraw>) -> Result<()> {
-        ctx.accounts.request_draw_handler()
+        ctx.accounts.request_draw_handler(&ctx.bumps)
     }
 
     pub fn resolve_draw(ctx: Context<ResolveDraw>, randomness: [u8; 32]) -> Result<()> {
@@ -50,9 +69,13 @@ pub mod hastrology_program {
         ctx.accounts.resolve_draw_handler(randomness)
     }
 
-    pub fn payout(ctx: Context<Payout>) -> Result<()> {
+    pub fn resolve_second_draw(ctx: Context<ResolveDraw>, randomness: [u8; 32]) -> Result<()> {
+        ctx.accounts.resolve_second_draw_handler(randomness)
+    }
+
+    pub fn payout<'info>(ctx: Context<'_, '_, 'info, 'info, Payout<'info>>) -> Result<()> {
 
-        ctx.accounts.payout_handler()
+        ctx.accounts.payout_handler(&ctx.bumps, ctx.remaining_accounts)
     }
 
     pub fn update_config(
@@ -61,12 +84,649 @@ pub mod hastrology_program {
         new_platform_fee_bps: Option<u16>,
         new_platform_wallet: Option<Pubkey>,
         new_lottery_endtime: Option<i64>,
+        new_max_pot_lamports: Option<u64>,
+        new_referral_bonus_bps: Option<u16>,
     ) -> Result<()> {
         ctx.accounts.update_config_handler(
             new_ticket_price,
             new_platform_fee_bps,
             new_platform_wallet,
             new_lottery_endtime,
+            new_max_pot_lamports,
+            new_referral_bonus_bps,
+        )
+    }
+
+    pub fn configure_prize_mint(ctx: Context<ConfigurePrizeMint>, token_prize_amount: u64, symbol: String) -> Result<()> {
+        ctx.accounts.configure_prize_mint_handler(token_prize_amount, symbol)
+    }
+
+    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+        ctx.accounts.claim_prize_handler()
+    }
+
+    pub fn set_draw_operators(
+        ctx: Context<SetDrawOperators>,
+        operators: Vec<Pubkey>,
+        threshold: u8,
+        attestation_min_pot: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_draw_operators_handler(operators, threshold, attestation_min_pot)
+    }
+
+    pub fn approve_draw(ctx: Context<ApproveDraw>) -> Result<()> {
+        ctx.accounts.approve_draw_handler(&ctx.bumps)
+    }
+
+    pub fn enter_next_round(ctx: Context<EnterNextRound>) -> Result<()> {
+        ctx.accounts.enter_next_round_handler(&ctx.bumps)
+    }
+
+    pub fn process_queued_entry(ctx: Context<ProcessQueuedEntry>) -> Result<()> {
+        ctx.accounts.process_queued_entry_handler(&ctx.bumps)
+    }
+
+    pub fn cancel_queued_entry(ctx: Context<CancelQueuedEntry>) -> Result<()> {
+        ctx.accounts.cancel_queued_entry_handler()
+    }
+
+    pub fn configure_staking(
+        ctx: Context<ConfigureStaking>,
+        stake_pool_program: Pubkey,
+        stake_pool: Pubkey,
+        yield_to_prize: bool,
+    ) -> Result<()> {
+        ctx.accounts.configure_staking_handler(stake_pool_program, stake_pool, yield_to_prize)
+    }
+
+    pub fn stake_pot(ctx: Context<StakePot>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_pot_handler(amount, &ctx.bumps)
+    }
+
+    pub fn unstake_pot(ctx: Context<UnstakePot>) -> Result<()> {
+        ctx.accounts.unstake_pot_handler(&ctx.bumps)
+    }
+
+    pub fn set_no_loss_mode(ctx: Context<SetNoLossMode>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_no_loss_mode_handler(enabled)
+    }
+
+    pub fn deposit_no_loss(ctx: Context<DepositNoLoss>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_no_loss_handler(amount)
+    }
+
+    pub fn withdraw_no_loss(ctx: Context<WithdrawNoLoss>) -> Result<()> {
+        ctx.accounts.withdraw_no_loss_handler()
+    }
+
+    pub fn payout_no_loss(ctx: Context<PayoutNoLoss>) -> Result<()> {
+        ctx.accounts.payout_no_loss_handler()
+    }
+
+    pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+        ctx.accounts.initialize_audit_log_handler()
+    }
+
+    pub fn migrate_ticket(ctx: Context<MigrateTicket>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.migrate_ticket_handler(lottery_id, ticket_index)
+    }
+
+    pub fn record_referral(ctx: Context<RecordReferral>, referrer: Pubkey) -> Result<()> {
+        ctx.accounts.record_referral_handler(referrer, &ctx.bumps)
+    }
+
+    pub fn distribute_referral_bonus(ctx: Context<DistributeReferralBonus>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.distribute_referral_bonus_handler(lottery_id)
+    }
+
+    pub fn credit_deposit(ctx: Context<CreditDeposit>) -> Result<()> {
+        ctx.accounts.credit_deposit_handler(&ctx.bumps)
+    }
+
+    pub fn refund_deposit(ctx: Context<RefundDeposit>) -> Result<()> {
+        ctx.accounts.refund_deposit_handler(&ctx.bumps)
+    }
+
+    pub fn set_entry_eligibility(ctx: Context<SetEntryEligibility>, eligibility: EntryEligibility) -> Result<()> {
+        ctx.accounts.set_entry_eligibility_handler(eligibility)
+    }
+
+    pub fn initialize_metrics(ctx: Context<InitializeMetrics>) -> Result<()> {
+        ctx.accounts.initialize_metrics_handler(&ctx.bumps)
+    }
+
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        expires_at: i64,
+        spend_cap: u64
+    ) -> Result<()> {
+        ctx.accounts.create_session_handler(session_key, expires_at, spend_cap, &ctx.bumps)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        ctx.accounts.revoke_session_handler()
+    }
+
+    pub fn enter_with_session(ctx: Context<EnterWithSession>) -> Result<()> {
+        ctx.accounts.enter_with_session_handler(&ctx.bumps)
+    }
+
+    pub fn retry_draw(ctx: Context<RequestDraw>) -> Result<()> {
+        ctx.accounts.retry_draw_handler(&ctx.bumps)
+    }
+
+    pub fn set_payout_destination(ctx: Context<SetPayoutDestination>, destination: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_payout_destination_handler(destination)
+    }
+
+    pub fn set_merkle_entry_mode(ctx: Context<SetMerkleEntryMode>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_merkle_entry_mode_handler(enabled)
+    }
+
+    pub fn init_merkle_tree(ctx: Context<InitMerkleTree>) -> Result<()> {
+        ctx.accounts.init_merkle_tree_handler()
+    }
+
+    pub fn enter_merkle(ctx: Context<EnterMerkle>) -> Result<()> {
+        ctx.accounts.enter_merkle_handler(&ctx.bumps)
+    }
+
+    pub fn claim_merkle_prize(ctx: Context<ClaimMerklePrize>, proof: Vec<[u8; 32]>) -> Result<()> {
+        ctx.accounts.claim_merkle_prize_handler(proof, &ctx.bumps)
+    }
+
+    pub fn set_anti_snipe(ctx: Context<SetAntiSnipe>, max_extension: i64) -> Result<()> {
+        ctx.accounts.set_anti_snipe_handler(max_extension)
+    }
+
+    pub fn open_snipe_window(ctx: Context<OpenSnipeWindow>) -> Result<()> {
+        ctx.accounts.open_snipe_window_handler(&ctx.bumps)
+    }
+
+    pub fn refund_late_entry(ctx: Context<RefundLateEntry>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.refund_late_entry_handler(lottery_id, ticket_index)
+    }
+
+    pub fn set_rate_limits(ctx: Context<SetRateLimits>, max_entries_per_wallet: u32, max_entries_per_slot: u32) -> Result<()> {
+        ctx.accounts.set_rate_limits_handler(max_entries_per_wallet, max_entries_per_slot)
+    }
+
+    pub fn set_round_kind(ctx: Context<SetRoundKind>, round_kind: RoundKind) -> Result<()> {
+        ctx.accounts.set_round_kind_handler(round_kind)
+    }
+
+    pub fn set_keeper_reward(ctx: Context<SetKeeperReward>, reward_lamports: u64) -> Result<()> {
+        ctx.accounts.set_keeper_reward_handler(reward_lamports)
+    }
+
+    pub fn set_reveal_delay(ctx: Context<SetRevealDelay>, reveal_delay: i64) -> Result<()> {
+        ctx.accounts.set_reveal_delay_handler(reveal_delay)
+    }
+
+    pub fn reveal_winner(ctx: Context<RevealWinner>, winner: u64, winning_weight: u128) -> Result<()> {
+        ctx.accounts.reveal_winner_handler(winner, winning_weight)
+    }
+
+    pub fn pick_lucky_sign(ctx: Context<PickLuckySign>, lottery_id: u64, ticket_index: u32, sign: u8) -> Result<()> {
+        ctx.accounts.pick_lucky_sign_handler(lottery_id, ticket_index, sign, &ctx.bumps)
+    }
+
+    pub fn settle_lucky_sign(ctx: Context<SettleLuckySign>) -> Result<()> {
+        ctx.accounts.settle_lucky_sign_handler(&ctx.bumps)
+    }
+
+    pub fn claim_co_winner_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCoWinnerPrizes<'info>>,
+        lottery_id: u64
+    ) -> Result<()> {
+        ctx.accounts.claim_co_winner_prizes_handler(lottery_id, ctx.remaining_accounts)
+    }
+
+    pub fn pack_round_tickets(ctx: Context<PackRoundTickets>, lottery_id: u64, page: u32) -> Result<()> {
+        ctx.accounts.pack_round_tickets_handler(lottery_id, page, &ctx.bumps)
+    }
+
+    pub fn open_round(ctx: Context<OpenRound>) -> Result<()> {
+        ctx.accounts.open_round_handler()
+    }
+
+    pub fn set_round_duration(ctx: Context<SetRoundDuration>, round_duration: i64) -> Result<()> {
+        ctx.accounts.set_round_duration_handler(round_duration)
+    }
+
+    pub fn set_draw_window_queueing(ctx: Context<SetDrawWindowQueueing>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_draw_window_queueing_handler(enabled)
+    }
+
+    pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+        ctx.accounts.initialize_registry_handler(&ctx.bumps)
+    }
+
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        ctx.accounts.create_pool_handler(&ctx.bumps)
+    }
+
+    pub fn join_pool(ctx: Context<JoinPool>, amount: u64) -> Result<()> {
+        ctx.accounts.join_pool_handler(amount, &ctx.bumps)
+    }
+
+    pub fn leave_pool(ctx: Context<LeavePool>) -> Result<()> {
+        ctx.accounts.leave_pool_handler()
+    }
+
+    pub fn enter_pool<'info>(ctx: Context<'_, '_, 'info, 'info, EnterPool<'info>>) -> Result<()> {
+        ctx.accounts.enter_pool_handler(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    pub fn claim_pool_share(ctx: Context<ClaimPoolShare>) -> Result<()> {
+        ctx.accounts.claim_pool_share_handler()
+    }
+
+    pub fn apply_config(ctx: Context<ApplyConfig>) -> Result<()> {
+        ctx.accounts.apply_config_handler()
+    }
+
+    pub fn set_season(ctx: Context<SetSeason>, season_length: i64) -> Result<()> {
+        ctx.accounts.set_season_handler(season_length, &ctx.bumps)
+    }
+
+    pub fn preview_payout(ctx: Context<PreviewPayout>) -> Result<PayoutPreview> {
+        ctx.accounts.preview_payout_handler()
+    }
+
+    pub fn set_spending_cap(ctx: Context<SetSpendingCap>, cap: u64) -> Result<()> {
+        ctx.accounts.set_spending_cap_handler(cap, &ctx.bumps)
+    }
+
+    pub fn self_exclude(ctx: Context<SelfExclude>, duration: i64) -> Result<()> {
+        ctx.accounts.self_exclude_handler(duration, &ctx.bumps)
+    }
+
+    pub fn pick_lotto_numbers(ctx: Context<PickLottoNumbers>, lottery_id: u64, ticket_index: u32, numbers: Vec<u8>) -> Result<()> {
+        ctx.accounts.pick_lotto_numbers_handler(lottery_id, ticket_index, numbers, &ctx.bumps)
+    }
+
+    pub fn settle_lotto(ctx: Context<SettleLotto>) -> Result<()> {
+        ctx.accounts.settle_lotto_handler(&ctx.bumps)
+    }
+
+    pub fn register_lotto_ticket(ctx: Context<RegisterLottoTicket>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.register_lotto_ticket_handler(lottery_id, ticket_index)
+    }
+
+    pub fn finalize_lotto_tiers(ctx: Context<FinalizeLottoTiers>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.finalize_lotto_tiers_handler(lottery_id)
+    }
+
+    pub fn claim_lotto_prize(ctx: Context<ClaimLottoPrize>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.claim_lotto_prize_handler(lottery_id, ticket_index, &ctx.bumps)
+    }
+
+    pub fn initialize_fee_treasury(ctx: Context<InitializeFeeTreasury>) -> Result<()> {
+        ctx.accounts.initialize_fee_treasury_handler(&ctx.bumps)
+    }
+
+    pub fn forward_fees(ctx: Context<ForwardFees>) -> Result<()> {
+        ctx.accounts.forward_fees_handler()
+    }
+
+    pub fn set_cpi_allowlist(ctx: Context<SetCpiAllowlist>, programs: Vec<Pubkey>) -> Result<()> {
+        ctx.accounts.set_cpi_allowlist_handler(programs)
+    }
+
+    pub fn validate_entry(ctx: Context<ValidateEntry>) -> Result<()> {
+        ctx.accounts.validate_entry_handler()
+    }
+
+    pub fn enter_future_round(ctx: Context<EnterFutureRound>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.enter_future_round_handler(lottery_id, &ctx.bumps)
+    }
+
+    pub fn init_claim_bitmap(ctx: Context<InitClaimBitmap>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.init_claim_bitmap_handler(lottery_id, &ctx.bumps)
+    }
+
+    pub fn get_version(ctx: Context<GetVersion>) -> Result<String> {
+        ctx.accounts.get_version_handler()
+    }
+
+    pub fn initialize_platform_config(ctx: Context<InitializePlatformConfig>, protocol_fee_bps: u16) -> Result<()> {
+        ctx.accounts.initialize_platform_config_handler(protocol_fee_bps, &ctx.bumps)
+    }
+
+    pub fn set_host_approval(ctx: Context<SetHostApproval>, host: Pubkey, approved: bool) -> Result<()> {
+        ctx.accounts.set_host_approval_handler(host, approved)
+    }
+
+    pub fn create_hosted_lottery(
+        ctx: Context<CreateHostedLottery>,
+        index: u64,
+        fee_wallet: Pubkey,
+        host_fee_bps: u16,
+        ticket_price: u64,
+        endtime: i64
+    ) -> Result<()> {
+        ctx.accounts.create_hosted_lottery_handler(index, fee_wallet, host_fee_bps, ticket_price, endtime, &ctx.bumps)
+    }
+
+    pub fn set_min_prize(ctx: Context<SetMinPrize>, min_prize_lamports: u64) -> Result<()> {
+        ctx.accounts.set_min_prize_handler(min_prize_lamports)
+    }
+
+    pub fn set_entropy_mixing(ctx: Context<SetEntropyMixing>, min_pot: u64) -> Result<()> {
+        ctx.accounts.set_entropy_mixing_handler(min_pot)
+    }
+
+    pub fn commit_draw_nonce(ctx: Context<CommitDrawNonce>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_draw_nonce_handler(commitment)
+    }
+
+    pub fn reveal_draw_nonce(ctx: Context<RevealDrawNonce>, nonce: [u8; 32]) -> Result<()> {
+        ctx.accounts.reveal_draw_nonce_handler(nonce)
+    }
+
+    pub fn settle_unrevealed_draw(ctx: Context<RevealDrawNonce>) -> Result<()> {
+        ctx.accounts.settle_unrevealed_draw_handler()
+    }
+
+    pub fn set_swap_program(ctx: Context<SetSwapProgram>, swap_program: Pubkey) -> Result<()> {
+        ctx.accounts.set_swap_program_handler(swap_program)
+    }
+
+    pub fn request_prize_swap(ctx: Context<RequestPrizeSwap>) -> Result<()> {
+        ctx.accounts.request_prize_swap_handler(&ctx.bumps)
+    }
+
+    pub fn cancel_prize_swap(ctx: Context<CancelPrizeSwap>) -> Result<()> {
+        ctx.accounts.cancel_prize_swap_handler()
+    }
+
+    pub fn claim_swapped_prize<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimSwappedPrize<'info>>,
+        min_amount_out: u64,
+        route_data: Vec<u8>
+    ) -> Result<()> {
+        ctx.accounts.claim_swapped_prize_handler(min_amount_out, route_data, ctx.remaining_accounts)
+    }
+
+    pub fn reconcile(ctx: Context<Reconcile>) -> Result<()> {
+        ctx.accounts.reconcile_handler()
+    }
+
+    pub fn set_early_bird(ctx: Context<SetEarlyBird>, window: i64, bonus: u8) -> Result<()> {
+        ctx.accounts.set_early_bird_handler(window, bonus)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+        ctx.accounts.grant_role_handler(member, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, member: Pubkey, role: Role) -> Result<()> {
+        ctx.accounts.revoke_role_handler(member, role)
+    }
+
+    pub fn set_entries_paused(ctx: Context<SetEntriesPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.set_entries_paused_handler(paused)
+    }
+
+    pub fn initialize_recover(
+        ctx: Context<InitializeRecover>,
+        platform_wallet_pubkey: Pubkey,
+        ticket_price: u64,
+        platform_fee_bps: u16,
+        first_lottery_endtime: i64,
+    ) -> Result<()> {
+        ctx.accounts.initialize_recover_handler(
+            platform_wallet_pubkey,
+            ticket_price,
+            platform_fee_bps,
+            first_lottery_endtime,
+            &ctx.bumps
         )
     }
+
+    pub fn set_fee_governance(ctx: Context<SetFeeGovernance>, fee_governance: Pubkey) -> Result<()> {
+        ctx.accounts.set_fee_governance_handler(fee_governance)
+    }
+
+    pub fn approve_fee_ceiling(ctx: Context<ApproveFeeCeiling>, ceiling_bps: u16) -> Result<()> {
+        ctx.accounts.approve_fee_ceiling_handler(ceiling_bps)
+    }
+
+    pub fn set_kyc_verifier(ctx: Context<SetKycVerifier>, verifier: Pubkey) -> Result<()> {
+        ctx.accounts.set_kyc_verifier_handler(verifier)
+    }
+
+    pub fn enter_pending_kyc(ctx: Context<EnterPendingKyc>) -> Result<()> {
+        ctx.accounts.enter_pending_kyc_handler(&ctx.bumps)
+    }
+
+    pub fn approve_entry(ctx: Context<ApproveEntry>) -> Result<()> {
+        ctx.accounts.approve_entry_handler(&ctx.bumps)
+    }
+
+    pub fn refund_pending_entry(ctx: Context<RefundPendingEntry>) -> Result<()> {
+        ctx.accounts.refund_pending_entry_handler()
+    }
+
+    pub fn initialize_mega_jackpot(ctx: Context<InitializeMegaJackpot>, draw_interval: i64) -> Result<()> {
+        ctx.accounts.initialize_mega_jackpot_handler(draw_interval, &ctx.bumps)
+    }
+
+    pub fn set_mega_jackpot_contribution(ctx: Context<SetMegaJackpotContribution>, contribution_bps: u16) -> Result<()> {
+        ctx.accounts.set_mega_jackpot_contribution_handler(contribution_bps)
+    }
+
+    pub fn request_mega_draw(ctx: Context<RequestMegaDraw>) -> Result<()> {
+        ctx.accounts.request_mega_draw_handler()
+    }
+
+    pub fn resolve_mega_draw(ctx: Context<ResolveMegaDraw>, randomness: [u8; 32]) -> Result<()> {
+        ctx.accounts.resolve_mega_draw_handler(randomness)
+    }
+
+    pub fn settle_mega_draw(ctx: Context<SettleMegaDraw>, lottery_id: u64, ticket_index: u64) -> Result<()> {
+        ctx.accounts.settle_mega_draw_handler(lottery_id, ticket_index)
+    }
+
+    pub fn set_endtime_grace(ctx: Context<SetEndtimeGrace>, grace: i64) -> Result<()> {
+        ctx.accounts.set_endtime_grace_handler(grace)
+    }
+
+    pub fn claim_held_prize(ctx: Context<ClaimHeldPrize>) -> Result<()> {
+        ctx.accounts.claim_held_prize_handler()
+    }
+
+    pub fn set_streak_bonus(ctx: Context<SetStreakBonus>, streak_bonus_lamports: u64) -> Result<()> {
+        ctx.accounts.set_streak_bonus_handler(streak_bonus_lamports)
+    }
+
+    pub fn reconcile_vault(ctx: Context<ReconcileVault>) -> Result<()> {
+        ctx.accounts.reconcile_vault_handler()
+    }
+
+    pub fn set_winner_hook(ctx: Context<SetWinnerHook>, winner_hook_program: Pubkey) -> Result<()> {
+        ctx.accounts.set_winner_hook_handler(winner_hook_program)
+    }
+
+    pub fn set_ticket_classes(ctx: Context<SetTicketClasses>, ticket_classes: Vec<TicketClassConfig>) -> Result<()> {
+        ctx.accounts.set_ticket_classes_handler(ticket_classes)
+    }
+
+    pub fn locate_class_winner(ctx: Context<LocateClassWinner>, ticket_index: u32) -> Result<()> {
+        ctx.accounts.locate_class_winner_handler(ticket_index)
+    }
+
+    pub fn configure_loser_rewards(ctx: Context<ConfigureLoserRewards>, loser_reward_amount: u64) -> Result<()> {
+        ctx.accounts.configure_loser_rewards_handler(loser_reward_amount)
+    }
+
+    pub fn claim_loser_reward(ctx: Context<ClaimLoserReward>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.claim_loser_reward_handler(lottery_id, ticket_index, &ctx.bumps)
+    }
+
+    pub fn set_prize_granularity(ctx: Context<SetPrizeGranularity>, prize_granularity: u64) -> Result<()> {
+        ctx.accounts.set_prize_granularity_handler(prize_granularity)
+    }
+
+    pub fn close_losing_tickets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseLosingTickets<'info>>,
+        lottery_id: u64,
+        ticket_indexes: Vec<u32>
+    ) -> Result<()> {
+        ctx.accounts.close_losing_tickets_handler(lottery_id, ticket_indexes, ctx.remaining_accounts)
+    }
+
+    pub fn set_config_bounds(ctx: Context<SetConfigBounds>, bounds: ConfigBounds) -> Result<()> {
+        ctx.accounts.set_config_bounds_handler(bounds)
+    }
+
+    pub fn announce_round(ctx: Context<AnnounceRound>, theme: String, expected_prize_seed: u64, opens_at: i64) -> Result<()> {
+        ctx.accounts.announce_round_handler(theme, expected_prize_seed, opens_at, &ctx.bumps)
+    }
+
+    pub fn open_announced_round(ctx: Context<OpenAnnouncedRound>) -> Result<()> {
+        ctx.accounts.open_announced_round_handler()
+    }
+
+    pub fn place_conditional_entry(ctx: Context<PlaceConditionalEntry>, min_pot_lamports: u64, max_participants: u64) -> Result<()> {
+        ctx.accounts.place_conditional_entry_handler(min_pot_lamports, max_participants, &ctx.bumps)
+    }
+
+    pub fn execute_conditional_entry(ctx: Context<ExecuteConditionalEntry>) -> Result<()> {
+        ctx.accounts.execute_conditional_entry_handler(&ctx.bumps)
+    }
+
+    pub fn refund_conditional_entry(ctx: Context<RefundConditionalEntry>) -> Result<()> {
+        ctx.accounts.refund_conditional_entry_handler()
+    }
+
+    pub fn set_stake_payout(ctx: Context<SetStakePayout>, vote_account: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_stake_payout_handler(vote_account)
+    }
+
+    pub fn claim_prize_as_stake(ctx: Context<ClaimPrizeAsStake>) -> Result<()> {
+        ctx.accounts.claim_prize_as_stake_handler()
+    }
+
+    pub fn set_trivial_resolution(ctx: Context<SetTrivialResolution>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_trivial_resolution_handler(enabled)
+    }
+
+    pub fn resolve_trivial_round(ctx: Context<ResolveTrivialRound>) -> Result<()> {
+        ctx.accounts.resolve_trivial_round_handler(&ctx.bumps)
+    }
+
+    pub fn quote_entry(ctx: Context<QuoteEntry>, ticket_class: Option<u8>) -> Result<EntryQuote> {
+        ctx.accounts.quote_entry_handler(ticket_class)
+    }
+
+    pub fn initialize_insurance_reserve(ctx: Context<InitializeInsuranceReserve>) -> Result<()> {
+        ctx.accounts.initialize_insurance_reserve_handler(&ctx.bumps)
+    }
+
+    pub fn set_insurance_bps(ctx: Context<SetInsuranceBps>, insurance_bps: u16) -> Result<()> {
+        ctx.accounts.set_insurance_bps_handler(insurance_bps)
+    }
+
+    pub fn propose_compensation(
+        ctx: Context<ProposeCompensation>,
+        recipient: Pubkey,
+        amount: u64,
+        lottery_id: u64,
+        reason_hash: [u8; 32]
+    ) -> Result<()> {
+        ctx.accounts.propose_compensation_handler(recipient, amount, lottery_id, reason_hash, &ctx.bumps)
+    }
+
+    pub fn compensate(ctx: Context<Compensate>, compensation_id: u64) -> Result<()> {
+        ctx.accounts.compensate_handler(compensation_id)
+    }
+
+    pub fn cancel_compensation(ctx: Context<CancelCompensation>, compensation_id: u64) -> Result<()> {
+        ctx.accounts.cancel_compensation_handler(compensation_id)
+    }
+
+    pub fn set_dual_draw(ctx: Context<SetDualDraw>, min_pot_lamports: u64, second_oracle_queue: Pubkey) -> Result<()> {
+        ctx.accounts.set_dual_draw_handler(min_pot_lamports, second_oracle_queue)
+    }
+
+    pub fn prune_history<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneHistory<'info>>,
+        first_lottery_id: u64
+    ) -> Result<()> {
+        ctx.accounts.prune_history_handler(first_lottery_id, ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    pub fn migrate_pot_vault(ctx: Context<MigratePotVault>) -> Result<()> {
+        ctx.accounts.migrate_pot_vault_handler()
+    }
+
+    pub fn attach_nft_prize(ctx: Context<AttachNftPrize>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.attach_nft_prize_handler(lottery_id, &ctx.bumps)
+    }
+
+    pub fn claim_nft_prize(ctx: Context<ClaimNftPrize>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.claim_nft_prize_handler(lottery_id)
+    }
+
+    pub fn reclaim_nft_prize(ctx: Context<ReclaimNftPrize>, lottery_id: u64) -> Result<()> {
+        ctx.accounts.reclaim_nft_prize_handler(lottery_id)
+    }
+
+    pub fn set_fee_promotion(ctx: Context<SetFeePromotion>, lottery_id: u64, starts_at: i64, ends_at: i64, fee_bps: u16) -> Result<()> {
+        ctx.accounts.set_fee_promotion_handler(lottery_id, starts_at, ends_at, fee_bps)
+    }
+
+    pub fn set_class_draws(ctx: Context<SetClassDraws>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_class_draws_handler(enabled)
+    }
+
+    pub fn claim_class_prize(ctx: Context<ClaimClassPrize>, lottery_id: u64, ticket_index: u32) -> Result<()> {
+        ctx.accounts.claim_class_prize_handler(lottery_id, ticket_index)
+    }
+
+    pub fn sunset(ctx: Context<Sunset>) -> Result<()> {
+        ctx.accounts.sunset_handler()
+    }
+
+    pub fn refund_sunset_entry(ctx: Context<RefundSunsetEntry>) -> Result<()> {
+        ctx.accounts.refund_sunset_entry_handler()
+    }
+
+    pub fn close_sunset_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseSunsetAccounts<'info>>,
+        close_state: bool
+    ) -> Result<()> {
+        ctx.accounts.close_sunset_accounts_handler(close_state, ctx.remaining_accounts)
+    }
+
+    pub fn set_slot_deadline(ctx: Context<SetSlotDeadline>, enabled: bool, min_round_slots: u64) -> Result<()> {
+        ctx.accounts.set_slot_deadline_handler(enabled, min_round_slots)
+    }
+
+    pub fn set_randomness_provider(ctx: Context<SetRandomnessProvider>, program_id: Pubkey, queue: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_randomness_provider_handler(program_id, queue)
+    }
+
+    pub fn set_device_entry_limit(ctx: Context<SetDeviceEntryLimit>, max_entries_per_device: u32) -> Result<()> {
+        ctx.accounts.set_device_entry_limit_handler(max_entries_per_device)
+    }
+
+    pub fn snapshot_airdrop(ctx: Context<SnapshotAirdrop>, first_lottery_id: u64, round_count: u8) -> Result<()> {
+        ctx.accounts.snapshot_airdrop_handler(first_lottery_id, round_count, &ctx.bumps)
+    }
+
+    pub fn crank_airdrop_snapshot(ctx: Context<CrankAirdropSnapshot>) -> Result<()> {
+        ctx.accounts.crank_airdrop_snapshot_handler()
+    }
+
+    pub fn claim_held_token_prize(ctx: Context<ClaimHeldTokenPrize>) -> Result<()> {
+        ctx.accounts.claim_held_token_prize_handler()
+    }
+
+    pub fn migrate_lottery_state(ctx: Context<MigrateLotteryState>) -> Result<()> {
+        ctx.accounts.migrate_lottery_state_handler()
+    }
 }
\ No newline at end of file
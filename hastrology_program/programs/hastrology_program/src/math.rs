@@ -0,0 +1,44 @@
+//! Thin wrappers over `lottery_core::math` that surface failures as program errors.
+
+use anchor_lang::prelude::*;
+use lottery_core::{math, LotteryError};
+
+use crate::errors::HashtrologyErrors;
+
+pub use lottery_core::math::{available_balance, round_down, sample_index, sample_weight, weight_contains, BPS_DENOMINATOR};
+
+fn to_program<T>(result: core::result::Result<T, LotteryError>) -> Result<T> {
+    result.map_err(|err| HashtrologyErrors::from(err).into())
+}
+
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    to_program(math::bps_of(amount, bps))
+}
+
+pub fn split_fee(pot: u64, fee_bps: u16) -> Result<(u64, u64)> {
+    to_program(math::split_fee(pot, fee_bps))
+}
+
+pub fn split_prize_tiers(prize: u64, tier_bps: &[u16]) -> Result<Vec<u64>> {
+    to_program(math::split_prize_tiers(prize, tier_bps))
+}
+
+pub fn rollover_amount(pot: u64, distributed: u64) -> Result<u64> {
+    to_program(math::rollover_amount(pot, distributed))
+}
+
+pub fn time_weight(amount: u64, seconds: i64) -> Result<u128> {
+    to_program(math::time_weight(amount, seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_errors_map_to_program_errors() {
+        assert_eq!(bps_of(1, 10_001).unwrap_err(), HashtrologyErrors::InvalidBasisPoints.into());
+        assert_eq!(rollover_amount(1, 2).unwrap_err(), HashtrologyErrors::InsufficientLamports.into());
+        assert_eq!(time_weight(1, 0).unwrap_err(), HashtrologyErrors::LotteryIsOver.into());
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use ephemeral_vrf_sdk::{
+    consts::{VRF_PROGRAM_ID, VRF_PROGRAM_IDENTITY},
+    instructions::{create_request_randomness_ix, RequestRandomnessParams},
+    types::SerializableAccountMeta
+};
+
+use crate::{constants::{ORACLE_QUEUE, RANDOMNESS_IDENTITY_SEED}, ID};
+
+use super::{ProviderAccounts, RandomnessProvider, RandomnessRequest};
+
+/// MagicBlock's ephemeral VRF, the provider every lottery starts with.
+pub struct EphemeralVrf;
+
+impl RandomnessProvider for EphemeralVrf {
+    fn program_id(&self) -> Pubkey {
+        VRF_PROGRAM_ID
+    }
+
+    fn default_queue(&self) -> Pubkey {
+        ORACLE_QUEUE
+    }
+
+    fn callback_signer(&self) -> Pubkey {
+        VRF_PROGRAM_IDENTITY
+    }
+
+    fn request(&self, request: RandomnessRequest, accounts: ProviderAccounts) -> Result<()> {
+        let accounts_metas = request.accounts
+            .iter()
+            .map(|meta| SerializableAccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect();
+
+        let ix = create_request_randomness_ix(RequestRandomnessParams {
+            payer: request.payer,
+            oracle_queue: request.queue,
+            callback_program_id: ID,
+            callback_discriminator: request.callback_discriminator,
+            caller_seed: request.caller_seed,
+            accounts_metas: Some(accounts_metas),
+            ..Default::default()
+        });
+
+        let (_, identity_bump) = Pubkey::find_program_address(&[RANDOMNESS_IDENTITY_SEED], &ID);
+
+        invoke_signed(
+            &ix,
+            &[
+                accounts.payer.clone(),
+                accounts.program_identity.clone(),
+                accounts.queue.clone(),
+                accounts.slot_hashes.clone(),
+                accounts.provider_program.clone(),
+            ],
+            &[&[RANDOMNESS_IDENTITY_SEED, &[identity_bump]]]
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//! Randomness providers draws can be requested from. Instructions only talk to the
+//! [`RandomnessProvider`] stored as `LotteryState::randomness_provider`, so switching oracles
+//! is an adapter module here plus `set_randomness_provider`.
+
+use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta};
+
+use crate::errors::HashtrologyErrors;
+
+pub mod ephemeral_vrf;
+
+pub use ephemeral_vrf::EphemeralVrf;
+
+/// Every provider the program can be switched to.
+const PROVIDERS: &[&dyn RandomnessProvider] = &[&EphemeralVrf];
+
+/// A request for 32 random bytes, answered by the provider calling this program's
+/// `callback_discriminator` instruction with `accounts`.
+pub struct RandomnessRequest {
+    pub payer: Pubkey,
+    pub queue: Pubkey,
+    pub callback_discriminator: Vec<u8>,
+    pub caller_seed: [u8; 32],
+    pub accounts: Vec<AccountMeta>,
+}
+
+/// Accounts a request CPI is made with. `program_identity` is the `RANDOMNESS_IDENTITY_SEED`
+/// PDA the program signs requests as.
+pub struct ProviderAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub program_identity: &'a AccountInfo<'info>,
+    pub queue: &'a AccountInfo<'info>,
+    pub slot_hashes: &'a AccountInfo<'info>,
+    pub provider_program: &'a AccountInfo<'info>,
+}
+
+pub trait RandomnessProvider: Sync {
+    /// Program requests go to.
+    fn program_id(&self) -> Pubkey;
+
+    /// Queue requests go to unless the authority picks another.
+    fn default_queue(&self) -> Pubkey;
+
+    /// Signer of the callback; resolve instructions take randomness from nobody else.
+    fn callback_signer(&self) -> Pubkey;
+
+    /// Makes the request CPI, signed by the program identity.
+    fn request(&self, request: RandomnessRequest, accounts: ProviderAccounts) -> Result<()>;
+}
+
+/// The provider whose program is `program_id`.
+pub fn provider_for(program_id: &Pubkey) -> Result<&'static dyn RandomnessProvider> {
+    PROVIDERS
+        .iter()
+        .copied()
+        .find(|provider| provider.program_id() == *program_id)
+        .ok_or_else(|| HashtrologyErrors::UnsupportedRandomnessProvider.into())
+}
@@ -0,0 +1,126 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::{
+    constants::{MAX_SNAPSHOT_ROUNDS, MERKLE_TREE_DEPTH},
+    state::MerkleEntryTree
+};
+
+/// Merkle root over every ticket sold in lotteries `first_lottery_id..` (one round, or a run
+/// of rounds such as a season), for airdrop programs to pay participants against. Each round
+/// gets the tree a Merkle-mode round builds as it sells, with one `MerkleEntryTree::leaf` per
+/// ticket, so a wallet proves each of its tickets; the root is the tree over those round
+/// roots, in lottery order.
+///
+/// Built by `crank_airdrop_snapshot` a batch of tickets at a time; `root` is only set once
+/// every round has been read.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct AirdropSnapshot {
+    pub first_lottery_id: u64,
+    pub round_count: u8,
+    pub rounds_read: u8, // rounds whose root is in round_roots
+    pub next_ticket: u32, // next ticket to read from the round after them
+    pub filled_subtrees: [[u8; 32]; MERKLE_TREE_DEPTH], // frontier of the round being read
+    pub round_root: [u8; 32], // its root over the tickets read so far
+    pub round_roots: [[u8; 32]; MAX_SNAPSHOT_ROUNDS],
+    pub ticket_count: u64,
+    pub depth: u8, // smallest tree holding round_count round roots; missing ones are zero
+    pub root: [u8; 32],
+    pub snapshotted_at: i64, // 0 until every round is read
+    pub bump: u8
+}
+
+impl AirdropSnapshot {
+    fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&[1u8], left, right]).to_bytes()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.rounds_read == self.round_count
+    }
+
+    /// Lottery the crank reads next.
+    pub fn reading_lottery_id(&self) -> u64 {
+        self.first_lottery_id + self.rounds_read as u64
+    }
+
+    /// Adds the next ticket of the round being read.
+    pub fn append_ticket(&mut self, wallet: &Pubkey) {
+        let leaf = MerkleEntryTree::leaf(wallet, self.reading_lottery_id(), self.next_ticket);
+
+        self.round_root = MerkleEntryTree::append_to(&mut self.filled_subtrees, self.next_ticket as u64, leaf);
+        self.next_ticket += 1;
+        self.ticket_count += 1;
+    }
+
+    /// Root of the tickets read so far from the round being read.
+    pub fn read_root(&self) -> [u8; 32] {
+        if self.next_ticket == 0 {
+            MerkleEntryTree::empty_root()
+        } else {
+            self.round_root
+        }
+    }
+
+    /// Closes the round being read with its tree's `root`, rooting the snapshot after the last one.
+    pub fn finish_round(&mut self, root: [u8; 32], now: i64) {
+        self.round_roots[self.rounds_read as usize] = root;
+        self.rounds_read += 1;
+        self.next_ticket = 0;
+
+        if self.is_complete() {
+            let mut nodes = self.round_roots[..self.round_count as usize].to_vec();
+            (self.root, self.depth) = Self::fold_root(&mut nodes);
+            self.snapshotted_at = now;
+        }
+    }
+
+    /// Folds `nodes` into its root in place, returning the root and the tree depth.
+    pub fn fold_root(nodes: &mut [[u8; 32]]) -> ([u8; 32], u8) {
+        let mut len = nodes.len();
+        let mut zero = [0u8; 32];
+        let mut depth = 0u8;
+
+        while len > 1 {
+            for i in 0..len.div_ceil(2) {
+                let right = if 2 * i + 1 < len { nodes[2 * i + 1] } else { zero };
+                nodes[i] = Self::node(&nodes[2 * i], &right);
+            }
+
+            len = len.div_ceil(2);
+            zero = Self::node(&zero, &zero);
+            depth += 1;
+        }
+
+        (nodes.first().copied().unwrap_or(zero), depth)
+    }
+
+    /// Whether `wallet` held ticket `ticket_index` of `lottery_id`: `round_proof` leads from the
+    /// ticket to its round's root, `proof` from there to the snapshot root.
+    pub fn verify(
+        &self,
+        wallet: &Pubkey,
+        lottery_id: u64,
+        ticket_index: u32,
+        round_proof: &[[u8; 32]],
+        proof: &[[u8; 32]]
+    ) -> bool {
+        let Some(round) = lottery_id.checked_sub(self.first_lottery_id) else {
+            return false;
+        };
+
+        if !self.is_complete()
+            || round >= self.round_count as u64
+            || round_proof.len() != MERKLE_TREE_DEPTH
+            || proof.len() != self.depth as usize
+        {
+            return false;
+        }
+
+        let leaf = MerkleEntryTree::leaf(wallet, lottery_id, ticket_index);
+        let round_root = MerkleEntryTree::root_from_proof(leaf, ticket_index as u64, round_proof);
+
+        MerkleEntryTree::root_from_proof(round_root, round, proof) == self.root
+    }
+}
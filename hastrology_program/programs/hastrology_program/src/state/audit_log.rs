@@ -0,0 +1,53 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+pub const AUDIT_LOG_CAPACITY: usize = 64;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum AuditAction {
+    Reset = 0,
+    UpdateConfig = 1,
+    Payout = 2,
+    ProposeConfig = 3,
+    Reconcile = 4,
+    GrantRole = 5,
+    RevokeRole = 6,
+}
+
+#[zero_copy]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AuditEntry {
+    pub actor: Pubkey,
+    pub slot: u64,
+    pub params_hash: [u8; 32],
+    pub action: u8,
+    pub _padding: [u8; 7]
+}
+
+/// Append-only ring buffer of privileged calls. Once full, the oldest entry is overwritten;
+/// `total_entries` keeps counting so gaps are detectable.
+#[account(zero_copy)]
+pub struct AuditLog {
+    pub next_index: u64,
+    pub total_entries: u64,
+    pub entries: [AuditEntry; AUDIT_LOG_CAPACITY]
+}
+
+impl AuditLog {
+    pub fn record(&mut self, action: AuditAction, actor: Pubkey, params: &[&[u8]]) -> Result<()> {
+        let index = self.next_index as usize % AUDIT_LOG_CAPACITY;
+
+        self.entries[index] = AuditEntry {
+            actor,
+            slot: Clock::get()?.slot,
+            params_hash: hashv(params).to_bytes(),
+            action: action as u8,
+            _padding: [0u8; 7]
+        };
+
+        self.next_index = ((index + 1) % AUDIT_LOG_CAPACITY) as u64;
+        self.total_entries = self.total_entries.wrapping_add(1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Claimed flags of a round's tickets, one bit per ticket, so a single fetch shows which
+/// tickets are still unclaimed. `init_claim_bitmap` sizes it to the round's ticket count,
+/// growing it over several calls past the CPI allocation limit; claims need it at full size.
+/// The header is followed by `ticket_count.div_ceil(8)` raw bitmap bytes.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClaimBitmap {
+    pub lottery_id: u64,
+    pub ticket_count: u32,
+    pub claimed_count: u32,
+    pub bump: u8
+}
+
+impl ClaimBitmap {
+    // discriminator + lottery_id + ticket_count + claimed_count + bump
+    pub const BITS_OFFSET: usize = 8 + 8 + 4 + 4 + 1;
+
+    pub fn space(ticket_count: u32) -> usize {
+        Self::BITS_OFFSET + (ticket_count as usize).div_ceil(8)
+    }
+
+    /// Whether ticket `index` is claimed, read without deserializing the header.
+    pub fn raw_is_claimed(data: &[u8], index: u32) -> bool {
+        data.get(Self::BITS_OFFSET + index as usize / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    /// Sets ticket `index`'s bit, failing if it is out of range, already set, or the
+    /// bitmap has not been grown to full size yet.
+    pub fn mark_claimed(bitmap: &mut Account<ClaimBitmap>, index: u32) -> Result<()> {
+        require!(
+            index < bitmap.ticket_count,
+            HashtrologyErrors::InvalidClaimIndex
+        );
+
+        let info = bitmap.to_account_info();
+
+        require!(
+            info.data_len() >= Self::space(bitmap.ticket_count),
+            HashtrologyErrors::ClaimBitmapIncomplete
+        );
+
+        {
+            let mut data = info.try_borrow_mut_data()?;
+            let byte = &mut data[Self::BITS_OFFSET + index as usize / 8];
+
+            require!(
+                *byte & (1 << (index % 8)) == 0,
+                HashtrologyErrors::PrizeAlreadyClaimed
+            );
+
+            *byte |= 1 << (index % 8);
+        }
+
+        bitmap.claimed_count = bitmap.claimed_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Escrowed entry into the current round that `execute_conditional_entry` only turns into
+/// a ticket once its conditions hold. Refunded by `refund_conditional_entry` if the round
+/// ends first.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct ConditionalEntry {
+    pub user: Pubkey,
+    pub lottery_id: u64,
+    pub amount: u64, // escrowed lamports, held by this account
+    pub min_pot_lamports: u64, // round pot the entry waits for; 0 for any
+    pub max_participants: u64, // most tickets the round may hold once entered; 0 for any
+    pub bump: u8
+}
+
+impl ConditionalEntry {
+    /// Whether entering a round with `pot_lamports` in its pot and `total_participants`
+    /// tickets sold meets the conditions.
+    pub fn conditions_met(&self, pot_lamports: u64, total_participants: u64) -> bool {
+        pot_lamports >= self.min_pot_lamports
+            && (self.max_participants == 0 || total_participants < self.max_participants)
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_PLATFORM_FEE_BPS, MAX_ROUND_DURATION, MIN_ROUND_DURATION, MIN_TICKET_PRICE},
+    errors::HashtrologyErrors,
+    state::PlatformConfig,
+    ID
+};
+
+/// Limits `initialize` and `update_config` check the lottery's config against, kept in
+/// `PlatformConfig` so a cluster can tune them without a redeploy. They can only tighten
+/// the compiled constants, which stay the hard limits.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ConfigBounds {
+    pub min_round_duration: i64, // shortest time from now to a new endtime
+    pub max_round_duration: i64, // longest
+    pub max_fee_bps: u16,
+    pub min_ticket_price: u64
+}
+
+impl Default for ConfigBounds {
+    fn default() -> Self {
+        Self {
+            min_round_duration: MIN_ROUND_DURATION,
+            max_round_duration: MAX_ROUND_DURATION,
+            max_fee_bps: MAX_PLATFORM_FEE_BPS,
+            min_ticket_price: MIN_TICKET_PRICE
+        }
+    }
+}
+
+impl ConfigBounds {
+    /// Bounds stored in the `PLATFORM_CONFIG_SEED` account, or the compiled defaults until
+    /// `initialize_platform_config` has created it.
+    pub fn load(platform_config: &AccountInfo) -> Result<Self> {
+        if platform_config.owner != &ID {
+            return Ok(Self::default());
+        }
+
+        let config = PlatformConfig::try_deserialize(&mut &platform_config.try_borrow_data()?[..])?;
+
+        Ok(config.bounds)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_round_duration >= MIN_ROUND_DURATION
+                && self.max_round_duration >= self.min_round_duration
+                && self.max_round_duration <= MAX_ROUND_DURATION
+                && self.max_fee_bps <= MAX_PLATFORM_FEE_BPS
+                && self.min_ticket_price >= MIN_TICKET_PRICE,
+            HashtrologyErrors::InvalidConfigBounds
+        );
+
+        Ok(())
+    }
+
+    pub fn check_ticket_price(&self, ticket_price: u64) -> Result<()> {
+        require!(
+            ticket_price >= self.min_ticket_price,
+            HashtrologyErrors::InvalidTicketPrice
+        );
+
+        Ok(())
+    }
+
+    pub fn check_fee(&self, fee_bps: u16) -> Result<()> {
+        require!(
+            fee_bps <= self.max_fee_bps,
+            HashtrologyErrors::InvalidPlatformFee
+        );
+
+        Ok(())
+    }
+
+    /// `endtime` must fall between the minimum and maximum round duration from `now`.
+    pub fn check_endtime(&self, now: i64, endtime: i64) -> Result<()> {
+        require!(
+            endtime >= now.saturating_add(self.min_round_duration),
+            HashtrologyErrors::EndtimeTooSoon
+        );
+
+        require!(
+            endtime <= now.saturating_add(self.max_round_duration),
+            HashtrologyErrors::EndtimeTooFar
+        );
+
+        Ok(())
+    }
+}
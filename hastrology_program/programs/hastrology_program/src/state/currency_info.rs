@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_CURRENCY_SYMBOL_LEN, SOL_DECIMALS},
+    errors::HashtrologyErrors
+};
+
+/// Display metadata for a currency, snapshotted on-chain so amounts in state and
+/// history stay renderable even if the off-chain token metadata disappears.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CurrencyInfo {
+    pub mint: Pubkey, // Pubkey::default() for native SOL
+    pub decimals: u8,
+    #[max_len(MAX_CURRENCY_SYMBOL_LEN)]
+    pub symbol: String
+}
+
+impl CurrencyInfo {
+    pub fn sol() -> Self {
+        Self {
+            mint: Pubkey::default(),
+            decimals: SOL_DECIMALS,
+            symbol: String::from("SOL")
+        }
+    }
+
+    pub fn new(mint: Pubkey, decimals: u8, symbol: String) -> Result<Self> {
+        require!(
+            !symbol.is_empty() && symbol.len() <= MAX_CURRENCY_SYMBOL_LEN,
+            HashtrologyErrors::InvalidCurrencySymbol
+        );
+
+        Ok(Self { mint, decimals, symbol })
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Entries made in a round under one device commitment: the hash of a salt the app keeps on
+/// the device. Caps entries from one device across wallets without the program learning
+/// anything about the device itself. Best effort, since the app can be bypassed.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct DeviceEntries {
+    pub lottery_id: u64,
+    pub commitment: [u8; 32],
+    pub entries: u32,
+    pub bump: u8
+}
+
+impl DeviceEntries {
+    /// Counts an entry, failing once the round already has `max_entries` under the commitment.
+    pub fn record_entry(&mut self, lottery_id: u64, commitment: [u8; 32], max_entries: u32, bump: u8) -> Result<()> {
+        require!(
+            max_entries == 0 || self.entries < max_entries,
+            HashtrologyErrors::DeviceEntryLimitReached
+        );
+
+        self.lottery_id = lottery_id;
+        self.commitment = commitment;
+        self.bump = bump;
+        self.entries = self.entries.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+}
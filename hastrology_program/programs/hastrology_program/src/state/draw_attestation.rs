@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct DrawAttestation {
+    pub lottery_id: u64,
+    pub approvals: u8, // bitmask over lottery_state.draw_operators
+    pub approval_count: u8,
+    pub bump: u8
+}
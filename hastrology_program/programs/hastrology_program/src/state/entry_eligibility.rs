@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{constants::ATTESTATION_SEED, errors::HashtrologyErrors};
+
+/// Optional gate checked at `enter_lottery` to make bot farming of promo rounds costlier,
+/// or to require a verified credential (e.g. age or territory) in regulated markets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EntryEligibility {
+    Open,
+    MinLamports { amount: u64 },
+    TokenBalance { mint: Pubkey, amount: u64 },
+    VerificationNft { mint: Pubkey },
+    /// Unexpired attestation for the user under `credential` and `schema`, issued by
+    /// `program` using the Solana Attestation Service account layout.
+    Attestation { program: Pubkey, credential: Pubkey, schema: Pubkey }
+}
+
+impl EntryEligibility {
+    pub fn check(
+        &self,
+        user: &AccountInfo,
+        token_account: Option<&InterfaceAccount<TokenAccount>>,
+        attestation: Option<&AccountInfo>
+    ) -> Result<()> {
+        match *self {
+            EntryEligibility::Open => Ok(()),
+            EntryEligibility::MinLamports { amount } => {
+                require!(
+                    user.lamports() >= amount,
+                    HashtrologyErrors::NotEligible
+                );
+                Ok(())
+            }
+            EntryEligibility::TokenBalance { mint, amount } => {
+                let balance = Self::held_balance(user, token_account, mint)?;
+                require!(balance >= amount, HashtrologyErrors::NotEligible);
+                Ok(())
+            }
+            EntryEligibility::VerificationNft { mint } => {
+                let balance = Self::held_balance(user, token_account, mint)?;
+                require!(balance == 1, HashtrologyErrors::NotEligible);
+                Ok(())
+            }
+            EntryEligibility::Attestation { program, credential, schema } => {
+                let attestation = attestation.ok_or(HashtrologyErrors::EligibilityAccountMissing)?;
+                Self::check_attestation(user, attestation, program, credential, schema)
+            }
+        }
+    }
+
+    /// Attestations are PDAs of `["attestation", credential, schema, nonce]` with the user as
+    /// nonce, laid out as: discriminator (1), nonce (32), credential (32), schema (32),
+    /// data (u32 length + bytes), signer (32), expiry (i64, 0 = never).
+    fn check_attestation(
+        user: &AccountInfo,
+        attestation: &AccountInfo,
+        program: Pubkey,
+        credential: Pubkey,
+        schema: Pubkey
+    ) -> Result<()> {
+        let (expected, _) = Pubkey::find_program_address(
+            &[ATTESTATION_SEED, credential.as_ref(), schema.as_ref(), user.key.as_ref()],
+            &program
+        );
+
+        require!(
+            attestation.key() == expected && attestation.owner == &program,
+            HashtrologyErrors::InvalidEligibilityAccount
+        );
+
+        let data = attestation.try_borrow_data()?;
+        let read_pubkey = |offset: usize| -> Option<Pubkey> {
+            Some(Pubkey::new_from_array(data.get(offset..offset + 32)?.try_into().ok()?))
+        };
+
+        let data_len = data.get(97..101)
+            .map(|len| u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize)
+            .ok_or(HashtrologyErrors::InvalidEligibilityAccount)?;
+        let expiry_offset = 101 + data_len + 32;
+        let expiry = data.get(expiry_offset..expiry_offset + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(i64::from_le_bytes)
+            .ok_or(HashtrologyErrors::InvalidEligibilityAccount)?;
+
+        require!(
+            read_pubkey(1) == Some(user.key()) &&
+            read_pubkey(33) == Some(credential) &&
+            read_pubkey(65) == Some(schema),
+            HashtrologyErrors::InvalidEligibilityAccount
+        );
+
+        require!(
+            expiry == 0 || expiry > Clock::get()?.unix_timestamp,
+            HashtrologyErrors::NotEligible
+        );
+
+        Ok(())
+    }
+
+    fn held_balance(
+        user: &AccountInfo,
+        token_account: Option<&InterfaceAccount<TokenAccount>>,
+        mint: Pubkey
+    ) -> Result<u64> {
+        let token_account = token_account.ok_or(HashtrologyErrors::EligibilityAccountMissing)?;
+
+        require!(
+            token_account.owner == user.key() && token_account.mint == mint,
+            HashtrologyErrors::InvalidEligibilityAccount
+        );
+
+        Ok(token_account.amount)
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// A shared ticket funded by several members. The pool account escrows contributions
+/// until `enter_pool`, owns the resulting ticket, and receives its prize, which members
+/// then claim pro-rata to what they put in.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct EntryPool {
+    pub creator: Pubkey,
+    pub lottery_id: u64,
+    pub total_contributed: u64,
+    pub member_count: u32,
+    pub is_entered: bool,
+    pub ticket_index: u32, // valid once `is_entered`
+    pub bump: u8
+}
+
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PoolMember {
+    pub pool: Pubkey,
+    pub member: Pubkey,
+    pub contribution: u64,
+    pub has_claimed: bool,
+    pub bump: u8
+}
+
+impl PoolMember {
+    /// This member's cut of `prize_amount`, rounded down.
+    pub fn share_of(&self, prize_amount: u64, total_contributed: u64) -> Option<u64> {
+        if total_contributed == 0 {
+            return None;
+        }
+
+        let share = (prize_amount as u128)
+            .checked_mul(self.contribution as u128)?
+            .checked_div(total_contributed as u128)?;
+
+        u64::try_from(share).ok()
+    }
+}
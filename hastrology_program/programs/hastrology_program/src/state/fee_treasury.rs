@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Holds platform fees in its own lamports between `forward_fees` cranks, so settlements
+/// don't need the platform wallet. `initialize_fee_treasury` creates it.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct FeeTreasury {
+    pub accrued_fees: u64, // lamports owed to the platform wallet, on top of rent
+    pub total_forwarded: u64,
+    pub last_forwarded_at: i64,
+    pub bump: u8
+}
+
+impl FeeTreasury {
+    /// Records fee lamports the caller has just credited to the treasury.
+    pub fn accrue(&mut self, amount: u64) -> Result<()> {
+        self.accrued_fees = self.accrued_fees.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    /// Records fee lamports the caller has just taken out of the treasury.
+    pub fn spend(&mut self, amount: u64) -> Result<()> {
+        self.accrued_fees = self.accrued_fees.checked_sub(amount).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    /// Whether `interval` has passed since the last forward.
+    pub fn can_forward(&self, now: i64, interval: i64) -> bool {
+        self.last_forwarded_at.saturating_add(interval) <= now
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::constants::HISTORY_ARCHIVE_DEPTH;
+
+/// Merkle root over a page of consecutive round histories closed by `prune_history`. Each
+/// leaf hashes a history's full account data, which the `HistoryArchived` event carries
+/// the leaves of, so a kept copy of any archived round can still be proven against `root`.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct HistoryArchive {
+    pub first_lottery_id: u64,
+    pub round_count: u8, // rounds first_lottery_id.. in leaf order
+    pub root: [u8; 32],
+    pub archived_at: i64,
+    pub bump: u8
+}
+
+impl HistoryArchive {
+    pub fn leaf(history_data: &[u8]) -> [u8; 32] {
+        hashv(&[&[0u8], history_data]).to_bytes()
+    }
+
+    fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&[1u8], left, right]).to_bytes()
+    }
+
+    /// Root of a `HISTORY_ARCHIVE_DEPTH` tree holding `leaves` from index 0, zero-padded.
+    pub fn root_of(leaves: &[[u8; 32]]) -> [u8; 32] {
+        let mut level = leaves.to_vec();
+        let mut zero = [0u8; 32];
+
+        for _ in 0..HISTORY_ARCHIVE_DEPTH {
+            level = level
+                .chunks(2)
+                .map(|pair| Self::node(&pair[0], pair.get(1).unwrap_or(&zero)))
+                .collect();
+            zero = Self::node(&zero, &zero);
+        }
+
+        level.first().copied().unwrap_or(zero)
+    }
+
+    pub fn verify(&self, leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> bool {
+        if proof.len() != HISTORY_ARCHIVE_DEPTH || index >= self.round_count as u64 {
+            return false;
+        }
+
+        let node = proof.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+            if (index >> level) & 1 == 0 {
+                Self::node(&node, sibling)
+            } else {
+                Self::node(sibling, &node)
+            }
+        });
+
+        node == self.root
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_APPROVED_HOSTS, math::{bps_of, split_fee}, state::ConfigBounds};
+
+/// Platform-wide settings for third-party hosted lotteries. `initialize_platform_config` creates it.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub protocol_fee_bps: u16, // platform's cut of every hosted pot, taken before the host fee
+    #[max_len(MAX_APPROVED_HOSTS)]
+    pub approved_hosts: Vec<Pubkey>,
+    pub bump: u8,
+
+    // ----Config Bounds----
+    pub bounds: ConfigBounds, // checked by initialize and update_config
+}
+
+impl PlatformConfig {
+    pub fn is_approved(&self, host: &Pubkey) -> bool {
+        self.approved_hosts.contains(host)
+    }
+}
+
+/// A lottery instance run by an approved host, seeded by host and the host's own index.
+/// Its pot is held by the `HOSTED_VAULT_SEED` PDA of this account.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct HostedLottery {
+    pub host: Pubkey,
+    pub index: u64,
+    pub fee_wallet: Pubkey, // receives the host fee
+    pub host_fee_bps: u16,
+    pub protocol_fee_bps: u16, // fixed at creation so later platform changes don't affect running lotteries
+    pub ticket_price: u64,
+    pub endtime: i64,
+    pub total_participants: u64,
+    pub bump: u8,
+    pub vault_bump: u8
+}
+
+/// Lamport split of a hosted pot.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HostedSplit {
+    pub protocol_fee: u64,
+    pub host_fee: u64,
+    pub prize: u64
+}
+
+impl HostedLottery {
+    /// The protocol cut comes off the whole pot, then the host fee off what remains.
+    pub fn split(&self, pot: u64) -> Result<HostedSplit> {
+        let protocol_fee = bps_of(pot, self.protocol_fee_bps)?;
+        let (host_fee, prize) = split_fee(pot - protocol_fee, self.host_fee_bps)?;
+
+        Ok(HostedSplit { protocol_fee, host_fee, prize })
+    }
+}
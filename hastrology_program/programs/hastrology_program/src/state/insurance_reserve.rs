@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Lamports set aside out of each `payout`'s platform share, held in its own lamports to
+/// compensate users after a failed payout or a compromised round. Only `compensate` spends
+/// it, and only after `COMPENSATION_TIMELOCK`. `initialize_insurance_reserve` creates it.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct InsuranceReserve {
+    pub balance: u64, // lamports held for compensation, on top of rent
+    pub committed_lamports: u64, // proposed compensations not yet paid or cancelled
+    pub total_compensated: u64,
+    pub compensation_count: u64, // ids handed out so far; the next proposal takes this one
+    pub bump: u8
+}
+
+impl InsuranceReserve {
+    /// Records reserve lamports the caller has just credited to this account.
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    /// Sets `amount` aside for a proposed compensation and returns the id it takes.
+    pub fn commit(&mut self, amount: u64) -> Result<u64> {
+        let committed = self.committed_lamports.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+
+        require!(
+            committed <= self.balance,
+            HashtrologyErrors::InsuranceReserveTooLow
+        );
+
+        let id = self.compensation_count;
+        self.committed_lamports = committed;
+        self.compensation_count = id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(id)
+    }
+
+    /// Drops a cancelled compensation's commitment.
+    pub fn release(&mut self, amount: u64) {
+        self.committed_lamports = self.committed_lamports.saturating_sub(amount);
+    }
+
+    /// Records a committed compensation the caller is about to pay out of this account.
+    pub fn pay(&mut self, amount: u64) -> Result<()> {
+        self.release(amount);
+        self.balance = self.balance.checked_sub(amount).ok_or(HashtrologyErrors::InsuranceReserveTooLow)?;
+        self.total_compensated = self.total_compensated.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+}
+
+/// One compensation from the reserve. Kept once paid, as the record of who was compensated,
+/// for which round and why.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct Compensation {
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub lottery_id: u64, // round the compensation is for
+    pub reason_hash: [u8; 32], // hash of the published incident report
+    pub proposed_by: Pubkey,
+    pub activates_at: i64,
+    pub paid_at: i64, // 0 until compensate pays it
+    pub bump: u8
+}
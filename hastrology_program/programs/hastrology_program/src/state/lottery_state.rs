@@ -1,6 +1,21 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    Discriminator,
+    solana_program::{
+        hash::hashv,
+        instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked}
+    }
+};
 
+use crate::{constants::{MAX_SNIPE_WINDOW_ENTRIES, MAX_STREAK_BONUS_STEPS, MAX_TICKETS_PER_ROUND, MAX_TICKET_CLASSES, MS_PER_SLOT, SOFT_MAX_PLATFORM_FEE_BPS}, errors::HashtrologyErrors, instructions::initialize::fresh_state, math::split_fee, randomness::{provider_for, RandomnessProvider}, require_ctx, state::{CurrencyInfo, EntryEligibility, PendingConfig, Role, RoleGrant, RoundKind, SnipeWindow, TicketClass}};
+
+// New fields are appended after the bumps so the original layout stays a
+// stable prefix for clients that decode this account by offset. Accounts created
+// with the original layout are too short to load until `migrate_lottery_state`
+// grows them.
 #[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(InitSpace)]
 pub struct LotteryState{
     // ----Config----
@@ -20,5 +35,1031 @@ pub struct LotteryState{
 
     // ----Bumps----
     pub lottery_state_bump: u8,
+    pub pot_vault_bump: u8,
+
+    // ----Draw Requests----
+    pub draw_request_id: u64,
+    pub resolved_request_id: u64,
+
+    // ----Prize Config----
+    pub max_pot_lamports: u64, // 0 means no cap
+    pub prize_mint: Pubkey, // Pubkey::default() when prizes are SOL only
+    pub token_prize_amount: u64,
+
+    // ----Draw Attestation----
+    pub draw_threshold: u8, // 0 disables attestation
+    pub attestation_min_pot: u64,
+    #[max_len(5)]
+    pub draw_operators: Vec<Pubkey>,
+
+    // ----Pot Staking----
+    pub stake_pool_program: Pubkey, // Pubkey::default() disables staking
+    pub stake_pool: Pubkey,
+    pub staked_lamports: u64,
+    pub stake_yield_to_prize: bool, // false sends yield to the platform wallet
+
+    // ----No-Loss Mode----
+    pub no_loss_mode: bool, // mirrors `round_kind == NoLoss` for clients decoding by offset
+    pub total_deposits: u64, // unwithdrawn principal across all rounds
+    pub no_loss_total_weight: u128,
+    pub no_loss_winning_weight: u128,
+
+    // ----Referrals----
+    pub referral_bonus_bps: u16, // share of the platform fee paid to the top referrer
+    pub reserved_referral_lamports: u64, // held in the pot vault until distributed
+
+    // ----Eligibility----
+    pub entry_eligibility: EntryEligibility,
+
+    // ----Draw Retries----
+    pub draw_retries: u8, // retries of the current round's draw; reset by request_draw
+
+    // ----Merkle Entries----
+    pub merkle_entry_mode: bool, // entries are leaves of a per-round Merkle tree instead of ticket PDAs
+
+    // ----Anti-Snipe----
+    pub anti_snipe_max_extension: i64, // 0 disables the hidden end time
+    pub late_entry_lamports: u64, // refunds owed to entries after a hidden end, held in the pot vault
+
+    // ----Rate Limits----
+    pub max_entries_per_wallet: u32, // per round; 0 means unlimited
+    pub max_entries_per_slot: u32, // across all wallets; 0 means unlimited
+    pub rate_limit_slot: u64,
+    pub rate_limit_slot_entries: u32,
+
+    // ----Round Kind----
+    pub round_kind: RoundKind,
+
+    // ----Keeper Rewards----
+    pub keeper_reward_lamports: u64, // paid from the pot to whoever triggers a draw; 0 disables
+
+    // ----Currency Metadata----
+    pub payment_currency: CurrencyInfo, // what ticket prices and the pot are denominated in
+    pub token_prize_currency: Option<CurrencyInfo>, // None when prizes are SOL only
+
+    // ----Winner Reveal----
+    pub reveal_delay: i64, // seconds between resolve_draw and reveal_winner; 0 reveals immediately
+    pub winner_commitment: [u8; 32], // all zeroes when no reveal is pending
+    pub reveal_after: i64,
+
+    // ----Round Opening----
+    pub round_duration: i64, // length of rounds started by open_round
+    pub round_finalized: bool, // set by payout; entries and draws wait for open_round
+
+    // ----Draw Window Queueing----
+    pub queue_entries_while_drawing: bool, // entries between draw request and open_round are escrowed for the next round
+
+    // ----Config Timelock----
+    pub pending_config: Option<PendingConfig>, // applied by apply_config once its activation time passes
+
+    // ----Seasons----
+    pub season_length: i64, // seconds; 0 disables the one-win-per-season rule
+    pub season_start: i64,
+
+    // ----Events----
+    pub event_seq: u64, // sequence number of the last emitted event
+
+    // ----CPI Guard----
+    #[max_len(5)]
+    pub cpi_allowlist: Vec<Pubkey>, // programs whose transactions may enter through CPI; direct entries are always allowed
+
+    // ----Prize Floor----
+    pub min_prize_lamports: u64, // winner prizes are topped up from the fee treasury to this; 0 disables
+
+    // ----Entropy Mixing----
+    pub entropy_mix_min_pot: u64, // pots this large need a committed operator nonce to draw; 0 disables
+    pub entropy_commitment: [u8; 32], // hash of the operator nonce for this round; zero when none
+    pub entropy_mix_pending: bool, // VRF output is in, waiting for reveal_draw_nonce
+
+    // ----Prize Swaps----
+    pub swap_program: Pubkey, // DEX that claim_swapped_prize routes through; Pubkey::default() disables swaps
+    pub swap_prize_lamports: u64, // paid-out prizes held in the pot vault until swapped
+
+    // ----Early Bird----
+    pub early_bird_window: i64, // seconds after a round opens in which entries earn bonus tickets; 0 disables
+    pub early_bird_bonus: u8, // free tickets per paid entry inside the window
+    pub round_started_at: i64,
+
+    // ----Roles----
+    #[max_len(8)]
+    pub role_grants: Vec<RoleGrant>,
+    pub entries_paused: bool,
+
+    // ----Fee Governance----
+    pub fee_governance: Pubkey, // Pubkey::default() until set; fees stay at or under the soft cap
+    pub fee_ceiling_bps: u16, // approved by fee_governance; only above the soft cap once active
+    pub fee_ceiling_activates_at: i64,
+
+    // ----KYC Escrow----
+    pub kyc_verifier: Pubkey, // approves escrowed entries; Pubkey::default() disables the mode
+
+    // ----Mega Jackpot----
+    pub mega_jackpot_bps: u16, // cut of each ticket sale sent to the shared mega jackpot; 0 unlinks the lottery
+
+    // ----Clock Skew----
+    pub end_slot: u64, // estimated slot of lottery_endtime; reaching either ends the round
+    pub endtime_grace: i64, // seconds the cluster clock may trail lottery_endtime and still count as past it
+
+    // ----Held Prizes----
+    pub held_prize_lamports: u64, // prizes payout couldn't credit, kept in the pot vault until claim_held_prize
+
+    // ----Win Streaks----
+    pub streak_bonus_lamports: u64, // treasury bonus per consecutive win after the first; 0 disables
+    pub streak_wallet: Pubkey, // winner of streak_round
+    pub streak_round: u64,
+    pub win_streak: u16, // consecutive rounds streak_wallet has won, ending at streak_round
+
+    // ----Vault Ledger----
+    pub vault_ledger_base: u64, // pot vault balance when the ledger was last rebased
+    pub vault_inflow_lamports: u64, // entries, deposits, rollovers and top-ups tracked into the vault since then
+    pub vault_outflow_lamports: u64, // prizes, fees, refunds and rewards tracked out of it
+    pub needs_review: bool, // set by reconcile_vault when the vault drifts past VAULT_REVIEW_THRESHOLD
+
+    // ----Winner Hook----
+    pub winner_hook_program: Pubkey, // called by payout with the round's result; Pubkey::default() disables
+
+    // ----Ticket Classes----
+    #[max_len(MAX_TICKET_CLASSES)]
+    pub ticket_classes: Vec<TicketClass>, // sold by enter_lottery next to regular tickets; empty disables
+    pub class_weight_total: u64, // extra draw weight of this round's class tickets
+    pub class_winning_weight: Option<u64>, // drawn point in class_weight_total, until locate_class_winner names its ticket
+
+    // ----Loser Rebates----
+    pub reward_mint: Pubkey, // platform reward token, minted by the REWARD_MINT_AUTHORITY_SEED PDA
+    pub loser_reward_amount: u64, // reward tokens each losing ticket can claim; 0 disables
+
+    // ----Prize Rounding----
+    pub prize_granularity: u64, // winner prizes are rounded down to a multiple of this, the dust going to the fee treasury; 0 disables
+
+    // ----Round Announcements----
+    pub announced_opens_at: i64, // the next round can't open before this; 0 when it isn't announced
+
+    // ----Trivial Rounds----
+    pub trivial_resolution: bool, // single-ticket rounds may skip the VRF through resolve_trivial_round
+
+    // ----Insurance Reserve----
+    pub insurance_bps: u16, // share of each payout's pot moved from the platform share into the insurance reserve
+
+    // ----Dual Draw----
+    pub dual_draw_min_pot: u64, // pots at least this large draw from both oracle queues; 0 disables
+    pub second_oracle_queue: Pubkey,
+
+    // ----Fee Promotion----
+    // Entries paid between `fee_promo_start` and `fee_promo_end` in round `fee_promo_lottery_id`
+    // are charged `fee_promo_bps` at payout instead of `platform_fee_bps`
+    pub fee_promo_lottery_id: u64,
+    pub fee_promo_start: i64,
+    pub fee_promo_end: i64, // 0 when no promotion is set
+    pub fee_promo_bps: u16,
+    pub fee_promo_lamports: u64, // paid into the pot inside the window so far
+
+    // ----Class Draws----
+    pub class_draws: bool, // each ticket class also draws its own pot among its own tickets, instead of extra main-draw weight
+    pub class_pots: [u64; MAX_TICKET_CLASSES], // this round's class pots: what class tickets paid above the regular price
+    pub class_prize_lamports: u64, // class pots of paid-out rounds, kept in the pot vault until claim_class_prize
+
+    // ----Sunset----
+    pub sunset_at: i64, // when the program was set to wind down for good; 0 while live
+
+    // ----Slot Deadline----
+    pub slot_deadline: bool, // rounds end by `end_slot` alone, ignoring the cluster clock
+    pub min_round_slots: u64, // with slot_deadline, slots a round lasts at least, however end_slot was estimated
+    pub round_started_slot: u64,
+
+    // ----Randomness Provider----
+    pub randomness_provider: Pubkey, // program draws request randomness from; one of `randomness::provider_for`
+    pub randomness_queue: Pubkey, // the provider's queue requests go to
+
+    // ----Device Commitments----
+    pub max_entries_per_device: u32, // per device commitment per round; 0 disables, and commitments are then optional
+}
+
+impl LotteryState {
+    /// The authority holds every role and `Role::Admin` satisfies any check.
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        *member == self.authority
+            || self.role_grants
+                .iter()
+                .any(|grant| grant.member == *member && (grant.role == role || grant.role == Role::Admin))
+    }
+
+    /// Advances and returns the event sequence number. Every emitted event carries one.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq += 1;
+        self.event_seq
+    }
+
+    /// Rejects an entry made through CPI unless the transaction's top-level instruction
+    /// belongs to an allowlisted program. The runtime doesn't expose the immediate caller,
+    /// so the outermost program is the one held responsible for the wrapper.
+    pub fn check_entry_caller(&self, instructions_sysvar: &AccountInfo) -> Result<()> {
+        if get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Ok(());
+        }
+
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let outer = load_instruction_at_checked(current_index as usize, instructions_sysvar)?;
+
+        require!(
+            self.cpi_allowlist.contains(&outer.program_id),
+            HashtrologyErrors::CpiEntryNotAllowed
+        );
+
+        Ok(())
+    }
+
+    /// Index of the season `now` falls in, counted from `season_start`.
+    pub fn current_season(&self, now: i64) -> u64 {
+        if self.season_length <= 0 {
+            return 0;
+        }
+
+        (now.saturating_sub(self.season_start).max(0) / self.season_length) as u64
+    }
+
+    /// Highest platform fee `update_config` may set at `now`: the soft cap, unless fee
+    /// governance has approved a higher ceiling and its timelock has passed.
+    pub fn max_platform_fee_bps(&self, now: i64) -> u16 {
+        if self.fee_ceiling_bps > SOFT_MAX_PLATFORM_FEE_BPS && now >= self.fee_ceiling_activates_at {
+            self.fee_ceiling_bps
+        } else {
+            SOFT_MAX_PLATFORM_FEE_BPS
+        }
+    }
+
+    /// Lamports of the current round's pot that were paid in during its fee promotion.
+    pub fn round_promo_lamports(&self) -> u64 {
+        if self.fee_promo_lottery_id == self.current_lottery_id {
+            self.fee_promo_lamports
+        } else {
+            0
+        }
+    }
+
+    /// Counts an entry payment toward the fee promotion if it lands inside the window.
+    pub fn record_promo_entry(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.fee_promo_lottery_id == self.current_lottery_id
+            && (self.fee_promo_start..self.fee_promo_end).contains(&now)
+        {
+            self.fee_promo_lamports = self.fee_promo_lamports
+                .checked_add(amount)
+                .ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Platform fee and prize out of `pot_lamports`, with what was paid in during the
+    /// round's fee promotion charged at `fee_promo_bps`.
+    pub fn split_round_fee(&self, pot_lamports: u64) -> Result<(u64, u64)> {
+        let promo_lamports = self.round_promo_lamports().min(pot_lamports);
+
+        let (fee, prize) = split_fee(pot_lamports - promo_lamports, self.platform_fee_bps)?;
+        let (promo_fee, promo_prize) = split_fee(promo_lamports, self.fee_promo_bps)?;
+
+        Ok((
+            fee.checked_add(promo_fee).ok_or(HashtrologyErrors::Overflow)?,
+            prize.checked_add(promo_prize).ok_or(HashtrologyErrors::Overflow)?
+        ))
+    }
+
+    /// Fails once the program is winding down: no new tickets, rounds or draws, only refunds,
+    /// claims and draws already underway.
+    pub fn check_not_sunset(&self) -> Result<()> {
+        require_ctx!(
+            self.sunset_at == 0,
+            HashtrologyErrors::ProgramSunset,
+            "lottery_id={} sunset_at={}", self.current_lottery_id, self.sunset_at
+        );
+
+        Ok(())
+    }
+
+    /// Whether a sunset has left the current round's tickets to be refunded instead of drawn.
+    pub fn sunset_refunds_open(&self) -> bool {
+        self.sunset_at != 0 && !self.is_drawing && !self.round_finalized && self.total_participants > 0
+    }
+
+    /// Short name of where the round stands, logged alongside errors.
+    pub fn phase(&self) -> &'static str {
+        if self.sunset_at != 0 && !self.is_drawing {
+            "sunset"
+        } else if self.round_finalized {
+            "finalized"
+        } else if self.is_drawing {
+            "drawing"
+        } else if self.entries_paused {
+            "paused"
+        } else {
+            "open"
+        }
+    }
+
+    /// 1-based number of the next ticket. Ticket PDAs are seeded with the
+    /// 0-based index as a u32, so the round is capped at `MAX_TICKETS_PER_ROUND`.
+    pub fn next_ticket_number(&self) -> Result<u32> {
+        require_ctx!(
+            !self.round_finalized,
+            HashtrologyErrors::RoundNotOpen,
+            "lottery_id={} phase={}", self.current_lottery_id, self.phase()
+        );
+
+        require!(
+            !self.entries_paused,
+            HashtrologyErrors::EntriesPaused
+        );
+
+        self.check_not_sunset()?;
+
+        require_ctx!(
+            self.total_participants < MAX_TICKETS_PER_ROUND as u64,
+            HashtrologyErrors::RoundIsFull,
+            "lottery_id={} participants={} max={}", self.current_lottery_id, self.total_participants, MAX_TICKETS_PER_ROUND
+        );
+
+        self.round_kind.check_ticket_supply(self.total_participants)?;
+
+        Ok(self.total_participants as u32 + 1)
+    }
+
+    /// Switches the game mode between rounds, keeping the legacy no-loss flag in sync.
+    pub fn set_round_kind(&mut self, round_kind: RoundKind) -> Result<()> {
+        // Only switch between rounds so tickets and deposits never share a pot
+        require!(
+            !self.is_drawing && self.total_participants == 0,
+            HashtrologyErrors::CannotSwitchModeMidRound
+        );
+
+        require!(
+            round_kind.has_single_winner() || !self.merkle_entry_mode,
+            HashtrologyErrors::MerkleEntryModeActive
+        );
+
+        require!(
+            round_kind.has_single_winner() || self.ticket_classes.is_empty(),
+            HashtrologyErrors::TicketClassesNeedSingleWinner
+        );
+
+        self.round_kind = round_kind;
+        self.no_loss_mode = round_kind == RoundKind::NoLoss;
+
+        Ok(())
+    }
+
+    /// Counts an entry against the per-slot limit.
+    pub fn record_slot_entry(&mut self, slot: u64) -> Result<()> {
+        self.check_slot_entry(slot)?;
+
+        if self.rate_limit_slot != slot {
+            self.rate_limit_slot = slot;
+            self.rate_limit_slot_entries = 0;
+        }
+
+        self.rate_limit_slot_entries += 1;
+
+        Ok(())
+    }
+
+    pub fn check_slot_entry(&self, slot: u64) -> Result<()> {
+        let slot_entries = if self.rate_limit_slot == slot { self.rate_limit_slot_entries } else { 0 };
+
+        require!(
+            self.max_entries_per_slot == 0 || slot_entries < self.max_entries_per_slot,
+            HashtrologyErrors::SlotEntryLimitReached
+        );
+
+        Ok(())
+    }
+
+    /// Whether the current round takes tickets from a wallet entering directly, which the
+    /// KYC gate may require to hold a credential.
+    pub fn check_ticket_entry(&self) -> Result<()> {
+        self.check_takes_tickets(false)?;
+        self.check_kyc_gate()
+    }
+
+    /// With KYC escrow on, only a credential gate counts as verification for direct entry.
+    pub fn check_kyc_gate(&self) -> Result<()> {
+        require!(
+            self.kyc_verifier == Pubkey::default() || self.entry_eligibility != EntryEligibility::Open,
+            HashtrologyErrors::KycApprovalRequired
+        );
+
+        Ok(())
+    }
+
+    /// Whether the current round takes tickets at all, as Merkle leaves when `merkle_leaf`
+    /// and as ticket accounts otherwise.
+    pub fn check_takes_tickets(&self, merkle_leaf: bool) -> Result<()> {
+        require_ctx!(
+            !self.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing,
+            "lottery_id={} phase={} draw_request_id={}", self.current_lottery_id, self.phase(), self.draw_request_id
+        );
+
+        require!(
+            self.round_kind.accepts_tickets(),
+            HashtrologyErrors::NoLossModeActive
+        );
+
+        if merkle_leaf {
+            require!(
+                self.merkle_entry_mode,
+                HashtrologyErrors::MerkleEntryModeDisabled
+            );
+        } else {
+            require!(
+                !self.merkle_entry_mode,
+                HashtrologyErrors::MerkleEntryModeActive
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Pot balance once one more ticket is paid in, failing if that passes the cap.
+    pub fn check_pot_cap(&self, pot_lamports: u64, price: u64) -> Result<u64> {
+        let pot_after_entry = pot_lamports
+            .checked_add(price)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        require_ctx!(
+            self.max_pot_lamports == 0 || pot_after_entry <= self.max_pot_lamports,
+            HashtrologyErrors::PotCapReached,
+            "pot_after_entry={} max_pot={}", pot_after_entry, self.max_pot_lamports
+        );
+
+        Ok(pot_after_entry)
+    }
+
+    /// Free tickets a paid entry at `now` earns, capped by the room left in the round after it.
+    pub fn early_bird_bonus_at(&self, now: i64) -> u8 {
+        if self.early_bird_bonus == 0
+            || now >= self.round_started_at.saturating_add(self.early_bird_window)
+            || self.in_snipe_window(now)
+        {
+            return 0;
+        }
+
+        let after_entry = self.total_participants + 1;
+        let mut room = (MAX_TICKETS_PER_ROUND as u64).saturating_sub(after_entry);
+
+        if let RoundKind::Raffle { ticket_supply } = self.round_kind {
+            room = room.min((ticket_supply as u64).saturating_sub(after_entry));
+        }
+
+        room.min(self.early_bird_bonus as u64) as u8
+    }
+
+    /// Checks an entry against the anti-snipe window. Returns whether the entry falls in
+    /// it, in which case it must be recorded in `snipe_window`.
+    pub fn check_snipe_entry(&self, now: i64, snipe_window: Option<&SnipeWindow>) -> Result<bool> {
+        if !self.in_snipe_window(now) {
+            return Ok(false);
+        }
+
+        require_ctx!(
+            now < self.lottery_endtime.saturating_add(self.anti_snipe_max_extension),
+            HashtrologyErrors::SnipeWindowClosed,
+            "now={} window_end={}", now, self.lottery_endtime.saturating_add(self.anti_snipe_max_extension)
+        );
+
+        let snipe_window = snipe_window.ok_or(HashtrologyErrors::SnipeWindowRequired)?;
+
+        require!(
+            snipe_window.entries.len() < MAX_SNIPE_WINDOW_ENTRIES,
+            HashtrologyErrors::SnipeWindowFull
+        );
+
+        Ok(true)
+    }
+
+    /// Closes a settled round. The next one only starts with `open_round`.
+    pub fn finalize_round(&mut self) {
+        self.winner = 0;
+        self.is_drawing = false;
+        self.commit_slot = 0;
+        self.round_finalized = true;
+    }
+
+    /// Starts the next round with the current config. Keeps the existing end-time
+    /// cadence, unless the round was opened so late that its slot already passed.
+    pub fn open_next_round(&mut self, clock: &Clock) -> Result<()> {
+        require!(
+            self.round_finalized,
+            HashtrologyErrors::RoundNotFinalized
+        );
+
+        self.check_not_sunset()?;
+
+        let now = clock.unix_timestamp;
+
+        require!(
+            now >= self.announced_opens_at,
+            HashtrologyErrors::AnnouncementNotDue
+        );
+
+        let scheduled_endtime = self.lottery_endtime
+            .checked_add(self.round_duration)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        let endtime = if scheduled_endtime > now {
+            scheduled_endtime
+        } else {
+            now.checked_add(self.round_duration).ok_or(HashtrologyErrors::Overflow)?
+        };
+        self.set_endtime(endtime, clock);
+        self.current_lottery_id = self.current_lottery_id.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.total_participants = 0;
+        self.round_finalized = false;
+        self.entropy_commitment = [0u8; 32];
+        self.round_started_at = now;
+        self.round_started_slot = clock.slot;
+        self.class_weight_total = 0;
+        self.class_winning_weight = None;
+        self.announced_opens_at = 0;
+
+        for ticket_class in self.ticket_classes.iter_mut() {
+            ticket_class.tickets_sold = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Hash binding the drawn winner (and no-loss weight) to this round's VRF output.
+    pub fn winner_commitment_for(&self, winner: u64, winning_weight: u128, randomness: &[u8; 32]) -> [u8; 32] {
+        hashv(&[
+            &self.current_lottery_id.to_le_bytes(),
+            &winner.to_le_bytes(),
+            &winning_weight.to_le_bytes(),
+            randomness
+        ]).to_bytes()
+    }
+
+    /// Lamports the current round pays out of, leaving what the vault holds for referrers,
+    /// late entries and prizes waiting to be swapped or claimed.
+    pub fn round_pot(&self, pot_vault_lamports: u64) -> Result<u64> {
+        let pot_lamports = pot_vault_lamports
+            .checked_sub(self.reserved_referral_lamports)
+            .and_then(|balance| balance.checked_sub(self.late_entry_lamports))
+            .and_then(|balance| balance.checked_sub(self.swap_prize_lamports))
+            .and_then(|balance| balance.checked_sub(self.held_prize_lamports))
+            .and_then(|balance| balance.checked_sub(self.class_pots.iter().sum()))
+            .and_then(|balance| balance.checked_sub(self.class_prize_lamports))
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(pot_lamports)
+    }
+
+    /// Lamports the vault holds on behalf of referrers, late entries and swapped prizes.
+    pub fn reserved_lamports(&self) -> Result<u64> {
+        let reserved = self.reserved_referral_lamports
+            .checked_add(self.late_entry_lamports)
+            .and_then(|reserved| reserved.checked_add(self.swap_prize_lamports))
+            .and_then(|reserved| reserved.checked_add(self.held_prize_lamports))
+            .and_then(|reserved| reserved.checked_add(self.class_prize_lamports))
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(reserved)
+    }
+
+    /// Fails when the counters disagree with the vault: tickets recorded with nothing to pay
+    /// them from, or more reserved than the vault holds. `reconcile` repairs either.
+    pub fn check_accounting(&self, spendable_lamports: u64) -> Result<()> {
+        let pot_lamports = spendable_lamports
+            .checked_sub(self.reserved_lamports()?)
+            .ok_or(HashtrologyErrors::AccountingMismatch)?;
+
+        require!(
+            self.total_participants == 0
+                || pot_lamports > 0
+                || self.ticket_price == 0
+                || !self.round_kind.accepts_tickets(),
+            HashtrologyErrors::AccountingMismatch
+        );
+
+        Ok(())
+    }
+
+    /// Hash over what a draw depends on: the round, its tickets, its pot and the payout config.
+    /// Taken by `request_draw` and checked again before the draw is resolved and paid.
+    pub fn round_snapshot_hash(&self, pot_lamports: u64) -> Result<[u8; 32]> {
+        let mut round_kind = Vec::new();
+        self.round_kind.serialize(&mut round_kind)?;
+
+        Ok(hashv(&[
+            &self.current_lottery_id.to_le_bytes(),
+            &self.total_participants.to_le_bytes(),
+            &pot_lamports.to_le_bytes(),
+            self.platform_wallet.as_ref(),
+            &self.platform_fee_bps.to_le_bytes(),
+            &self.ticket_price.to_le_bytes(),
+            &self.referral_bonus_bps.to_le_bytes(),
+            self.prize_mint.as_ref(),
+            &self.token_prize_amount.to_le_bytes(),
+            &self.min_prize_lamports.to_le_bytes(),
+            &round_kind
+        ]).to_bytes())
+    }
+
+    /// Hash an operator commits to before the round ends, binding its nonce to this round.
+    pub fn entropy_commitment_for(&self, nonce: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&self.current_lottery_id.to_le_bytes(), nonce]).to_bytes()
+    }
+
+    pub fn has_entropy_commitment(&self) -> bool {
+        self.entropy_commitment != [0u8; 32]
+    }
+
+    pub fn has_pending_reveal(&self) -> bool {
+        self.winner_commitment != [0u8; 32]
+    }
+
+    /// Slot `endtime` is expected to arrive at, counting from the current slot at `MS_PER_SLOT`.
+    pub fn end_slot_for(endtime: i64, clock: &Clock) -> u64 {
+        let seconds_left = endtime.saturating_sub(clock.unix_timestamp).max(0) as u64;
+        clock.slot.saturating_add(seconds_left.saturating_mul(1000) / MS_PER_SLOT)
+    }
+
+    /// Moves the round end, keeping `end_slot` in step with `lottery_endtime`.
+    pub fn set_endtime(&mut self, endtime: i64, clock: &Clock) {
+        self.lottery_endtime = endtime;
+        self.end_slot = Self::end_slot_for(endtime, clock);
+    }
+
+    /// Whether the round is past `endtime`, which may add the anti-snipe window to `lottery_endtime`.
+    /// Either deadline counts: the cluster clock within `endtime_grace`, or the matching slot,
+    /// so a validator clock running behind can't hold up a draw the app already shows as due.
+    /// With `slot_deadline` only the slot counts, and no sooner than `min_round_slots` after
+    /// the round opened, so a skewed `unix_timestamp` can't end a round early.
+    pub fn has_ended(&self, endtime: i64, clock: &Clock) -> bool {
+        let window_slots = (endtime.saturating_sub(self.lottery_endtime).max(0) as u64)
+            .saturating_mul(1000) / MS_PER_SLOT;
+
+        if self.slot_deadline {
+            return clock.slot >= self.end_slot.saturating_add(window_slots)
+                && clock.slot >= self.round_started_slot.saturating_add(self.min_round_slots);
+        }
+
+        clock.unix_timestamp.saturating_add(self.endtime_grace) >= endtime
+            || (self.end_slot > 0 && clock.slot >= self.end_slot.saturating_add(window_slots))
+    }
+
+    /// Extends the winner's streak when they also won the previous round, or starts a new
+    /// one, and returns its length.
+    pub fn record_win_streak(&mut self, winner: Pubkey) -> u16 {
+        let consecutive = self.win_streak > 0
+            && self.streak_wallet == winner
+            && self.streak_round.checked_add(1) == Some(self.current_lottery_id);
+
+        self.win_streak = if consecutive { self.win_streak.saturating_add(1) } else { 1 };
+        self.streak_wallet = winner;
+        self.streak_round = self.current_lottery_id;
+
+        self.win_streak
+    }
+
+    /// Bonus owed for a win making a streak of `win_streak`: one step per consecutive win
+    /// after the first, up to `MAX_STREAK_BONUS_STEPS`.
+    pub fn streak_bonus(&self, win_streak: u16) -> u64 {
+        let steps = win_streak.saturating_sub(1).min(MAX_STREAK_BONUS_STEPS) as u64;
+        self.streak_bonus_lamports.saturating_mul(steps)
+    }
+
+    /// Replaces the ticket classes between rounds, like `set_round_kind`.
+    pub fn set_ticket_classes(&mut self, ticket_classes: Vec<TicketClass>) -> Result<()> {
+        require!(
+            !self.is_drawing && self.total_participants == 0,
+            HashtrologyErrors::CannotSwitchModeMidRound
+        );
+
+        require!(
+            ticket_classes.len() <= MAX_TICKET_CLASSES,
+            HashtrologyErrors::TooManyTicketClasses
+        );
+
+        require!(
+            ticket_classes.is_empty() || self.round_kind.has_single_winner(),
+            HashtrologyErrors::TicketClassesNeedSingleWinner
+        );
+
+        for ticket_class in &ticket_classes {
+            ticket_class.validate()?;
+        }
+
+        self.ticket_classes = ticket_classes;
+        self.class_weight_total = 0;
+
+        Ok(())
+    }
+
+    /// Price of a ticket of class `ticket_class`, or a regular ticket for `None`.
+    pub fn ticket_price_for(&self, ticket_class: Option<u8>) -> Result<u64> {
+        match ticket_class {
+            Some(index) => self.ticket_classes
+                .get(index as usize)
+                .map(|ticket_class| ticket_class.price)
+                .ok_or_else(|| HashtrologyErrors::UnknownTicketClass.into()),
+            None => Ok(self.ticket_price)
+        }
+    }
+
+    /// Tickets sold this round per class, for the round history.
+    pub fn class_tickets_sold(&self) -> [u32; MAX_TICKET_CLASSES] {
+        let mut sold = [0u32; MAX_TICKET_CLASSES];
+
+        for (count, ticket_class) in sold.iter_mut().zip(&self.ticket_classes) {
+            *count = ticket_class.tickets_sold;
+        }
+
+        sold
+    }
+
+    /// Counts a ticket of class `index` and returns where its extra weight starts, or with
+    /// class draws, its position among the class's tickets.
+    pub fn record_class_ticket(&mut self, index: u8) -> Result<u64> {
+        let ticket_class = self.ticket_classes
+            .get_mut(index as usize)
+            .ok_or(HashtrologyErrors::UnknownTicketClass)?;
+
+        let position = ticket_class.tickets_sold as u64;
+        ticket_class.tickets_sold = ticket_class.tickets_sold.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        if self.class_draws {
+            return Ok(position);
+        }
+
+        let weight_start = self.class_weight_total;
+        self.class_weight_total = weight_start
+            .checked_add(ticket_class.extra_weight())
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(weight_start)
+    }
+
+    /// With class draws, sets aside what a class ticket paid above the regular price as its
+    /// class's pot, out of the `pot_contribution` that reached the vault.
+    pub fn record_class_pot(&mut self, ticket_class: Option<u8>, pot_contribution: u64) -> Result<()> {
+        let Some(index) = ticket_class.filter(|_| self.class_draws) else {
+            return Ok(());
+        };
+
+        let premium = self.ticket_price_for(Some(index))?
+            .saturating_sub(self.ticket_price)
+            .min(pot_contribution);
+
+        let class_pot = &mut self.class_pots[index as usize];
+        *class_pot = class_pot.checked_add(premium).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Moves the round's class pots to the reserve claim_class_prize pays from, once the
+    /// main prize is paid.
+    pub fn reserve_class_pots(&mut self) -> Result<()> {
+        let class_pots: u64 = self.class_pots.iter().sum();
+
+        self.class_prize_lamports = self.class_prize_lamports
+            .checked_add(class_pots)
+            .ok_or(HashtrologyErrors::Overflow)?;
+        self.class_pots = [0; MAX_TICKET_CLASSES];
+
+        Ok(())
+    }
+
+    /// Switches rounds to slot deadlines between rounds, like `set_ticket_classes`. The minimum
+    /// can't exceed a round's expected length, or every round would overrun its end time.
+    pub fn set_slot_deadline(&mut self, enabled: bool, min_round_slots: u64) -> Result<()> {
+        require!(
+            !self.is_drawing && self.total_participants == 0,
+            HashtrologyErrors::CannotSwitchModeMidRound
+        );
+
+        let round_slots = (self.round_duration.max(0) as u64).saturating_mul(1000) / MS_PER_SLOT;
+
+        require_ctx!(
+            min_round_slots <= round_slots,
+            HashtrologyErrors::InvalidMinRoundSlots,
+            "min_round_slots={} round_slots={}", min_round_slots, round_slots
+        );
+
+        self.slot_deadline = enabled;
+        self.min_round_slots = min_round_slots;
+
+        Ok(())
+    }
+
+    /// The configured randomness provider.
+    pub fn provider(&self) -> Result<&'static dyn RandomnessProvider> {
+        provider_for(&self.randomness_provider)
+    }
+
+    /// Who resolve instructions accept randomness from; nobody while the provider is unknown.
+    pub fn randomness_callback_signer(&self) -> Pubkey {
+        self.provider().map_or(Pubkey::default(), |provider| provider.callback_signer())
+    }
+
+    /// Switches draws to another provider and queue. Not while a draw waits on the old one.
+    pub fn set_randomness_provider(&mut self, program_id: Pubkey, queue: Option<Pubkey>) -> Result<()> {
+        require!(
+            !self.is_drawing,
+            HashtrologyErrors::LotteryIsDrawing
+        );
+
+        let provider = provider_for(&program_id)?;
+
+        self.randomness_provider = program_id;
+        self.randomness_queue = queue.unwrap_or_else(|| provider.default_queue());
+
+        Ok(())
+    }
+
+    /// Turns class draws on or off between rounds, like `set_ticket_classes`.
+    pub fn set_class_draws(&mut self, enabled: bool) -> Result<()> {
+        require!(
+            !self.is_drawing && self.total_participants == 0,
+            HashtrologyErrors::CannotSwitchModeMidRound
+        );
+
+        self.class_draws = enabled;
+        self.class_weight_total = 0;
+
+        Ok(())
+    }
+
+    /// Rebate the round being drawn pays each losing ticket: only single-winner rounds, where
+    /// losing is just not holding the drawn ticket, and only with a reward mint configured.
+    pub fn loser_reward_for_round(&self) -> u64 {
+        if self.reward_mint == Pubkey::default() || !self.round_kind.has_single_winner() {
+            return 0;
+        }
+
+        self.loser_reward_amount
+    }
+
+    pub fn record_vault_inflow(&mut self, lamports: u64) -> Result<()> {
+        self.vault_inflow_lamports = self.vault_inflow_lamports.checked_add(lamports).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_vault_outflow(&mut self, lamports: u64) -> Result<()> {
+        self.vault_outflow_lamports = self.vault_outflow_lamports.checked_add(lamports).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    /// Balance the pot vault should hold if every lamport moved through a tracked instruction.
+    pub fn expected_vault_lamports(&self) -> i128 {
+        self.vault_ledger_base as i128 + self.vault_inflow_lamports as i128 - self.vault_outflow_lamports as i128
+    }
+
+    /// Restarts the ledger from the vault's current balance.
+    pub fn rebase_vault_ledger(&mut self, pot_vault_lamports: u64) {
+        self.vault_ledger_base = pot_vault_lamports;
+        self.vault_inflow_lamports = 0;
+        self.vault_outflow_lamports = 0;
+        self.needs_review = false;
+    }
+
+    /// Whether entries now fall inside the anti-snipe window and must be logged.
+    pub fn in_snipe_window(&self, now: i64) -> bool {
+        self.anti_snipe_max_extension > 0 && now >= self.lottery_endtime
+    }
+}
+
+/// `LotteryState` as `initialize` created it before any field was appended. Frozen so
+/// `migrate_lottery_state` keeps reading those accounts however the live layout grows;
+/// it shares `LotteryState`'s discriminator.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyLotteryState {
+    pub authority: Pubkey,
+    pub pot_vault: Pubkey,
+    pub platform_wallet: Pubkey,
+    pub platform_fee_bps: u16,
+    pub ticket_price: u64,
+    pub winner: u64,
+    pub current_lottery_id: u64,
+    pub total_participants: u64,
+    pub is_drawing: bool,
+    pub lottery_endtime: i64,
+    pub commit_slot: u64,
+    pub lottery_state_bump: u8,
     pub pot_vault_bump: u8
+}
+
+impl LegacyLotteryState {
+    pub const LEN: usize = 8 + 32 * 3 + 2 + 8 * 4 + 1 + 8 * 2 + 1 + 1;
+
+    pub fn load(info: &AccountInfo) -> Result<LegacyLotteryState> {
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+
+        let data = info.try_borrow_data()?;
+
+        require!(
+            data.get(..8) == Some(LotteryState::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+
+    /// The state in the current layout: the round in progress carries over and every field
+    /// added since starts where `initialize` would start it.
+    pub fn into_current(self, clock: &Clock) -> LotteryState {
+        LotteryState {
+            winner: self.winner,
+            current_lottery_id: self.current_lottery_id,
+            total_participants: self.total_participants,
+            is_drawing: self.is_drawing,
+            commit_slot: self.commit_slot,
+            ..fresh_state(
+                self.authority,
+                self.pot_vault,
+                self.platform_wallet,
+                self.ticket_price,
+                self.platform_fee_bps,
+                self.lottery_endtime,
+                self.lottery_state_bump,
+                self.pot_vault_bump,
+                clock
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_state() -> LegacyLotteryState {
+        LegacyLotteryState {
+            authority: Pubkey::new_unique(),
+            pot_vault: Pubkey::new_unique(),
+            platform_wallet: Pubkey::new_unique(),
+            platform_fee_bps: 250,
+            ticket_price: 10_000_000,
+            winner: 3,
+            current_lottery_id: 42,
+            total_participants: 7,
+            is_drawing: false,
+            lottery_endtime: 1_700_003_600,
+            commit_slot: 99,
+            lottery_state_bump: 254,
+            pot_vault_bump: 253
+        }
+    }
+
+    fn with_legacy_account<F: FnOnce(&AccountInfo)>(discriminator: &[u8], f: F) {
+        let mut data = discriminator.to_vec();
+        legacy_state().serialize(&mut data).unwrap();
+        assert_eq!(data.len(), LegacyLotteryState::LEN);
+
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        f(&account);
+    }
+
+    #[test]
+    fn legacy_state_loads_from_original_layout() {
+        with_legacy_account(LotteryState::DISCRIMINATOR, |account| {
+            let legacy = LegacyLotteryState::load(account).unwrap();
+            assert_eq!(legacy.current_lottery_id, 42);
+            assert_eq!(legacy.pot_vault_bump, 253);
+        });
+    }
+
+    #[test]
+    fn legacy_state_rejects_other_accounts() {
+        with_legacy_account(&[0u8; 8], |account| {
+            assert!(LegacyLotteryState::load(account).is_err());
+        });
+    }
+
+    #[test]
+    fn migrated_state_keeps_round_and_defaults_new_fields() {
+        let legacy = legacy_state();
+        let authority = legacy.authority;
+        let clock = Clock { slot: 500, unix_timestamp: 1_700_000_000, ..Clock::default() };
+
+        let state = legacy.into_current(&clock);
+
+        assert_eq!(state.authority, authority);
+        assert_eq!(state.ticket_price, 10_000_000);
+        assert_eq!(state.platform_fee_bps, 250);
+        assert_eq!(state.winner, 3);
+        assert_eq!(state.current_lottery_id, 42);
+        assert_eq!(state.total_participants, 7);
+        assert_eq!(state.commit_slot, 99);
+        assert_eq!(state.lottery_endtime, 1_700_003_600);
+        assert_eq!((state.lottery_state_bump, state.pot_vault_bump), (254, 253));
+        assert_eq!(state.end_slot, LotteryState::end_slot_for(1_700_003_600, &clock));
+        assert_eq!(state.max_pot_lamports, 0);
+        assert!(matches!(state.round_kind, RoundKind::Standard));
+        assert!(state.role_grants.is_empty());
+
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= 8 + LotteryState::INIT_SPACE);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LOTTO_MIN_MATCHES, LOTTO_TIER_COUNT};
+
+/// Settlement of a `RoundKind::LuckyNumbers` round. Holds the prize pool in its own
+/// lamports: winning tickets register their tier until `registration_ends`, then
+/// `finalize_lotto_tiers` fixes each tier's share and returns the rest to the pot.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct LottoRound {
+    pub lottery_id: u64,
+    pub drawn_numbers: u64, // bit `n` set for each drawn number `n`
+    pub registration_ends: i64,
+    pub tier_pools: [u64; LOTTO_TIER_COUNT],
+    pub tier_counts: [u32; LOTTO_TIER_COUNT],
+
+    // ----Filled by finalize_lotto_tiers----
+    pub is_finalized: bool,
+    pub tier_shares: [u64; LOTTO_TIER_COUNT],
+    pub claimed_count: u32,
+
+    pub bump: u8
+}
+
+impl LottoRound {
+    /// Prize tier for a match count, or `None` below `LOTTO_MIN_MATCHES`.
+    pub fn tier_of(matches: u8) -> Option<usize> {
+        (matches as usize)
+            .checked_sub(LOTTO_MIN_MATCHES)
+            .filter(|tier| *tier < LOTTO_TIER_COUNT)
+    }
+}
+
+/// Numbers picked for one ticket, seeded by its round and ticket index.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct LottoPick {
+    pub lottery_id: u64,
+    pub ticket_index: u32,
+    pub numbers: u64, // bit `n` set for each picked number `n`
+    pub tier: u8, // set on registration
+    pub is_registered: bool,
+    pub is_claimed: bool,
+    pub bump: u8
+}
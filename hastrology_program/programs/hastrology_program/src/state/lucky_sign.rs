@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ZODIAC_SIGN_COUNT;
+
+/// Sign tally for a `RoundKind::LuckySign` round. After settlement it holds the
+/// co-winners' prize pool in its own lamports until every share is claimed.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct LuckySignRound {
+    pub lottery_id: u64,
+    pub sign_counts: [u32; ZODIAC_SIGN_COUNT],
+
+    // ----Filled by settle_lucky_sign----
+    pub is_settled: bool,
+    pub winning_sign: u8,
+    pub share_lamports: u64,
+    pub claimed_count: u32,
+
+    pub bump: u8
+}
+
+impl LuckySignRound {
+    pub fn winner_count(&self) -> u32 {
+        self.sign_counts[self.winning_sign as usize]
+    }
+}
+
+/// The sign picked for one ticket, seeded by its round and ticket index.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct LuckySignPick {
+    pub lottery_id: u64,
+    pub ticket_index: u32,
+    pub sign: u8,
+    pub is_claimed: bool,
+    pub bump: u8
+}
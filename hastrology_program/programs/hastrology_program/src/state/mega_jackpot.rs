@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_MEGA_JACKPOT_SEGMENTS, errors::HashtrologyErrors};
+
+/// A run of consecutive tickets of one round linked into the mega jackpot: ticket
+/// `first_ticket + i` of `lottery_id` holds jackpot entry `first_entry + i`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MegaJackpotSegment {
+    pub lottery_id: u64,
+    pub first_ticket: u64,
+    pub first_entry: u64,
+    pub entries: u64
+}
+
+/// Shared jackpot fed by a cut of every linked lottery's ticket sales and held in its own
+/// lamports. Once per `draw_interval` one entry is drawn over every ticket linked during the
+/// epoch. `initialize_mega_jackpot` creates it.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct MegaJackpot {
+    pub epoch: u64,
+    pub draw_interval: i64,
+    pub next_draw_at: i64,
+    pub jackpot_lamports: u64, // owed to the next winner, on top of rent
+    pub total_entries: u64, // tickets linked this epoch
+    #[max_len(MAX_MEGA_JACKPOT_SEGMENTS)]
+    pub segments: Vec<MegaJackpotSegment>,
+    pub is_drawing: bool,
+    pub request_slot: u64,
+    pub winning_entry: Option<u64>,
+    pub bump: u8
+}
+
+impl MegaJackpot {
+    /// Whether new tickets can still join this epoch: not once the draw has started, or the segment list is full.
+    pub fn accepts_entries(&self, lottery_id: u64, first_ticket: u64) -> bool {
+        if self.is_drawing || self.winning_entry.is_some() {
+            return false;
+        }
+
+        self.extends_last_segment(lottery_id, first_ticket) || self.segments.len() < MAX_MEGA_JACKPOT_SEGMENTS
+    }
+
+    /// Links `tickets` consecutive tickets starting at `first_ticket` and records their contribution.
+    /// Callers check `accepts_entries` first.
+    pub fn link(&mut self, lottery_id: u64, first_ticket: u64, tickets: u64, contribution: u64) -> Result<()> {
+        if self.extends_last_segment(lottery_id, first_ticket) {
+            let segment = self.segments.last_mut().ok_or(HashtrologyErrors::Overflow)?;
+            segment.entries = segment.entries.checked_add(tickets).ok_or(HashtrologyErrors::Overflow)?;
+        } else {
+            self.segments.push(MegaJackpotSegment {
+                lottery_id,
+                first_ticket,
+                first_entry: self.total_entries,
+                entries: tickets
+            });
+        }
+
+        self.total_entries = self.total_entries.checked_add(tickets).ok_or(HashtrologyErrors::Overflow)?;
+        self.jackpot_lamports = self.jackpot_lamports.checked_add(contribution).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Round and ticket index holding jackpot entry `entry`.
+    pub fn ticket_for_entry(&self, entry: u64) -> Option<(u64, u64)> {
+        self.segments
+            .iter()
+            .find(|segment| entry >= segment.first_entry && entry - segment.first_entry < segment.entries)
+            .map(|segment| (segment.lottery_id, segment.first_ticket + (entry - segment.first_entry)))
+    }
+
+    /// Clears the linked tickets and schedules the next draw. The jackpot balance carries over.
+    pub fn start_next_epoch(&mut self, now: i64) -> Result<()> {
+        self.epoch = self.epoch.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.next_draw_at = now.checked_add(self.draw_interval).ok_or(HashtrologyErrors::Overflow)?;
+        self.total_entries = 0;
+        self.segments.clear();
+        self.is_drawing = false;
+        self.request_slot = 0;
+        self.winning_entry = None;
+
+        Ok(())
+    }
+
+    fn extends_last_segment(&self, lottery_id: u64, first_ticket: u64) -> bool {
+        self.segments
+            .last()
+            .is_some_and(|segment| segment.lottery_id == lottery_id && segment.first_ticket + segment.entries == first_ticket)
+    }
+}
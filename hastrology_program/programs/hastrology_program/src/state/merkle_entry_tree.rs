@@ -0,0 +1,91 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+use crate::constants::MERKLE_TREE_DEPTH;
+
+/// Append-only incremental Merkle tree over a round's entries. Only the frontier and root
+/// live on-chain; leaves are emitted as events and indexers rebuild proofs from them.
+/// Kept in-program rather than CPI-ing into spl-account-compression so we don't take on
+/// its pinned Anchor version.
+#[account(zero_copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MerkleEntryTree {
+    pub lottery_id: u64,
+    pub leaf_count: u64,
+    pub root: [u8; 32],
+    pub filled_subtrees: [[u8; 32]; MERKLE_TREE_DEPTH]
+}
+
+impl MerkleEntryTree {
+    pub fn leaf(user: &Pubkey, lottery_id: u64, index: u32) -> [u8; 32] {
+        hashv(&[&[0u8], user.as_ref(), &lottery_id.to_le_bytes(), &index.to_le_bytes()]).to_bytes()
+    }
+
+    fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&[1u8], left, right]).to_bytes()
+    }
+
+    pub fn init(&mut self, lottery_id: u64) {
+        let mut zero = [0u8; 32];
+
+        for level in 0..MERKLE_TREE_DEPTH {
+            self.filled_subtrees[level] = zero;
+            zero = Self::node(&zero, &zero);
+        }
+
+        self.lottery_id = lottery_id;
+        self.leaf_count = 0;
+        self.root = zero;
+    }
+
+    /// Appends `leaf` at index `leaf_count` and updates the root.
+    pub fn append(&mut self, leaf: [u8; 32]) {
+        self.root = Self::append_to(&mut self.filled_subtrees, self.leaf_count, leaf);
+        self.leaf_count += 1;
+    }
+
+    /// Appends `leaf` at `index` to the tree whose frontier is `filled_subtrees`, returning
+    /// the new root. Lets other accounts build trees proven the same way as a round's.
+    pub fn append_to(filled_subtrees: &mut [[u8; 32]; MERKLE_TREE_DEPTH], index: u64, leaf: [u8; 32]) -> [u8; 32] {
+        let mut index = index;
+        let mut node = leaf;
+        let mut zero = [0u8; 32];
+
+        for subtree in filled_subtrees.iter_mut() {
+            if index.is_multiple_of(2) {
+                *subtree = node;
+                node = Self::node(&node, &zero);
+            } else {
+                node = Self::node(subtree, &node);
+            }
+
+            zero = Self::node(&zero, &zero);
+            index /= 2;
+        }
+
+        node
+    }
+
+    /// Root of a tree with no leaves.
+    pub fn empty_root() -> [u8; 32] {
+        (0..MERKLE_TREE_DEPTH).fold([0u8; 32], |zero, _| Self::node(&zero, &zero))
+    }
+
+    /// Folds `leaf` at `index` up through `proof` to the root it implies.
+    pub fn root_from_proof(leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> [u8; 32] {
+        proof.iter().enumerate().fold(leaf, |node, (level, sibling)| {
+            if (index >> level) & 1 == 0 {
+                Self::node(&node, sibling)
+            } else {
+                Self::node(sibling, &node)
+            }
+        })
+    }
+
+    pub fn verify(&self, leaf: [u8; 32], index: u64, proof: &[[u8; 32]]) -> bool {
+        if proof.len() != MERKLE_TREE_DEPTH || index >= self.leaf_count {
+            return false;
+        }
+
+        Self::root_from_proof(leaf, index, proof) == self.root
+    }
+}
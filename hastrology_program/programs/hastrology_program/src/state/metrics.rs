@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Lifetime counters for monitoring, so dashboards can read one account instead of replaying history.
+/// Handlers update it only when it is passed in; `initialize_metrics` creates it.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct Metrics {
+    pub total_entries: u64,
+    pub total_lamports_processed: u64,
+    pub draws_requested: u64,
+    pub failed_draws: u64, // requests superseded before their VRF callback landed
+    pub retried_draws: u64,
+    pub paused_seconds: u64, // time entries were blocked by an in-flight draw
+    pub pause_started_at: i64, // 0 while entries are open
+    pub vault_discrepancy: i64, // pot vault balance minus the ledger's expected balance at the last reconcile_vault
+    pub vault_checked_at: i64,
+    pub bump: u8
+}
+
+impl Metrics {
+    pub fn record_entry(&mut self, lamports: u64) -> Result<()> {
+        self.total_entries = self.total_entries.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.total_lamports_processed = self.total_lamports_processed.checked_add(lamports).ok_or(HashtrologyErrors::Overflow)?;
+        Ok(())
+    }
+
+    pub fn record_draw_request(&mut self, is_retry: bool, previous_unresolved: bool, now: i64) -> Result<()> {
+        self.draws_requested = self.draws_requested.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        if is_retry {
+            self.retried_draws = self.retried_draws.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        if previous_unresolved {
+            self.failed_draws = self.failed_draws.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        }
+
+        if self.pause_started_at == 0 {
+            self.pause_started_at = now;
+        }
+
+        Ok(())
+    }
+
+    pub fn record_vault_check(&mut self, discrepancy: i64, now: i64) {
+        self.vault_discrepancy = discrepancy;
+        self.vault_checked_at = now;
+    }
+
+    pub fn end_pause(&mut self, now: i64) -> Result<()> {
+        if self.pause_started_at > 0 {
+            let paused = now.saturating_sub(self.pause_started_at).max(0) as u64;
+            self.paused_seconds = self.paused_seconds.checked_add(paused).ok_or(HashtrologyErrors::Overflow)?;
+            self.pause_started_at = 0;
+        }
+
+        Ok(())
+    }
+}
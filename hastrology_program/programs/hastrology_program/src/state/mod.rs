@@ -1,5 +1,85 @@
 pub mod lottery_state;
 pub mod user;
+pub mod draw_attestation;
+pub mod queued_entry;
+pub mod round_history;
+pub mod participant_index;
+pub mod no_loss_deposit;
+pub mod audit_log;
+pub mod referral;
+pub mod entry_eligibility;
+pub mod metrics;
+pub mod session_authority;
+pub mod merkle_entry_tree;
+pub mod snipe_window;
+pub mod player_profile;
+pub mod round_kind;
+pub mod currency_info;
+pub mod lucky_sign;
+pub mod ticket_page;
+pub mod registry;
+pub mod entry_pool;
+pub mod pending_config;
+pub mod season_winners;
+pub mod lotto;
+pub mod fee_treasury;
+pub mod claim_bitmap;
+pub mod hosted_lottery;
+pub mod prize_swap;
+pub mod role;
+pub mod winner_record;
+pub mod pending_kyc_entry;
+pub mod mega_jackpot;
+pub mod ticket_class;
+pub mod config_bounds;
+pub mod upcoming_round;
+pub mod conditional_entry;
+pub mod insurance_reserve;
+pub mod history_archive;
+pub mod pot_vault;
+pub mod nft_prize;
+pub mod device_entries;
+pub mod airdrop_snapshot;
 
 pub use lottery_state::*;
-pub use user::*;
\ No newline at end of file
+pub use user::*;
+pub use draw_attestation::*;
+pub use queued_entry::*;
+pub use round_history::*;
+pub use participant_index::*;
+pub use no_loss_deposit::*;
+pub use audit_log::*;
+pub use referral::*;
+pub use entry_eligibility::*;
+pub use metrics::*;
+pub use session_authority::*;
+pub use merkle_entry_tree::*;
+pub use snipe_window::*;
+pub use player_profile::*;
+pub use round_kind::*;
+pub use currency_info::*;
+pub use lucky_sign::*;
+pub use ticket_page::*;
+pub use registry::*;
+pub use entry_pool::*;
+pub use pending_config::*;
+pub use season_winners::*;
+pub use lotto::*;
+pub use fee_treasury::*;
+pub use claim_bitmap::*;
+pub use hosted_lottery::*;
+pub use prize_swap::*;
+pub use role::*;
+pub use winner_record::*;
+pub use pending_kyc_entry::*;
+pub use mega_jackpot::*;
+pub use ticket_class::*;
+pub use config_bounds::*;
+pub use upcoming_round::*;
+pub use conditional_entry::*;
+pub use insurance_reserve::*;
+pub use history_archive::*;
+pub use pot_vault::*;
+pub use nft_prize::*;
+pub use device_entries::*;
+pub use airdrop_snapshot::*;
\ No newline at end of file
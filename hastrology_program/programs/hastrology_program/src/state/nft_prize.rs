@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// An NFT attached to a round as a prize on top of the SOL pot. The token sits in an
+/// escrow account owned by the lottery state until the round's winner claims it, or the
+/// depositor reclaims it because the round ended without one.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct NftPrize {
+    pub lottery_id: u64,
+    pub mint: Pubkey,
+    pub depositor: Pubkey, // gets the NFT back on reclaim, and the escrow rent either way
+    pub attached_at: i64,
+    pub bump: u8
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// A withdrawable no-loss entry. It wins when the drawn weight falls inside
+/// `[weight_start, weight_start + weight)`, where `weight` is lamports times the
+/// seconds left in the round at deposit time.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct NoLossDeposit {
+    pub user: Pubkey,
+    pub lottery_id: u64,
+    pub amount: u64,
+    pub weight_start: u128,
+    pub weight: u128,
+    pub withdrawn: bool
+}
@@ -0,0 +1,85 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{Transfer, transfer},
+    Discriminator
+};
+
+use crate::{constants::PARTICIPANT_INDEX_SEED, lamports::create_program_account, ID};
+
+/// Ordered wallets of a round; `participants[i]` owns ticket `i`.
+/// Starts empty and grows by one slot per entry, with the entrant paying the extra rent.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParticipantIndex {
+    pub lottery_id: u64,
+    pub participants: Vec<Pubkey>
+}
+
+impl ParticipantIndex {
+    // discriminator + lottery_id + Vec length prefix
+    const PARTICIPANTS_OFFSET: usize = 8 + 8 + 4;
+
+    pub fn space(count: usize) -> usize {
+        Self::PARTICIPANTS_OFFSET + count * 32
+    }
+
+    /// Number of participants, read without deserializing the whole index.
+    pub fn raw_len(data: &[u8]) -> usize {
+        data.get(Self::PARTICIPANTS_OFFSET - 4..Self::PARTICIPANTS_OFFSET)
+            .map_or(0, |len| u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize)
+    }
+
+    /// Owner of ticket `index`, read without deserializing the whole index.
+    pub fn raw_participant(data: &[u8], index: usize) -> Option<Pubkey> {
+        let start = Self::PARTICIPANTS_OFFSET + index * 32;
+        let bytes: [u8; 32] = data.get(start..start + 32)?.try_into().ok()?;
+        Some(Pubkey::new_from_array(bytes))
+    }
+
+    /// Appends `participant` as the owner of the round's next ticket, creating the index with
+    /// the round's first entry. Writes the raw bytes like `raw_participant` reads them, so an
+    /// entry costs the same however many came before it.
+    pub fn raw_push<'info>(
+        index: &AccountInfo<'info>,
+        lottery_id: u64,
+        bump: u8,
+        participant: Pubkey,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        if index.owner != &ID {
+            let lottery_id_bytes = lottery_id.to_le_bytes();
+            let seeds: &[&[u8]] = &[PARTICIPANT_INDEX_SEED, &lottery_id_bytes, &[bump]];
+            create_program_account(index, payer, system_program, Self::space(0), &[seeds])?;
+
+            let mut data = index.try_borrow_mut_data()?;
+            data[..8].copy_from_slice(ParticipantIndex::DISCRIMINATOR);
+            data[8..16].copy_from_slice(&lottery_id_bytes);
+        }
+
+        let count = Self::raw_len(&index.try_borrow_data()?);
+        let new_len = Self::space(count + 1);
+
+        let required_lamports = Rent::get()?.minimum_balance(new_len);
+        let top_up = required_lamports.saturating_sub(index.lamports());
+
+        if top_up > 0 {
+            let accounts = Transfer {
+                from: payer.clone(),
+                to: index.clone()
+            };
+
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), accounts);
+
+            transfer(cpi_ctx, top_up)?;
+        }
+
+        index.realloc(new_len, false)?;
+
+        let mut data = index.try_borrow_mut_data()?;
+        data[Self::PARTICIPANTS_OFFSET - 4..Self::PARTICIPANTS_OFFSET].copy_from_slice(&(count as u32 + 1).to_le_bytes());
+        data[Self::space(count)..new_len].copy_from_slice(participant.as_ref());
+
+        Ok(())
+    }
+}
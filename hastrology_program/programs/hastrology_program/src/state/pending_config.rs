@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Config change proposed by `update_config`. Nothing takes effect until `apply_config`
+/// runs after `activates_at`, so entrants always see fee or price changes coming.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PendingConfig {
+    pub ticket_price: Option<u64>,
+    pub platform_fee_bps: Option<u16>,
+    pub platform_wallet: Option<Pubkey>,
+    pub lottery_endtime: Option<i64>,
+    pub max_pot_lamports: Option<u64>,
+    pub referral_bonus_bps: Option<u16>,
+    pub activates_at: i64
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Entry escrowed while its user awaits KYC approval. `approve_entry` turns it into a ticket
+/// in the same round; until then the user can take it back with `refund_pending_entry`.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PendingKycEntry {
+    pub user: Pubkey,
+    pub lottery_id: u64, // only approvable while this round is open
+    pub amount: u64, // escrowed lamports, held by this account
+    pub created_at: i64,
+    pub bump: u8
+}
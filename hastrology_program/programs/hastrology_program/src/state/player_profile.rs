@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_SELF_EXCLUSION, SPENDING_CAP_INCREASE_DELAY, SPENDING_PERIOD},
+    errors::HashtrologyErrors,
+    ID
+};
+
+/// Per-wallet counters used to rate-limit entries that don't create a receipt PDA,
+/// plus the wallet's self-imposed spending cap and exclusion.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PlayerProfile {
+    pub user: Pubkey,
+    pub lottery_id: u64, // round `round_entries` counts towards
+    pub round_entries: u32,
+    pub total_entries: u64,
+    pub bump: u8,
+
+    // ----Wins----
+    pub season: u64, // season `season_wins` counts towards
+    pub season_wins: u32,
+    pub total_wins: u32,
+
+    // ----Spending Cap----
+    pub spending_cap: u64, // lamports per `SPENDING_PERIOD`; 0 means no cap
+    pub pending_spending_cap: u64, // raise waiting out `SPENDING_CAP_INCREASE_DELAY`
+    pub pending_cap_effective_at: i64, // 0 when no raise is pending
+    pub period_start: i64,
+    pub period_spent: u64,
+
+    // ----Self-Exclusion----
+    pub excluded_until: i64, // no entries before this time; only ever extended
+}
+
+impl PlayerProfile {
+    /// Reads a profile passed as an unchecked account, or `None` while the wallet hasn't
+    /// created one. Entry paths take it unchecked so leaving it out can't skip its limits.
+    pub fn load(info: &AccountInfo) -> Result<Option<PlayerProfile>> {
+        if info.owner != &ID {
+            return Ok(None);
+        }
+
+        Ok(Some(PlayerProfile::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Writes a profile read with `load` back to its account.
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+
+    /// Holds an entry costing `amount` to the wallet's self-exclusion and spending cap and
+    /// records the spend. A wallet without a profile has neither.
+    pub fn charge(info: &AccountInfo, amount: u64, now: i64) -> Result<()> {
+        if let Some(mut profile) = Self::load(info)? {
+            profile.check_not_excluded(now)?;
+            profile.record_spend(amount, now)?;
+            profile.store(info)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn record_entry(&mut self, lottery_id: u64, max_entries_per_wallet: u32) -> Result<()> {
+        if self.lottery_id != lottery_id {
+            self.lottery_id = lottery_id;
+            self.round_entries = 0;
+        }
+
+        require!(
+            max_entries_per_wallet == 0 || self.round_entries < max_entries_per_wallet,
+            HashtrologyErrors::WalletEntryLimitReached
+        );
+
+        self.round_entries += 1;
+        self.total_entries = self.total_entries.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Lowering the cap applies at once; raising or removing it only after
+    /// `SPENDING_CAP_INCREASE_DELAY`. Returns when the new cap takes effect.
+    pub fn request_spending_cap(&mut self, cap: u64, now: i64) -> Result<i64> {
+        self.apply_pending_cap(now);
+
+        // 0 means no cap, so it ranks above every real cap
+        let is_lower = self.spending_cap == 0 || (cap != 0 && cap <= self.spending_cap);
+
+        if is_lower {
+            self.spending_cap = cap;
+            self.pending_spending_cap = 0;
+            self.pending_cap_effective_at = 0;
+            return Ok(now);
+        }
+
+        self.pending_spending_cap = cap;
+        self.pending_cap_effective_at = now
+            .checked_add(SPENDING_CAP_INCREASE_DELAY)
+            .ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(self.pending_cap_effective_at)
+    }
+
+    /// Counts `amount` against the current period, rejecting it if it would exceed the cap.
+    pub fn record_spend(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.apply_pending_cap(now);
+
+        if now.saturating_sub(self.period_start) >= SPENDING_PERIOD {
+            self.period_start = now;
+            self.period_spent = 0;
+        }
+
+        let period_spent = self.period_spent.checked_add(amount).ok_or(HashtrologyErrors::Overflow)?;
+
+        require!(
+            self.spending_cap == 0 || period_spent <= self.spending_cap,
+            HashtrologyErrors::SpendingCapExceeded
+        );
+
+        self.period_spent = period_spent;
+
+        Ok(())
+    }
+
+    /// Locks the wallet out until `now + duration`. A shorter exclusion never cuts an active one short.
+    pub fn self_exclude(&mut self, duration: i64, now: i64) -> Result<i64> {
+        require!(
+            duration > 0 && duration <= MAX_SELF_EXCLUSION,
+            HashtrologyErrors::InvalidExclusionPeriod
+        );
+
+        let until = now.checked_add(duration).ok_or(HashtrologyErrors::Overflow)?;
+        self.excluded_until = self.excluded_until.max(until);
+
+        Ok(self.excluded_until)
+    }
+
+    pub fn check_not_excluded(&self, now: i64) -> Result<()> {
+        require!(
+            now >= self.excluded_until,
+            HashtrologyErrors::SelfExcluded
+        );
+
+        Ok(())
+    }
+
+    fn apply_pending_cap(&mut self, now: i64) {
+        if self.pending_cap_effective_at != 0 && now >= self.pending_cap_effective_at {
+            self.spending_cap = self.pending_spending_cap;
+            self.pending_spending_cap = 0;
+            self.pending_cap_effective_at = 0;
+        }
+    }
+
+    pub fn record_win(&mut self, season: u64) -> Result<()> {
+        if self.season != season {
+            self.season = season;
+            self.season_wins = 0;
+        }
+
+        self.season_wins = self.season_wins.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.total_wins = self.total_wins.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Typed layout of the pot vault PDA. Vaults start as 8 zero bytes holding only lamports;
+/// `migrate_pot_vault` grows them into this account so accounting can move into the vault
+/// itself. Instructions that only move lamports keep taking the vault as a raw account.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PotVault {
+    // Snapshot of the LotteryState vault ledger at migration
+    pub ledger_base: u64,
+    pub total_inflow: u64,
+    pub total_outflow: u64,
+
+    pub migrated_at: i64,
+    pub bump: u8
+}
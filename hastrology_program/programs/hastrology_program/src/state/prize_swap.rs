@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// A winner's request to receive their SOL prize swapped into `output_mint`. Opened
+/// before payout; `payout` then holds the prize in the pot vault for `claim_swapped_prize`.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct PrizeSwap {
+    pub ticket: Pubkey,
+    pub output_mint: Pubkey,
+    pub lamports: u64, // held prize; 0 until payout
+    pub bump: u8
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Escrowed entry for a round that isn't open yet, queued during a draw or pre-purchased
+/// for a later round. `process_queued_entry` converts it once its round is current.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct QueuedEntry {
+    pub user: Pubkey,
+    pub lottery_id: u64, // the round this entry will be converted into
+    pub amount: u64, // escrowed lamports, held by this account
+    pub bump: u8
+}
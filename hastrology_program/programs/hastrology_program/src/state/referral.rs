@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct ReferralStats {
+    pub referrer: Pubkey,
+    pub lottery_id: u64,
+    pub count: u32,
+    pub bump: u8
+}
+
+/// Per-round referral leaderboard. `bonus_lamports` is carved out of the
+/// platform fee at payout and held in the pot vault until distributed.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct ReferralEpoch {
+    pub lottery_id: u64,
+    pub top_referrer: Pubkey,
+    pub top_count: u32,
+    pub bonus_lamports: u64,
+    pub is_distributed: bool,
+    pub bump: u8
+}
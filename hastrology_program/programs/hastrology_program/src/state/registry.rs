@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Addresses of the program's singleton accounts, written at a fixed PDA so
+/// integrators can bootstrap without hard-coding every seed. `version` bumps
+/// whenever the PDA scheme changes.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct Registry {
+    pub version: u16,
+    pub lottery_state: Pubkey,
+    pub pot_vault: Pubkey,
+    pub audit_log: Pubkey,
+    pub metrics: Pubkey,
+    pub stake_staging: Pubkey,
+    pub prize_vault: Pubkey, // Pubkey::default() when prizes are SOL only
+    pub treasury: Pubkey, // the platform wallet fees are paid to
+    pub authority: Pubkey,
+    pub bump: u8
+}
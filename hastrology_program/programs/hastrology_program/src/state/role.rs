@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Delegated permissions held alongside the authority, which implicitly has every role.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Role {
+    Admin, // config, modes and role grants; satisfies every other role
+    Operator, // payouts, settlements and pot staking
+    FeeManager, // keeper reward, prize floor and the fee treasury
+    Pauser, // pausing and resuming entries
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RoleGrant {
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+/// Fails with `MissingRole` unless `$signer` holds `$role` on `$lottery_state`.
+#[macro_export]
+macro_rules! require_role {
+    ($lottery_state:expr, $signer:expr, $role:expr) => {
+        anchor_lang::require!(
+            $lottery_state.has_role(&$signer.key(), $role),
+            $crate::errors::HashtrologyErrors::MissingRole
+        )
+    };
+}
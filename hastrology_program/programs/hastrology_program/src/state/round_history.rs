@@ -0,0 +1,146 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+use lottery_core::selection::select_ticket;
+
+use crate::{constants::{CLASS_DRAW_DOMAIN, DRAW_SOURCE_PRIMARY, DRAW_SOURCE_SECOND, MAX_TICKET_CLASSES}, errors::HashtrologyErrors, instructions::VrfRandomness, state::{CurrencyInfo, LotteryState, RoundKind}};
+
+/// Per-round draw record. `caller_seed`, `oracle_queue` and `resolve_slot` let a
+/// third party locate the oracle's fulfillment transaction and check its VRF proof
+/// against `randomness`, instead of trusting that the callback signer was honest.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct RoundHistory {
+    pub lottery_id: u64,
+    pub draw_request_id: u64,
+    pub caller_seed: [u8; 32],
+    pub oracle_queue: Pubkey,
+    pub request_slot: u64,
+
+    // ----Filled by resolve_draw----
+    pub randomness: [u8; 32],
+    pub vrf_signer: Pubkey,
+    pub resolve_slot: u64,
+    pub total_participants: u64,
+    pub winner: u64,
+
+    pub bump: u8,
+
+    // ----Currency Metadata----
+    // Snapshot at request time so the round renders correctly after config changes
+    pub payment_currency: CurrencyInfo,
+    pub token_prize_currency: Option<CurrencyInfo>,
+    pub token_prize_amount: u64,
+
+    // ----Replay----
+    // Mode the round was drawn in, so verifiers know which selection to rerun
+    pub round_kind: RoundKind,
+    pub winner_rerolls: u8, // draws skipped because the wallet had already won this season
+
+    // ----Entropy Mixing----
+    pub entropy_mixed: bool, // the winner is drawn from `mixed_randomness`, not the raw VRF output
+    pub snipe_window_forwarded: bool, // whether the draw applied an anti-snipe window
+    pub entropy_blockhash: [u8; 32], // most recent slot hash when the VRF callback landed
+    pub entropy_nonce: [u8; 32], // operator nonce, zero until revealed
+
+    // ----Round Snapshot----
+    pub snapshot_hash: [u8; 32], // `round_snapshot_hash` at request time
+    pub snapshot_pot: u64, // round pot at request time, after the keeper reward
+    pub late_refunds: u64, // lamports set aside at resolve for entries after the hidden end
+
+    // ----Ticket Classes----
+    pub class_tickets_sold: [u32; MAX_TICKET_CLASSES], // star, moon and sun tickets in the draw; the rest were regular
+    pub class_weight_total: u64, // extra weight those tickets drew with
+
+    // ----Loser Rebates----
+    // Snapshot at request time; single-winner rounds only
+    pub reward_mint: Pubkey,
+    pub loser_reward_amount: u64,
+
+    // ----Dual Draw----
+    // Set when the request also went to a second queue; `randomness` then holds the first
+    // queue's output and the draw waits for both
+    pub second_oracle_queue: Option<Pubkey>,
+    pub second_randomness: [u8; 32],
+    pub draw_sources: u8, // DRAW_SOURCE_* bits of the queues that have called back
+
+    // ----Class Draws----
+    pub class_pots: [u64; MAX_TICKET_CLASSES], // snapshot at request time; 0 for classes without their own draw
+    pub class_prizes_paid: u8, // bit per class whose pot claim_class_prize has paid
+}
+
+impl RoundHistory {
+    /// The VRF output keyed by the operator nonce and mixed with the slot hash, so neither the
+    /// oracle nor the operator alone can steer the result.
+    pub fn mixed_randomness(&self) -> [u8; 32] {
+        hashv(&[&self.entropy_nonce, &self.vrf_output(), &self.entropy_blockhash]).to_bytes()
+    }
+
+    /// The VRF output the draw uses: for a dual draw, the hash of both queues' outputs, so
+    /// neither oracle operator alone can steer the result.
+    pub fn vrf_output(&self) -> [u8; 32] {
+        match self.second_oracle_queue {
+            Some(_) => hashv(&[&self.randomness, &self.second_randomness]).to_bytes(),
+            None => self.randomness,
+        }
+    }
+
+    /// Records one queue's callback. Returns the randomness to draw from once every queue the
+    /// request went to has answered, or `None` while a dual draw waits for the other one.
+    pub fn record_vrf_output(&mut self, source: u8, randomness: [u8; 32]) -> Result<Option<[u8; 32]>> {
+        let expected = match self.second_oracle_queue {
+            Some(_) => DRAW_SOURCE_PRIMARY | DRAW_SOURCE_SECOND,
+            None => DRAW_SOURCE_PRIMARY,
+        };
+
+        // A queue that wasn't asked, or one answering twice, can't count towards the draw
+        require!(
+            expected & source != 0 && self.draw_sources & source == 0,
+            HashtrologyErrors::UnexpectedDrawSource
+        );
+
+        if source == DRAW_SOURCE_SECOND {
+            self.second_randomness = randomness;
+        } else {
+            self.randomness = randomness;
+        }
+
+        self.draw_sources |= source;
+
+        Ok((self.draw_sources == expected).then(|| self.vrf_output()))
+    }
+
+    /// Fails when the tickets or payout config changed since the draw was requested.
+    pub fn verify_snapshot(&self, lottery_state: &LotteryState) -> Result<()> {
+        require!(
+            self.snapshot_hash == lottery_state.round_snapshot_hash(self.snapshot_pot)?,
+            HashtrologyErrors::RoundSnapshotMismatch
+        );
+
+        Ok(())
+    }
+
+    /// Randomness the winner was drawn from, or `None` while the operator nonce is unrevealed.
+    pub fn draw_randomness(&self) -> Option<[u8; 32]> {
+        match self.entropy_mixed {
+            true if self.entropy_nonce == [0u8; 32] => None,
+            true => Some(self.mixed_randomness()),
+            false => Some(self.vrf_output()),
+        }
+    }
+
+    /// Position among class `index`'s tickets that wins the class pot, drawn from the round's
+    /// randomness keyed by the class. `None` while the randomness isn't final, or when the
+    /// class has no pot of its own.
+    pub fn class_winner(&self, index: usize) -> Option<u64> {
+        if self.class_pots.get(index).copied().unwrap_or(0) == 0 || self.resolve_slot == 0 {
+            return None;
+        }
+
+        let randomness = self.draw_randomness()?;
+        let class_randomness = hashv(&[CLASS_DRAW_DOMAIN, &[index as u8], &randomness]).to_bytes();
+
+        // `select_ticket` numbers from 1; positions within a class from 0
+        select_ticket(&VrfRandomness(class_randomness), self.class_tickets_sold[index] as u64)
+            .map(|ticket| ticket - 1)
+    }
+}
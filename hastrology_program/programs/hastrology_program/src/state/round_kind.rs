@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// Game mode of the current round. Handlers ask the kind what is allowed instead of
+/// branching on per-mode flags, so new modes only add a variant and its arms here.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RoundKind {
+    /// Timed round; one uniform draw over all tickets.
+    Standard,
+    /// Fixed ticket supply; can be drawn as soon as it sells out.
+    Raffle { ticket_supply: u32 },
+    /// Withdrawable deposits; only the yield is paid out.
+    NoLoss,
+    /// Ticket holders pick a zodiac sign; the draw picks a sign and every
+    /// matching ticket splits the prize.
+    LuckySign,
+    /// Ticket holders pick numbers and the draw picks as many; prizes are split
+    /// pari-mutuel across tiers by how many numbers each ticket matched.
+    LuckyNumbers
+}
+
+impl RoundKind {
+    pub fn accepts_tickets(&self) -> bool {
+        !matches!(self, RoundKind::NoLoss)
+    }
+
+    pub fn accepts_deposits(&self) -> bool {
+        matches!(self, RoundKind::NoLoss)
+    }
+
+    /// Whether `payout` settles the round, as opposed to a co-winner or deposit settlement.
+    pub fn has_single_winner(&self) -> bool {
+        matches!(self, RoundKind::Standard | RoundKind::Raffle { .. })
+    }
+
+    /// Kind-specific cap on top of `MAX_TICKETS_PER_ROUND`.
+    pub fn check_ticket_supply(&self, total_participants: u64) -> Result<()> {
+        if let RoundKind::Raffle { ticket_supply } = self {
+            require!(
+                total_participants < *ticket_supply as u64,
+                HashtrologyErrors::RoundIsFull
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Whether the draw may be requested; `ended` is whether the round, including any
+    /// anti-snipe window, is past its end.
+    pub fn draw_ready(&self, ended: bool, total_participants: u64) -> bool {
+        match self {
+            RoundKind::Raffle { ticket_supply } => {
+                total_participants >= *ticket_supply as u64 || ended
+            }
+            RoundKind::Standard | RoundKind::NoLoss | RoundKind::LuckySign | RoundKind::LuckyNumbers => ended
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let RoundKind::Raffle { ticket_supply } = self {
+            require!(
+                *ticket_supply > 0,
+                HashtrologyErrors::InvalidRoundKind
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SEASON_WINNERS;
+
+/// Wallets that already won this season. `resolve_draw` re-rolls past them; it reads this
+/// ledger because the VRF callback's accounts are fixed before the winner is known, so it
+/// can't load the winner's `PlayerProfile`.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct SeasonWinners {
+    pub season: u64,
+    #[max_len(MAX_SEASON_WINNERS)]
+    pub winners: Vec<Pubkey>,
+    pub bump: u8
+}
+
+impl SeasonWinners {
+    pub fn has_won(&self, season: u64, wallet: &Pubkey) -> bool {
+        self.season == season && self.winners.contains(wallet)
+    }
+
+    /// Adds a winner, starting a fresh list when the season has rolled over.
+    /// Once full, further winners of the season are no longer excluded.
+    pub fn record(&mut self, season: u64, wallet: Pubkey) {
+        if self.season != season {
+            self.season = season;
+            self.winners.clear();
+        }
+
+        if self.winners.len() < MAX_SEASON_WINNERS && !self.winners.contains(&wallet) {
+            self.winners.push(wallet);
+        }
+    }
+}
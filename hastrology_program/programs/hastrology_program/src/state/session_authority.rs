@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Temporary delegation letting `session_key` buy tickets for `user` without a wallet prompt.
+/// The spend cap is escrowed in this account when the session is created.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct SessionAuthority {
+    pub user: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub spend_cap: u64,
+    pub spent: u64,
+    pub bump: u8
+}
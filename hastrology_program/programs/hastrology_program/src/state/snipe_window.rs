@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SNIPE_WINDOW_ENTRIES;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SnipeEntry {
+    pub ticket_index: u32,
+    pub entered_at: i64
+}
+
+/// Entries made after `lottery_endtime`, inside the anti-snipe window. The true end of
+/// the round is only fixed by the VRF at resolution; entries after it are excluded from
+/// the draw and refundable.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct SnipeWindow {
+    pub lottery_id: u64,
+    pub ticket_price: u64, // refund amount for late entries
+    #[max_len(MAX_SNIPE_WINDOW_ENTRIES)]
+    pub entries: Vec<SnipeEntry>,
+
+    // ----Filled by resolve_draw----
+    pub is_resolved: bool,
+    pub true_endtime: i64,
+    pub eligible_participants: u64,
+
+    pub bump: u8
+}
+
+impl SnipeWindow {
+    /// Tickets are sequential in time, so everything before the first entry past
+    /// `true_endtime` is eligible and everything from it on is late.
+    pub fn eligible_count(&self, true_endtime: i64, total_participants: u64) -> u64 {
+        self.entries
+            .iter()
+            .find(|entry| entry.entered_at > true_endtime)
+            .map_or(total_participants, |entry| entry.ticket_index as u64)
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_TICKET_CLASS_WEIGHT, errors::HashtrologyErrors};
+
+/// A ticket tier sold next to the others in the same round, by index: 0 star, 1 moon, 2 sun.
+/// A class ticket draws with `weight` where a regular ticket draws with 1.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TicketClass {
+    pub price: u64,
+    pub weight: u16,
+    pub tickets_sold: u32, // this round
+}
+
+impl TicketClass {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.price > 0 && (1..=MAX_TICKET_CLASS_WEIGHT).contains(&self.weight),
+            HashtrologyErrors::InvalidTicketClass
+        );
+
+        Ok(())
+    }
+
+    /// Weight the ticket carries on top of the 1 every ticket has.
+    pub fn extra_weight(&self) -> u64 {
+        self.weight.saturating_sub(1) as u64
+    }
+}
+
+/// A class as configured by `set_ticket_classes`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TicketClassConfig {
+    pub price: u64,
+    pub weight: u16,
+}
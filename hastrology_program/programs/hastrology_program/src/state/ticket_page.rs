@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TICKET_PAGE_SIZE;
+
+#[zero_copy]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PackedTicket {
+    pub user: Pubkey,
+    pub ticket_number: u32
+}
+
+/// Compact copy of up to `TICKET_PAGE_SIZE` tickets of a round, so clients read one
+/// account per page instead of one PDA per ticket. Page `p` holds ticket numbers
+/// `p * TICKET_PAGE_SIZE + 1` onwards; `count` entries are filled.
+#[account(zero_copy)]
+pub struct TicketPage {
+    pub lottery_id: u64,
+    pub page: u32,
+    pub count: u32,
+    pub entries: [PackedTicket; TICKET_PAGE_SIZE]
+}
+
+impl TicketPage {
+    pub const SPACE: usize = 8 + std::mem::size_of::<TicketPage>();
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ROUND_THEME_LEN;
+
+/// Pre-announcement of the round after the current one, so apps can count down to it from
+/// chain data. Seeded by the id the round will get; `open_announced_round` opens it and
+/// closes this account back to `announced_by`.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct UpcomingRound {
+    pub lottery_id: u64,
+    #[max_len(MAX_ROUND_THEME_LEN)]
+    pub theme: String,
+    pub expected_prize_seed: u64, // lamports the operator plans to seed the pot with; display only
+    pub opens_at: i64, // the round can't open before this
+    pub announced_by: Pubkey, // paid the rent and gets it back when the round opens
+    pub announced_at: i64,
+    pub bump: u8
+}
@@ -1,14 +1,81 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 
+use crate::{constants::USER_TICKET_SEED, errors::HashtrologyErrors, lamports::create_program_account, ID};
+
+// Receipts written before `ticket_number` shrank from a u64 or before any of the
+// fields after it were added are too short to load. That only matters for a wallet
+// entering again, recording a referral or claiming a sunset refund in a round that
+// spans the upgrade.
 #[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(InitSpace)]
 pub struct UserEntryReceipt {
     pub user: Pubkey,
     pub lottery_id: u64,
-    pub ticket_number: u64 
+    pub ticket_number: u32,
+    pub referral_recorded: bool,
+
+    // ----Payment Proof----
+    pub lamports_paid: u64, // exact amount moved into the pot for the entry
+    pub entry_slot: u64,
+    pub entered_at: i64, // clock.unix_timestamp of the transaction that counted the entry
+
+    // ----Entry Nonce----
+    pub entry_nonce: Option<u64>, // highest client-chosen nonce entered with this round
+
+    // ----Aggregate----
+    // One receipt per wallet per round; the fields above describe its latest entry
+    pub entry_count: u32,
+    pub total_paid: u64, // sum of lamports_paid, so without any mega jackpot cut
+
+    // ----Device----
+    pub device_commitment: Option<[u8; 32]>, // from the latest entry that sent one
+}
+
+impl UserEntryReceipt {
+    /// Adds an entry to the receipt, which `init_if_needed` hands over zeroed for a wallet's
+    /// first entry of the round. A nonce must be higher than any this round, so a resent
+    /// entry fails instead of buying again. Every ticket-creating path records here, so
+    /// `max_entries_per_wallet` (0 for unlimited) holds whichever one the wallet uses.
+    #[allow(clippy::too_many_arguments)]
+    pub fn record_entry(
+        &mut self,
+        user: Pubkey,
+        lottery_id: u64,
+        ticket_number: u32,
+        lamports_paid: u64,
+        clock: &Clock,
+        entry_nonce: Option<u64>,
+        max_entries_per_wallet: u32
+    ) -> Result<()> {
+        require!(
+            max_entries_per_wallet == 0 || self.entry_count < max_entries_per_wallet,
+            HashtrologyErrors::WalletEntryLimitReached
+        );
+
+        if let Some(nonce) = entry_nonce {
+            require!(
+                self.entry_nonce.is_none_or(|last| nonce > last),
+                HashtrologyErrors::EntryNonceReused
+            );
+            self.entry_nonce = Some(nonce);
+        }
+
+        self.user = user;
+        self.lottery_id = lottery_id;
+        self.ticket_number = ticket_number;
+        self.lamports_paid = lamports_paid;
+        self.entry_slot = clock.slot;
+        self.entered_at = clock.unix_timestamp;
+        self.entry_count = self.entry_count.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+        self.total_paid = self.total_paid.checked_add(lamports_paid).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
 }
 
 #[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(InitSpace)]
 pub struct UserTicket {
     pub user: Pubkey,
@@ -16,5 +83,120 @@ pub struct UserTicket {
 
     pub is_winner: bool, // default: false
     pub prize_amount: u64, // default: 0
-    pub is_claimed: bool //default: false
-}
\ No newline at end of file
+    pub is_claimed: bool, //default: false
+    pub token_prize_amount: u64, // default: 0, set at payout in SPL prize mode
+    pub payout_destination: Option<Pubkey>, // default: None, prizes go to `user`
+    pub is_bonus: bool, // free early-bird ticket, numbered right after the paid one it came with
+    pub bonus_tickets: u8, // early-bird tickets granted with this paid one
+    pub held_prize_lamports: u64, // prize kept in the pot vault because the payout address couldn't receive it
+
+    // ----Ticket Classes----
+    pub ticket_class: Option<u8>, // index into LotteryState::ticket_classes; None for a regular ticket
+    pub class_weight_start: u64, // where this ticket's extra weight starts in class_weight_total
+
+    // ----Rent----
+    pub rent_payer: Pubkey, // paid this ticket's rent and gets it back from close_losing_tickets
+
+    // ----Stake Payout----
+    pub stake_vote_account: Option<Pubkey>, // validator a won prize is delegated to by claim_prize_as_stake; None pays liquid SOL
+
+    // ----Held Token Prize----
+    pub held_token_prize: u64, // SPL prize kept in the prize vault because the recipient's token account was frozen
+}
+
+impl UserTicket {
+    /// Where prizes for this ticket are sent.
+    pub fn payout_address(&self) -> Pubkey {
+        self.payout_destination.unwrap_or(self.user)
+    }
+
+    /// Creates the free early-bird ticket at `ticket_index` for `payer`, who also pays its rent.
+    pub fn create_bonus<'info>(
+        ticket: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &Program<'info, System>,
+        lottery_id: u64,
+        ticket_index: u32
+    ) -> Result<()> {
+        let lottery_id_bytes = lottery_id.to_le_bytes();
+        let index_bytes = ticket_index.to_le_bytes();
+        let (address, bump) = Pubkey::find_program_address(&[USER_TICKET_SEED, &lottery_id_bytes, &index_bytes], &ID);
+
+        require_keys_eq!(ticket.key(), address, HashtrologyErrors::InvalidBonusTicket);
+
+        let seeds: &[&[u8]] = &[USER_TICKET_SEED, &lottery_id_bytes, &index_bytes, &[bump]];
+        create_program_account(ticket, payer, system_program, 8 + UserTicket::INIT_SPACE, &[seeds])?;
+
+        let bonus_ticket = UserTicket {
+            user: payer.key(),
+            lottery_id,
+            is_winner: false,
+            prize_amount: 0,
+            is_claimed: false,
+            token_prize_amount: 0,
+            payout_destination: None,
+            is_bonus: true,
+            bonus_tickets: 0,
+            held_prize_lamports: 0,
+            ticket_class: None,
+            class_weight_start: 0,
+            rent_payer: payer.key(),
+            stake_vote_account: None,
+            held_token_prize: 0
+        };
+
+        bonus_ticket.try_serialize(&mut &mut ticket.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+}
+
+/// `UserTicket` as stored under the legacy 8-byte index seed. Frozen so `migrate_ticket`
+/// keeps reading those accounts however the live layout grows; it shares `UserTicket`'s
+/// discriminator.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyUserTicket {
+    pub user: Pubkey,
+    pub lottery_id: u64,
+    pub is_winner: bool,
+    pub prize_amount: u64,
+    pub is_claimed: bool,
+    pub token_prize_amount: u64
+}
+
+impl LegacyUserTicket {
+    pub fn load(info: &AccountInfo) -> Result<LegacyUserTicket> {
+        require_keys_eq!(*info.owner, ID, ErrorCode::AccountOwnedByWrongProgram);
+
+        let data = info.try_borrow_data()?;
+
+        require!(
+            data.get(..8) == Some(UserTicket::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+
+    /// The ticket in the current layout, with everything added since the legacy seeds at
+    /// its default.
+    pub fn into_current(self, rent_payer: Pubkey) -> UserTicket {
+        UserTicket {
+            user: self.user,
+            lottery_id: self.lottery_id,
+            is_winner: self.is_winner,
+            prize_amount: self.prize_amount,
+            is_claimed: self.is_claimed,
+            token_prize_amount: self.token_prize_amount,
+            payout_destination: None,
+            is_bonus: false,
+            bonus_tickets: 0,
+            held_prize_lamports: 0,
+            ticket_class: None,
+            class_weight_start: 0,
+            rent_payer,
+            stake_vote_account: None,
+            held_token_prize: 0
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::HashtrologyErrors;
+
+/// One per wallet and round won, so a wallet's wins can be listed by deriving
+/// `[WINNER_RECORD_SEED, wallet, lottery_id]` for each round instead of scanning them.
+#[account]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(InitSpace)]
+pub struct WinnerRecord {
+    pub wallet: Pubkey, // ticket owner, even when the prize went to a payout destination
+    pub lottery_id: u64,
+    pub prize_lamports: u64, // summed over the wallet's winning tickets in the round
+    pub winning_tickets: u32,
+    pub won_at: i64,
+    pub bump: u8
+}
+
+impl WinnerRecord {
+    pub fn record(&mut self, wallet: Pubkey, lottery_id: u64, prize_lamports: u64, now: i64, bump: u8) -> Result<()> {
+        if self.winning_tickets == 0 {
+            self.wallet = wallet;
+            self.lottery_id = lottery_id;
+            self.won_at = now;
+            self.bump = bump;
+        }
+
+        self.prize_lamports = self.prize_lamports.checked_add(prize_lamports).ok_or(HashtrologyErrors::Overflow)?;
+        self.winning_tickets = self.winning_tickets.checked_add(1).ok_or(HashtrologyErrors::Overflow)?;
+
+        Ok(())
+    }
+}
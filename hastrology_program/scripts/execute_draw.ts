@@ -41,6 +41,11 @@ async function main() {
         program.programId
     );
 
+    const [feeTreasuryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_treasury")],
+        program.programId
+    );
+
     // Fetch State
     let state = await program.account.lotteryState.fetch(lotteryStatePda);
     console.log(`Current Lottery ID: ${state.currentLotteryId.toString()}`);
@@ -69,6 +74,8 @@ async function main() {
                 authority: provider.wallet.publicKey,
                 lotteryState: lotteryStatePda,
                 oracleQueue: VRF_QUEUE,
+                // Large pots also draw from the configured second queue
+                secondOracleQueue: state.dualDrawMinPot.gtn(0) ? state.secondOracleQueue : null,
             })
             .rpc();
 
@@ -111,17 +118,22 @@ async function main() {
         [
             Buffer.from("user-ticket"),
             currentLotteryId.toArrayLike(Buffer, "le", 8),
-            new BN(winnerIndex - 1).toArrayLike(Buffer, "le", 8),
+            new BN(winnerIndex - 1).toArrayLike(Buffer, "le", 4),
         ],
         program.programId
     );
 
     // Fetch Winning Ticket to get User Address
     const ticketAccount = await program.account.userTicket.fetch(winningTicketPda);
-    const winnerPubkey = ticketAccount.user;
+    const winnerPubkey = ticketAccount.payoutDestination ?? ticketAccount.user;
 
     console.log("   Winner Address:", winnerPubkey.toBase58());
 
+    // A registered winner hook has to ride along as the first remaining account
+    const remainingAccounts = state.winnerHookProgram.equals(PublicKey.default)
+        ? []
+        : [{ pubkey: state.winnerHookProgram, isSigner: false, isWritable: false }];
+
     try {
         const payoutSig = await program.methods
             .payout()
@@ -129,11 +141,12 @@ async function main() {
                 authority: provider.wallet.publicKey,
                 lotteryState: lotteryStatePda,
                 potVault: potVaultPda,
-                platformWallet: state.platformWallet,
+                feeTreasury: feeTreasuryPda,
                 winningTicket: winningTicketPda,
                 winner: winnerPubkey,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts)
             .preInstructions([
                 ComputeBudgetProgram.setComputeUnitPrice({
                     microLamports: 5000,
@@ -143,10 +156,28 @@ async function main() {
 
         console.log("✅ Payout Successful! Signature:", payoutSig);
         console.log("   Prize transferred to winner.");
-        console.log("   Lottery Reset for next round.");
 
     } catch (e: any) {
         console.log("❌ Payout Failed:", e.message);
+        return;
+    }
+
+    // Open next round
+    console.log("\n4. Opening Next Round...");
+
+    try {
+        const openSig = await program.methods
+            .openRound()
+            .accounts({
+                signer: provider.wallet.publicKey,
+                lotteryState: lotteryStatePda,
+            })
+            .rpc();
+
+        console.log("✅ Next round opened! Signature:", openSig);
+
+    } catch (e: any) {
+        console.log("❌ Open Round Failed:", e.message);
     }
 
     console.log("=".repeat(60));
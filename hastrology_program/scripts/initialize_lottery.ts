@@ -26,6 +26,11 @@ async function main() {
         program.programId
     );
 
+    const [platformConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+    );
+
     console.log("=".repeat(60));
     console.log("HASTROLOGY LOTTERY INITIALIZATION");
     console.log("=".repeat(60));
@@ -87,6 +92,7 @@ async function main() {
                 authority: provider.wallet.publicKey,
                 lotteryState: lotteryStatePda,
                 potVault: potVaultPda,
+                platformConfig: platformConfigPda,
                 systemProgram: SystemProgram.programId,
             })
             .preInstructions([
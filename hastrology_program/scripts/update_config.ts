@@ -1,16 +1,20 @@
 /**
  * Script to update the lottery configuration
  * 
- * This script calls the update_config instruction to set:
+ * This script calls the update_config instruction to propose:
  * - ticket_price: 0.01 SOL (10,000,000 lamports)
  * - lottery_endtime: End of today (midnight UTC)
+ *
+ * Config changes are timelocked. The proposal is stored as `pending_config` and only
+ * takes effect once `apply` is run after its activation time:
+ *   npx ts-node scripts/update_config.ts apply
  * 
  * Prerequisites:
  * 1. Program must be built with: anchor build
  * 2. Program must be deployed with: anchor deploy
  * 3. Your wallet must be the authority (Dv8EZLYymKDdXnnTuw2M1MD31TjVru5kpnhrk8Ki6wth)
  * 
- * Run with: npx ts-node scripts/update_config.ts
+ * Run with: npx ts-node scripts/update_config.ts [apply]
  */
 
 import * as anchor from "@coral-xyz/anchor";
@@ -38,6 +42,17 @@ async function main() {
         program.programId
     );
 
+    // Derive AuditLog PDA (every config change is recorded there)
+    const [auditLogPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("audit_log")],
+        program.programId
+    );
+
+    const [platformConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+    );
+
     console.log("=".repeat(60));
     console.log("HASTROLOGY CONFIG UPDATE");
     console.log("=".repeat(60));
@@ -66,6 +81,11 @@ async function main() {
         return;
     }
 
+    if (process.argv[2] === "apply") {
+        await applyPendingConfig(program, provider, lotteryStatePda, auditLogPda, stateBefore);
+        return;
+    }
+
     // New values
     const newTicketPrice = new BN(LAMPORTS_PER_SOL / 100); // 0.01 SOL = 10,000,000 lamports
 
@@ -85,7 +105,7 @@ async function main() {
     console.log("");
 
     // Call update_config
-    console.log("Sending update_config transaction...");
+    console.log("Sending update_config proposal...");
 
     try {
         const sig = await program.methods
@@ -93,11 +113,15 @@ async function main() {
                 newTicketPrice,           // new_ticket_price
                 null,                     // new_platform_fee_bps (keep current)
                 null,                     // new_platform_wallet (keep current)
-                newLotteryEndtime         // new_lottery_endtime
+                newLotteryEndtime,        // new_lottery_endtime
+                null,                     // new_max_pot_lamports (keep current)
+                null                      // new_referral_bonus_bps (keep current)
             )
             .accountsStrict({
                 authority: provider.wallet.publicKey,
                 lotteryState: lotteryStatePda,
+                auditLog: auditLogPda,
+                platformConfig: platformConfigPda,
             })
             .preInstructions([
                 ComputeBudgetProgram.setComputeUnitPrice({
@@ -106,11 +130,64 @@ async function main() {
             ])
             .rpc();
 
+        console.log("✅ Proposal submitted!");
+        console.log("   Signature:", sig);
+
+        const stateAfter = await program.account["lotteryState"].fetch(lotteryStatePda);
+        const activatesAt = Number(stateAfter.pendingConfig.activatesAt);
+
+        console.log("   Applies after:", activatesAt, `(${new Date(activatesAt * 1000).toISOString()})`);
+        console.log("   Then run: npx ts-node scripts/update_config.ts apply");
+    } catch (error: any) {
+        console.log("❌ Transaction failed!");
+        console.log("   Error:", error.message);
+
+        if (error.logs) {
+            console.log("\nProgram Logs:");
+            error.logs.forEach((log: string) => console.log("  ", log));
+        }
+    }
+
+    console.log("=".repeat(60));
+}
+
+async function applyPendingConfig(
+    program: Program<any>,
+    provider: anchor.AnchorProvider,
+    lotteryStatePda: PublicKey,
+    auditLogPda: PublicKey,
+    stateBefore: any
+) {
+    const pending = stateBefore.pendingConfig;
+
+    if (!pending) {
+        console.log("❌ ERROR: No pending config change to apply!");
+        return;
+    }
+
+    const activatesAt = Number(pending.activatesAt);
+    if (Date.now() / 1000 < activatesAt) {
+        console.log("❌ ERROR: Pending config is still timelocked until",
+            new Date(activatesAt * 1000).toISOString());
+        return;
+    }
+
+    console.log("Sending apply_config transaction...");
+
+    try {
+        const sig = await program.methods
+            .applyConfig()
+            .accountsStrict({
+                caller: provider.wallet.publicKey,
+                lotteryState: lotteryStatePda,
+                auditLog: auditLogPda,
+            })
+            .rpc();
+
         console.log("✅ Transaction successful!");
         console.log("   Signature:", sig);
         console.log("");
 
-        // Verify the update
         const stateAfter = await program.account["lotteryState"].fetch(lotteryStatePda);
 
         console.log("UPDATED CONFIG:");
@@ -119,19 +196,6 @@ async function main() {
             `(${Number(stateAfter.ticketPrice) / LAMPORTS_PER_SOL} SOL)`);
         console.log("Lottery Endtime:", stateAfter.lotteryEndtime.toString(),
             `(${new Date(Number(stateAfter.lotteryEndtime) * 1000).toISOString()})`);
-
-        if (stateAfter.ticketPrice.eq(newTicketPrice)) {
-            console.log("✅ Ticket price updated correctly!");
-        } else {
-            console.log("❌ Ticket price update failed!");
-        }
-
-        if (stateAfter.lotteryEndtime.eq(newLotteryEndtime)) {
-            console.log("✅ Lottery endtime updated correctly!");
-        } else {
-            console.log("❌ Lottery endtime update failed!");
-        }
-
     } catch (error: any) {
         console.log("❌ Transaction failed!");
         console.log("   Error:", error.message);
@@ -141,8 +205,6 @@ async function main() {
             error.logs.forEach((log: string) => console.log("  ", log));
         }
     }
-
-    console.log("=".repeat(60));
 }
 
 main().catch(console.error);
@@ -2,8 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { BN, Program } from "@coral-xyz/anchor";
 import { HastrologyProgram } from "../target/types/hastrology_program";
 import { assert, use } from "chai";
-import { Connection, Keypair, PublicKey, LAMPORTS_PER_SOL, SystemProgram, Transaction, sendAndConfirmTransaction, ComputeBudgetProgram } from "@solana/web3.js";
+import { Connection, Keypair, PublicKey, LAMPORTS_PER_SOL, SystemProgram, Transaction, sendAndConfirmTransaction, ComputeBudgetProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 import { confirmTransaction } from "@solana-developers/helpers";
+import { createHash, randomBytes } from "crypto";
 const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
 
 describe("hastrology_program", () => {
@@ -18,6 +19,7 @@ describe("hastrology_program", () => {
   let platformWalletKey: PublicKey;
   let lotteryStatePda: PublicKey;
   let potVaultPda: PublicKey;
+  let feeTreasuryPda: PublicKey;
   
   const ticketPrice = new anchor.BN(LAMPORTS_PER_SOL/2); 
   const platformFeeBps = 100; 
@@ -38,6 +40,11 @@ describe("hastrology_program", () => {
       [Buffer.from("pot_vault")],
       program.programId
     );
+
+    [feeTreasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_treasury")],
+      program.programId
+    );
     user1 = Keypair.generate();
     user2 = Keypair.generate();
 
@@ -47,6 +54,209 @@ describe("hastrology_program", () => {
     // await airdrop_fund(connection,authority , [user1.publicKey, user2.publicKey], 1 * LAMPORTS_PER_SOL);
   })
 
+  // Behaviour tests play whole rounds this long, drawn by the devnet VRF oracle
+  const roundSeconds = 30;
+
+  const roundPda = (seed: string, lotteryId: BN) => PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), lotteryId.toBuffer("le", 8)],
+    program.programId
+  )[0];
+
+  const ticketPda = (lotteryId: BN, index: number) => PublicKey.findProgramAddressSync(
+    [Buffer.from("user-ticket"), lotteryId.toBuffer("le", 8), new BN(index).toBuffer("le", 4)],
+    program.programId
+  )[0];
+
+  const fetchState = () => program.account.lotteryState.fetch(lotteryStatePda);
+
+  // A fresh wallet per round keeps win streaks, and so streak bonuses, out of the balances
+  async function fundedPlayer(lamports: number = LAMPORTS_PER_SOL): Promise<Keypair> {
+    const player = Keypair.generate();
+    await airdrop_fund(connection, authority, [player.publicKey], lamports);
+    return player;
+  }
+
+  async function openShortRound() {
+    await program.methods
+      .setRoundDuration(new BN(roundSeconds))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .openRound()
+      .accountsPartial({
+        signer: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    return fetchState();
+  }
+
+  // Buys a regular ticket, or one of `ticketClass`, and returns its PDA
+  async function enterRound(
+    user: Keypair,
+    remainingAccounts: anchor.web3.AccountMeta[] = [],
+    ticketClass: number | null = null
+  ): Promise<PublicKey> {
+    const state = await fetchState();
+    const userTicketPda = ticketPda(state.currentLotteryId, state.totalParticipants.toNumber());
+
+    await program.methods
+      .enterLottery(null, ticketClass, null, null)
+      .accountsStrict({
+        user: user.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        userEntryReceipt: PublicKey.findProgramAddressSync(
+          [Buffer.from("user-receipt"), user.publicKey.toBuffer(), state.currentLotteryId.toBuffer("le", 8)],
+          program.programId
+        )[0],
+        userTicket: userTicketPda,
+        participantIndex: roundPda("participant_index", state.currentLotteryId),
+        eligibilityTokenAccount: null,
+        eligibilityAttestation: null,
+        metrics: null,
+        snipeWindow: null,
+        megaJackpot: null,
+        deviceEntries: null,
+        playerProfile: PublicKey.findProgramAddressSync(
+          [Buffer.from("player_profile"), user.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([user])
+      .rpc();
+
+    return userTicketPda;
+  }
+
+  async function waitForRoundEnd() {
+    const state = await fetchState();
+    const msLeft = state.lotteryEndtime.toNumber() * 1000 - Date.now();
+
+    if (msLeft > 0) {
+      await sleep(msLeft + 2000);
+    }
+  }
+
+  async function waitForResolution() {
+    for (let attempt = 0; attempt < 30; attempt++) {
+      const state = await fetchState();
+
+      if (state.isDrawing && state.resolvedRequestId.eq(state.drawRequestId)) {
+        return state;
+      }
+
+      await sleep(2000);
+    }
+
+    assert.fail("The VRF callback did not resolve the draw");
+  }
+
+  async function requestDraw(accounts: Record<string, PublicKey | null> = {}) {
+    await program.methods
+      .requestDraw()
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        drawAttestation: null,
+        metrics: null,
+        snipeWindow: null,
+        feeTreasury: feeTreasuryPda,
+        secondOracleQueue: null,
+        ...accounts,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  // Waits out the round, requests the draw and waits for the oracle's callback
+  async function drawRound(accounts: Record<string, PublicKey | null> = {}) {
+    await waitForRoundEnd();
+    await requestDraw(accounts);
+
+    return waitForResolution();
+  }
+
+  const previewPayout = () => program.methods
+    .previewPayout()
+    .accountsPartial({
+      lotteryState: lotteryStatePda,
+      potVault: potVaultPda,
+      referralEpoch: null,
+      feeTreasury: feeTreasuryPda,
+    })
+    .view();
+
+  // Pays out the drawn round and returns the winning ticket as it was before payout
+  async function payoutRound(
+    accounts: Record<string, PublicKey | null> = {},
+    remainingAccounts: anchor.web3.AccountMeta[] = []
+  ) {
+    const state = await fetchState();
+    const winningTicketPda = ticketPda(state.currentLotteryId, state.winner.toNumber() - 1);
+    const winningTicket = await program.account.userTicket.fetch(winningTicketPda);
+    const winner = winningTicket.payoutDestination ?? winningTicket.user;
+
+    await program.methods
+      .payout()
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        feeTreasury: feeTreasuryPda,
+        winningTicket: winningTicketPda,
+        winner,
+        auditLog: PublicKey.findProgramAddressSync([Buffer.from("audit_log")], program.programId)[0],
+        referralEpoch: null,
+        metrics: null,
+        winnerProfile: null,
+        insuranceReserve: null,
+        roundHistory: roundPda("round_history", state.currentLotteryId),
+        prizeSwap: PublicKey.findProgramAddressSync(
+          [Buffer.from("prize_swap"), winningTicketPda.toBuffer()],
+          program.programId
+        )[0],
+        winnerRecord: PublicKey.findProgramAddressSync(
+          [Buffer.from("winner_record"), winningTicket.user.toBuffer(), state.currentLotteryId.toBuffer("le", 8)],
+          program.programId
+        )[0],
+        systemProgram: SystemProgram.programId,
+        ...accounts,
+      })
+      .remainingAccounts(remainingAccounts)
+      .preInstructions([
+          ComputeBudgetProgram.setComputeUnitPrice({
+            microLamports: 5000,
+          })
+        ])
+      .signers([authority])
+      .rpc();
+
+    return { winningTicketPda, winningTicket, winner };
+  }
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+    } catch (err) {
+      assert.include(String(err), code);
+      return;
+    }
+
+    assert.fail(`Expected the transaction to fail with ${code}`);
+  }
+
   it("Initializes the lottery state!", async () => {
     const stateAccount = await connection.getAccountInfo(lotteryStatePda);
 
@@ -64,6 +274,10 @@ describe("hastrology_program", () => {
           authority: authority.publicKey,
           lotteryState: lotteryStatePda,
           potVault: potVaultPda,
+          platformConfig: PublicKey.findProgramAddressSync(
+            [Buffer.from("platform_config")],
+            program.programId
+          )[0],
           systemProgram: SystemProgram.programId,
         })
         .signers([authority])
@@ -93,6 +307,60 @@ describe("hastrology_program", () => {
     // console.log(`LOTTERY end time: ${firstLotteryEndtime} and current time: ${Math.floor(Date.now() / 1000)}`);
   });
 
+  it("Reports the program version", async () => {
+    const version = await program.methods.getVersion().view();
+
+    assert.match(version, /^\d+\.\d+\.\d+/);
+  });
+
+  it("Initializes the fee treasury", async () => {
+    const treasuryAccount = await connection.getAccountInfo(feeTreasuryPda);
+
+    if (treasuryAccount === null) {
+      await program.methods
+        .initializeFeeTreasury()
+        .accountsStrict({
+          authority: authority.publicKey,
+          lotteryState: lotteryStatePda,
+          feeTreasury: feeTreasuryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    } else {
+      console.log("Fee treasury already initialized, skipping.");
+    }
+
+    const treasury = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.ok(treasury.accruedFees.gte(new anchor.BN(0)));
+  });
+
+  it("Initializes the audit log", async () => {
+    const [auditLogPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_log")],
+      program.programId
+    );
+
+    if ((await connection.getAccountInfo(auditLogPda)) === null) {
+      await program.methods
+        .initializeAuditLog()
+        .accountsStrict({
+          authority: authority.publicKey,
+          lotteryState: lotteryStatePda,
+          auditLog: auditLogPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    } else {
+      console.log("Audit log already initialized, skipping.");
+    }
+
+    // payout records into it, so it must exist before the first round is paid out
+    const auditLog = await program.account.auditLog.fetch(auditLogPda);
+    assert.isNotNull(auditLog);
+  });
+
   // it("Reset the lottery state!", async () => {
   //   const stateAccount = await provider.connection.getAccountInfo(lotteryStatePda);
 
@@ -150,25 +418,45 @@ describe("hastrology_program", () => {
       [
         Buffer.from("user-ticket"),
         currentLotteryId.toBuffer("le", 8),
-        totalParticipants.toBuffer("le", 8), 
+        totalParticipants.toBuffer("le", 4), 
       ],
       program.programId
     );
     // console.log("user1 entry receipt: ", userTicketPda);
 
 
+    const [participantIndexPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("participant_index"),
+        currentLotteryId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
     const userBalanceBefore = await provider.connection.getBalance(user1.publicKey);
     const vaultBalanceBefore = await provider.connection.getBalance(potVaultPda);
 
 
     const sig = await program.methods
-      .enterLottery()
+      .enterLottery(null, null, null, null)
       .accountsStrict({
         user: user1.publicKey,
         lotteryState: lotteryStatePda,
         potVault: potVaultPda,
         userEntryReceipt: userEntryReceiptPda,
         userTicket: userTicketPda,
+        participantIndex: participantIndexPda,
+        eligibilityTokenAccount: null,
+        eligibilityAttestation: null,
+        metrics: null,
+        snipeWindow: null,
+        megaJackpot: null,
+        deviceEntries: null,
+        playerProfile: PublicKey.findProgramAddressSync(
+          [Buffer.from("player_profile"), user1.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
       })
       .preInstructions([
@@ -187,6 +475,11 @@ describe("hastrology_program", () => {
     assert.ok(receipt.user.equals(user1.publicKey));
     assert.ok(receipt.lotteryId.eq(currentLotteryId));
     // assert.ok(receipt.ticketNumber.eq(totalParticipants)); 
+    assert.ok(receipt.lamportsPaid.eq(ticketPrice));
+    assert.isNull(receipt.entryNonce);
+    assert.ok(receipt.enteredAt.gtn(0));
+    assert.equal(receipt.entryCount, 1);
+    assert.ok(receipt.totalPaid.eq(ticketPrice));
 
     const ticket = await program.account.userTicket.fetch(userTicketPda);
     assert.ok(ticket.user.equals(user1.publicKey));
@@ -220,24 +513,50 @@ describe("hastrology_program", () => {
       [
         Buffer.from("user-ticket"),
         currentLotteryId.toBuffer("le", 8),
-        totalParticipants.toBuffer("le", 8), 
+        totalParticipants.toBuffer("le", 4), 
       ],
       program.programId
     );
 
 
+    const [participantIndexPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("participant_index"),
+        currentLotteryId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
     const userBalanceBefore = await provider.connection.getBalance(user2.publicKey);
     const vaultBalanceBefore = await provider.connection.getBalance(potVaultPda);
 
+    // Enter at the quoted price so a price change in between fails the entry
+    const quote = await program.methods
+      .quoteEntry(null)
+      .accountsPartial({ lotteryState: lotteryStatePda })
+      .view();
+    assert.ok(quote.lotteryId.eq(currentLotteryId));
 
     const sig = await program.methods
-      .enterLottery()
+      .enterLottery(null, null, quote.price, null)
       .accountsStrict({
         user: user2.publicKey,
         lotteryState: lotteryStatePda,
         potVault: potVaultPda,
         userEntryReceipt: userEntryReceiptPda,
         userTicket: userTicketPda,
+        participantIndex: participantIndexPda,
+        eligibilityTokenAccount: null,
+        eligibilityAttestation: null,
+        metrics: null,
+        snipeWindow: null,
+        megaJackpot: null,
+        deviceEntries: null,
+        playerProfile: PublicKey.findProgramAddressSync(
+          [Buffer.from("player_profile"), user2.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         systemProgram: SystemProgram.programId,
       })
       .preInstructions([
@@ -319,18 +638,19 @@ describe("hastrology_program", () => {
       [
         Buffer.from("user-ticket"),
         currentLotteryId.toBuffer("le", 8),
-        winningIndex.toBuffer("le", 8),
+        winningIndex.toBuffer("le", 4),
       ],
       program.programId
     );
 
     const ticketAccount = await program.account.userTicket.fetch(winningTicketPda);
-    const winnerPubkey = ticketAccount.user;
+    const winnerPubkey = ticketAccount.payoutDestination ?? ticketAccount.user;
     
     console.log("Winner Wallet:", winnerPubkey.toBase58());
 
     const potBalanceBefore = await provider.connection.getBalance(potVaultPda);
     const platformBalanceBefore = await provider.connection.getBalance(platformWallet);
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
     const winnerBalanceBefore = await provider.connection.getBalance(winnerPubkey);
 
     console.log(`pot balance before: ${potBalanceBefore / LAMPORTS_PER_SOL}, platform wallet before: ${platformBalanceBefore / LAMPORTS_PER_SOL}`);
@@ -342,7 +662,7 @@ describe("hastrology_program", () => {
       .accountsPartial({
         authority: authority.publicKey,
         lotteryState: lotteryStatePda,
-        platformWallet: platformWallet,
+        feeTreasury: feeTreasuryPda,
         winner: winnerPubkey,
         potVault: potVaultPda,
         systemProgram: SystemProgram.programId,
@@ -365,6 +685,1237 @@ describe("hastrology_program", () => {
     console.log("Pot emptied?", potBalanceAfter === 0);
     assert.strictEqual(potBalanceAfter, 0);
     assert.isTrue(winnerBalanceAfter > winnerBalanceBefore);
+
+    // The fee waits in the treasury for forward_fees instead of reaching the platform wallet
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.strictEqual(platformBalanceAfter, platformBalanceBefore);
+    assert.isTrue(treasuryAfter.accruedFees.gt(treasuryBefore.accruedFees));
+
+    // The win is listed under the ticket owner for the profile page
+    const [winnerRecordPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("winner_record"), ticketAccount.user.toBuffer(), ticketAccount.lotteryId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const winnerRecord = await program.account.winnerRecord.fetch(winnerRecordPda);
+    assert.ok(winnerRecord.wallet.equals(ticketAccount.user));
+    assert.strictEqual(winnerRecord.winningTickets, 1);
+
+    const stateAfter = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(stateAfter.roundFinalized);
+    assert.ok(stateAfter.currentLotteryId.eq(currentLotteryId));
+
+    // A wallet winner is paid directly; nothing is held back for claim_held_prize
+    assert.isTrue(stateAfter.heldPrizeLamports.eqn(0));
+
+    // The first win starts a streak for the ticket owner
+    assert.ok(stateAfter.streakWallet.equals(ticketAccount.user));
+    assert.strictEqual(stateAfter.winStreak, 1);
+  });
+
+  it("Closes the losing ticket and returns its rent", async () => {
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    const lotteryId = state.currentLotteryId;
+
+    const ticketPdas = [0, 1].map((index) => PublicKey.findProgramAddressSync(
+      [Buffer.from("user-ticket"), lotteryId.toBuffer("le", 8), new anchor.BN(index).toBuffer("le", 4)],
+      program.programId
+    )[0]);
+    const tickets = await Promise.all(ticketPdas.map((pda) => program.account.userTicket.fetch(pda)));
+    const loserIndex = tickets.findIndex((ticket) => !ticket.isWinner);
+    const loser = tickets[loserIndex];
+    const loserPda = ticketPdas[loserIndex];
+
+    assert.ok(loser.rentPayer.equals(loser.user));
+
+    const rent = await provider.connection.getBalance(loserPda);
+    const payerBalanceBefore = await provider.connection.getBalance(loser.rentPayer);
+
+    await program.methods
+      .closeLosingTickets(lotteryId, [loserIndex])
+      .accountsPartial({
+        payer: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        claimBitmap: null,
+      })
+      .remainingAccounts([
+        { pubkey: loserPda, isSigner: false, isWritable: true },
+        { pubkey: loser.rentPayer, isSigner: false, isWritable: true },
+      ])
+      .signers([authority])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(loserPda));
+    assert.strictEqual(await provider.connection.getBalance(loser.rentPayer), payerBalanceBefore + rent);
+  });
+
+  it("Forwards accrued fees to the platform wallet", async () => {
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const platformBalanceBefore = await provider.connection.getBalance(platformWalletKey);
+
+    await program.methods
+      .forwardFees()
+      .accountsPartial({
+        caller: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        feeTreasury: feeTreasuryPda,
+        platformWallet: platformWalletKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const platformBalanceAfter = await provider.connection.getBalance(platformWalletKey);
+
+    assert.ok(treasuryAfter.accruedFees.eq(new anchor.BN(0)));
+    assert.strictEqual(platformBalanceAfter - platformBalanceBefore, treasuryBefore.accruedFees.toNumber());
+    assert.ok(treasuryAfter.totalForwarded.eq(treasuryBefore.totalForwarded.add(treasuryBefore.accruedFees)));
+  });
+
+  it("Rolls a round over below the prize floor and tops the prize up from the treasury", async () => {
+    const player = await fundedPlayer();
+    await openShortRound();
+    await enterRound(player);
+
+    // Not even the whole treasury can lift a one-ticket pot to twice the ticket price
+    const setMinPrize = (minPrize: BN) => program.methods
+      .setMinPrize(minPrize)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setMinPrize(ticketPrice.muln(2));
+
+    await waitForRoundEnd();
+    const stateBefore = await fetchState();
+    const vaultBefore = await connection.getBalance(potVaultPda);
+
+    await requestDraw();
+
+    let state = await fetchState();
+    assert.isFalse(state.isDrawing);
+    assert.ok(state.currentLotteryId.eq(stateBefore.currentLotteryId));
+    assert.isTrue(state.lotteryEndtime.gt(stateBefore.lotteryEndtime));
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore);
+
+    // The round's own fee is enough to reach a floor just above the prize
+    const preview = await previewPayout();
+    const minPrize = preview.winnerPrize.add(preview.platformShare);
+    await setMinPrize(minPrize);
+
+    await drawRound();
+
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const toppedUp = await previewPayout();
+    assert.isTrue(toppedUp.prizeTopUp.gtn(0));
+
+    const { winningTicketPda } = await payoutRound();
+
+    const winnerAfter = await connection.getBalance(player.publicKey);
+    assert.strictEqual(winnerAfter - winnerBefore, minPrize.toNumber());
+
+    const ticket = await program.account.userTicket.fetch(winningTicketPda);
+    assert.ok(ticket.prizeAmount.eq(minPrize));
+
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.ok(treasuryAfter.accruedFees.eq(treasuryBefore.accruedFees.add(toppedUp.platformShare).sub(toppedUp.prizeTopUp)));
+
+    await setMinPrize(new BN(0));
+
+    state = await fetchState();
+    assert.ok(state.minPrizeLamports.isZero());
+    assert.isTrue(state.roundFinalized);
+  });
+
+  it("Rounds the prize down to the granularity and sends the dust to the treasury", async () => {
+    const granularity = new BN(7_000_000);
+
+    const setPrizeGranularity = (amount: BN) => program.methods
+      .setPrizeGranularity(amount)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setPrizeGranularity(granularity);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    await enterRound(player);
+    await drawRound();
+
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+
+    // A one-ticket pot's prize isn't a multiple of 0.007 SOL, so some dust is left over
+    assert.isTrue(preview.winnerPrize.mod(granularity).isZero());
+    assert.isTrue(preview.prizeDust.gtn(0));
+
+    const { winningTicketPda } = await payoutRound();
+
+    const ticket = await program.account.userTicket.fetch(winningTicketPda);
+    assert.ok(ticket.prizeAmount.eq(preview.winnerPrize));
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    // The dust is part of the platform share, so the treasury takes it with the fee
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.ok(treasuryAfter.accruedFees.eq(treasuryBefore.accruedFees.add(preview.platformShare)));
+
+    await setPrizeGranularity(new BN(0));
+
+    const state = await fetchState();
+    assert.isTrue(state.prizeGranularity.isZero());
+  });
+
+  it("Sets the insurance cut aside from the platform share at payout", async () => {
+    const insuranceBps = 50;
+    const [insuranceReservePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_reserve")],
+      program.programId
+    );
+
+    if ((await connection.getAccountInfo(insuranceReservePda)) === null) {
+      await program.methods
+        .initializeInsuranceReserve()
+        .accountsStrict({
+          authority: authority.publicKey,
+          lotteryState: lotteryStatePda,
+          insuranceReserve: insuranceReservePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    const setInsuranceBps = (bps: number) => program.methods
+      .setInsuranceBps(bps)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        insuranceReserve: insuranceReservePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setInsuranceBps(insuranceBps);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    await enterRound(player);
+    await drawRound();
+
+    // The reserve has to be passed while a cut is set
+    await expectError(payoutRound(), "InsuranceReserveRequired");
+
+    const reserveBefore = await program.account.insuranceReserve.fetch(insuranceReservePda);
+    const reserveLamportsBefore = await connection.getBalance(insuranceReservePda);
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+
+    assert.ok(preview.insuranceCut.eq(preview.potLamports.muln(insuranceBps).divn(10_000)));
+    assert.isTrue(preview.insuranceCut.gtn(0));
+
+    await payoutRound({ insuranceReserve: insuranceReservePda });
+
+    const reserveAfter = await program.account.insuranceReserve.fetch(insuranceReservePda);
+    assert.ok(reserveAfter.balance.eq(reserveBefore.balance.add(preview.insuranceCut)));
+    assert.strictEqual(await connection.getBalance(insuranceReservePda), reserveLamportsBefore + preview.insuranceCut.toNumber());
+
+    // The cut comes out of the platform's share, never the prize
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.ok(treasuryAfter.accruedFees.eq(treasuryBefore.accruedFees.add(preview.platformShare)));
+    assert.ok(preview.platformShare.add(preview.insuranceCut).eq(preview.platformFee));
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    await setInsuranceBps(0);
+
+    const state = await fetchState();
+    assert.strictEqual(state.insuranceBps, 0);
+  });
+
+  it("Draws a high-value round only once both oracle queues have answered", async () => {
+    // The VRF SDK's ephemeral queue, which is distinct from the lottery's own queue
+    const secondQueue = new PublicKey("5hBR571xnXppuCPveTrctfTU7tJLSN94nq7kv7FRK5Tc");
+
+    const setDualDraw = (minPot: BN, queue: PublicKey) => program.methods
+      .setDualDraw(minPot, queue)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    await enterRound(player);
+
+    // A one-ticket pot already reaches the threshold
+    await setDualDraw(ticketPrice.divn(2), secondQueue);
+
+    await waitForRoundEnd();
+    await expectError(requestDraw(), "SecondOracleQueueRequired");
+
+    await requestDraw({ secondOracleQueue: secondQueue });
+    const state = await waitForResolution();
+
+    const history = await program.account.roundHistory.fetch(roundPda("round_history", state.currentLotteryId));
+    assert.isTrue(history.secondOracleQueue.equals(secondQueue));
+    assert.strictEqual(history.drawSources, 3);
+    assert.isFalse(history.secondRandomness.every((byte) => byte === 0));
+    assert.isTrue(state.winner.gtn(0) && state.winner.lte(state.totalParticipants));
+
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+    await payoutRound();
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    await setDualDraw(new BN(0), PublicKey.default);
+
+    const cleared = await fetchState();
+    assert.isTrue(cleared.dualDrawMinPot.isZero());
+  });
+
+  it("Resolves a single-ticket round without a VRF request", async () => {
+    const setTrivialResolution = (enabled: boolean) => program.methods
+      .setTrivialResolution(enabled)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setTrivialResolution(true);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    await enterRound(player);
+    await waitForRoundEnd();
+
+    const stateBefore = await fetchState();
+    const lotteryId = stateBefore.currentLotteryId;
+
+    await program.methods
+      .resolveTrivialRound()
+      .accountsStrict({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        roundHistory: roundPda("round_history", lotteryId),
+        snipeWindow: roundPda("snipe_window", lotteryId),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    // Resolved in the same transaction, with nothing requested from an oracle
+    const state = await fetchState();
+    assert.isTrue(state.isDrawing);
+    assert.ok(state.drawRequestId.eq(stateBefore.drawRequestId.addn(1)));
+    assert.ok(state.resolvedRequestId.eq(state.drawRequestId));
+    assert.equal(state.winner.toNumber(), 1);
+
+    const history = await program.account.roundHistory.fetch(roundPda("round_history", lotteryId));
+    assert.isTrue(history.oracleQueue.equals(PublicKey.default));
+    assert.equal(history.winner.toNumber(), 1);
+
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+    const { winner } = await payoutRound();
+
+    assert.ok(winner.equals(player.publicKey));
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    await setTrivialResolution(false);
+  });
+
+  it("Waives the platform fee on entries paid during a promotion", async () => {
+    let state = await fetchState();
+    const nextLotteryId = state.currentLotteryId.addn(1);
+    const startsAt = new BN(Math.floor(Date.now() / 1000) - 60);
+    const endsAt = startsAt.addn(3600);
+
+    await program.methods
+      .setFeePromotion(nextLotteryId, startsAt, endsAt, 0)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    state = await openShortRound();
+    assert.ok(state.currentLotteryId.eq(nextLotteryId));
+
+    await enterRound(player);
+
+    state = await fetchState();
+    assert.ok(state.feePromoLamports.eq(ticketPrice));
+
+    await drawRound();
+
+    const treasuryBefore = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+
+    // Every lamport in the pot was paid under the promotion, so the winner takes all of it
+    assert.isTrue(preview.platformFee.isZero());
+    assert.ok(preview.winnerPrize.eq(preview.potLamports));
+
+    await payoutRound();
+
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    const treasuryAfter = await program.account.feeTreasury.fetch(feeTreasuryPda);
+    assert.ok(treasuryAfter.accruedFees.eq(treasuryBefore.accruedFees));
+  });
+
+  it("Mixes a committed operator nonce into the draw", async () => {
+    const setEntropyMixing = (minPot: BN) => program.methods
+      .setEntropyMixing(minPot)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const players = [await fundedPlayer(), await fundedPlayer()];
+    const round = await openShortRound();
+    await setEntropyMixing(ticketPrice);
+
+    for (const player of players) {
+      await enterRound(player);
+    }
+
+    // The commitment binds the nonce to this round and has to land before it ends
+    const nonce = randomBytes(32);
+    const commitment = createHash("sha256")
+      .update(Buffer.concat([round.currentLotteryId.toBuffer("le", 8), nonce]))
+      .digest();
+
+    await program.methods
+      .commitDrawNonce([...commitment])
+      .accountsPartial({
+        operator: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    // The VRF output is recorded, but the winner waits for the nonce
+    let state = await drawRound();
+    assert.isTrue(state.entropyMixPending);
+
+    const roundHistoryPda = roundPda("round_history", round.currentLotteryId);
+    let history = await program.account.roundHistory.fetch(roundHistoryPda);
+    assert.isTrue(history.entropyMixed);
+    assert.isFalse(history.entropyBlockhash.every((byte) => byte === 0));
+
+    const revealDrawNonce = (revealed: Buffer) => program.methods
+      .revealDrawNonce([...revealed])
+      .accountsPartial({
+        caller: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        roundHistory: roundHistoryPda,
+        snipeWindow: null,
+        participantIndex: null,
+        seasonWinners: null,
+      })
+      .signers([authority])
+      .rpc();
+
+    await expectError(revealDrawNonce(randomBytes(32)), "InvalidEntropyNonce");
+
+    await revealDrawNonce(nonce);
+
+    state = await fetchState();
+    assert.isFalse(state.entropyMixPending);
+    assert.isTrue(state.entropyCommitment.every((byte) => byte === 0));
+    assert.isTrue(state.winner.gten(1) && state.winner.lten(players.length));
+
+    history = await program.account.roundHistory.fetch(roundHistoryPda);
+    assert.deepEqual(Buffer.from(history.entropyNonce), nonce);
+
+    await payoutRound();
+    await setEntropyMixing(new BN(0));
+
+    state = await fetchState();
+    assert.ok(state.entropyMixMinPot.isZero());
+  });
+
+  it("Holds a prize the winner asked to swap and releases it to the claim", async () => {
+    // Any executable program stands in for the DEX; the claim below takes the no-route path
+    const swapProgram = new PublicKey("MemoSq4gqABAXKb96qnH8TysNBWxMyWCqXgDLGmfcHr");
+    const tokenProgram = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const associatedTokenProgram = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+    const nativeMint = new PublicKey("So11111111111111111111111111111111111111112");
+    const outputMint = new PublicKey("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU");
+    const associatedTokenAccount = (owner: PublicKey, mint: PublicKey) => PublicKey.findProgramAddressSync(
+      [owner.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
+      associatedTokenProgram
+    )[0];
+
+    const setSwapProgram = (programId: PublicKey) => program.methods
+      .setSwapProgram(programId)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setSwapProgram(swapProgram);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    const userTicketPda = await enterRound(player);
+
+    const [prizeSwapPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("prize_swap"), userTicketPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .requestPrizeSwap()
+      .accountsStrict({
+        user: player.publicKey,
+        lotteryState: lotteryStatePda,
+        userTicket: userTicketPda,
+        outputMint,
+        prizeSwap: prizeSwapPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc();
+
+    await drawRound();
+
+    const stateBefore = await fetchState();
+    const vaultBefore = await connection.getBalance(potVaultPda);
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+
+    await payoutRound();
+
+    // The prize stays in the pot vault, set aside from the next round's pot
+    const prizeSwap = await program.account.prizeSwap.fetch(prizeSwapPda);
+    assert.ok(prizeSwap.lamports.eq(preview.winnerPrize));
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore);
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore - preview.platformShare.toNumber() - preview.insuranceCut.toNumber());
+
+    let state = await fetchState();
+    assert.ok(state.swapPrizeLamports.eq(stateBefore.swapPrizeLamports.add(preview.winnerPrize)));
+
+    const cancelPrizeSwap = program.methods
+      .cancelPrizeSwap()
+      .accountsStrict({
+        user: player.publicKey,
+        userTicket: userTicketPda,
+        prizeSwap: prizeSwapPda,
+      })
+      .signers([player])
+      .rpc();
+
+    await expectError(cancelPrizeSwap, "PrizeSwapLocked");
+
+    // An empty route pays the held prize out as SOL
+    await program.methods
+      .claimSwappedPrize(new BN(0), Buffer.alloc(0))
+      .accountsStrict({
+        winner: player.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        winningTicket: userTicketPda,
+        prizeSwap: prizeSwapPda,
+        prizeRecipient: player.publicKey,
+        nativeMint,
+        wsolAccount: associatedTokenAccount(player.publicKey, nativeMint),
+        outputMint,
+        recipientTokenAccount: associatedTokenAccount(player.publicKey, outputMint),
+        swapProgram,
+        tokenProgram,
+        outputTokenProgram: tokenProgram,
+        associatedTokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([player])
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(prizeSwapPda));
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore - preview.platformShare.toNumber() - preview.insuranceCut.toNumber() - preview.winnerPrize.toNumber());
+
+    state = await fetchState();
+    assert.ok(state.swapPrizeLamports.eq(stateBefore.swapPrizeLamports));
+
+    await setSwapProgram(PublicKey.default);
+  });
+
+  it("Grants early-bird tickets that the buyer's entry pays for once", async () => {
+    const setEarlyBird = (window: number, bonus: number) => program.methods
+      .setEarlyBird(new BN(window), bonus)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setEarlyBird(3600, 2);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    const opened = await openShortRound();
+    const lotteryId = opened.currentLotteryId;
+    const vaultBefore = await connection.getBalance(potVaultPda);
+
+    // The bonus tickets take the two indexes right after the paid one
+    const bonusTicketPdas = [1, 2].map((index) => ticketPda(lotteryId, index));
+    const userTicketPda = await enterRound(
+      player,
+      bonusTicketPdas.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+    );
+
+    const state = await fetchState();
+    assert.equal(state.totalParticipants.toNumber(), 3);
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore + ticketPrice.toNumber());
+
+    const userTicket = await program.account.userTicket.fetch(userTicketPda);
+    assert.equal(userTicket.bonusTickets, 2);
+    assert.isFalse(userTicket.isBonus);
+
+    for (const bonusTicketPda of bonusTicketPdas) {
+      const bonusTicket = await program.account.userTicket.fetch(bonusTicketPda);
+      assert.ok(bonusTicket.user.equals(player.publicKey));
+      assert.ok(bonusTicket.lotteryId.eq(lotteryId));
+      assert.isTrue(bonusTicket.isBonus);
+    }
+
+    await setEarlyBird(0, 0);
+
+    // Whichever of the three tickets is drawn, the prize comes from the one paid ticket
+    await drawRound();
+
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+    const { winner } = await payoutRound();
+
+    assert.ok(winner.equals(player.publicKey));
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+  });
+
+  it("Pauses and resumes entries", async () => {
+    await program.methods
+      .setEntriesPaused(true)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(state.entriesPaused);
+
+    await program.methods
+      .setEntriesPaused(false)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isFalse(state.entriesPaused);
+  });
+
+  it("Approves and withdraws a fee ceiling through fee governance", async () => {
+    await program.methods
+      .setFeeGovernance(authority.publicKey)
+      .accountsPartial({
+        signer: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .approveFeeCeiling(1500)
+      .accountsPartial({
+        feeGovernance: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.ok(state.feeGovernance.equals(authority.publicKey));
+    assert.equal(state.feeCeilingBps, 1500);
+
+    await program.methods
+      .approveFeeCeiling(0)
+      .accountsPartial({
+        feeGovernance: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.feeCeilingBps, 0);
+    assert.equal(state.platformFeeBps, platformFeeBps);
+  });
+
+  it("Escrows entries until the KYC verifier approves them", async () => {
+    const verifier = await fundedPlayer();
+
+    const setKycVerifier = (key: PublicKey) => program.methods
+      .setKycVerifier(key)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setKycVerifier(verifier.publicKey);
+
+    const approved = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    const refunded = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    const opened = await openShortRound();
+    const lotteryId = opened.currentLotteryId;
+
+    const pendingEntryPda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+      [Buffer.from("pending-kyc"), user.toBuffer(), lotteryId.toBuffer("le", 8)],
+      program.programId
+    )[0];
+    const playerProfilePda = (user: PublicKey) => PublicKey.findProgramAddressSync(
+      [Buffer.from("player_profile"), user.toBuffer()],
+      program.programId
+    )[0];
+
+    for (const user of [approved, refunded]) {
+      await program.methods
+        .enterPendingKyc()
+        .accountsStrict({
+          user: user.publicKey,
+          lotteryState: lotteryStatePda,
+          pendingEntry: pendingEntryPda(user.publicKey),
+          playerProfile: playerProfilePda(user.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const pendingEntry = await program.account.pendingKycEntry.fetch(pendingEntryPda(user.publicKey));
+      assert.ok(pendingEntry.user.equals(user.publicKey));
+      assert.ok(pendingEntry.amount.eq(ticketPrice));
+    }
+
+    // Escrowed entries hold no ticket yet
+    let state = await fetchState();
+    assert.equal(state.totalParticipants.toNumber(), 0);
+
+    const vaultBefore = await connection.getBalance(potVaultPda);
+    const approvedBefore = await connection.getBalance(approved.publicKey);
+    const escrowLamports = await connection.getBalance(pendingEntryPda(approved.publicKey));
+    const userTicketPda = ticketPda(lotteryId, 0);
+
+    await program.methods
+      .approveEntry()
+      .accountsStrict({
+        verifier: verifier.publicKey,
+        user: approved.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        pendingEntry: pendingEntryPda(approved.publicKey),
+        userEntryReceipt: PublicKey.findProgramAddressSync(
+          [Buffer.from("user-receipt"), approved.publicKey.toBuffer(), lotteryId.toBuffer("le", 8)],
+          program.programId
+        )[0],
+        userTicket: userTicketPda,
+        participantIndex: roundPda("participant_index", lotteryId),
+        metrics: null,
+        megaJackpot: null,
+        playerProfile: playerProfilePda(approved.publicKey),
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([verifier])
+      .rpc();
+
+    // The escrowed price moves to the pot and the escrow account's rent goes back to the user
+    assert.isNull(await connection.getAccountInfo(pendingEntryPda(approved.publicKey)));
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore + ticketPrice.toNumber());
+    assert.strictEqual(await connection.getBalance(approved.publicKey), approvedBefore + escrowLamports - ticketPrice.toNumber());
+
+    const userTicket = await program.account.userTicket.fetch(userTicketPda);
+    assert.ok(userTicket.user.equals(approved.publicKey));
+
+    state = await fetchState();
+    assert.equal(state.totalParticipants.toNumber(), 1);
+
+    const refundedBefore = await connection.getBalance(refunded.publicKey);
+    const refundLamports = await connection.getBalance(pendingEntryPda(refunded.publicKey));
+
+    await program.methods
+      .refundPendingEntry()
+      .accountsStrict({
+        user: refunded.publicKey,
+        pendingEntry: pendingEntryPda(refunded.publicKey),
+      })
+      .signers([refunded])
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(pendingEntryPda(refunded.publicKey)));
+    assert.strictEqual(await connection.getBalance(refunded.publicKey), refundedBefore + refundLamports);
+
+    await setKycVerifier(PublicKey.default);
+
+    await drawRound();
+    const { winner } = await payoutRound();
+    assert.ok(winner.equals(approved.publicKey));
+  });
+
+  it("Links and unlinks the lottery from the mega jackpot", async () => {
+    const [megaJackpotPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mega_jackpot")],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMegaJackpot(new BN(30 * 86400))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        megaJackpot: megaJackpotPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const megaJackpot = await program.account.megaJackpot.fetch(megaJackpotPda);
+    assert.equal(megaJackpot.epoch.toNumber(), 0);
+    assert.equal(megaJackpot.totalEntries.toNumber(), 0);
+    assert.isNull(megaJackpot.winningEntry);
+
+    await program.methods
+      .setMegaJackpotContribution(500)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        megaJackpot: megaJackpotPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.megaJackpotBps, 500);
+
+    await program.methods
+      .setMegaJackpotContribution(0)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        megaJackpot: megaJackpotPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.megaJackpotBps, 0);
+  });
+
+  it("Sets the endtime grace and tracks the end slot", async () => {
+    await program.methods
+      .setEndtimeGrace(new BN(30))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.endtimeGrace.toNumber(), 30);
+    assert.isTrue(state.endSlot.gtn(0));
+
+    await program.methods
+      .setEndtimeGrace(new BN(0))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.endtimeGrace.toNumber(), 0);
+  });
+
+  it("Sets the streak bonus", async () => {
+    await program.methods
+      .setStreakBonus(new BN(1_000_000))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(state.streakBonusLamports.eqn(1_000_000));
+  });
+
+  it("Reconciles the pot vault against its ledger", async () => {
+    const [metricsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metrics")],
+      program.programId
+    );
+
+    await program.methods
+      .initializeMetrics()
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        metrics: metricsPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .reconcileVault()
+      .accountsPartial({
+        keeper: authority.publicKey,
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        metrics: metricsPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const metrics = await program.account.metrics.fetch(metricsPda);
+    assert.equal(metrics.vaultDiscrepancy.toNumber(), 0);
+    assert.isTrue(metrics.vaultCheckedAt.gtn(0));
+
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isFalse(state.needsReview);
+  });
+
+  it("Calls the winner hook from payout", async () => {
+    // The SPL Noop program accepts any instruction, so it stands in for a hook
+    const hookProgram = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+    const setWinnerHook = (key: PublicKey) => program.methods
+      .setWinnerHook(key)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setWinnerHook(hookProgram);
+
+    const player = await fundedPlayer(2 * LAMPORTS_PER_SOL);
+    await openShortRound();
+    await enterRound(player);
+    await drawRound();
+
+    // A set hook can't be skipped by leaving its program out
+    await expectError(payoutRound(), "WinnerHookMissing");
+
+    const winnerBefore = await connection.getBalance(player.publicKey);
+    const preview = await previewPayout();
+    const { winningTicketPda } = await payoutRound({}, [
+      { pubkey: hookProgram, isSigner: false, isWritable: false },
+    ]);
+
+    const winningTicket = await program.account.userTicket.fetch(winningTicketPda);
+    assert.isTrue(winningTicket.isWinner);
+    assert.strictEqual(await connection.getBalance(player.publicKey), winnerBefore + preview.winnerPrize.toNumber());
+
+    await setWinnerHook(PublicKey.default);
+  });
+
+  it("Opens the next round", async () => {
+    const stateBefore = await program.account.lotteryState.fetch(lotteryStatePda);
+
+    await program.methods
+      .openRound()
+      .accountsPartial({
+        signer: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const stateAfter = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isFalse(stateAfter.roundFinalized);
+    assert.ok(stateAfter.currentLotteryId.eq(stateBefore.currentLotteryId.addn(1)));
+    assert.ok(stateAfter.totalParticipants.eqn(0));
+    assert.isTrue(stateAfter.lotteryEndtime.gt(stateBefore.lotteryEndtime));
+  });
+
+  it("Escrows a conditional entry and refunds it to its owner", async () => {
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    const [conditionalEntryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("conditional-entry"), user1.publicKey.toBuffer(), state.currentLotteryId.toBuffer("le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .placeConditionalEntry(new anchor.BN(1_000 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accountsPartial({
+        user: user1.publicKey,
+        lotteryState: lotteryStatePda,
+        conditionalEntry: conditionalEntryPda,
+      })
+      .signers([user1])
+      .rpc();
+
+    const entry = await program.account.conditionalEntry.fetch(conditionalEntryPda);
+    assert.ok(entry.amount.eq(state.ticketPrice));
+    assert.ok(entry.minPotLamports.eq(new anchor.BN(1_000 * LAMPORTS_PER_SOL)));
+
+    await program.methods
+      .refundConditionalEntry()
+      .accountsPartial({
+        signer: user1.publicKey,
+        user: user1.publicKey,
+        lotteryState: lotteryStatePda,
+        conditionalEntry: conditionalEntryPda,
+      })
+      .signers([user1])
+      .rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(conditionalEntryPda));
+  });
+
+  it("Sets and clears the star, moon and sun ticket classes", async () => {
+    await program.methods
+      .setTicketClasses([
+        { price: new BN(10_000_000), weight: 1 },
+        { price: new BN(25_000_000), weight: 3 },
+        { price: new BN(50_000_000), weight: 7 },
+      ])
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.deepEqual(state.ticketClasses.map((ticketClass) => ticketClass.weight), [1, 3, 7]);
+    assert.isTrue(state.ticketClasses[2].price.eqn(50_000_000));
+    assert.isTrue(state.classWeightTotal.eqn(0));
+
+    await program.methods
+      .setTicketClasses([])
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isEmpty(state.ticketClasses);
+  });
+
+  it("Draws a class pot among the class's own tickets", async () => {
+    const setTicketClasses = (ticketClasses: { price: BN, weight: number }[]) => program.methods
+      .setTicketClasses(ticketClasses)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const setClassDraws = (enabled: boolean) => program.methods
+      .setClassDraws(enabled)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    // Only between rounds: the round opened above has no tickets yet
+    const classPrice = ticketPrice.muln(2);
+    const premium = classPrice.sub(ticketPrice);
+    await setTicketClasses([{ price: classPrice, weight: 1 }]);
+    await setClassDraws(true);
+
+    const [classPlayer, regularPlayer] = await Promise.all([
+      fundedPlayer(3 * LAMPORTS_PER_SOL),
+      fundedPlayer(2 * LAMPORTS_PER_SOL),
+    ]);
+
+    const vaultBefore = await connection.getBalance(potVaultPda);
+    const classTicketPda = await enterRound(classPlayer, [], 0);
+    await enterRound(regularPlayer);
+
+    // What the class ticket paid above the regular price is its class's pot, not main-draw weight
+    let state = await fetchState();
+    const lotteryId = state.currentLotteryId;
+    assert.ok(state.classPots[0].eq(premium));
+    assert.isTrue(state.classWeightTotal.isZero());
+    assert.strictEqual(await connection.getBalance(potVaultPda), vaultBefore + ticketPrice.muln(2).add(premium).toNumber());
+
+    const classTicket = await program.account.userTicket.fetch(classTicketPda);
+    assert.strictEqual(classTicket.ticketClass, 0);
+    assert.isTrue(classTicket.classWeightStart.isZero());
+
+    await drawRound();
+
+    const history = await program.account.roundHistory.fetch(roundPda("round_history", lotteryId));
+    assert.ok(history.classPots[0].eq(premium));
+    assert.strictEqual(history.classTicketsSold[0], 1);
+
+    const stateBefore = await fetchState();
+    const preview = await previewPayout();
+    await payoutRound();
+
+    // The main prize leaves the class pot in the vault for its own claim
+    state = await fetchState();
+    assert.isTrue(state.classPots.every((pot) => pot.isZero()));
+    assert.ok(state.classPrizeLamports.eq(stateBefore.classPrizeLamports.add(premium)));
+    assert.ok(preview.winnerPrize.add(preview.platformShare).eq(preview.potLamports));
+
+    // The class has one ticket, so it takes the class pot whoever won the main draw
+    const classPlayerBefore = await connection.getBalance(classPlayer.publicKey);
+
+    await program.methods
+      .claimClassPrize(lotteryId, 0)
+      .accountsStrict({
+        lotteryState: lotteryStatePda,
+        potVault: potVaultPda,
+        roundHistory: roundPda("round_history", lotteryId),
+        winningTicket: classTicketPda,
+        recipient: classPlayer.publicKey,
+      })
+      .rpc();
+
+    assert.strictEqual(await connection.getBalance(classPlayer.publicKey), classPlayerBefore + premium.toNumber());
+
+    state = await fetchState();
+    assert.ok(state.classPrizeLamports.eq(stateBefore.classPrizeLamports));
+
+    const claimed = await program.account.roundHistory.fetch(roundPda("round_history", lotteryId));
+    assert.strictEqual(claimed.classPrizesPaid, 1);
+
+    // Classes can only be turned off again once the next round is open
+    await program.methods
+      .openRound()
+      .accountsPartial({
+        signer: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setClassDraws(false);
+    await setTicketClasses([]);
+
+    state = await fetchState();
+    assert.isFalse(state.classDraws);
+    assert.isEmpty(state.ticketClasses);
+  });
+
+  it("Switches rounds to slot deadlines and back", async () => {
+    await program.methods
+      .setSlotDeadline(true, new BN(150))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(state.slotDeadline);
+    assert.isTrue(state.minRoundSlots.eqn(150));
+
+    await program.methods
+      .setSlotDeadline(false, new BN(0))
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isFalse(state.slotDeadline);
+  });
+
+  it("Keeps draws on the configured randomness provider", async () => {
+    const before = await program.account.lotteryState.fetch(lotteryStatePda);
+
+    await program.methods
+      .setRandomnessProvider(before.randomnessProvider, before.randomnessQueue)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(state.randomnessProvider.equals(before.randomnessProvider));
+    assert.isTrue(state.randomnessQueue.equals(before.randomnessQueue));
+  });
+
+  it("Caps entries per device commitment", async () => {
+    await program.methods
+      .setDeviceEntryLimit(3)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    let state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.maxEntriesPerDevice, 3);
+
+    await program.methods
+      .setDeviceEntryLimit(0)
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.equal(state.maxEntriesPerDevice, 0);
+  });
+
+  // Irreversible, so it runs last
+  it("Sunsets the program", async () => {
+    await program.methods
+      .sunset()
+      .accountsPartial({
+        authority: authority.publicKey,
+        lotteryState: lotteryStatePda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const state = await program.account.lotteryState.fetch(lotteryStatePda);
+    assert.isTrue(state.sunsetAt.gtn(0));
   });
 
   // after(async () => {
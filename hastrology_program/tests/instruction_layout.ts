@@ -0,0 +1,74 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { HastrologyProgram } from "../target/types/hastrology_program";
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { readFileSync } from "fs";
+
+// Golden discriminators and account orders. Clients (the frontend, keepers, the VRF
+// callback built in request_draw) encode these by hand, so a rename or reordered
+// account must show up here as a deliberate golden-file change.
+interface InstructionLayout {
+  name: string;
+  discriminator: number[];
+  accounts: string[];
+}
+
+const golden: InstructionLayout[] = JSON.parse(
+  readFileSync("tests/golden/instruction_layout.json", "utf8")
+);
+
+const globalDiscriminator = (name: string): number[] =>
+  Array.from(createHash("sha256").update(`global:${name}`).digest().subarray(0, 8));
+
+describe("instruction layout", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.hastrologyProgram as Program<HastrologyProgram>;
+  const instructions = program.idl.instructions;
+
+  it("Lists the same instructions as the golden file", () => {
+    assert.deepEqual(
+      instructions.map((ix) => ix.name).sort(),
+      golden.map((ix) => ix.name).sort()
+    );
+  });
+
+  it("Keeps every discriminator", () => {
+    for (const expected of golden) {
+      const ix = instructions.find((candidate) => candidate.name === expected.name);
+      assert.deepEqual(Array.from(ix.discriminator), expected.discriminator, expected.name);
+      assert.deepEqual(expected.discriminator, globalDiscriminator(expected.name), expected.name);
+    }
+  });
+
+  it("Keeps every account order", () => {
+    for (const expected of golden) {
+      const ix = instructions.find((candidate) => candidate.name === expected.name);
+      assert.deepEqual(ix.accounts.map((account) => account.name), expected.accounts, expected.name);
+    }
+  });
+
+  it("Wires the VRF callbacks to their resolve instructions", () => {
+    for (const [request, callback] of [
+      ["request_draw", "resolve_draw"],
+      ["retry_draw", "resolve_draw"],
+      ["request_mega_draw", "resolve_mega_draw"],
+    ]) {
+      const ix = instructions.find((candidate) => candidate.name === callback);
+      assert.deepEqual(Array.from(ix.discriminator), globalDiscriminator(callback), request);
+    }
+  });
+
+  it("Matches the discriminators hardcoded in the frontend", () => {
+    const source = readFileSync("../frontend/lib/hastrology_program.ts", "utf8");
+    const hardcoded = [...source.matchAll(/\/\/ (\w+): \[([\d, ]+)\]/g)];
+
+    assert.isNotEmpty(hardcoded);
+
+    for (const [, name, bytes] of hardcoded) {
+      const expected = golden.find((ix) => ix.name === name);
+      assert.exists(expected, name);
+      assert.deepEqual(bytes.split(",").map((byte) => Number(byte.trim())), expected.discriminator, name);
+    }
+  });
+});